@@ -1,4 +1,4 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId, Throughput};
 use ophanion::*;
 use ophanion::spectral::SpectralEngine;
 use ophanion::resonance::ResonanceEngine;
@@ -174,6 +174,73 @@ fn bench_full_pipeline(c: &mut Criterion) {
     });
 }
 
+/// Throughput of `SpectralEngine::create_signatures_batch` and
+/// `ResonanceEngine::compute_scores_batch` over realistic relay-sized
+/// batches (100/1000/10000 circuits), at a range of rayon thread-pool
+/// sizes, so scaling is measured in circuits-per-second rather than
+/// only per-call latency.
+fn bench_batch_throughput(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_throughput");
+
+    for &num_circuits in [100usize, 1000, 10000].iter() {
+        let circuits: Vec<TorCircuitMetadata> = (0..num_circuits)
+            .map(|i| create_test_circuit(i as u32, 50))
+            .collect();
+
+        for &num_threads in [1usize, 2, 4, 8].iter() {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .expect("failed to build rayon thread pool");
+
+            group.throughput(Throughput::Elements(num_circuits as u64));
+            group.bench_with_input(
+                BenchmarkId::new(
+                    format!("create_signatures_batch/threads={num_threads}"),
+                    num_circuits,
+                ),
+                &circuits,
+                |b, circuits| {
+                    let engine = SpectralEngine::new();
+
+                    pool.install(|| {
+                        b.iter(|| black_box(engine.create_signatures_batch(circuits)));
+                    });
+                },
+            );
+
+            let config = OphanionSettings {
+                num_gabriel_cells: 64,
+                spectral_dim: 128,
+                ..Default::default()
+            };
+            let resonance = ResonanceEngine::new(config.clone());
+            let signature = Array1::from_vec(vec![0.5; config.spectral_dim]);
+            for _ in 0..10 {
+                resonance.learn_signature(&signature);
+            }
+            let signatures: Vec<Array1<f64>> =
+                (0..num_circuits).map(|_| signature.clone()).collect();
+
+            group.throughput(Throughput::Elements(num_circuits as u64));
+            group.bench_with_input(
+                BenchmarkId::new(
+                    format!("compute_scores_batch/threads={num_threads}"),
+                    num_circuits,
+                ),
+                &signatures,
+                |b, signatures| {
+                    pool.install(|| {
+                        b.iter(|| black_box(resonance.compute_scores_batch(signatures)));
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_spectral_fingerprint,
@@ -181,7 +248,8 @@ criterion_group!(
     bench_resonance_scoring,
     bench_knn_scoring,
     bench_learning,
-    bench_full_pipeline
+    bench_full_pipeline,
+    bench_batch_throughput
 );
 
 criterion_main!(benches);
@@ -0,0 +1,10 @@
+//! cargo-fuzz target for `OphanionConfig`'s TOML parser and validator.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ophanion::fuzz::fuzz_parse_config;
+
+fuzz_target!(|data: &[u8]| {
+    fuzz_parse_config(data);
+});
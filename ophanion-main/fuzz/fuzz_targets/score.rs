@@ -0,0 +1,18 @@
+//! cargo-fuzz target for `ResonanceEngine::compute_score`/`learn_signature`.
+//!
+//! The first byte picks the signature dimension; the rest is the
+//! little-endian `f64` byte stream `fuzz_score` builds the signature
+//! from.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ophanion::fuzz::fuzz_score;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&dim_byte, rest)) = data.split_first() else {
+        return;
+    };
+
+    fuzz_score(dim_byte as usize, rest);
+});
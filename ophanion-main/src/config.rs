@@ -1,6 +1,6 @@
+use crate::error::ConfigError;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use anyhow::{Context, Result};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OphanionConfig {
@@ -48,6 +48,114 @@ pub struct OphanionSettings {
     
     #[serde(default = "default_convergence_epsilon")]
     pub convergence_epsilon: f64,
+
+    /// Window length (samples) the FFT-based spectral feature extractor
+    /// resamples/pads the cell-timing series to before windowing.
+    #[serde(default = "default_fft_len")]
+    pub fft_len: usize,
+
+    /// Number of low-frequency FFT magnitude bins kept in the spectral
+    /// signature (the rest of the spectrum is discarded as noise).
+    #[serde(default = "default_fft_num_bins")]
+    pub fft_num_bins: usize,
+
+    /// Number of most-recent per-introduction-point scores
+    /// `DecisionEngine::decide_windowed` aggregates over.
+    #[serde(default = "default_window_len")]
+    pub window_len: usize,
+
+    /// Number of consecutive windowed-aggregate threshold breaches
+    /// required before `DecisionEngine::decide_windowed` emits `Absorb`.
+    #[serde(default = "default_detection_step")]
+    pub detection_step: usize,
+
+    /// Whether `GabrielCluster::find_k_nearest` uses the VP-tree spatial
+    /// index instead of always falling back to an exact linear scan.
+    #[serde(default = "default_use_spatial_index")]
+    pub use_spatial_index: bool,
+
+    /// Minimum cluster size below which `find_k_nearest` always uses
+    /// the exact linear scan, since the VP-tree build overhead isn't
+    /// worth it for small clusters.
+    #[serde(default = "default_spatial_index_min_cells")]
+    pub spatial_index_min_cells: usize,
+
+    /// Total centroid drift (summed Euclidean movement since the last
+    /// build) at which `find_k_nearest` rebuilds the VP-tree index.
+    #[serde(default = "default_spatial_index_drift_threshold")]
+    pub spatial_index_drift_threshold: f64,
+
+    /// Proportional gain of `AdaptiveThreshold::tune`'s PID controller
+    /// on the absorption-rate error.
+    #[serde(default = "default_pid_kp")]
+    pub pid_kp: f64,
+
+    /// Integral gain of `AdaptiveThreshold::tune`'s PID controller.
+    #[serde(default = "default_pid_ki")]
+    pub pid_ki: f64,
+
+    /// Derivative gain of `AdaptiveThreshold::tune`'s PID controller.
+    #[serde(default = "default_pid_kd")]
+    pub pid_kd: f64,
+
+    /// Anti-windup clamp (±) on the PID controller's accumulated
+    /// integral term.
+    #[serde(default = "default_pid_integral_limit")]
+    pub pid_integral_limit: f64,
+
+    /// Concentration parameter α of the Dirichlet-process stick-breaking
+    /// prior `GabrielCluster::observe` uses to grow the cell population:
+    /// each new stick-breaking weight is drawn as `βₖ ~ Beta(1, α)`.
+    /// Larger α favors spawning more, smaller cells.
+    #[serde(default = "default_dp_concentration_alpha")]
+    pub dp_concentration_alpha: f64,
+
+    /// Base radius factor for the novelty test in `GabrielCluster::observe`:
+    /// a signature spawns a new cell only if its distance to the nearest
+    /// existing cell exceeds `dp_base_radius * sqrt(cell.covariance)`.
+    #[serde(default = "default_dp_base_radius")]
+    pub dp_base_radius: f64,
+
+    /// Minimum remaining unallocated stick mass `∏(1−βⱼ)` below which
+    /// `GabrielCluster::observe` stops spawning new cells even for novel
+    /// signatures, and instead assigns them to the nearest existing cell.
+    #[serde(default = "default_dp_min_stick_mass")]
+    pub dp_min_stick_mass: f64,
+
+    /// Whether `SpectralEngine::create_signature` extracts its
+    /// frequency-domain block via Welch's averaged, overlapping-segment
+    /// periodogram instead of a single windowed FFT over the whole
+    /// inter-cell-gap series. Welch's method trades frequency resolution
+    /// for much lower variance, giving more stable signatures for short
+    /// or noisy circuits.
+    #[serde(default = "default_use_welch_psd")]
+    pub use_welch_psd: bool,
+
+    /// Hop size (cells) `SpectralEngine::compute_spectrogram` and
+    /// `create_signature_stream` advance the sliding analysis frame by
+    /// between successive spectrogram slices.
+    #[serde(default = "default_stft_hop_len")]
+    pub stft_hop_len: usize,
+
+    /// Blending weight `DecisionEngine::decide_blended` gives the
+    /// supervised `GbdtClassifier::predict_proba` output against the
+    /// unsupervised resonance score, i.e.
+    /// `blended = w*predicted_probability + (1-w)*resonance_score`.
+    #[serde(default = "default_gbdt_blend_weight")]
+    pub gbdt_blend_weight: f64,
+
+    /// Gain with which `DecisionEngine::decide_adaptive` raises its
+    /// effective threshold as the observed Kuramoto order parameter `r`
+    /// approaches 1 (tightly synchronized, i.e. likely coordinated
+    /// traffic): `adjusted_threshold = base_threshold + gain * r`.
+    #[serde(default = "default_kuramoto_sync_gain")]
+    pub kuramoto_sync_gain: f64,
+
+    /// Resonance-strength floor below which `GabrielCluster::prune_dead_cells`
+    /// seeds a cell as dead, before propagating liveness across the
+    /// connection graph.
+    #[serde(default = "default_prune_threshold")]
+    pub prune_threshold: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,6 +215,25 @@ fn default_threshold_learning_rate() -> f64 { 0.001 }
 fn default_optimization_eta() -> f64 { 0.0001 }
 fn default_target_absorption() -> f64 { 0.95 }
 fn default_convergence_epsilon() -> f64 { 0.001 }
+fn default_fft_len() -> usize { 64 }
+fn default_fft_num_bins() -> usize { 16 }
+fn default_window_len() -> usize { 10 }
+fn default_detection_step() -> usize { 3 }
+fn default_use_spatial_index() -> bool { true }
+fn default_spatial_index_min_cells() -> usize { 64 }
+fn default_spatial_index_drift_threshold() -> f64 { 0.05 }
+fn default_pid_kp() -> f64 { 0.6 }
+fn default_pid_ki() -> f64 { 0.05 }
+fn default_pid_kd() -> f64 { 0.1 }
+fn default_pid_integral_limit() -> f64 { 5.0 }
+fn default_dp_concentration_alpha() -> f64 { 1.0 }
+fn default_dp_base_radius() -> f64 { 2.0 }
+fn default_dp_min_stick_mass() -> f64 { 0.001 }
+fn default_use_welch_psd() -> bool { false }
+fn default_stft_hop_len() -> usize { 16 }
+fn default_gbdt_blend_weight() -> f64 { 0.5 }
+fn default_kuramoto_sync_gain() -> f64 { 0.3 }
+fn default_prune_threshold() -> f64 { 0.05 }
 fn default_control_port() -> u16 { 9051 }
 fn default_listen_port() -> u16 { 8080 }
 fn default_backend_port() -> u16 { 8081 }
@@ -140,6 +267,25 @@ impl Default for OphanionSettings {
             optimization_eta: default_optimization_eta(),
             target_absorption_rate: default_target_absorption(),
             convergence_epsilon: default_convergence_epsilon(),
+            fft_len: default_fft_len(),
+            fft_num_bins: default_fft_num_bins(),
+            window_len: default_window_len(),
+            detection_step: default_detection_step(),
+            use_spatial_index: default_use_spatial_index(),
+            spatial_index_min_cells: default_spatial_index_min_cells(),
+            spatial_index_drift_threshold: default_spatial_index_drift_threshold(),
+            pid_kp: default_pid_kp(),
+            pid_ki: default_pid_ki(),
+            pid_kd: default_pid_kd(),
+            pid_integral_limit: default_pid_integral_limit(),
+            dp_concentration_alpha: default_dp_concentration_alpha(),
+            dp_base_radius: default_dp_base_radius(),
+            dp_min_stick_mass: default_dp_min_stick_mass(),
+            use_welch_psd: default_use_welch_psd(),
+            stft_hop_len: default_stft_hop_len(),
+            gbdt_blend_weight: default_gbdt_blend_weight(),
+            kuramoto_sync_gain: default_kuramoto_sync_gain(),
+            prune_threshold: default_prune_threshold(),
         }
     }
 }
@@ -185,44 +331,374 @@ impl Default for PerformanceSettings {
     }
 }
 
+/// One entry of [`OphanionConfig::schema`]: describes a single config
+/// field without requiring a reader to go find it in source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSchema {
+    /// Which top-level sub-struct (`[ophanion]`, `[tor]`, ...) the field
+    /// lives under.
+    pub section: &'static str,
+    pub name: &'static str,
+    pub ty: &'static str,
+    /// The field's default value, rendered via its `Display`/`Debug`
+    /// impl. Always sourced from the same `default_*` function (or
+    /// literal in `impl Default`) the field actually uses, so the
+    /// schema can never drift from the real defaults.
+    pub default: String,
+    /// Valid range or constraint enforced by [`OphanionConfig::validate`],
+    /// if any.
+    pub range: Option<&'static str>,
+}
+
+impl FieldSchema {
+    fn new(section: &'static str, name: &'static str, ty: &'static str, default: impl ToString, range: Option<&'static str>) -> Self {
+        Self {
+            section,
+            name,
+            ty,
+            default: default.to_string(),
+            range,
+        }
+    }
+}
+
 impl OphanionConfig {
-    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let content = std::fs::read_to_string(path.as_ref())
-            .context("Failed to read config file")?;
-        
-        let config: OphanionConfig = toml::from_str(&content)
-            .context("Failed to parse TOML config")?;
-        
+    /// Machine-readable description of every setting in the config --
+    /// field name, type, default value and valid range, grouped by
+    /// section -- so operators can discover and audit the many tuning
+    /// knobs (`optimization_eta`, `target_absorption_rate`, ...) without
+    /// reading source. Defaults are read live from the same `default_*`
+    /// functions [`Default`] itself uses, so this can never drift from
+    /// the actual defaults.
+    pub fn schema() -> Vec<FieldSchema> {
+        vec![
+            FieldSchema::new("ophanion", "num_gabriel_cells", "usize", default_gabriel_cells(), Some("> 0")),
+            FieldSchema::new("ophanion", "spectral_dim", "usize", default_spectral_dim(), Some("> 0")),
+            FieldSchema::new("ophanion", "learning_rate_alpha", "f64", default_learning_rate(), None),
+            FieldSchema::new("ophanion", "decay_rate_beta", "f64", default_decay_rate(), None),
+            FieldSchema::new("ophanion", "initial_threshold", "f64", default_initial_threshold(), Some("[0, 1]")),
+            FieldSchema::new("ophanion", "threshold_learning_rate", "f64", default_threshold_learning_rate(), None),
+            FieldSchema::new("ophanion", "optimization_eta", "f64", default_optimization_eta(), None),
+            FieldSchema::new("ophanion", "target_absorption_rate", "f64", default_target_absorption(), Some("[0, 1]")),
+            FieldSchema::new("ophanion", "convergence_epsilon", "f64", default_convergence_epsilon(), None),
+            FieldSchema::new("ophanion", "fft_len", "usize", default_fft_len(), Some("> 0")),
+            FieldSchema::new("ophanion", "fft_num_bins", "usize", default_fft_num_bins(), Some("> 0 and <= fft_len")),
+            FieldSchema::new("ophanion", "window_len", "usize", default_window_len(), Some("> 0")),
+            FieldSchema::new("ophanion", "detection_step", "usize", default_detection_step(), Some("> 0 and <= window_len")),
+            FieldSchema::new("ophanion", "use_spatial_index", "bool", default_use_spatial_index(), None),
+            FieldSchema::new("ophanion", "spatial_index_min_cells", "usize", default_spatial_index_min_cells(), None),
+            FieldSchema::new("ophanion", "spatial_index_drift_threshold", "f64", default_spatial_index_drift_threshold(), Some(">= 0")),
+            FieldSchema::new("ophanion", "pid_kp", "f64", default_pid_kp(), None),
+            FieldSchema::new("ophanion", "pid_ki", "f64", default_pid_ki(), None),
+            FieldSchema::new("ophanion", "pid_kd", "f64", default_pid_kd(), None),
+            FieldSchema::new("ophanion", "pid_integral_limit", "f64", default_pid_integral_limit(), None),
+            FieldSchema::new("ophanion", "dp_concentration_alpha", "f64", default_dp_concentration_alpha(), Some("> 0")),
+            FieldSchema::new("ophanion", "dp_base_radius", "f64", default_dp_base_radius(), Some("> 0")),
+            FieldSchema::new("ophanion", "dp_min_stick_mass", "f64", default_dp_min_stick_mass(), Some("[0, 1)")),
+            FieldSchema::new("ophanion", "use_welch_psd", "bool", default_use_welch_psd(), None),
+            FieldSchema::new("ophanion", "stft_hop_len", "usize", default_stft_hop_len(), Some("> 0")),
+            FieldSchema::new("ophanion", "gbdt_blend_weight", "f64", default_gbdt_blend_weight(), Some("[0, 1]")),
+            FieldSchema::new("ophanion", "kuramoto_sync_gain", "f64", default_kuramoto_sync_gain(), Some(">= 0")),
+            FieldSchema::new("ophanion", "prune_threshold", "f64", default_prune_threshold(), Some("[0, 1]")),
+            FieldSchema::new("tor", "control_port", "u16", default_control_port(), None),
+            FieldSchema::new("tor", "cookie_path", "Option<String>", "Some(\"/var/run/tor/control.authcookie\")", None),
+            FieldSchema::new("tor", "control_password", "Option<String>", "None", None),
+            FieldSchema::new("service", "listen_port", "u16", default_listen_port(), None),
+            FieldSchema::new("service", "backend_port", "u16", default_backend_port(), None),
+            FieldSchema::new("service", "bind_address", "String", default_bind_address(), None),
+            FieldSchema::new("monitoring", "enable_metrics", "bool", default_true(), None),
+            FieldSchema::new("monitoring", "metrics_port", "u16", default_metrics_port(), None),
+            FieldSchema::new("monitoring", "verbose_logging", "bool", false, None),
+            FieldSchema::new("monitoring", "log_file", "Option<String>", "Some(\"/var/log/ophanion/ophanion.log\")", None),
+            FieldSchema::new("performance", "worker_threads", "usize", 0, None),
+            FieldSchema::new("performance", "max_tracked_circuits", "usize", default_max_circuits(), None),
+            FieldSchema::new("performance", "metadata_retention", "u64", default_retention(), None),
+        ]
+    }
+
+    /// Serializes this fully-resolved config (after all defaults have
+    /// been filled in, e.g. by [`Self::from_file`]) back to TOML, so an
+    /// operator can see exactly what values are in force rather than
+    /// guessing how a partial file was filled in.
+    pub fn effective_config(&self) -> String {
+        toml::to_string_pretty(self).expect("OphanionConfig always serializes to TOML")
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path.as_ref())?;
+
+        let config: OphanionConfig = toml::from_str(&content).map_err(|source| ConfigError::Parse {
+            source: Box::new(source),
+        })?;
+
         config.validate()?;
-        
+
         Ok(config)
     }
-    
-    pub fn validate(&self) -> Result<()> {
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
         if self.ophanion.num_gabriel_cells == 0 {
-            anyhow::bail!("num_gabriel_cells must be > 0");
+            return Err(ConfigError::out_of_range("num_gabriel_cells", 0, "> 0"));
         }
-        
+
         if self.ophanion.spectral_dim == 0 {
-            anyhow::bail!("spectral_dim must be > 0");
+            return Err(ConfigError::out_of_range("spectral_dim", 0, "> 0"));
         }
-        
+
         if !(0.0..=1.0).contains(&self.ophanion.initial_threshold) {
-            anyhow::bail!("initial_threshold must be in range [0, 1]");
+            return Err(ConfigError::out_of_range(
+                "initial_threshold",
+                self.ophanion.initial_threshold,
+                "[0, 1]",
+            ));
         }
-        
+
         if !(0.0..=1.0).contains(&self.ophanion.target_absorption_rate) {
-            anyhow::bail!("target_absorption_rate must be in range [0, 1]");
+            return Err(ConfigError::out_of_range(
+                "target_absorption_rate",
+                self.ophanion.target_absorption_rate,
+                "[0, 1]",
+            ));
+        }
+
+        if self.ophanion.fft_len == 0 {
+            return Err(ConfigError::out_of_range("fft_len", 0, "> 0"));
+        }
+
+        if self.ophanion.fft_num_bins == 0 || self.ophanion.fft_num_bins > self.ophanion.fft_len {
+            return Err(ConfigError::validation(
+                "fft_num_bins",
+                "must be > 0 and <= fft_len",
+            ));
+        }
+
+        if self.ophanion.window_len == 0 {
+            return Err(ConfigError::out_of_range("window_len", 0, "> 0"));
+        }
+
+        if self.ophanion.detection_step == 0 || self.ophanion.detection_step > self.ophanion.window_len
+        {
+            return Err(ConfigError::validation(
+                "detection_step",
+                "must be > 0 and <= window_len",
+            ));
+        }
+
+        if self.ophanion.spatial_index_drift_threshold < 0.0 {
+            return Err(ConfigError::out_of_range(
+                "spatial_index_drift_threshold",
+                self.ophanion.spatial_index_drift_threshold,
+                ">= 0",
+            ));
+        }
+
+        if self.ophanion.dp_concentration_alpha <= 0.0 {
+            return Err(ConfigError::out_of_range(
+                "dp_concentration_alpha",
+                self.ophanion.dp_concentration_alpha,
+                "> 0",
+            ));
+        }
+
+        if self.ophanion.dp_base_radius <= 0.0 {
+            return Err(ConfigError::out_of_range(
+                "dp_base_radius",
+                self.ophanion.dp_base_radius,
+                "> 0",
+            ));
         }
-        
+
+        if !(0.0..1.0).contains(&self.ophanion.dp_min_stick_mass) {
+            return Err(ConfigError::out_of_range(
+                "dp_min_stick_mass",
+                self.ophanion.dp_min_stick_mass,
+                "[0, 1)",
+            ));
+        }
+
+        if self.ophanion.stft_hop_len == 0 {
+            return Err(ConfigError::out_of_range("stft_hop_len", 0, "> 0"));
+        }
+
+        if !(0.0..=1.0).contains(&self.ophanion.gbdt_blend_weight) {
+            return Err(ConfigError::out_of_range(
+                "gbdt_blend_weight",
+                self.ophanion.gbdt_blend_weight,
+                "[0, 1]",
+            ));
+        }
+
+        if self.ophanion.kuramoto_sync_gain < 0.0 {
+            return Err(ConfigError::out_of_range(
+                "kuramoto_sync_gain",
+                self.ophanion.kuramoto_sync_gain,
+                ">= 0",
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&self.ophanion.prune_threshold) {
+            return Err(ConfigError::out_of_range(
+                "prune_threshold",
+                self.ophanion.prune_threshold,
+                "[0, 1]",
+            ));
+        }
+
         Ok(())
     }
 }
 
+impl OphanionConfig {
+    /// Starts a layered configuration build: defaults, then zero or more
+    /// files in precedence order (base, site, local, ...), then
+    /// environment-variable overrides, validated once at the end.
+    ///
+    /// Essential for containerized Tor deployments, where base images
+    /// ship defaults that get overridden per host via mounted files, and
+    /// secrets like `tor.control_password` come from the environment
+    /// rather than a checked-in TOML file.
+    pub fn builder() -> OphanionConfigBuilder {
+        OphanionConfigBuilder::new()
+    }
+}
+
+/// Layered config builder: `OphanionConfig::builder().with_defaults().merge_file(path).merge_env("OPHANION_").build()`.
+///
+/// Each layer deep-merges a [`toml::Value`] table on top of the previous
+/// one (later layers win field-by-field, not whole-section), and
+/// [`Self::build`] only validates the fully-merged result.
+pub struct OphanionConfigBuilder {
+    value: toml::Value,
+}
+
+impl OphanionConfigBuilder {
+    fn new() -> Self {
+        Self {
+            value: toml::Value::Table(Default::default()),
+        }
+    }
+
+    /// Merges in `OphanionConfig::default()` as the base layer.
+    pub fn with_defaults(mut self) -> Self {
+        let defaults = toml::Value::try_from(OphanionConfig::default())
+            .expect("OphanionConfig::default() always serializes to TOML");
+        deep_merge(&mut self.value, defaults);
+        self
+    }
+
+    /// Deep-merges the TOML file at `path` on top of the current layers.
+    /// Call this once per file, in precedence order (e.g. base, then
+    /// site, then local) -- each call's contents win over everything
+    /// merged so far.
+    pub fn merge_file<P: AsRef<Path>>(mut self, path: P) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path.as_ref())?;
+        let overlay: toml::Value =
+            toml::from_str(&content).map_err(|source| ConfigError::Parse {
+                source: Box::new(source),
+            })?;
+
+        deep_merge(&mut self.value, overlay);
+        Ok(self)
+    }
+
+    /// Merges environment variables whose name starts with `prefix` on
+    /// top of the current layers. A dotted field path `ophanion.tor.control_port`
+    /// is addressed by `__`-joined segments after the prefix, e.g.
+    /// `OPHANION_TOR__CONTROL_PORT` (with `prefix = "OPHANION_"`) sets
+    /// `tor.control_port`. Segment matching is case-insensitive; scalars
+    /// are parsed as bool, then integer, then float, falling back to a
+    /// plain string.
+    pub fn merge_env(mut self, prefix: &str) -> Self {
+        for (key, raw_value) in std::env::vars() {
+            let Some(rest) = key.strip_prefix(prefix) else {
+                continue;
+            };
+
+            let path: Vec<String> = rest
+                .split("__")
+                .map(|segment| segment.to_lowercase())
+                .collect();
+
+            if path.is_empty() || path.iter().any(|segment| segment.is_empty()) {
+                continue;
+            }
+
+            set_nested(&mut self.value, &path, parse_env_scalar(&raw_value));
+        }
+        self
+    }
+
+    /// Deserializes the merged layers into an [`OphanionConfig`] and
+    /// validates it.
+    pub fn build(self) -> Result<OphanionConfig, ConfigError> {
+        let config =
+            OphanionConfig::deserialize(self.value).map_err(|source| ConfigError::Parse {
+                source: Box::new(source),
+            })?;
+
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Recursively merges `overlay` into `base`: matching tables merge
+/// key-by-key, with `overlay` winning on conflicts; anything else
+/// (scalars, arrays) is replaced wholesale by `overlay`.
+fn deep_merge(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base_table), toml::Value::Table(overlay_table)) => {
+            for (key, value) in overlay_table {
+                match base_table.get_mut(&key) {
+                    Some(existing) => deep_merge(existing, value),
+                    None => {
+                        base_table.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base_slot, overlay_value) => *base_slot = overlay_value,
+    }
+}
+
+/// Sets `scalar` at the nested table path `path` within `value`,
+/// creating intermediate tables as needed.
+fn set_nested(value: &mut toml::Value, path: &[String], scalar: toml::Value) {
+    if !value.is_table() {
+        *value = toml::Value::Table(Default::default());
+    }
+    let table = value.as_table_mut().expect("just ensured this is a table");
+
+    if path.len() == 1 {
+        table.insert(path[0].clone(), scalar);
+        return;
+    }
+
+    let entry = table
+        .entry(path[0].clone())
+        .or_insert_with(|| toml::Value::Table(Default::default()));
+    set_nested(entry, &path[1..], scalar);
+}
+
+/// Parses a raw environment-variable string into the most specific TOML
+/// scalar type it matches: bool, then integer, then float, falling back
+/// to a plain string.
+fn parse_env_scalar(raw: &str) -> toml::Value {
+    if let Ok(value) = raw.parse::<bool>() {
+        return toml::Value::Boolean(value);
+    }
+    if let Ok(value) = raw.parse::<i64>() {
+        return toml::Value::Integer(value);
+    }
+    if let Ok(value) = raw.parse::<f64>() {
+        return toml::Value::Float(value);
+    }
+    toml::Value::String(raw.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    
+
     #[test]
     fn test_default_config() {
         let config = OphanionConfig::default();
@@ -233,7 +709,214 @@ mod tests {
     fn test_config_validation() {
         let mut config = OphanionConfig::default();
         config.ophanion.num_gabriel_cells = 0;
-        
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_fft_num_bins_cannot_exceed_fft_len() {
+        let mut config = OphanionConfig::default();
+        config.ophanion.fft_len = 16;
+        config.ophanion.fft_num_bins = 32;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_detection_step_cannot_exceed_window_len() {
+        let mut config = OphanionConfig::default();
+        config.ophanion.window_len = 5;
+        config.ophanion.detection_step = 6;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_negative_spatial_index_drift_threshold_rejected() {
+        let mut config = OphanionConfig::default();
+        config.ophanion.spatial_index_drift_threshold = -0.1;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_nonpositive_dp_concentration_alpha_rejected() {
+        let mut config = OphanionConfig::default();
+        config.ophanion.dp_concentration_alpha = 0.0;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_dp_min_stick_mass_out_of_range_rejected() {
+        let mut config = OphanionConfig::default();
+        config.ophanion.dp_min_stick_mass = 1.0;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_zero_stft_hop_len_rejected() {
+        let mut config = OphanionConfig::default();
+        config.ophanion.stft_hop_len = 0;
+
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_gbdt_blend_weight_out_of_range_rejected() {
+        let mut config = OphanionConfig::default();
+        config.ophanion.gbdt_blend_weight = 1.5;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_negative_kuramoto_sync_gain_rejected() {
+        let mut config = OphanionConfig::default();
+        config.ophanion.kuramoto_sync_gain = -0.1;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_prune_threshold_out_of_range_rejected() {
+        let mut config = OphanionConfig::default();
+        config.ophanion.prune_threshold = 1.5;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_schema_covers_every_ophanion_field_and_matches_defaults() {
+        let schema = OphanionConfig::schema();
+        let defaults = OphanionSettings::default();
+
+        let ophanion_fields: Vec<&FieldSchema> = schema
+            .iter()
+            .filter(|field| field.section == "ophanion")
+            .collect();
+
+        assert_eq!(ophanion_fields.len(), 28);
+
+        let num_gabriel_cells = ophanion_fields
+            .iter()
+            .find(|field| field.name == "num_gabriel_cells")
+            .expect("schema should describe num_gabriel_cells");
+        assert_eq!(num_gabriel_cells.default, defaults.num_gabriel_cells.to_string());
+        assert_eq!(num_gabriel_cells.range, Some("> 0"));
+    }
+
+    #[test]
+    fn test_effective_config_round_trips_through_toml() {
+        let config = OphanionConfig::default();
+        let dumped = config.effective_config();
+
+        let reparsed: OphanionConfig = toml::from_str(&dumped).expect("effective_config output should parse");
+
+        assert_eq!(reparsed.ophanion.num_gabriel_cells, config.ophanion.num_gabriel_cells);
+        assert_eq!(reparsed.ophanion.spectral_dim, config.ophanion.spectral_dim);
+    }
+
+    #[test]
+    fn test_builder_with_defaults_only_matches_default() {
+        let config = OphanionConfig::builder()
+            .with_defaults()
+            .build()
+            .expect("defaults alone should validate");
+
+        assert_eq!(
+            config.ophanion.num_gabriel_cells,
+            OphanionConfig::default().ophanion.num_gabriel_cells
+        );
+    }
+
+    #[test]
+    fn test_builder_merges_files_in_precedence_order() {
+        let base_path = std::env::temp_dir().join("ophanion_builder_test_base.toml");
+        let local_path = std::env::temp_dir().join("ophanion_builder_test_local.toml");
+
+        std::fs::write(&base_path, "[ophanion]\nnum_gabriel_cells = 64\nspectral_dim = 32\n")
+            .expect("failed to write base layer");
+        std::fs::write(&local_path, "[ophanion]\nnum_gabriel_cells = 128\n")
+            .expect("failed to write local layer");
+
+        let config = OphanionConfig::builder()
+            .with_defaults()
+            .merge_file(&base_path)
+            .expect("base layer should parse")
+            .merge_file(&local_path)
+            .expect("local layer should parse")
+            .build()
+            .expect("merged layers should validate");
+
+        // local overrides base...
+        assert_eq!(config.ophanion.num_gabriel_cells, 128);
+        // ...but fields the local layer doesn't touch still come from base.
+        assert_eq!(config.ophanion.spectral_dim, 32);
+
+        std::fs::remove_file(&base_path).ok();
+        std::fs::remove_file(&local_path).ok();
+    }
+
+    #[test]
+    fn test_builder_merge_env_overrides_nested_field() {
+        let key = "OPHANION_TEST_ENV_OPHANION__NUM_GABRIEL_CELLS";
+        std::env::set_var(key, "256");
+
+        let config = OphanionConfig::builder()
+            .with_defaults()
+            .merge_env("OPHANION_TEST_ENV_")
+            .build()
+            .expect("env override should validate");
+
+        assert_eq!(config.ophanion.num_gabriel_cells, 256);
+
+        std::env::remove_var(key);
+    }
+
+    #[test]
+    fn test_builder_merge_env_overrides_win_over_files() {
+        let base_path = std::env::temp_dir().join("ophanion_builder_test_env_over_file.toml");
+        std::fs::write(&base_path, "[tor]\ncontrol_port = 9051\n")
+            .expect("failed to write base layer");
+
+        let key = "OPHANION_TEST_ENV2_TOR__CONTROL_PORT";
+        std::env::set_var(key, "9151");
+
+        let config = OphanionConfig::builder()
+            .with_defaults()
+            .merge_file(&base_path)
+            .expect("base layer should parse")
+            .merge_env("OPHANION_TEST_ENV2_")
+            .build()
+            .expect("env override should validate");
+
+        assert_eq!(config.tor.control_port, 9151);
+
+        std::env::remove_var(key);
+        std::fs::remove_file(&base_path).ok();
+    }
+
+    #[test]
+    fn test_builder_propagates_validation_errors() {
+        let result = OphanionConfig::builder()
+            .with_defaults()
+            .merge_env("OPHANION_TEST_ENV_INVALID_")
+            .build();
+
+        assert!(result.is_ok());
+
+        let key = "OPHANION_TEST_ENV_INVALID_OPHANION__GBDT_BLEND_WEIGHT";
+        std::env::set_var(key, "5.0");
+
+        let result = OphanionConfig::builder()
+            .with_defaults()
+            .merge_env("OPHANION_TEST_ENV_INVALID_")
+            .build();
+
+        assert!(matches!(result, Err(ConfigError::OutOfRange { field: "gbdt_blend_weight", .. })));
+
+        std::env::remove_var(key);
+    }
 }
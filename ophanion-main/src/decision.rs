@@ -1,20 +1,58 @@
+use crate::config::OphanionSettings;
+use crate::scorer::Scorer;
 use crate::CircuitAction;
+use std::collections::{HashMap, VecDeque};
 
 pub struct DecisionEngine {
     decisions_made: u64,
     circuits_forwarded: u64,
     circuits_absorbed: u64,
+    /// Number of most-recent per-introduction-point scores
+    /// [`Self::decide_windowed`] aggregates over.
+    window_len: usize,
+    /// Number of consecutive windowed-aggregate threshold breaches
+    /// required before [`Self::decide_windowed`] emits `Absorb`.
+    detection_step: usize,
+    /// Sliding window of recent scores, keyed by introduction point.
+    windows: HashMap<String, VecDeque<f64>>,
+    /// Consecutive threshold-breach count, keyed by introduction point.
+    consecutive_breaches: HashMap<String, usize>,
+    /// Weight [`Self::decide_blended`] gives a supervised predicted
+    /// probability (e.g. from `GbdtClassifier::predict_proba`) against
+    /// the unsupervised resonance score.
+    gbdt_blend_weight: f64,
+    /// Gain with which [`Self::decide_adaptive`] raises its effective
+    /// threshold as the observed synchronization order parameter
+    /// approaches 1.
+    kuramoto_sync_gain: f64,
+    /// History of thresholds [`Self::decide_adaptive`] has actually
+    /// applied, most recent last, capped at 1000 entries.
+    adaptive_threshold_history: VecDeque<f64>,
 }
 
 impl DecisionEngine {
     pub fn new() -> Self {
+        Self::with_settings(&OphanionSettings::default())
+    }
+
+    /// Builds a decision engine whose sliding-window size and
+    /// consecutive-breach requirement for [`Self::decide_windowed`] are
+    /// driven by `settings`.
+    pub fn with_settings(settings: &OphanionSettings) -> Self {
         Self {
             decisions_made: 0,
             circuits_forwarded: 0,
             circuits_absorbed: 0,
+            window_len: settings.window_len,
+            detection_step: settings.detection_step,
+            windows: HashMap::new(),
+            consecutive_breaches: HashMap::new(),
+            gbdt_blend_weight: settings.gbdt_blend_weight,
+            kuramoto_sync_gain: settings.kuramoto_sync_gain,
+            adaptive_threshold_history: VecDeque::new(),
         }
     }
-    
+
     /// Make decision: Forward or Absorb
     pub fn decide(&mut self, resonance_score: f64, threshold: f64) -> CircuitAction {
         self.decisions_made += 1;
@@ -28,6 +66,158 @@ impl DecisionEngine {
         }
     }
     
+    /// Make a decision from a feature vector, scored by a pluggable
+    /// [`Scorer`] (e.g. [`crate::scorer::GbdtScorer`]) instead of the
+    /// resonance engine's k-NN score.
+    pub fn decide_with_scorer(
+        &mut self,
+        scorer: &dyn Scorer,
+        features: &[f64],
+        threshold: f64,
+    ) -> CircuitAction {
+        let score = scorer.score(features);
+        self.decide(score, threshold)
+    }
+
+    /// Decision that blends the unsupervised resonance score with an
+    /// optional supervised predicted probability (e.g.
+    /// `GbdtClassifier::predict_proba`) via a weighted average,
+    /// `blended = w*predicted_probability + (1-w)*resonance_score`,
+    /// where `w` is `gbdt_blend_weight`, then decides against
+    /// `threshold` exactly as [`Self::decide`] would. Passing `None`
+    /// (no trained classifier available) falls back to the bare
+    /// resonance score.
+    pub fn decide_blended(
+        &mut self,
+        resonance_score: f64,
+        predicted_probability: Option<f64>,
+        threshold: f64,
+    ) -> CircuitAction {
+        let blended = match predicted_probability {
+            Some(probability) => {
+                self.gbdt_blend_weight * probability
+                    + (1.0 - self.gbdt_blend_weight) * resonance_score
+            }
+            None => resonance_score,
+        };
+
+        self.decide(blended, threshold)
+    }
+
+    /// Decision modulated by a Kuramoto-style synchronization order
+    /// parameter `r` over recent traffic (e.g. the oscillator network
+    /// obtained by mapping each circuit's resonance score onto an
+    /// oscillator's natural frequency and evolving it over the recent
+    /// window). Tightly synchronized populations (`r` near 1) tend to
+    /// indicate coordinated, correlated circuits rather than independent
+    /// legitimate traffic, so the effective threshold is raised
+    /// proportionally, making absorption more likely; an incoherent
+    /// population (`r` near 0) relaxes it back toward `base_threshold`:
+    ///
+    /// `adjusted_threshold = base_threshold + kuramoto_sync_gain * order_parameter`
+    ///
+    /// Every applied threshold is recorded so [`Self::adaptive_threshold_statistics`]
+    /// can report how it drifted over time.
+    pub fn decide_adaptive(
+        &mut self,
+        resonance_score: f64,
+        order_parameter: f64,
+        base_threshold: f64,
+    ) -> CircuitAction {
+        let adjusted_threshold = base_threshold + self.kuramoto_sync_gain * order_parameter;
+
+        self.adaptive_threshold_history.push_back(adjusted_threshold);
+        while self.adaptive_threshold_history.len() > 1000 {
+            self.adaptive_threshold_history.pop_front();
+        }
+
+        self.decide(resonance_score, adjusted_threshold)
+    }
+
+    /// Statistics on how [`Self::decide_adaptive`]'s effective threshold
+    /// has drifted over its recorded history.
+    pub fn adaptive_threshold_statistics(&self) -> AdaptiveThresholdStatistics {
+        let samples = self.adaptive_threshold_history.len();
+
+        if samples == 0 {
+            return AdaptiveThresholdStatistics {
+                samples: 0,
+                mean_threshold: 0.0,
+                min_threshold: 0.0,
+                max_threshold: 0.0,
+            };
+        }
+
+        let mean_threshold =
+            self.adaptive_threshold_history.iter().sum::<f64>() / samples as f64;
+        let min_threshold = self
+            .adaptive_threshold_history
+            .iter()
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+        let max_threshold = self
+            .adaptive_threshold_history
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        AdaptiveThresholdStatistics {
+            samples: samples as u64,
+            mean_threshold,
+            min_threshold,
+            max_threshold,
+        }
+    }
+
+    /// Windowed decision: aggregates scores across a sliding window of
+    /// recent circuits from the same `introduction_point`, instead of
+    /// deciding on each circuit in isolation.
+    ///
+    /// Only emits [`CircuitAction::Absorb`] once the windowed mean score
+    /// has been at or below `threshold` for `detection_step` consecutive
+    /// calls, so a single atypical circuit from an otherwise healthy
+    /// intro point doesn't trigger absorption, while a sustained flood
+    /// still trips within `detection_step` circuits.
+    pub fn decide_windowed(
+        &mut self,
+        introduction_point: &str,
+        score: f64,
+        threshold: f64,
+    ) -> CircuitAction {
+        let window = self
+            .windows
+            .entry(introduction_point.to_string())
+            .or_insert_with(VecDeque::new);
+
+        window.push_back(score);
+        while window.len() > self.window_len {
+            window.pop_front();
+        }
+
+        let windowed_mean = window.iter().sum::<f64>() / window.len() as f64;
+
+        let breaches = self
+            .consecutive_breaches
+            .entry(introduction_point.to_string())
+            .or_insert(0);
+
+        if windowed_mean <= threshold {
+            *breaches += 1;
+        } else {
+            *breaches = 0;
+        }
+
+        self.decisions_made += 1;
+
+        if *breaches >= self.detection_step {
+            self.circuits_absorbed += 1;
+            CircuitAction::Absorb
+        } else {
+            self.circuits_forwarded += 1;
+            CircuitAction::Forward
+        }
+    }
+
     /// Get current absorption rate
     pub fn absorption_rate(&self) -> f64 {
         if self.decisions_made == 0 {
@@ -52,6 +242,9 @@ impl DecisionEngine {
         self.decisions_made = 0;
         self.circuits_forwarded = 0;
         self.circuits_absorbed = 0;
+        self.windows.clear();
+        self.consecutive_breaches.clear();
+        self.adaptive_threshold_history.clear();
     }
 }
 
@@ -69,6 +262,16 @@ pub struct DecisionStatistics {
     pub absorption_rate: f64,
 }
 
+/// Drift of [`DecisionEngine::decide_adaptive`]'s effective threshold
+/// over its recorded history.
+#[derive(Debug, Clone)]
+pub struct AdaptiveThresholdStatistics {
+    pub samples: u64,
+    pub mean_threshold: f64,
+    pub min_threshold: f64,
+    pub max_threshold: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,4 +293,155 @@ mod tests {
         assert_eq!(stats.forwarded, 1);
         assert_eq!(stats.absorbed, 1);
     }
+
+    struct StubScorer(f64);
+
+    impl Scorer for StubScorer {
+        fn score(&self, _features: &[f64]) -> f64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_decide_with_scorer() {
+        let mut engine = DecisionEngine::new();
+        let scorer = StubScorer(0.8);
+
+        let action = engine.decide_with_scorer(&scorer, &[0.1, 0.2], 0.5);
+
+        assert_eq!(action, CircuitAction::Forward);
+    }
+
+    #[test]
+    fn test_decide_blended_falls_back_to_resonance_score_without_probability() {
+        let mut engine = DecisionEngine::new();
+
+        let action = engine.decide_blended(0.8, None, 0.5);
+
+        assert_eq!(action, CircuitAction::Forward);
+    }
+
+    #[test]
+    fn test_decide_blended_mixes_resonance_and_predicted_probability() {
+        let settings = OphanionSettings {
+            gbdt_blend_weight: 0.5,
+            ..Default::default()
+        };
+        let mut engine = DecisionEngine::with_settings(&settings);
+
+        // Resonance alone says absorb, predicted probability alone says
+        // forward; a 50/50 blend lands right on the threshold boundary.
+        let action = engine.decide_blended(0.2, Some(0.8), 0.5);
+
+        assert_eq!(action, CircuitAction::Absorb);
+    }
+
+    #[test]
+    fn test_decide_blended_weight_favors_classifier_when_high() {
+        let settings = OphanionSettings {
+            gbdt_blend_weight: 0.9,
+            ..Default::default()
+        };
+        let mut engine = DecisionEngine::with_settings(&settings);
+
+        // Resonance score alone would absorb, but a high blend weight
+        // lets a confident classifier probability override it.
+        let action = engine.decide_blended(0.1, Some(0.95), 0.5);
+
+        assert_eq!(action, CircuitAction::Forward);
+    }
+
+    #[test]
+    fn test_decide_adaptive_raises_threshold_under_high_synchronization() {
+        let settings = OphanionSettings {
+            kuramoto_sync_gain: 0.3,
+            ..Default::default()
+        };
+        let mut engine = DecisionEngine::with_settings(&settings);
+
+        // A score that would forward against a bare threshold of 0.5...
+        let relaxed = engine.decide_adaptive(0.6, 0.0, 0.5);
+        assert_eq!(relaxed, CircuitAction::Forward);
+
+        // ...but absorbs once the population is tightly synchronized
+        // (order parameter near 1), since the effective threshold rises
+        // to 0.5 + 0.3*1.0 = 0.8.
+        let mut engine = DecisionEngine::with_settings(&settings);
+        let synchronized = engine.decide_adaptive(0.6, 1.0, 0.5);
+        assert_eq!(synchronized, CircuitAction::Absorb);
+    }
+
+    #[test]
+    fn test_adaptive_threshold_statistics_track_history() {
+        let mut engine = DecisionEngine::with_settings(&OphanionSettings {
+            kuramoto_sync_gain: 0.2,
+            ..Default::default()
+        });
+
+        assert_eq!(engine.adaptive_threshold_statistics().samples, 0);
+
+        engine.decide_adaptive(0.5, 0.0, 0.5);
+        engine.decide_adaptive(0.5, 1.0, 0.5);
+
+        let stats = engine.adaptive_threshold_statistics();
+        assert_eq!(stats.samples, 2);
+        assert!((stats.min_threshold - 0.5).abs() < 1e-9);
+        assert!((stats.max_threshold - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reset_clears_adaptive_threshold_history() {
+        let mut engine = DecisionEngine::new();
+        engine.decide_adaptive(0.5, 0.5, 0.5);
+        engine.reset();
+
+        assert_eq!(engine.adaptive_threshold_statistics().samples, 0);
+    }
+
+    fn windowed_config() -> OphanionSettings {
+        OphanionSettings {
+            window_len: 5,
+            detection_step: 3,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_windowed_decision_ignores_single_atypical_circuit() {
+        let mut engine = DecisionEngine::with_settings(&windowed_config());
+
+        for _ in 0..10 {
+            let action = engine.decide_windowed("intro-1", 0.9, 0.5);
+            assert_eq!(action, CircuitAction::Forward);
+        }
+
+        // One atypical low score should not flip the windowed mean
+        // below threshold by itself.
+        let action = engine.decide_windowed("intro-1", 0.0, 0.5);
+        assert_eq!(action, CircuitAction::Forward);
+    }
+
+    #[test]
+    fn test_windowed_decision_absorbs_sustained_flood() {
+        let mut engine = DecisionEngine::with_settings(&windowed_config());
+
+        let mut last_action = CircuitAction::Forward;
+        for _ in 0..5 {
+            last_action = engine.decide_windowed("intro-2", 0.1, 0.5);
+        }
+
+        assert_eq!(last_action, CircuitAction::Absorb);
+    }
+
+    #[test]
+    fn test_windowed_decision_tracks_introduction_points_independently() {
+        let mut engine = DecisionEngine::with_settings(&windowed_config());
+
+        for _ in 0..5 {
+            engine.decide_windowed("flooded", 0.1, 0.5);
+        }
+        let healthy_action = engine.decide_windowed("healthy", 0.9, 0.5);
+
+        assert_eq!(healthy_action, CircuitAction::Forward);
+    }
 }
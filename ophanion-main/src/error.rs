@@ -0,0 +1,133 @@
+//! Typed error surface for [`crate::config`].
+//!
+//! `OphanionConfig::from_file`/`validate` used to return a blanket
+//! `anyhow::Result`, forcing callers to string-match error messages to
+//! tell a missing file apart from a malformed TOML document apart from a
+//! rejected field value. [`ConfigError`] gives each of those failure
+//! modes its own variant so a caller (e.g. the service's startup path)
+//! can match on exactly which config field failed validation.
+
+use std::fmt;
+
+/// Boxed lower-level error carried as the `source` of a [`ConfigError`]
+/// variant.
+///
+/// `Send + Sync` by default, so `ConfigError` itself stays
+/// `Send + Sync + 'static` and converts into `anyhow::Error` for free at
+/// the service's startup path. Degrades to a bare `Box<dyn Error>` when
+/// the `fragile-send-sync` feature is off, for embedders in
+/// single-threaded or wasm contexts where a `Send + Sync` bound can't
+/// always be met. Requires `fragile-send-sync` to be a default-on
+/// Cargo feature (`default = ["fragile-send-sync"]`).
+#[cfg(feature = "fragile-send-sync")]
+pub type ErrorSource = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+#[cfg(not(feature = "fragile-send-sync"))]
+pub type ErrorSource = Box<dyn std::error::Error + 'static>;
+
+/// Failure modes of loading and validating an [`crate::config::OphanionConfig`].
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file could not be read.
+    Io { source: std::io::Error },
+    /// The config file's contents are not valid TOML.
+    Parse { source: ErrorSource },
+    /// A field failed a validation rule that isn't a simple numeric
+    /// range (e.g. a cross-field constraint).
+    Validation { field: &'static str, reason: String },
+    /// A field's value fell outside its documented valid range.
+    OutOfRange {
+        field: &'static str,
+        value: String,
+        range: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io { source } => write!(f, "failed to read config file: {source}"),
+            ConfigError::Parse { source } => write!(f, "failed to parse TOML config: {source}"),
+            ConfigError::Validation { field, reason } => {
+                write!(f, "invalid config field `{field}`: {reason}")
+            }
+            ConfigError::OutOfRange {
+                field,
+                value,
+                range,
+            } => write!(
+                f,
+                "config field `{field}` = {value} is out of range {range}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io { source } => Some(source),
+            ConfigError::Parse { source } => Some(source.as_ref()),
+            ConfigError::Validation { .. } | ConfigError::OutOfRange { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(source: std::io::Error) -> Self {
+        ConfigError::Io { source }
+    }
+}
+
+impl ConfigError {
+    /// Builds an [`ConfigError::OutOfRange`] from a field's value and the
+    /// textual description of its valid range.
+    pub fn out_of_range(field: &'static str, value: impl fmt::Display, range: &'static str) -> Self {
+        ConfigError::OutOfRange {
+            field,
+            value: value.to_string(),
+            range: range.to_string(),
+        }
+    }
+
+    /// Builds a [`ConfigError::Validation`] from a field and a reason.
+    pub fn validation(field: &'static str, reason: impl Into<String>) -> Self {
+        ConfigError::Validation {
+            field,
+            reason: reason.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_out_of_range_display() {
+        let err = ConfigError::out_of_range("gbdt_blend_weight", 1.5, "[0, 1]");
+        assert_eq!(
+            err.to_string(),
+            "config field `gbdt_blend_weight` = 1.5 is out of range [0, 1]"
+        );
+    }
+
+    #[test]
+    fn test_validation_display() {
+        let err = ConfigError::validation("fft_num_bins", "must be <= fft_len");
+        assert_eq!(
+            err.to_string(),
+            "invalid config field `fft_num_bins`: must be <= fft_len"
+        );
+    }
+
+    #[test]
+    fn test_io_error_has_source() {
+        use std::error::Error;
+
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        let err = ConfigError::from(io_err);
+
+        assert!(err.source().is_some());
+    }
+}
@@ -0,0 +1,135 @@
+//! Deterministic entrypoints for fuzzing the TOML config parser and the
+//! Gabriel-cell anomaly-scoring math.
+//!
+//! Gated behind the `fuzz` Cargo feature so these functions (and the
+//! `cargo fuzz` target crate under `fuzz/` that calls them) are compiled
+//! out of ordinary builds. Complements the honggfuzz harness in
+//! `thronion/fuzz`: this one drives `ophanion`'s own config-parsing and
+//! anomaly-scoring code paths directly from raw fuzz bytes instead of
+//! replaying packets through the Tor-facing pipeline.
+
+use crate::config::{OphanionConfig, OphanionSettings};
+use crate::resonance::ResonanceEngine;
+use ndarray::Array1;
+
+/// Feeds `data` through `OphanionConfig`'s TOML parser and validator.
+///
+/// Interprets `data` as (possibly invalid) UTF-8 TOML and runs it
+/// through the same `toml::from_str` + `validate` steps
+/// [`OphanionConfig::from_file`](crate::config::OphanionConfig::from_file)
+/// uses. Malformed TOML and out-of-range field values are expected to
+/// surface as an `Err` -- the invariant this harness checks is that no
+/// input, however malformed, makes either step panic or overflow.
+pub fn fuzz_parse_config(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(config) = toml::from_str::<OphanionConfig>(text) {
+        let _ = config.validate();
+    }
+}
+
+/// Feeds `dim` and `bytes` through `ResonanceEngine::compute_score` and
+/// `learn_signature`.
+///
+/// `bytes` is interpreted as a little-endian `f64` stream to build an
+/// `Array1<f64>` signature of length `dim` (reduced to a small range so
+/// a single fuzz input can't force an unbounded allocation). Asserts the
+/// scoring invariants that must hold no matter how degenerate the
+/// signature is: a finite signature always scores in `[0, 1]`, and a
+/// signature containing NaN or infinite components is rejected before
+/// it reaches `learn_signature` -- `find_nearest`'s `partial_cmp`-based
+/// distance comparison and the Gaussian kernel's `2.0 * cell.covariance`
+/// division are not NaN-safe, so a non-finite signature must never be
+/// allowed to poison a cell's learned covariance. For finite signatures,
+/// learning is exercised and the cluster is scored again afterwards to
+/// confirm the `total_weight > 0.0` guard still holds.
+pub fn fuzz_score(dim: usize, bytes: &[u8]) {
+    let dim = (dim % 64) + 1;
+    let signature = signature_from_bytes(dim, bytes);
+
+    let engine = ResonanceEngine::new(OphanionSettings {
+        spectral_dim: dim,
+        ..OphanionSettings::default()
+    });
+
+    let score = engine.compute_score(&signature);
+
+    if signature.iter().any(|value| !value.is_finite()) {
+        assert!(
+            !score.is_nan(),
+            "NaN signature produced a NaN score instead of being rejected"
+        );
+        return;
+    }
+
+    assert!(score.is_finite(), "score must be finite: {score}");
+    assert!((0.0..=1.0).contains(&score), "score out of [0, 1]: {score}");
+
+    engine.learn_signature(&signature);
+
+    let probe = Array1::zeros(dim);
+    let after_learning = engine.compute_score(&probe);
+    assert!(
+        after_learning.is_finite(),
+        "learn_signature poisoned a cell: subsequent score is non-finite"
+    );
+    assert!(
+        (0.0..=1.0).contains(&after_learning),
+        "learn_signature left cluster scoring out of [0, 1]: {after_learning}"
+    );
+}
+
+/// Builds a length-`dim` signature from a little-endian `f64` byte
+/// stream, wrapping around `bytes` (or zero-filling if it's empty) so
+/// every `dim` is reachable regardless of the fuzzer's input length.
+fn signature_from_bytes(dim: usize, bytes: &[u8]) -> Array1<f64> {
+    if bytes.is_empty() {
+        return Array1::zeros(dim);
+    }
+
+    let mut values = Vec::with_capacity(dim);
+    for i in 0..dim {
+        let mut chunk = [0u8; 8];
+        for (offset, byte) in chunk.iter_mut().enumerate() {
+            *byte = bytes[(i * 8 + offset) % bytes.len()];
+        }
+        values.push(f64::from_le_bytes(chunk));
+    }
+    Array1::from_vec(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzz_parse_config_never_panics_on_arbitrary_bytes() {
+        fuzz_parse_config(&[]);
+        fuzz_parse_config(b"not valid toml {{{");
+        fuzz_parse_config(&[0xff, 0x00, 0xfe, 0x12, 0x34]);
+        fuzz_parse_config(b"[ophanion]\nnum_gabriel_cells = 99999999999999999999\n");
+    }
+
+    #[test]
+    fn test_fuzz_score_never_panics_on_arbitrary_bytes() {
+        fuzz_score(0, &[]);
+        fuzz_score(4, &[0xff; 3]);
+        fuzz_score(8, &[0x00, 0xff].repeat(40));
+    }
+
+    #[test]
+    fn test_fuzz_score_rejects_nan_signature() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&f64::NAN.to_le_bytes());
+        bytes.extend_from_slice(&f64::INFINITY.to_le_bytes());
+        fuzz_score(2, &bytes);
+    }
+
+    #[test]
+    fn test_signature_from_bytes_matches_requested_dim() {
+        let signature = signature_from_bytes(3, &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(signature.len(), 3);
+    }
+}
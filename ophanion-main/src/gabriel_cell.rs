@@ -1,29 +1,172 @@
-use crate::{GabrielCell, config::OphanionSettings};
+use crate::{config::OphanionSettings, vp_tree::VpTree, GabrielCell};
 use ndarray::Array1;
 use parking_lot::RwLock;
+use rand_distr::{Beta, Distribution};
 use std::sync::Arc;
 
 pub struct GabrielCluster {
     pub cells: Arc<RwLock<Vec<GabrielCell>>>,
     config: OphanionSettings,
+    /// Cached VP-tree index over the cell centroids, rebuilt once
+    /// `index_drift` exceeds `config.spatial_index_drift_threshold`.
+    spatial_index: RwLock<Option<VpTree>>,
+    /// Summed centroid movement since the index was last built.
+    index_drift: RwLock<f64>,
+    /// Remaining unallocated stick-breaking mass `∏_{j<k}(1−βⱼ)` of the
+    /// Dirichlet-process prior driving [`Self::observe`]'s cell growth.
+    stick_remaining: RwLock<f64>,
+    /// Next id handed to a freshly spawned cell; monotonically increasing
+    /// so ids stay unique even after [`Self::prune_rare_cells`] removes
+    /// cells and the vector shrinks.
+    next_id: RwLock<usize>,
 }
 
 impl GabrielCluster {
     pub fn new(config: OphanionSettings) -> Self {
+        let next_id = config.num_gabriel_cells;
         let cells = (0..config.num_gabriel_cells)
             .map(|id| GabrielCell::new(id, config.spectral_dim))
             .collect();
-        
+
         Self {
             cells: Arc::new(RwLock::new(cells)),
             config,
+            spatial_index: RwLock::new(None),
+            index_drift: RwLock::new(f64::INFINITY),
+            stick_remaining: RwLock::new(1.0),
+            next_id: RwLock::new(next_id),
         }
     }
-    
+
+    /// Nonparametric (Dirichlet-process) observation step: either assigns
+    /// `signature` to the nearest existing cell, or spawns a fresh cell
+    /// seeded at `signature` when it is novel enough and stick-breaking
+    /// mass remains to allocate to it.
+    ///
+    /// A signature is considered novel when its distance to the nearest
+    /// cell's centroid exceeds that cell's radius
+    /// `config.dp_base_radius * sqrt(covariance)`. A new cell is spawned
+    /// only if, additionally, the remaining unallocated stick mass
+    /// `∏_{j<k}(1−βⱼ)` is still above `config.dp_min_stick_mass` — once
+    /// the prior has allocated almost everything, novel-but-marginal
+    /// signatures are folded into the nearest cell instead of growing the
+    /// population further. Spawning draws a fresh weight `βₖ ~ Beta(1, α)`
+    /// and shrinks the remaining mass by `(1−βₖ)`.
+    ///
+    /// Returns the id of the cell the signature ended up assigned to.
+    pub fn observe(&self, signature: &Array1<f64>) -> usize {
+        let nearest_id = self.find_nearest(signature);
+
+        let (distance, radius) = {
+            let cells = self.cells.read();
+            let cell = &cells[nearest_id];
+            (
+                cell.distance_to(signature),
+                self.config.dp_base_radius * cell.covariance.sqrt(),
+            )
+        };
+
+        let remaining = *self.stick_remaining.read();
+
+        if distance > radius && remaining > self.config.dp_min_stick_mass {
+            let beta = Beta::new(1.0, self.config.dp_concentration_alpha)
+                .expect("dp_concentration_alpha must be > 0");
+            let weight: f64 = beta.sample(&mut rand::thread_rng());
+            *self.stick_remaining.write() = remaining * (1.0 - weight);
+
+            let mut next_id = self.next_id.write();
+            let new_id = *next_id;
+            *next_id += 1;
+
+            let mut cells = self.cells.write();
+            cells.push(GabrielCell::seeded(new_id, signature));
+            new_id
+        } else {
+            self.update_cell(nearest_id, signature);
+            let mut cells = self.cells.write();
+            cells[nearest_id].assignment_count += 1;
+            nearest_id
+        }
+    }
+
+    /// Retires cells whose `assignment_count` is below `min_assignments`,
+    /// as spawned by [`Self::observe`] but rarely (or never) reused since.
+    /// At least one cell — the one with the highest assignment count — is
+    /// always kept, so the cluster never goes empty.
+    ///
+    /// Every other method (`find_nearest`, `update_cell`, ...) addresses
+    /// cells by their position in `cells`, relying on `cell.id` matching
+    /// that position; removing cells from the middle of the vector would
+    /// break that invariant for everything after the gap. So surviving
+    /// cells are renumbered `id = 0..len` in their retained order,
+    /// `connections` are remapped accordingly (dropping links to pruned
+    /// ids), `next_id` resumes from the new length, and the now-stale
+    /// spatial index is dropped so it gets rebuilt against the new
+    /// layout.
+    ///
+    /// Returns the number of cells removed.
+    pub fn prune_rare_cells(&self, min_assignments: usize) -> usize {
+        let mut cells = self.cells.write();
+        let before = cells.len();
+
+        let mut keep: Vec<bool> = cells
+            .iter()
+            .map(|cell| cell.assignment_count >= min_assignments)
+            .collect();
+
+        if !keep.iter().any(|&k| k) {
+            if let Some((best_idx, _)) = cells
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, cell)| cell.assignment_count)
+            {
+                keep[best_idx] = true;
+            }
+        }
+
+        // Map each surviving cell's old id to its new, position-matching
+        // id, built in retained order so `new_id` ends up equal to the
+        // final index of that cell.
+        let mut new_id = 0usize;
+        let mut renumbered: std::collections::HashMap<usize, usize> =
+            std::collections::HashMap::new();
+        for (idx, cell) in cells.iter().enumerate() {
+            if keep[idx] {
+                renumbered.insert(cell.id, new_id);
+                new_id += 1;
+            }
+        }
+
+        let mut idx = 0usize;
+        cells.retain(|_| {
+            let keep_this = keep[idx];
+            idx += 1;
+            keep_this
+        });
+
+        for cell in cells.iter_mut() {
+            cell.id = renumbered[&cell.id];
+            cell.connections.retain_mut(|(other_id, _)| {
+                if let Some(&mapped) = renumbered.get(other_id) {
+                    *other_id = mapped;
+                    true
+                } else {
+                    false
+                }
+            });
+        }
+
+        *self.next_id.write() = cells.len();
+        *self.spatial_index.write() = None;
+        *self.index_drift.write() = f64::INFINITY;
+
+        before - cells.len()
+    }
+
     /// Find nearest Gabriel Cell to given signature
     pub fn find_nearest(&self, signature: &Array1<f64>) -> usize {
         let cells = self.cells.read();
-        
+
         cells.iter()
             .enumerate()
             .min_by(|(_, a), (_, b)| {
@@ -34,49 +177,90 @@ impl GabrielCluster {
             .map(|(idx, _)| idx)
             .unwrap_or(0)
     }
-    
-    /// Find k-nearest cells
+
+    /// Find k-nearest cells.
+    ///
+    /// For clusters at or above `spatial_index_min_cells`, this queries
+    /// a VP-tree index over the cell centroids (sub-linear instead of
+    /// the O(n log n) exact scan), rebuilding it once accumulated
+    /// centroid drift since the last build passes
+    /// `spatial_index_drift_threshold`. Smaller clusters, or
+    /// `use_spatial_index = false`, always use the exact scan. Either
+    /// path returns the same `Vec<usize>` of cell ids.
     pub fn find_k_nearest(&self, signature: &Array1<f64>, k: usize) -> Vec<usize> {
         let cells = self.cells.read();
-        
+
+        if !self.config.use_spatial_index || cells.len() < self.config.spatial_index_min_cells {
+            return Self::exact_k_nearest(&cells, signature, k);
+        }
+
+        let needs_rebuild = self.spatial_index.read().is_none()
+            || *self.index_drift.read() > self.config.spatial_index_drift_threshold;
+
+        if needs_rebuild {
+            *self.spatial_index.write() = Some(VpTree::build(&cells));
+            *self.index_drift.write() = 0.0;
+        }
+
+        match self.spatial_index.read().as_ref() {
+            Some(tree) => tree.k_nearest(signature, k.min(cells.len())),
+            None => Self::exact_k_nearest(&cells, signature, k),
+        }
+    }
+
+    /// Exact linear-scan k-nearest, used for small clusters and as the
+    /// fallback when the spatial index is disabled or unavailable.
+    fn exact_k_nearest(cells: &[GabrielCell], signature: &Array1<f64>, k: usize) -> Vec<usize> {
         let mut distances: Vec<(usize, f64)> = cells.iter()
             .enumerate()
             .map(|(idx, cell)| (idx, cell.distance_to(signature)))
             .collect();
-        
+
         distances.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
-        
+
         distances.iter()
             .take(k.min(cells.len()))
             .map(|(idx, _)| *idx)
             .collect()
     }
-    
+
     /// Update cell with new circuit signature
     pub fn update_cell(&self, cell_id: usize, signature: &Array1<f64>) {
         let mut cells = self.cells.write();
-        
+
         if let Some(cell) = cells.get_mut(cell_id) {
+            let before = cell.centroid.clone();
             cell.update_centroid(signature, self.config.learning_rate_alpha);
             cell.resonance_strength += 0.01;
             cell.resonance_strength = cell.resonance_strength.min(1.0);
+            self.track_drift(&before, &cell.centroid);
         }
     }
-    
+
     /// Update multiple cells (for k-nearest)
     pub fn update_cells(&self, cell_ids: &[usize], signature: &Array1<f64>, weights: &[f64]) {
         let mut cells = self.cells.write();
-        
+
         for (i, &cell_id) in cell_ids.iter().enumerate() {
             if let Some(cell) = cells.get_mut(cell_id) {
                 let weight = weights.get(i).copied().unwrap_or(1.0);
                 let alpha = self.config.learning_rate_alpha * weight;
+                let before = cell.centroid.clone();
                 cell.update_centroid(signature, alpha);
                 cell.resonance_strength += 0.01 * weight;
                 cell.resonance_strength = cell.resonance_strength.min(1.0);
+                self.track_drift(&before, &cell.centroid);
             }
         }
     }
+
+    /// Accumulates the centroid movement from `before` to `after` into
+    /// `index_drift`, so [`Self::find_k_nearest`] knows when the cached
+    /// VP-tree index has gone stale enough to rebuild.
+    fn track_drift(&self, before: &Array1<f64>, after: &Array1<f64>) {
+        let moved = (after - before).mapv(|x| x * x).sum().sqrt();
+        *self.index_drift.write() += moved;
+    }
     
     /// Update connection weights between all cells
     pub fn update_connections(&self) {
@@ -132,6 +316,70 @@ impl GabrielCluster {
         weighted_sum / total_strength
     }
     
+    /// Mark-and-sweep liveness pass over the cell connection graph.
+    ///
+    /// Seeds the live-set with every cell whose `resonance_strength`
+    /// exceeds `config.prune_threshold`, then propagates liveness
+    /// backward across connection edges in descending-strength order to
+    /// a fixpoint -- exactly like reverse-dataflow liveness analysis,
+    /// where a definition stays live if a later use reaches it: here, a
+    /// weak cell stays alive if it is still strongly connected to an
+    /// already-live cell. Cells that remain dead after the fixpoint are
+    /// recycled in place as fresh seeds (zeroed centroid, strength,
+    /// connections and assignment count) rather than removed, so the
+    /// fixed `num_gabriel_cells` budget is reclaimed for new signatures
+    /// instead of shrinking, unlike [`Self::prune_rare_cells`].
+    ///
+    /// Returns how many cells were reclaimed.
+    pub fn prune_dead_cells(&self) -> PruneReport {
+        let mut cells = self.cells.write();
+        let total_cells = cells.len();
+
+        let mut live: Vec<bool> = cells
+            .iter()
+            .map(|cell| cell.resonance_strength > self.config.prune_threshold)
+            .collect();
+
+        let mut edges: Vec<(usize, usize, f64)> = Vec::new();
+        for (idx, cell) in cells.iter().enumerate() {
+            for &(other_id, strength) in &cell.connections {
+                if other_id < cells.len() {
+                    edges.push((idx, other_id, strength));
+                }
+            }
+        }
+        edges.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());
+
+        loop {
+            let mut changed = false;
+            for &(a, b, _) in &edges {
+                if live[a] && !live[b] {
+                    live[b] = true;
+                    changed = true;
+                } else if live[b] && !live[a] {
+                    live[a] = true;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let mut reclaimed = 0;
+        for (idx, cell) in cells.iter_mut().enumerate() {
+            if !live[idx] {
+                *cell = GabrielCell::new(cell.id, self.config.spectral_dim);
+                reclaimed += 1;
+            }
+        }
+
+        PruneReport {
+            reclaimed,
+            total_cells,
+        }
+    }
+
     /// Get statistics about cell distribution
     pub fn statistics(&self) -> ClusterStatistics {
         let cells = self.cells.read();
@@ -169,6 +417,16 @@ pub struct ClusterStatistics {
     pub total_cells: usize,
 }
 
+/// Result of a single [`GabrielCluster::prune_dead_cells`] mark-and-sweep
+/// pass.
+#[derive(Debug, Clone)]
+pub struct PruneReport {
+    /// Number of cells that were dead after the liveness fixpoint and
+    /// were reset to fresh seeds.
+    pub reclaimed: usize,
+    pub total_cells: usize,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,4 +508,234 @@ mod tests {
         assert_eq!(stats.total_cells, 16);
         assert!(stats.active_cells <= 5);
     }
+
+    #[test]
+    fn test_find_k_nearest_matches_between_index_and_exact_scan() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 200,
+            spectral_dim: 8,
+            learning_rate_alpha: 0.1,
+            decay_rate_beta: 0.01,
+            spatial_index_min_cells: 32,
+            ..Default::default()
+        };
+
+        let indexed = GabrielCluster::new(config.clone());
+        let exact = GabrielCluster::new(OphanionSettings {
+            use_spatial_index: false,
+            ..config.clone()
+        });
+
+        for cluster in [&indexed, &exact] {
+            for i in 0..config.num_gabriel_cells {
+                let signature = Array1::from_vec(
+                    (0..config.spectral_dim)
+                        .map(|d| ((i * 7 + d * 3) % 11) as f64 * 0.1)
+                        .collect(),
+                );
+                cluster.update_cell(i, &signature);
+            }
+        }
+
+        let query = Array1::from_vec(vec![0.3; config.spectral_dim]);
+
+        let mut via_index = indexed.find_k_nearest(&query, 5);
+        let mut via_exact = exact.find_k_nearest(&query, 5);
+        via_index.sort_unstable();
+        via_exact.sort_unstable();
+
+        assert_eq!(via_index, via_exact);
+    }
+
+    #[test]
+    fn test_find_k_nearest_below_min_cells_uses_exact_scan() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 8,
+            spectral_dim: 4,
+            spatial_index_min_cells: 64,
+            ..Default::default()
+        };
+
+        let cluster = GabrielCluster::new(config.clone());
+        let query = Array1::from_vec(vec![0.1; config.spectral_dim]);
+
+        let result = cluster.find_k_nearest(&query, 3);
+
+        assert!(cluster.spatial_index.read().is_none());
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_observe_spawns_cell_for_novel_signature() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 1,
+            spectral_dim: 4,
+            dp_base_radius: 0.5,
+            ..Default::default()
+        };
+        let cluster = GabrielCluster::new(config);
+
+        // The lone seed cell sits at the origin; a far-away signature
+        // should clear the novelty radius and spawn a new cell rather
+        // than being folded into cell 0.
+        let far_signature = Array1::from_vec(vec![10.0; 4]);
+        let assigned = cluster.observe(&far_signature);
+
+        assert_eq!(assigned, 1);
+        assert_eq!(cluster.cells.read().len(), 2);
+        assert_eq!(cluster.cells.read()[1].assignment_count, 1);
+    }
+
+    #[test]
+    fn test_observe_assigns_nearby_signature_to_existing_cell() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 1,
+            spectral_dim: 4,
+            dp_base_radius: 100.0,
+            ..Default::default()
+        };
+        let cluster = GabrielCluster::new(config);
+
+        let near_signature = Array1::from_vec(vec![0.01; 4]);
+        let assigned = cluster.observe(&near_signature);
+
+        assert_eq!(assigned, 0);
+        assert_eq!(cluster.cells.read().len(), 1);
+        assert_eq!(cluster.cells.read()[0].assignment_count, 1);
+    }
+
+    #[test]
+    fn test_observe_stops_spawning_once_stick_mass_exhausted() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 1,
+            spectral_dim: 2,
+            dp_base_radius: 0.1,
+            dp_min_stick_mass: 0.999,
+            ..Default::default()
+        };
+        let cluster = GabrielCluster::new(config);
+
+        // dp_min_stick_mass is essentially 1.0, so even a maximally novel
+        // signature must be folded into the nearest cell instead of
+        // spawning, since there is no stick mass left to allocate.
+        let far_signature = Array1::from_vec(vec![99.0; 2]);
+        let assigned = cluster.observe(&far_signature);
+
+        assert_eq!(assigned, 0);
+        assert_eq!(cluster.cells.read().len(), 1);
+    }
+
+    #[test]
+    fn test_prune_rare_cells_removes_underused_cells() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 3,
+            spectral_dim: 2,
+            ..Default::default()
+        };
+        let cluster = GabrielCluster::new(config);
+
+        {
+            let mut cells = cluster.cells.write();
+            cells[0].assignment_count = 10;
+            cells[1].assignment_count = 0;
+            cells[2].assignment_count = 1;
+        }
+
+        let removed = cluster.prune_rare_cells(2);
+
+        assert_eq!(removed, 2);
+        let cells = cluster.cells.read();
+        assert_eq!(cells.len(), 1);
+        assert_eq!(cells[0].id, 0);
+    }
+
+    #[test]
+    fn test_prune_rare_cells_always_keeps_one_cell() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 2,
+            spectral_dim: 2,
+            ..Default::default()
+        };
+        let cluster = GabrielCluster::new(config);
+
+        {
+            let mut cells = cluster.cells.write();
+            cells[0].assignment_count = 0;
+            cells[1].assignment_count = 0;
+        }
+
+        cluster.prune_rare_cells(5);
+
+        assert_eq!(cluster.cells.read().len(), 1);
+    }
+
+    #[test]
+    fn test_prune_dead_cells_reclaims_unconnected_weak_cell() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 2,
+            spectral_dim: 2,
+            prune_threshold: 0.05,
+            ..Default::default()
+        };
+        let cluster = GabrielCluster::new(config);
+
+        {
+            let mut cells = cluster.cells.write();
+            cells[0].resonance_strength = 0.9;
+            cells[1].resonance_strength = 0.01;
+        }
+
+        let report = cluster.prune_dead_cells();
+
+        assert_eq!(report.reclaimed, 1);
+        assert_eq!(report.total_cells, 2);
+        assert_eq!(cluster.cells.read()[1].resonance_strength, 0.0);
+    }
+
+    #[test]
+    fn test_prune_dead_cells_keeps_weak_cell_strongly_connected_to_a_live_one() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 2,
+            spectral_dim: 2,
+            prune_threshold: 0.05,
+            ..Default::default()
+        };
+        let cluster = GabrielCluster::new(config);
+
+        {
+            let mut cells = cluster.cells.write();
+            cells[0].resonance_strength = 0.9;
+            cells[1].resonance_strength = 0.01;
+            cells[0].connections.push((1, 0.8));
+            cells[1].connections.push((0, 0.8));
+        }
+
+        let report = cluster.prune_dead_cells();
+
+        assert_eq!(report.reclaimed, 0);
+        assert_eq!(cluster.cells.read()[1].resonance_strength, 0.01);
+    }
+
+    #[test]
+    fn test_prune_rare_cells_drops_dangling_connections() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 2,
+            spectral_dim: 2,
+            ..Default::default()
+        };
+        let cluster = GabrielCluster::new(config);
+
+        {
+            let mut cells = cluster.cells.write();
+            cells[0].assignment_count = 10;
+            cells[0].connections.push((1, 0.5));
+            cells[1].assignment_count = 0;
+        }
+
+        cluster.prune_rare_cells(1);
+
+        let cells = cluster.cells.read();
+        assert_eq!(cells.len(), 1);
+        assert!(cells[0].connections.is_empty());
+    }
 }
@@ -1,12 +1,18 @@
 pub mod config;
+pub mod error;
 pub mod spectral;
 pub mod gabriel_cell;
+mod vp_tree;
 pub mod resonance;
 pub mod threshold;
 pub mod delta_kernel;
 pub mod tor_interface;
 pub mod circuit_monitor;
 pub mod decision;
+pub mod scorer;
+pub mod tor_control;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
 
 use ndarray::Array1;
 use std::time::{Duration, Instant};
@@ -107,6 +113,11 @@ pub struct GabrielCell {
     pub covariance: f64,
     pub resonance_strength: f64,
     pub connections: Vec<(usize, f64)>,
+    /// Number of signatures assigned to this cell since creation, used by
+    /// `GabrielCluster::prune_rare_cells` to retire cells the
+    /// stick-breaking allocator spawned but that turned out to be rarely
+    /// used.
+    pub assignment_count: usize,
 }
 
 impl GabrielCell {
@@ -117,9 +128,24 @@ impl GabrielCell {
             covariance: 1.0,
             resonance_strength: 0.0,
             connections: Vec::new(),
+            assignment_count: 0,
         }
     }
-    
+
+    /// Creates a new cell seeded directly at `signature`, as spawned by
+    /// `GabrielCluster::observe` when a novel signature falls outside the
+    /// radius of every existing cell.
+    pub fn seeded(id: usize, signature: &Array1<f64>) -> Self {
+        Self {
+            id,
+            centroid: signature.clone(),
+            covariance: 1.0,
+            resonance_strength: 0.0,
+            connections: Vec::new(),
+            assignment_count: 1,
+        }
+    }
+
     pub fn distance_to(&self, signature: &Array1<f64>) -> f64 {
         (&self.centroid - signature).mapv(|x| x * x).sum().sqrt()
     }
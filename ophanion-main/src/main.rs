@@ -60,96 +60,162 @@ async fn main() -> anyhow::Result<()> {
     info!("✓ Delta-Kernel Optimizer initialized");
     
     let decision_engine = Arc::new(parking_lot::Mutex::new(
-        decision::DecisionEngine::new()
+        decision::DecisionEngine::with_settings(&config.ophanion)
     ));
     info!("✓ Decision Engine initialized");
-    
+
+    let circuit_monitor = Arc::new(circuit_monitor::CircuitMonitor::new(
+        config.performance.max_tracked_circuits,
+    ));
+    info!("✓ Circuit Monitor initialized");
+
+    #[cfg(feature = "tor-control")]
+    let tor_interface = Arc::new(if let Some(password) = &config.tor.control_password {
+        tor_interface::TorInterface::with_password_auth(config.tor.control_port, password.clone())
+    } else if let Some(cookie_path) = &config.tor.cookie_path {
+        tor_interface::TorInterface::with_cookie_auth(config.tor.control_port, cookie_path.clone())
+    } else {
+        tor_interface::TorInterface::new(config.tor.control_port)
+    });
+    #[cfg(not(feature = "tor-control"))]
+    let tor_interface = Arc::new(tor_interface::TorInterface::new(config.tor.control_port));
+
+    let tor_subsystem = Arc::new(tor_control::TorControlSubsystem::new(
+        Arc::clone(&tor_interface),
+        Arc::clone(&circuit_monitor),
+        Arc::clone(&resonance_engine),
+        Arc::clone(&adaptive_threshold),
+        Arc::clone(&decision_engine),
+    ));
+    info!("✓ Tor Control Subsystem initialized");
+
     info!("");
     info!("All components initialized successfully!");
     info!("Target absorption rate: {:.1}%", 
           config.ophanion.target_absorption_rate * 100.0);
     info!("");
     
+    // Shutdown channel every spawned task selects on alongside its own
+    // work, so ctrl_c (or a crashed task) drains all of them before the
+    // process exits instead of leaving them running past "OPHANION
+    // stopped."
+    let (shutdown_tx, _) = tokio::sync::broadcast::channel::<()>(1);
+
     // Spawn maintenance task
     let resonance_clone = Arc::clone(&resonance_engine);
     let threshold_clone = Arc::clone(&adaptive_threshold);
     let decision_clone = Arc::clone(&decision_engine);
-    
-    tokio::spawn(async move {
+    let mut maintenance_shutdown = shutdown_tx.subscribe();
+
+    let maintenance_task = tokio::spawn(async move {
         let mut tick = interval(Duration::from_secs(10));
         loop {
-            tick.tick().await;
-            
-            // Run maintenance
-            resonance_clone.maintenance_cycle();
-            
-            let coherence = resonance_clone.coherence();
-            let absorption_rate = threshold_clone.absorption_rate();
-            let stats = decision_clone.lock().statistics();
-            
-            info!(
-                "Stats | Coherence: {:.3} | Absorption: {:.1}% | Decisions: {} | Forwarded: {} | Absorbed: {}",
-                coherence, 
-                absorption_rate * 100.0,
-                stats.total_decisions,
-                stats.forwarded,
-                stats.absorbed
-            );
-            
-            // Update threshold
-            let flood_energy = 1.0 - absorption_rate;
-            threshold_clone.update(coherence, flood_energy);
+            tokio::select! {
+                _ = tick.tick() => {
+                    // Run maintenance
+                    resonance_clone.maintenance_cycle();
+
+                    let coherence = resonance_clone.coherence();
+                    let absorption_rate = threshold_clone.absorption_rate();
+                    let stats = decision_clone.lock().statistics();
+
+                    info!(
+                        "Stats | Coherence: {:.3} | Absorption: {:.1}% | Decisions: {} | Forwarded: {} | Absorbed: {}",
+                        coherence,
+                        absorption_rate * 100.0,
+                        stats.total_decisions,
+                        stats.forwarded,
+                        stats.absorbed
+                    );
+
+                    // Update threshold
+                    let flood_energy = 1.0 - absorption_rate;
+                    threshold_clone.update(coherence, flood_energy);
+                }
+                _ = maintenance_shutdown.recv() => {
+                    info!("Maintenance task shutting down");
+                    break;
+                }
+            }
         }
     });
-    
+
     // Spawn Delta-Kernel optimization task
     let resonance_clone2 = Arc::clone(&resonance_engine);
     let threshold_clone2 = Arc::clone(&adaptive_threshold);
-    
-    tokio::spawn(async move {
+    let mut delta_shutdown = shutdown_tx.subscribe();
+
+    let delta_task = tokio::spawn(async move {
         let mut tick = interval(Duration::from_secs(30));
         loop {
-            tick.tick().await;
-            
-            let coherence = resonance_clone2.coherence();
-            let flood_energy = 1.0 - threshold_clone2.absorption_rate();
-            
-            delta_kernel.optimize_step(coherence, flood_energy);
-            
-            let gradient = delta_kernel.gradient_magnitude(coherence, flood_energy);
-            let (alpha, beta, theta) = delta_kernel.get_params();
-            
-            debug!(
-                "Δ-Optimization | ∇Ψ_Δ: {:.6} | α: {:.4} | β: {:.4} | θ: {:.3}",
-                gradient, alpha, beta, theta
-            );
-            
-            if delta_kernel.has_converged(coherence, flood_energy) {
-                info!("★ CONVERGENCE ACHIEVED: ∇Ψ_Δ ≈ 0 ★");
+            tokio::select! {
+                _ = tick.tick() => {
+                    let coherence = resonance_clone2.coherence();
+                    let flood_energy = 1.0 - threshold_clone2.absorption_rate();
+
+                    delta_kernel.optimize_step(coherence, flood_energy);
+
+                    let gradient = delta_kernel.gradient_magnitude(coherence, flood_energy);
+                    let (alpha, beta, theta) = delta_kernel.get_params();
+
+                    debug!(
+                        "Δ-Optimization | ∇Ψ_Δ: {:.6} | α: {:.4} | β: {:.4} | θ: {:.3}",
+                        gradient, alpha, beta, theta
+                    );
+
+                    if delta_kernel.has_converged(coherence, flood_energy) {
+                        info!("★ CONVERGENCE ACHIEVED: ∇Ψ_Δ ≈ 0 ★");
+                    }
+                }
+                _ = delta_shutdown.recv() => {
+                    info!("Delta-Kernel optimization task shutting down");
+                    break;
+                }
             }
         }
     });
-    
-    // Main event loop (simplified demo - would integrate with actual Tor control port)
-    info!("Entering main event loop...");
-    info!("(In production: would connect to Tor control port at localhost:{})", 
-          config.tor.control_port);
-    info!("");
-    warn!("NOTE: This is a demonstration version. Full Tor integration requires:");
-    warn!("  1. Tor control port connection (tor-control-proto crate)");
-    warn!("  2. Circuit event monitoring (SETEVENTS CIRC)");
-    warn!("  3. Cell timing extraction from Tor daemon");
+
+    // Spawn the Tor control-port subsystem: subscribes to circuit events,
+    // classifies each one through the resonance/decision pipeline, and
+    // reconnects with exponential backoff if the control connection drops.
+    info!(
+        "Connecting to Tor control port at localhost:{}...",
+        config.tor.control_port
+    );
+    let tor_subsystem_clone = Arc::clone(&tor_subsystem);
+    let tor_shutdown = shutdown_tx.subscribe();
+    let mut tor_task = tokio::spawn(async move {
+        if let Err(err) = tor_subsystem_clone.run(tor_shutdown).await {
+            warn!("Tor control subsystem exited with an error: {err:#}");
+        }
+    });
     info!("");
-    
-    // Keep running
+
+    // Keep running until ctrl_c, or until the Tor subsystem task exits
+    // unexpectedly (either is treated as a shutdown trigger for everyone
+    // else).
     tokio::select! {
         _ = tokio::signal::ctrl_c() => {
             info!("");
             info!("Shutdown signal received");
-            info!("OPHANION stopping gracefully...");
+        }
+        res = &mut tor_task => {
+            match res {
+                Ok(()) => warn!("Tor control subsystem stopped unexpectedly"),
+                Err(err) => warn!("Tor control subsystem task panicked: {err}"),
+            }
         }
     }
-    
+
+    info!("OPHANION stopping gracefully...");
+    let _ = shutdown_tx.send(());
+
+    let _ = maintenance_task.await;
+    let _ = delta_task.await;
+    if !tor_task.is_finished() {
+        let _ = tor_task.await;
+    }
+
     info!("OPHANION stopped.");
     Ok(())
 }
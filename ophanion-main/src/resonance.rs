@@ -5,12 +5,16 @@ use std::sync::Arc;
 
 pub struct ResonanceEngine {
     gabriel_cluster: Arc<GabrielCluster>,
+    /// Cluster of anti-pattern cells, learned from labeled attack
+    /// signatures via [`ResonanceEngine::learn_attack_signature`].
+    anti_pattern_cluster: Arc<GabrielCluster>,
 }
 
 impl ResonanceEngine {
     pub fn new(config: OphanionSettings) -> Self {
         Self {
-            gabriel_cluster: Arc::new(GabrielCluster::new(config)),
+            gabriel_cluster: Arc::new(GabrielCluster::new(config.clone())),
+            anti_pattern_cluster: Arc::new(GabrielCluster::new(config)),
         }
     }
     
@@ -39,6 +43,23 @@ impl ResonanceEngine {
         }
     }
     
+    /// Computes resonance scores for a whole batch of signatures in
+    /// parallel via rayon's `par_iter`.
+    ///
+    /// Safe to parallelize directly (no per-task cloning needed, unlike
+    /// [`crate::spectral::SpectralEngine::create_signatures_batch`]):
+    /// `Self::compute_score` only takes read locks on the shared
+    /// `GabrielCluster`, so concurrent callers never contend for a
+    /// mutable resource.
+    pub fn compute_scores_batch(&self, signatures: &[Array1<f64>]) -> Vec<f64> {
+        use rayon::prelude::*;
+
+        signatures
+            .par_iter()
+            .map(|signature| self.compute_score(signature))
+            .collect()
+    }
+
     /// Compute resonance score using k-nearest cells only
     pub fn compute_score_knn(&self, signature: &Array1<f64>, k: usize) -> f64 {
         let nearest_ids = self.gabriel_cluster.find_k_nearest(signature, k);
@@ -101,10 +122,100 @@ impl ResonanceEngine {
         self.gabriel_cluster.update_cells(&nearest_ids, signature, &normalized_weights);
     }
     
-    /// Periodic maintenance: update connections and apply decay
+    /// Update the anti-pattern cluster with a labeled attack signature.
+    ///
+    /// Mirrors [`Self::learn_signature`] but only ever touches the
+    /// anti-pattern centroids, keeping the legitimate-pattern cluster
+    /// untouched by attack data.
+    pub fn learn_attack_signature(&self, signature: &Array1<f64>) {
+        let nearest = self.anti_pattern_cluster.find_nearest(signature);
+        self.anti_pattern_cluster.update_cell(nearest, signature);
+    }
+
+    /// Update the anti-pattern cluster with a k-nearest weighted update.
+    ///
+    /// Mirrors [`Self::learn_signature_knn`] but only ever touches the
+    /// anti-pattern centroids.
+    pub fn learn_attack_signature_knn(&self, signature: &Array1<f64>, k: usize) {
+        let nearest_ids = self.anti_pattern_cluster.find_k_nearest(signature, k);
+
+        let cells = self.anti_pattern_cluster.cells.read();
+        let weights: Vec<f64> = nearest_ids
+            .iter()
+            .map(|&id| {
+                if let Some(cell) = cells.get(id) {
+                    let dist = cell.distance_to(signature);
+                    1.0 / (dist + 1e-10)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        drop(cells);
+
+        let sum: f64 = weights.iter().sum();
+        let normalized_weights: Vec<f64> = if sum > 0.0 {
+            weights.iter().map(|w| w / sum).collect()
+        } else {
+            vec![1.0 / nearest_ids.len() as f64; nearest_ids.len()]
+        };
+
+        self.anti_pattern_cluster
+            .update_cells(&nearest_ids, signature, &normalized_weights);
+    }
+
+    /// Mean distance from `signature` to its k-nearest cells in `cluster`.
+    fn mean_knn_distance(cluster: &GabrielCluster, signature: &Array1<f64>, k: usize) -> f64 {
+        let nearest_ids = cluster.find_k_nearest(signature, k);
+        let cells = cluster.cells.read();
+
+        let distances: Vec<f64> = nearest_ids
+            .iter()
+            .filter_map(|&id| cells.get(id).map(|cell| cell.distance_to(signature)))
+            .collect();
+
+        if distances.is_empty() {
+            return f64::INFINITY;
+        }
+
+        distances.iter().sum::<f64>() / distances.len() as f64
+    }
+
+    /// Combined pattern / anti-pattern discrimination score.
+    ///
+    /// Computed as the log-ratio of the mean k-nearest anti-pattern
+    /// distance to the mean k-nearest pattern distance: positive when
+    /// the signature sits closer to known legitimate centroids than to
+    /// known attack centroids, negative otherwise. A circuit close to a
+    /// known attack centroid therefore scores low even if it is also
+    /// close to a legitimate one.
+    pub fn discrimination_score(&self, signature: &Array1<f64>, k: usize) -> f64 {
+        let pattern_distance = Self::mean_knn_distance(&self.gabriel_cluster, signature, k);
+        let anti_pattern_distance =
+            Self::mean_knn_distance(&self.anti_pattern_cluster, signature, k);
+
+        ((anti_pattern_distance + 1e-10) / (pattern_distance + 1e-10)).ln()
+    }
+
+    /// Get anti-pattern cluster statistics
+    pub fn attack_statistics(&self) -> crate::gabriel_cell::ClusterStatistics {
+        self.anti_pattern_cluster.statistics()
+    }
+
+    /// Periodic maintenance: update connections, apply decay, and
+    /// reclaim cells that have died since the last cycle.
     pub fn maintenance_cycle(&self) {
         self.gabriel_cluster.update_connections();
         self.gabriel_cluster.apply_decay(0.99);
+        self.prune_cells();
+    }
+
+    /// Reclaims Gabriel cells that have died (see
+    /// [`crate::gabriel_cell::GabrielCluster::prune_dead_cells`] for the
+    /// mark-and-sweep liveness pass), keeping the fixed cell budget
+    /// concentrated on meaningful circuit signatures over long uptimes.
+    pub fn prune_cells(&self) -> crate::gabriel_cell::PruneReport {
+        self.gabriel_cluster.prune_dead_cells()
     }
     
     /// Get global system coherence
@@ -121,6 +232,59 @@ impl ResonanceEngine {
     pub fn statistics(&self) -> crate::gabriel_cell::ClusterStatistics {
         self.gabriel_cluster.statistics()
     }
+
+    /// Serializes the legitimate-pattern `GabrielCluster` as a Graphviz
+    /// `digraph`: one node per cell, labeled with its id, resonance
+    /// strength and covariance, and one edge per connection maintained
+    /// by `GabrielCluster::update_connections`. Node fill color scales
+    /// from pale to saturated red with `resonance_strength` (relative to
+    /// the strongest cell in the cluster) so dominant resonators stand
+    /// out at a glance; edge `penwidth` scales with connection strength.
+    /// Pipe the output into `dot -Tpng` (or similar) to visualize the
+    /// otherwise opaque learned state that [`Self::coherence`] and
+    /// [`Self::statistics`] only summarize numerically.
+    pub fn to_dot(&self) -> String {
+        let cells = self.gabriel_cluster.cells.read();
+
+        let max_strength = cells
+            .iter()
+            .map(|cell| cell.resonance_strength)
+            .fold(0.0_f64, f64::max)
+            .max(1e-10);
+
+        let mut dot = String::from("digraph gabriel_cluster {\n");
+
+        for cell in cells.iter() {
+            let saturation = (cell.resonance_strength / max_strength).clamp(0.0, 1.0);
+            dot.push_str(&format!(
+                "  \"{id}\" [label=\"cell {id}\\nstrength={strength:.3}\\ncovariance={covariance:.3}\", \
+                 style=filled, fillcolor=\"0.0,{saturation:.3},1.0\"];\n",
+                id = cell.id,
+                strength = cell.resonance_strength,
+                covariance = cell.covariance,
+                saturation = saturation,
+            ));
+        }
+
+        for cell in cells.iter() {
+            for &(other_id, strength) in &cell.connections {
+                let penwidth = 1.0 + 4.0 * strength.clamp(0.0, 1.0);
+                dot.push_str(&format!(
+                    "  \"{from}\" -> \"{to}\" [weight=\"{strength:.3}\", penwidth={penwidth:.3}];\n",
+                    from = cell.id,
+                    to = other_id,
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Writes [`Self::to_dot`]'s output to `writer`.
+    pub fn write_dot<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(self.to_dot().as_bytes())
+    }
 }
 
 #[cfg(test)]
@@ -183,7 +347,115 @@ mod tests {
         }
         
         let coherence_after = engine.coherence();
-        
+
         assert!(coherence_after > coherence_before);
     }
+
+    #[test]
+    fn test_attack_learning_leaves_pattern_cluster_untouched() {
+        let config = test_config();
+        let engine = ResonanceEngine::new(config.clone());
+
+        let coherence_before = engine.coherence();
+
+        let attack_sig = Array1::from_vec(vec![0.9; config.spectral_dim]);
+        engine.learn_attack_signature(&attack_sig);
+
+        assert_eq!(engine.coherence(), coherence_before);
+        assert!(engine.attack_statistics().mean_strength > 0.0);
+    }
+
+    #[test]
+    fn test_discrimination_score_favors_legitimate_cluster() {
+        let config = test_config();
+        let engine = ResonanceEngine::new(config.clone());
+
+        let legit_sig = Array1::from_vec(vec![0.2; config.spectral_dim]);
+        let attack_sig = Array1::from_vec(vec![0.9; config.spectral_dim]);
+
+        for _ in 0..10 {
+            engine.learn_signature_knn(&legit_sig, 3);
+            engine.learn_attack_signature_knn(&attack_sig, 3);
+        }
+
+        let legit_score = engine.discrimination_score(&legit_sig, 3);
+        let attack_score = engine.discrimination_score(&attack_sig, 3);
+
+        assert!(
+            legit_score > attack_score,
+            "legitimate signature ({legit_score}) should score higher than a known \
+             attack signature ({attack_score})"
+        );
+    }
+
+    #[test]
+    fn test_maintenance_cycle_reclaims_dead_cells() {
+        let config = OphanionSettings {
+            num_gabriel_cells: 4,
+            spectral_dim: 8,
+            prune_threshold: 0.5,
+            ..test_config()
+        };
+        let engine = ResonanceEngine::new(config);
+
+        // None of the fresh cells have ever been trained, so all sit at
+        // resonance_strength 0.0 -- well below the threshold, and none
+        // are connected yet, so the whole cluster is reclaimed.
+        let report = engine.prune_cells();
+
+        assert_eq!(report.reclaimed, 4);
+        assert_eq!(report.total_cells, 4);
+    }
+
+    #[test]
+    fn test_to_dot_emits_valid_digraph_with_nodes_and_edges() {
+        let config = test_config();
+        let engine = ResonanceEngine::new(config.clone());
+
+        let sig1 = Array1::from_vec(vec![0.5; config.spectral_dim]);
+        let sig2 = Array1::from_vec(vec![0.7; config.spectral_dim]);
+        engine.learn_signature(&sig1);
+        engine.learn_signature(&sig2);
+        engine.maintenance_cycle();
+
+        let dot = engine.to_dot();
+
+        assert!(dot.starts_with("digraph gabriel_cluster {"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains("label=\"cell"));
+    }
+
+    #[test]
+    fn test_write_dot_matches_to_dot() {
+        let config = test_config();
+        let engine = ResonanceEngine::new(config.clone());
+        let sig = Array1::from_vec(vec![0.5; config.spectral_dim]);
+        engine.learn_signature(&sig);
+
+        let mut buffer = Vec::new();
+        engine.write_dot(&mut buffer).expect("writing dot should succeed");
+
+        assert_eq!(String::from_utf8(buffer).unwrap(), engine.to_dot());
+    }
+
+    #[test]
+    fn test_compute_scores_batch_matches_sequential_scores() {
+        let config = test_config();
+        let engine = ResonanceEngine::new(config.clone());
+
+        let signature = Array1::from_vec(vec![0.5; config.spectral_dim]);
+        engine.learn_signature(&signature);
+
+        let signatures: Vec<Array1<f64>> = (0..10)
+            .map(|i| Array1::from_vec(vec![0.5 + i as f64 * 0.01; config.spectral_dim]))
+            .collect();
+
+        let batch_scores = engine.compute_scores_batch(&signatures);
+        let sequential_scores: Vec<f64> = signatures
+            .iter()
+            .map(|sig| engine.compute_score(sig))
+            .collect();
+
+        assert_eq!(batch_scores, sequential_scores);
+    }
 }
@@ -0,0 +1,250 @@
+//! Pluggable scoring backends for [`crate::decision::DecisionEngine`].
+//!
+//! `DecisionEngine::decide` only ever compares a bare scalar score
+//! against a threshold; it doesn't care where that score came from. The
+//! [`Scorer`] trait makes that scalar pluggable so the decision layer
+//! can be backed by different models: the existing unsupervised k-NN
+//! resonance score ([`crate::resonance::ResonanceEngine`]), or a
+//! supervised classifier such as [`GbdtScorer`] trained offline on
+//! labeled traffic.
+
+use anyhow::{Context, Result};
+use gbdt::config::Config;
+use gbdt::decision_tree::{Data, DataVec};
+use gbdt::gradient_boost::GBDT;
+use ndarray::Array1;
+use std::path::Path;
+
+/// Produces a calibrated score for a feature vector.
+///
+/// Higher scores mean "more likely legitimate", matching the convention
+/// already used by [`crate::resonance::ResonanceEngine::compute_score`]
+/// so a [`Scorer`] output can feed `DecisionEngine::decide` unchanged.
+pub trait Scorer {
+    /// Scores a single feature vector.
+    fn score(&self, features: &[f64]) -> f64;
+}
+
+/// Gradient-boosted decision tree scorer, backed by the `gbdt` crate.
+///
+/// Trains offline on a labeled dataset of feature vectors -- the FFT +
+/// statistical features `SpectralEngine::create_signature` extracts per
+/// circuit -- with legitimate circuits as the positive class and attack
+/// circuits as the negative class, producing a calibrated probability
+/// that feeds directly into `DecisionEngine::decide`'s existing
+/// threshold logic. This gives a supervised alternative to the
+/// unsupervised k-NN resonance path, and a baseline to benchmark it
+/// against.
+pub struct GbdtScorer {
+    model: GBDT,
+}
+
+impl GbdtScorer {
+    /// Trains a new scorer from labeled feature vectors.
+    ///
+    /// Each entry pairs a feature vector with `true` for legitimate
+    /// traffic and `false` for a known attack.
+    pub fn train(features: &[(Vec<f64>, bool)]) -> Result<Self> {
+        let feature_size = features
+            .first()
+            .map(|(vector, _)| vector.len())
+            .context("cannot train a scorer on an empty dataset")?;
+
+        let mut config = Config::new();
+        config.set_feature_size(feature_size);
+        config.set_max_depth(5);
+        config.set_iterations(50);
+        config.set_shrinkage(0.1);
+        config.set_loss("LogLikelyhood");
+
+        let mut data: DataVec = features
+            .iter()
+            .map(|(vector, is_legitimate)| {
+                let label = if *is_legitimate { 1.0 } else { 0.0 };
+                Data {
+                    feature: vector.clone(),
+                    target: label,
+                    weight: 1.0,
+                    label,
+                    residual: 0.0,
+                    initial_guess: 0.0,
+                }
+            })
+            .collect();
+
+        let mut model = GBDT::new(&config);
+        model.fit(&mut data);
+
+        Ok(Self { model })
+    }
+
+    /// Loads a previously fitted model from disk, so an operator can
+    /// ship a pre-trained detector instead of training on the fly.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_str().context("model path is not valid UTF-8")?;
+        let model = GBDT::load_model(path).context("failed to load GBDT model")?;
+        Ok(Self { model })
+    }
+
+    /// Serializes the fitted model to disk.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref().to_str().context("model path is not valid UTF-8")?;
+        self.model.save_model(path);
+        Ok(())
+    }
+}
+
+impl Scorer for GbdtScorer {
+    fn score(&self, features: &[f64]) -> f64 {
+        let data: DataVec = vec![Data {
+            feature: features.to_vec(),
+            target: 0.0,
+            weight: 1.0,
+            label: 0.0,
+            residual: 0.0,
+            initial_guess: 0.0,
+        }];
+
+        self.model.predict(&data).first().copied().unwrap_or(0.0)
+    }
+}
+
+/// Supervised classifier trained directly on `SpectralEngine::create_signature`
+/// output, with labeled corpora phrased the way operators think about
+/// them: "patterns" (circuits to forward) and "anti-patterns" (circuits
+/// to absorb).
+///
+/// A thin [`Array1<f64>`]-typed wrapper around [`GbdtScorer`] -- the
+/// same gradient-boosted ensemble, just with the signature's native
+/// `ndarray` type instead of a bare `Vec<f64>`/`&[f64]`, since signatures
+/// (unlike the generic feature vectors [`GbdtScorer`] also accepts from
+/// e.g. `SpectralEngine::extract_features`) always arrive as
+/// `Array1<f64>`. `predict_proba` feeds
+/// `DecisionEngine::decide_blended`, which mixes it with the
+/// unsupervised resonance score instead of replacing it outright.
+pub struct GbdtClassifier {
+    scorer: GbdtScorer,
+}
+
+impl GbdtClassifier {
+    /// Trains a new classifier from labeled signatures: `true` marks a
+    /// pattern (forward), `false` an anti-pattern (absorb).
+    pub fn train(samples: &[(Array1<f64>, bool)]) -> Result<Self> {
+        let features: Vec<(Vec<f64>, bool)> = samples
+            .iter()
+            .map(|(signature, is_pattern)| (signature.to_vec(), *is_pattern))
+            .collect();
+
+        Ok(Self {
+            scorer: GbdtScorer::train(&features)?,
+        })
+    }
+
+    /// Predicts the probability that `signature` is a pattern (i.e.
+    /// should be forwarded).
+    pub fn predict_proba(&self, signature: &Array1<f64>) -> f64 {
+        self.scorer.score(signature.as_slice().unwrap_or(&[]))
+    }
+
+    /// Loads a previously fitted classifier from disk.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self {
+            scorer: GbdtScorer::load(path)?,
+        })
+    }
+
+    /// Serializes the fitted classifier to disk.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.scorer.save(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labeled_dataset() -> Vec<(Vec<f64>, bool)> {
+        vec![
+            (vec![0.1, 0.1, 0.1], true),
+            (vec![0.15, 0.05, 0.2], true),
+            (vec![0.2, 0.1, 0.1], true),
+            (vec![0.9, 0.8, 0.95], false),
+            (vec![0.85, 0.9, 0.8], false),
+            (vec![0.95, 0.85, 0.9], false),
+        ]
+    }
+
+    #[test]
+    fn test_train_and_score_separates_classes() {
+        let dataset = labeled_dataset();
+        let scorer = GbdtScorer::train(&dataset).expect("training should succeed");
+
+        let legit_score = scorer.score(&[0.12, 0.08, 0.15]);
+        let attack_score = scorer.score(&[0.9, 0.85, 0.9]);
+
+        assert!(legit_score > attack_score);
+    }
+
+    #[test]
+    fn test_train_rejects_empty_dataset() {
+        assert!(GbdtScorer::train(&[]).is_err());
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dataset = labeled_dataset();
+        let scorer = GbdtScorer::train(&dataset).expect("training should succeed");
+
+        let path = std::env::temp_dir().join("ophanion_gbdt_scorer_test.model");
+        scorer.save(&path).expect("saving should succeed");
+
+        let loaded = GbdtScorer::load(&path).expect("loading should succeed");
+        let original_score = scorer.score(&[0.12, 0.08, 0.15]);
+        let loaded_score = loaded.score(&[0.12, 0.08, 0.15]);
+
+        assert!((original_score - loaded_score).abs() < 1e-9);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    fn labeled_signatures() -> Vec<(Array1<f64>, bool)> {
+        labeled_dataset()
+            .into_iter()
+            .map(|(vector, is_pattern)| (Array1::from_vec(vector), is_pattern))
+            .collect()
+    }
+
+    #[test]
+    fn test_gbdt_classifier_separates_patterns_from_anti_patterns() {
+        let dataset = labeled_signatures();
+        let classifier = GbdtClassifier::train(&dataset).expect("training should succeed");
+
+        let pattern_proba = classifier.predict_proba(&Array1::from_vec(vec![0.12, 0.08, 0.15]));
+        let anti_pattern_proba = classifier.predict_proba(&Array1::from_vec(vec![0.9, 0.85, 0.9]));
+
+        assert!(pattern_proba > anti_pattern_proba);
+    }
+
+    #[test]
+    fn test_gbdt_classifier_rejects_empty_dataset() {
+        assert!(GbdtClassifier::train(&[]).is_err());
+    }
+
+    #[test]
+    fn test_gbdt_classifier_save_and_load_roundtrip() {
+        let dataset = labeled_signatures();
+        let classifier = GbdtClassifier::train(&dataset).expect("training should succeed");
+
+        let path = std::env::temp_dir().join("ophanion_gbdt_classifier_test.model");
+        classifier.save(&path).expect("saving should succeed");
+
+        let loaded = GbdtClassifier::load(&path).expect("loading should succeed");
+        let signature = Array1::from_vec(vec![0.12, 0.08, 0.15]);
+        let original_proba = classifier.predict_proba(&signature);
+        let loaded_proba = loaded.predict_proba(&signature);
+
+        assert!((original_proba - loaded_proba).abs() < 1e-9);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
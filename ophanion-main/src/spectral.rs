@@ -1,16 +1,67 @@
-use crate::{Spectrum, TorCircuitMetadata};
+use crate::config::OphanionSettings;
+use crate::{Spectrum, TorCellType, TorCircuitMetadata};
 use num_complex::Complex64;
 use rustfft::{FftPlanner, num_complex};
 use ndarray::Array1;
+use std::f64::consts::PI;
+
+/// Number of [`TorCellType`] variants, i.e. the length of the cell-type
+/// histogram block appended to the spectral signature.
+const NUM_CELL_TYPES: usize = 6;
 
 pub struct SpectralEngine {
     planner: FftPlanner<f64>,
+    /// Window length the inter-cell-gap series is resampled/padded to
+    /// before the Hann window and forward FFT are applied. Also used as
+    /// the Welch segment length `L` when `use_welch_psd` is enabled.
+    fft_len: usize,
+    /// Number of low-frequency FFT magnitude bins kept in the signature.
+    fft_num_bins: usize,
+    /// Target dimension of the final, L2-normalized signature.
+    spectral_dim: usize,
+    /// Whether [`Self::fft_magnitude_bins`] averages a Welch periodogram
+    /// over overlapping segments instead of a single windowed FFT.
+    use_welch_psd: bool,
+    /// Hop size (cells) [`Self::compute_spectrogram`] and
+    /// [`Self::create_signature_stream`] advance their sliding analysis
+    /// frame by between successive slices.
+    stft_hop_len: usize,
+}
+
+impl Clone for SpectralEngine {
+    /// Clones the engine's configuration but not its `FftPlanner`,
+    /// which is neither `Clone` nor `Sync`; each clone gets its own
+    /// fresh planner, which is exactly what [`Self::create_signatures_batch`]
+    /// needs to give every rayon worker thread an independent planner.
+    fn clone(&self) -> Self {
+        Self {
+            planner: FftPlanner::new(),
+            fft_len: self.fft_len,
+            fft_num_bins: self.fft_num_bins,
+            spectral_dim: self.spectral_dim,
+            use_welch_psd: self.use_welch_psd,
+            stft_hop_len: self.stft_hop_len,
+        }
+    }
 }
 
 impl SpectralEngine {
     pub fn new() -> Self {
+        Self::with_settings(&OphanionSettings::default())
+    }
+
+    /// Builds an engine whose FFT window length, bin count, and output
+    /// dimension are driven by `settings` rather than hard-coded
+    /// defaults, so deployments can tune the frequency/time-domain
+    /// trade-off via `OphanionConfig`.
+    pub fn with_settings(settings: &OphanionSettings) -> Self {
         Self {
             planner: FftPlanner::new(),
+            fft_len: settings.fft_len,
+            fft_num_bins: settings.fft_num_bins.min(settings.fft_len),
+            spectral_dim: settings.spectral_dim,
+            use_welch_psd: settings.use_welch_psd,
+            stft_hop_len: settings.stft_hop_len,
         }
     }
     
@@ -56,7 +107,300 @@ impl SpectralEngine {
             amplitudes,
         }
     }
-    
+
+    /// Matches `circuit` against a library of known fingerprints,
+    /// returning the `(index, correlation_peak)` of the best-matching
+    /// template.
+    ///
+    /// Computes `circuit`'s own fingerprint via [`Self::compute_fingerprint`]
+    /// and runs [`Self::spectral_correlation`] against every template,
+    /// keeping the template with the highest correlation peak. A
+    /// shift-invariant match, so a re-used timing pattern is detected
+    /// even when its cells are offset in time relative to the library
+    /// entry. Returns `(0, 0.0)` for an empty library.
+    pub fn match_against_library(
+        &mut self,
+        circuit: &TorCircuitMetadata,
+        library: &[Spectrum],
+    ) -> (usize, f64) {
+        if library.is_empty() {
+            return (0, 0.0);
+        }
+
+        let query = self.compute_fingerprint(circuit);
+
+        let mut best_index = 0;
+        let mut best_peak = f64::MIN;
+
+        for (index, template) in library.iter().enumerate() {
+            let (_, peak) = self.spectral_correlation(&query, template);
+            if peak > best_peak {
+                best_peak = peak;
+                best_index = index;
+            }
+        }
+
+        (best_index, best_peak)
+    }
+
+    /// Shift-invariant matched-filter similarity between two spectra
+    /// via the frequency-domain cross-correlation identity.
+    ///
+    /// Resamples both magnitude spectra to a common length `N`, forms
+    /// the element-wise product `A_k * conj(B_k)`, inverse-FFTs it, and
+    /// normalizes by `N`; the maximum magnitude of the resulting
+    /// sequence is a shift-invariant similarity score, and its index is
+    /// the timing offset at which the two patterns best align. Since
+    /// [`Spectrum`] stores only magnitudes (no phase), both operands are
+    /// treated as real-valued sequences (zero imaginary part) -- this
+    /// still yields a valid shift-invariant correlation over the
+    /// *magnitude* spectra, trading away the true phase-alignment
+    /// information a raw complex FFT would carry.
+    ///
+    /// Returns `(peak_index, peak_magnitude)`.
+    fn spectral_correlation(&mut self, a: &Spectrum, b: &Spectrum) -> (usize, f64) {
+        let n = a.amplitudes.len().max(b.amplitudes.len()).max(1);
+
+        let mut buffer: Vec<Complex64> = Self::resample_to_fixed_length(&a.amplitudes, n)
+            .into_iter()
+            .zip(Self::resample_to_fixed_length(&b.amplitudes, n))
+            .map(|(x, y)| Complex64::new(x, 0.0) * Complex64::new(y, 0.0).conj())
+            .collect();
+
+        let ifft = self.planner.plan_fft_inverse(n);
+        ifft.process(&mut buffer);
+
+        let normalization = n as f64;
+        buffer
+            .iter()
+            .map(|c| c.norm() / normalization)
+            .enumerate()
+            .fold((0usize, f64::MIN), |(best_idx, best_val), (idx, val)| {
+                if val > best_val {
+                    (idx, val)
+                } else {
+                    (best_idx, best_val)
+                }
+            })
+    }
+
+    /// Compute a leakage-reduced spectral fingerprint via Welch's method
+    ///
+    /// Splits the (zero-padded if necessary) raw `cell_timings` series
+    /// into segments of length `fft_len` with 50% overlap, Hann-windows
+    /// each segment, FFTs it, and averages the squared-magnitude
+    /// periodograms across all segments, normalizing by the window
+    /// power `Σw[n]²` to keep the estimate unbiased. This trades the
+    /// frequency resolution of [`Self::compute_fingerprint`]'s single
+    /// raw FFT for far lower variance, which matters most for short,
+    /// noisy circuits. Falls back to a single windowed FFT when the
+    /// series is shorter than `fft_len`.
+    pub fn compute_psd(&mut self, circuit: &TorCircuitMetadata) -> Spectrum {
+        let timings: Vec<f64> = circuit
+            .cell_timings
+            .iter()
+            .map(|d| d.as_secs_f64())
+            .collect();
+
+        if timings.is_empty() {
+            return Spectrum {
+                frequencies: vec![0.0],
+                amplitudes: vec![0.0],
+            };
+        }
+
+        let psd = self.welch_psd(&timings);
+
+        let frequencies: Vec<f64> = (0..psd.len())
+            .map(|i| i as f64 / psd.len() as f64)
+            .collect();
+        let amplitudes: Vec<f64> = psd.iter().map(|&p| p.sqrt()).collect();
+
+        Spectrum {
+            frequencies,
+            amplitudes,
+        }
+    }
+
+    /// Averages squared-magnitude periodograms of overlapping,
+    /// Hann-windowed segments of `series` (Welch's method). Segment
+    /// length is `self.fft_len`, overlap is 50%. Falls back to
+    /// [`Self::single_segment_psd`] when `series` is shorter than one
+    /// segment.
+    fn welch_psd(&mut self, series: &[f64]) -> Vec<f64> {
+        let segment_len = self.fft_len.max(2);
+
+        if series.len() < segment_len {
+            return self.single_segment_psd(series, segment_len);
+        }
+
+        let hann = Self::hann_window(segment_len);
+        let window_power: f64 = hann.iter().map(|w| w * w).sum();
+        let step = (segment_len / 2).max(1);
+
+        let fft = self.planner.plan_fft_forward(segment_len);
+        let mut accum = vec![0.0; segment_len];
+        let mut num_segments = 0usize;
+
+        let mut start = 0;
+        while start + segment_len <= series.len() {
+            let mut buffer: Vec<Complex64> = series[start..start + segment_len]
+                .iter()
+                .zip(hann.iter())
+                .map(|(&x, &w)| Complex64::new(x * w, 0.0))
+                .collect();
+
+            fft.process(&mut buffer);
+            for (acc, c) in accum.iter_mut().zip(buffer.iter()) {
+                *acc += c.norm_sqr();
+            }
+
+            num_segments += 1;
+            start += step;
+        }
+
+        if num_segments == 0 {
+            return self.single_segment_psd(series, segment_len);
+        }
+
+        let scale = num_segments as f64 * window_power;
+        accum.iter_mut().for_each(|p| *p /= scale);
+        accum
+    }
+
+    /// Single-segment fallback for [`Self::welch_psd`]: resamples/pads
+    /// `series` to `segment_len`, Hann-windows, FFTs, and returns the
+    /// window-power-normalized squared magnitudes.
+    fn single_segment_psd(&mut self, series: &[f64], segment_len: usize) -> Vec<f64> {
+        let hann = Self::hann_window(segment_len);
+        let window_power: f64 = hann.iter().map(|w| w * w).sum::<f64>().max(1e-12);
+
+        let resampled = Self::resample_to_fixed_length(series, segment_len);
+        let mut buffer: Vec<Complex64> = resampled
+            .iter()
+            .zip(hann.iter())
+            .map(|(&x, &w)| Complex64::new(x * w, 0.0))
+            .collect();
+
+        let fft = self.planner.plan_fft_forward(segment_len);
+        fft.process(&mut buffer);
+
+        buffer
+            .iter()
+            .map(|c| c.norm_sqr() / window_power)
+            .collect()
+    }
+
+    /// Hann window `w[n] = 0.5 - 0.5*cos(2πn/(L-1))` of length `len`.
+    fn hann_window(len: usize) -> Vec<f64> {
+        let denom = (len.max(2) - 1) as f64;
+        (0..len)
+            .map(|n| 0.5 - 0.5 * (2.0 * PI * n as f64 / denom).cos())
+            .collect()
+    }
+
+    /// Computes a time-resolved spectrogram instead of one fingerprint
+    /// for the whole circuit: slides a Hann-windowed frame of
+    /// `window_len` raw `cell_timings` samples across the series,
+    /// advancing by `hop` cells between frames, and FFTs each frame. The
+    /// final frame is zero-padded if the series doesn't divide evenly
+    /// (or is shorter than one window), so every circuit yields at
+    /// least one slice. Lets long-lived circuits be scored as they
+    /// evolve via [`Self::create_signature_stream`], rather than only
+    /// once at teardown as with [`Self::compute_fingerprint`].
+    pub fn compute_spectrogram(
+        &mut self,
+        circuit: &TorCircuitMetadata,
+        window_len: usize,
+        hop: usize,
+    ) -> Vec<Spectrum> {
+        let timings: Vec<f64> = circuit
+            .cell_timings
+            .iter()
+            .map(|d| d.as_secs_f64())
+            .collect();
+
+        if timings.is_empty() {
+            return Vec::new();
+        }
+
+        let window_len = window_len.max(2);
+        let hop = hop.max(1);
+        let hann = Self::hann_window(window_len);
+        let fft = self.planner.plan_fft_forward(window_len);
+
+        let mut spectra = Vec::new();
+        let mut start = 0;
+        loop {
+            let end = (start + window_len).min(timings.len());
+
+            let mut frame = vec![0.0; window_len];
+            frame[..end - start].copy_from_slice(&timings[start..end]);
+
+            let mut buffer: Vec<Complex64> = frame
+                .iter()
+                .zip(hann.iter())
+                .map(|(&x, &w)| Complex64::new(x * w, 0.0))
+                .collect();
+            fft.process(&mut buffer);
+
+            let frequencies: Vec<f64> = (0..window_len)
+                .map(|i| i as f64 / window_len as f64)
+                .collect();
+            let amplitudes: Vec<f64> = buffer.iter().map(|c| c.norm()).collect();
+            spectra.push(Spectrum {
+                frequencies,
+                amplitudes,
+            });
+
+            if end >= timings.len() {
+                break;
+            }
+            start += hop;
+        }
+
+        spectra
+    }
+
+    /// Companion to [`Self::compute_spectrogram`]: emits one
+    /// `spectral_dim`-dimensional, L2-normalized signature per sliding
+    /// frame (window `fft_len`, hop `stft_hop_len`) instead of a single
+    /// whole-circuit signature, so `DecisionEngine` can re-decide
+    /// Forward/Absorb online and catch mid-circuit behavior changes a
+    /// single whole-circuit FFT would average away.
+    pub fn create_signature_stream(&mut self, circuit: &TorCircuitMetadata) -> Vec<Array1<f64>> {
+        let timings: Vec<f64> = circuit
+            .cell_timings
+            .iter()
+            .map(|d| d.as_secs_f64())
+            .collect();
+
+        if timings.is_empty() {
+            return Vec::new();
+        }
+
+        let window_len = self.fft_len.max(2);
+        let hop = self.stft_hop_len.max(1);
+
+        let mut signatures = Vec::new();
+        let mut start = 0;
+        loop {
+            let end = (start + window_len).min(timings.len());
+            signatures.push(self.build_signature(
+                &timings[start..end],
+                &circuit.cell_types,
+                circuit.total_bytes,
+            ));
+
+            if end >= timings.len() {
+                break;
+            }
+            start += hop;
+        }
+
+        signatures
+    }
+
     /// Extract additional statistical features
     pub fn extract_features(&self, circuit: &TorCircuitMetadata) -> Vec<f64> {
         let timings: Vec<f64> = circuit.cell_timings
@@ -98,28 +442,77 @@ impl SpectralEngine {
         vec![mean, std_dev, min, max, iqr, variance, duration, bytes_per_sec]
     }
     
-    /// Combine spectral and statistical features into unified signature
+    /// Combine principled frequency-domain and statistical features into
+    /// a unified signature.
+    ///
+    /// The inter-cell-gap series (consecutive differences of
+    /// `cell_timings`) is resampled/padded to `fft_len`, Hann-windowed,
+    /// and run through a forward FFT; the magnitudes of the first
+    /// `fft_num_bins` bins make up the frequency-domain block. Uniform
+    /// "bot" timings collapse almost all of their energy into the lowest
+    /// bin, while human browsing spreads energy across bins, giving the
+    /// resonance layer a far sharper separation than raw timing values.
+    /// A small block of statistical moments (mean, std, min, max of the
+    /// gaps), `total_bytes`, and a [`TorCellType`] histogram are
+    /// appended; the concatenated vector is L2-normalized and
+    /// padded/truncated to `spectral_dim`.
     pub fn create_signature(&mut self, circuit: &TorCircuitMetadata) -> Array1<f64> {
-        let spectrum = self.compute_fingerprint(circuit);
-        let features = self.extract_features(circuit);
-        
-        // Take dominant frequency components + statistical features
-        let n_freq = 120; // Use first 120 frequency bins
-        let mut signature = Vec::with_capacity(n_freq + features.len());
-        
-        signature.extend(
-            spectrum.amplitudes
-                .iter()
-                .take(n_freq)
-                .cloned()
-        );
-        signature.extend(features);
-        
-        // Pad to standard dimension if needed
-        while signature.len() < 128 {
+        let timings: Vec<f64> = circuit
+            .cell_timings
+            .iter()
+            .map(|d| d.as_secs_f64())
+            .collect();
+
+        self.build_signature(&timings, &circuit.cell_types, circuit.total_bytes)
+    }
+
+    /// Computes signatures for a whole slice of circuits in parallel via
+    /// rayon's `par_iter`.
+    ///
+    /// `FftPlanner` is neither `Clone` nor `Sync`, so each parallel task
+    /// works on its own [`Self::clone`] of the engine (configuration
+    /// only, fresh planner) rather than sharing `self.planner` across
+    /// threads -- effectively a thread-local planner per circuit/chunk.
+    /// Takes `&self` rather than `&mut self` so relays can call this
+    /// from a shared `SpectralEngine` even though signature creation is
+    /// internally mutable.
+    pub fn create_signatures_batch(&self, circuits: &[TorCircuitMetadata]) -> Vec<Array1<f64>> {
+        use rayon::prelude::*;
+
+        circuits
+            .par_iter()
+            .map(|circuit| {
+                let mut engine = self.clone();
+                engine.create_signature(circuit)
+            })
+            .collect()
+    }
+
+    /// Shared signature-assembly core behind [`Self::create_signature`]
+    /// and [`Self::create_signature_stream`]: takes raw inter-cell
+    /// timings, cell types, and a byte count directly (instead of a
+    /// whole [`TorCircuitMetadata`]) so the streaming path can build one
+    /// signature per sliding frame of a long-lived circuit.
+    fn build_signature(
+        &mut self,
+        timings: &[f64],
+        cell_types: &[TorCellType],
+        total_bytes: u64,
+    ) -> Array1<f64> {
+        let gaps = Self::gaps_from_timings(timings);
+
+        let mut signature = Vec::with_capacity(self.spectral_dim);
+        signature.extend(self.fft_magnitude_bins(&gaps));
+        signature.extend(Self::gap_moments(&gaps));
+        signature.push(total_bytes as f64);
+        signature.extend(Self::cell_type_histogram_from_slice(cell_types));
+
+        // Pad/truncate to the configured signature dimension
+        signature.truncate(self.spectral_dim);
+        while signature.len() < self.spectral_dim {
             signature.push(0.0);
         }
-        
+
         // Normalize signature
         let norm = signature.iter().map(|x| x * x).sum::<f64>().sqrt();
         if norm > 0.0 {
@@ -127,10 +520,101 @@ impl SpectralEngine {
                 *x /= norm;
             }
         }
-        
+
         Array1::from_vec(signature)
     }
-    
+
+    /// Converts a circuit's cell-timing series into inter-cell gaps
+    /// (seconds elapsed between consecutive cells).
+    fn inter_cell_gaps(circuit: &TorCircuitMetadata) -> Vec<f64> {
+        let timings: Vec<f64> = circuit
+            .cell_timings
+            .iter()
+            .map(|d| d.as_secs_f64())
+            .collect();
+
+        Self::gaps_from_timings(&timings)
+    }
+
+    /// Consecutive differences of a raw timing series.
+    fn gaps_from_timings(timings: &[f64]) -> Vec<f64> {
+        if timings.len() < 2 {
+            return Vec::new();
+        }
+
+        timings.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+
+    /// Resamples/pads `series` to exactly `target_len` samples: shorter
+    /// series are zero-padded, longer series are decimated to evenly
+    /// spaced samples.
+    fn resample_to_fixed_length(series: &[f64], target_len: usize) -> Vec<f64> {
+        if series.len() <= target_len {
+            let mut resampled = series.to_vec();
+            resampled.resize(target_len, 0.0);
+            resampled
+        } else {
+            (0..target_len)
+                .map(|i| series[i * series.len() / target_len])
+                .collect()
+        }
+    }
+
+    /// Returns the magnitudes of the first `fft_num_bins` bins of the
+    /// inter-cell-gap series' spectrum: either a single Hann-windowed
+    /// FFT, or (when `use_welch_psd` is set) an averaged Welch
+    /// periodogram, which gives a much more stable signature for short,
+    /// noisy circuits at the cost of frequency resolution.
+    fn fft_magnitude_bins(&mut self, gaps: &[f64]) -> Vec<f64> {
+        if gaps.is_empty() {
+            return vec![0.0; self.fft_num_bins];
+        }
+
+        let magnitudes: Vec<f64> = if self.use_welch_psd {
+            self.welch_psd(gaps).iter().map(|p| p.sqrt()).collect()
+        } else {
+            self.single_segment_psd(gaps, self.fft_len)
+                .iter()
+                .map(|p| p.sqrt())
+                .collect()
+        };
+
+        magnitudes.into_iter().take(self.fft_num_bins).collect()
+    }
+
+    /// Statistical moments (mean, std, min, max) of the inter-cell gaps.
+    fn gap_moments(gaps: &[f64]) -> Vec<f64> {
+        if gaps.is_empty() {
+            return vec![0.0; 4];
+        }
+
+        let n = gaps.len() as f64;
+        let mean = gaps.iter().sum::<f64>() / n;
+        let variance = gaps.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let std_dev = variance.sqrt();
+        let min = gaps.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = gaps.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        vec![mean, std_dev, min, max]
+    }
+
+    /// Counts of each [`TorCellType`] observed in `cell_types`.
+    fn cell_type_histogram_from_slice(cell_types: &[TorCellType]) -> Vec<f64> {
+        let mut hist = [0.0f64; NUM_CELL_TYPES];
+        for cell_type in cell_types {
+            let idx = match cell_type {
+                TorCellType::Introduce2 => 0,
+                TorCellType::Rendezvous1 => 1,
+                TorCellType::Rendezvous2 => 2,
+                TorCellType::Data => 3,
+                TorCellType::Padding => 4,
+                TorCellType::Other => 5,
+            };
+            hist[idx] += 1.0;
+        }
+        hist.to_vec()
+    }
+
     /// Compute entropy of timing distribution
     pub fn timing_entropy(&self, circuit: &TorCircuitMetadata) -> f64 {
         let timings: Vec<f64> = circuit.cell_timings
@@ -237,10 +721,296 @@ mod tests {
     fn test_timing_entropy() {
         let engine = SpectralEngine::new();
         let circuit = create_test_circuit();
-        
+
         let entropy = engine.timing_entropy(&circuit);
-        
+
         assert!(entropy >= 0.0);
         assert!(entropy <= 10.0_f64.log2());
     }
+
+    fn create_circuit_with_timings(timings_ms: &[u64]) -> TorCircuitMetadata {
+        TorCircuitMetadata {
+            circuit_id: 2,
+            created_at: Instant::now(),
+            cell_timings: timings_ms
+                .iter()
+                .map(|&ms| Duration::from_millis(ms))
+                .collect(),
+            cell_types: vec![],
+            introduction_point: None,
+            rendezvous_completed: false,
+            total_bytes: 1000,
+        }
+    }
+
+    /// Energy spread of the first `fft_num_bins` FFT magnitudes, as the
+    /// fraction of total bin energy NOT in the lowest (DC-adjacent) bin.
+    fn spectral_spread(engine: &mut SpectralEngine, circuit: &TorCircuitMetadata) -> f64 {
+        let gaps = SpectralEngine::inter_cell_gaps(circuit);
+        let bins = engine.fft_magnitude_bins(&gaps);
+        let total: f64 = bins.iter().map(|b| b * b).sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        1.0 - (bins[0] * bins[0]) / total
+    }
+
+    #[test]
+    fn test_uniform_timings_concentrate_in_low_frequency_bin() {
+        let mut engine = SpectralEngine::new();
+
+        let uniform_timings: Vec<u64> = (0..40).map(|i| i * 10).collect();
+        let uniform_circuit = create_circuit_with_timings(&uniform_timings);
+
+        let varied_timings = vec![
+            5, 37, 9, 120, 41, 3, 88, 15, 60, 2, 95, 18, 44, 7, 130, 21, 66, 4, 102, 30, 11, 77,
+            23, 91, 6, 54, 112, 17, 39, 84, 8, 126, 28, 61, 13, 98, 45, 20, 70, 34,
+        ];
+        let varied_circuit = create_circuit_with_timings(&varied_timings);
+
+        let uniform_spread = spectral_spread(&mut engine, &uniform_circuit);
+        let varied_spread = spectral_spread(&mut engine, &varied_circuit);
+
+        assert!(
+            varied_spread > uniform_spread,
+            "human-like varied timings ({varied_spread}) should spread more energy \
+             across bins than uniform bot timings ({uniform_spread})"
+        );
+    }
+
+    #[test]
+    fn test_create_signature_respects_settings() {
+        let mut settings = crate::config::OphanionSettings::default();
+        settings.fft_len = 32;
+        settings.fft_num_bins = 8;
+        settings.spectral_dim = 64;
+
+        let mut engine = SpectralEngine::with_settings(&settings);
+        let circuit = create_test_circuit();
+
+        let signature = engine.create_signature(&circuit);
+
+        assert_eq!(signature.len(), 64);
+        let norm = signature.iter().map(|&x| x * x).sum::<f64>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_compute_psd_matches_compute_fingerprint_shape() {
+        let mut engine = SpectralEngine::new();
+        let circuit = create_test_circuit();
+
+        let psd = engine.compute_psd(&circuit);
+
+        assert_eq!(psd.frequencies.len(), psd.amplitudes.len());
+        assert!(psd.amplitudes.iter().all(|&a| a.is_finite() && a >= 0.0));
+    }
+
+    #[test]
+    fn test_compute_psd_falls_back_to_single_segment_for_short_series() {
+        let mut settings = crate::config::OphanionSettings::default();
+        settings.fft_len = 64;
+        let mut engine = SpectralEngine::with_settings(&settings);
+
+        // Far fewer samples than one Welch segment.
+        let circuit = create_circuit_with_timings(&[5, 10, 15, 20]);
+        let psd = engine.compute_psd(&circuit);
+
+        assert_eq!(psd.amplitudes.len(), 64);
+        assert!(psd.amplitudes.iter().any(|&a| a > 0.0));
+    }
+
+    #[test]
+    fn test_compute_psd_averages_multiple_overlapping_segments() {
+        let mut settings = crate::config::OphanionSettings::default();
+        settings.fft_len = 16;
+        let mut engine = SpectralEngine::with_settings(&settings);
+
+        // 64 samples with a segment length of 16 and 50% overlap spans
+        // several segments, exercising the averaging path rather than
+        // the single-segment fallback.
+        let timings: Vec<u64> = (0..64).map(|i| i * 7).collect();
+        let circuit = create_circuit_with_timings(&timings);
+
+        let psd = engine.compute_psd(&circuit);
+
+        assert_eq!(psd.amplitudes.len(), 16);
+        assert!(psd.amplitudes.iter().all(|&a| a.is_finite()));
+    }
+
+    #[test]
+    fn test_use_welch_psd_flag_changes_signature_bins() {
+        let mut settings = crate::config::OphanionSettings::default();
+        settings.fft_len = 16;
+        settings.fft_num_bins = 4;
+
+        let timings: Vec<u64> = vec![
+            5, 37, 9, 120, 41, 3, 88, 15, 60, 2, 95, 18, 44, 7, 130, 21, 66, 4, 102, 30, 11, 77,
+            23, 91, 6, 54, 112, 17, 39, 84, 8, 126, 28, 61, 13, 98, 45, 20, 70, 34,
+        ];
+        let circuit = create_circuit_with_timings(&timings);
+
+        settings.use_welch_psd = false;
+        let mut raw_engine = SpectralEngine::with_settings(&settings);
+        let raw_signature = raw_engine.create_signature(&circuit);
+
+        settings.use_welch_psd = true;
+        let mut welch_engine = SpectralEngine::with_settings(&settings);
+        let welch_signature = welch_engine.create_signature(&circuit);
+
+        assert_eq!(raw_signature.len(), welch_signature.len());
+        assert!(raw_signature
+            .iter()
+            .zip(welch_signature.iter())
+            .any(|(a, b)| (a - b).abs() > 1e-9));
+    }
+
+    #[test]
+    fn test_compute_spectrogram_slides_across_long_circuit() {
+        let mut engine = SpectralEngine::new();
+        let timings: Vec<u64> = (0..100).map(|i| i * 5).collect();
+        let circuit = create_circuit_with_timings(&timings);
+
+        let spectrogram = engine.compute_spectrogram(&circuit, 32, 16);
+
+        assert!(spectrogram.len() > 1);
+        for spectrum in &spectrogram {
+            assert_eq!(spectrum.frequencies.len(), 32);
+            assert_eq!(spectrum.amplitudes.len(), 32);
+        }
+    }
+
+    #[test]
+    fn test_compute_spectrogram_yields_one_frame_for_short_circuit() {
+        let mut engine = SpectralEngine::new();
+        let circuit = create_circuit_with_timings(&[5, 10, 15]);
+
+        let spectrogram = engine.compute_spectrogram(&circuit, 32, 16);
+
+        assert_eq!(spectrogram.len(), 1);
+    }
+
+    #[test]
+    fn test_create_signature_stream_emits_normalized_frame_signatures() {
+        let mut settings = crate::config::OphanionSettings::default();
+        settings.fft_len = 16;
+        settings.stft_hop_len = 8;
+        settings.spectral_dim = 32;
+
+        let mut engine = SpectralEngine::with_settings(&settings);
+        let timings: Vec<u64> = (0..64).map(|i| i * 3).collect();
+        let circuit = create_circuit_with_timings(&timings);
+
+        let stream = engine.create_signature_stream(&circuit);
+
+        assert!(stream.len() > 1);
+        for signature in &stream {
+            assert_eq!(signature.len(), 32);
+            let norm = signature.iter().map(|&x| x * x).sum::<f64>().sqrt();
+            assert!((norm - 1.0).abs() < 1e-6 || norm == 0.0);
+        }
+    }
+
+    #[test]
+    fn test_create_signature_stream_detects_mid_circuit_change() {
+        let mut settings = crate::config::OphanionSettings::default();
+        settings.fft_len = 16;
+        settings.stft_hop_len = 16;
+
+        let mut engine = SpectralEngine::with_settings(&settings);
+
+        let mut timings_ms = Vec::new();
+        // First half: uniform, bot-like gaps.
+        for i in 0..32 {
+            timings_ms.push(i * 10);
+        }
+        // Second half: bursty, human-like gaps.
+        let bursty = [3, 37, 9, 120, 41, 5, 88, 15, 60, 2, 95, 18, 44, 7, 130, 21];
+        let mut t = *timings_ms.last().unwrap();
+        for gap in bursty {
+            t += gap;
+            timings_ms.push(t);
+        }
+        let circuit = create_circuit_with_timings(&timings_ms);
+
+        let stream = engine.create_signature_stream(&circuit);
+        assert!(stream.len() >= 2);
+
+        let first = &stream[0];
+        let last = stream.last().unwrap();
+        let diff: f64 = first
+            .iter()
+            .zip(last.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt();
+
+        assert!(diff > 1e-3, "signature should shift once timing behavior changes");
+    }
+
+    #[test]
+    fn test_create_signatures_batch_matches_sequential_results() {
+        let engine = SpectralEngine::new();
+
+        let circuits: Vec<TorCircuitMetadata> = (0u64..20)
+            .map(|i| create_circuit_with_timings(&[5 + i, 10 + i, 15 + i, 20 + i]))
+            .collect();
+
+        let batch_signatures = engine.create_signatures_batch(&circuits);
+
+        let mut sequential_engine = SpectralEngine::new();
+        let sequential_signatures: Vec<Array1<f64>> = circuits
+            .iter()
+            .map(|circuit| sequential_engine.create_signature(circuit))
+            .collect();
+
+        assert_eq!(batch_signatures.len(), circuits.len());
+        for (batch, sequential) in batch_signatures.iter().zip(sequential_signatures.iter()) {
+            for (a, b) in batch.iter().zip(sequential.iter()) {
+                assert!((a - b).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spectral_correlation_matches_self_at_zero_offset() {
+        let mut engine = SpectralEngine::new();
+        let circuit = create_circuit_with_timings(&[5, 12, 9, 20, 7, 15]);
+
+        let spectrum = engine.compute_fingerprint(&circuit);
+        let (peak_index, peak_value) = engine.spectral_correlation(&spectrum, &spectrum);
+
+        assert_eq!(peak_index, 0, "a spectrum should match itself at zero offset");
+        assert!(peak_value > 0.0);
+    }
+
+    #[test]
+    fn test_match_against_library_picks_closest_template() {
+        let mut engine = SpectralEngine::new();
+
+        let matching_circuit = create_circuit_with_timings(&[5, 12, 9, 20, 7, 15]);
+        let template = engine.compute_fingerprint(&matching_circuit);
+
+        let unrelated_circuit = create_circuit_with_timings(&[1, 1, 1, 1, 1, 1]);
+        let decoy = engine.compute_fingerprint(&unrelated_circuit);
+
+        let library = vec![decoy, template];
+
+        let query_circuit = create_circuit_with_timings(&[5, 12, 9, 20, 7, 15]);
+        let (best_index, peak) = engine.match_against_library(&query_circuit, &library);
+
+        assert_eq!(best_index, 1, "query should match its own template, not the decoy");
+        assert!(peak > 0.0);
+    }
+
+    #[test]
+    fn test_match_against_library_handles_empty_library() {
+        let mut engine = SpectralEngine::new();
+        let circuit = create_test_circuit();
+
+        let (index, peak) = engine.match_against_library(&circuit, &[]);
+
+        assert_eq!(index, 0);
+        assert_eq!(peak, 0.0);
+    }
 }
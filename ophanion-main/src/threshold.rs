@@ -8,20 +8,28 @@ pub struct AdaptiveThreshold {
     config: OphanionSettings,
     absorption_history: Arc<RwLock<Vec<f64>>>,
     coherence_history: Arc<RwLock<Vec<f64>>>,
+    /// Accumulated absorption-rate error, anti-windup clamped to
+    /// `±config.pid_integral_limit` -- see [`Self::tune`].
+    pid_integral: Arc<RwLock<f64>>,
+    /// Absorption-rate error from the previous [`Self::tune`] call, used
+    /// for the PID derivative term.
+    pid_prev_error: Arc<RwLock<f64>>,
 }
 
 impl AdaptiveThreshold {
     pub fn new(config: OphanionSettings) -> Self {
         let threshold = Threshold::new(
-            config.initial_threshold, 
+            config.initial_threshold,
             config.threshold_learning_rate
         );
-        
+
         Self {
             threshold: Arc::new(RwLock::new(threshold)),
             config,
             absorption_history: Arc::new(RwLock::new(Vec::new())),
             coherence_history: Arc::new(RwLock::new(Vec::new())),
+            pid_integral: Arc::new(RwLock::new(0.0)),
+            pid_prev_error: Arc::new(RwLock::new(0.0)),
         }
     }
     
@@ -43,26 +51,62 @@ impl AdaptiveThreshold {
     pub fn record_absorption(&self, was_absorbed: bool) {
         let mut history = self.absorption_history.write();
         history.push(if was_absorbed { 1.0 } else { 0.0 });
-        
+
         if history.len() > 1000 {
             history.remove(0);
         }
+        drop(history);
+
+        self.tune();
     }
-    
+
     pub fn absorption_rate(&self) -> f64 {
         let history = self.absorption_history.read();
         if history.is_empty() {
             return 0.0;
         }
-        
+
         history.iter().sum::<f64>() / history.len() as f64
     }
-    
+
+    /// Runs one discrete PID step against `config.target_absorption_rate`:
+    /// `error = target_absorption_rate - absorption_rate()`, folded into
+    /// an anti-windup-clamped integral term and an error-delta
+    /// derivative term, then applied as
+    /// `Kp*error + Ki*integral + Kd*derivative`. A higher threshold
+    /// makes `DecisionEngine::decide` absorb less (it requires
+    /// `resonance_score > threshold`), so a positive error (absorbing
+    /// too little) *lowers* the threshold rather than raising it.
+    /// Called automatically from [`Self::record_absorption`]; exposed
+    /// separately so callers can also re-tune on a timer without a
+    /// fresh sample.
+    pub fn tune(&self) {
+        let error = self.config.target_absorption_rate - self.absorption_rate();
+
+        let mut integral_guard = self.pid_integral.write();
+        *integral_guard = (*integral_guard + error)
+            .clamp(-self.config.pid_integral_limit, self.config.pid_integral_limit);
+        let integral = *integral_guard;
+        drop(integral_guard);
+
+        let mut prev_error = self.pid_prev_error.write();
+        let derivative = error - *prev_error;
+        *prev_error = error;
+        drop(prev_error);
+
+        let correction =
+            self.config.pid_kp * error + self.config.pid_ki * integral + self.config.pid_kd * derivative;
+
+        let mut threshold = self.threshold.write();
+        threshold.value = (threshold.value - correction).clamp(0.0, 1.0);
+    }
+
     pub fn has_converged(&self) -> bool {
         let current_rate = self.absorption_rate();
         let target = self.config.target_absorption_rate;
-        
+
         (current_rate - target).abs() < 0.05
+            && self.pid_integral.read().abs() < self.config.convergence_epsilon
     }
 }
 
@@ -77,7 +121,23 @@ mod tests {
         
         threshold.update(0.8, 0.3);
         let value = threshold.value();
-        
+
         assert!(value >= 0.0 && value <= 1.0);
     }
+
+    #[test]
+    fn test_pid_tuning_converges_absorption_rate_to_target() {
+        let mut config = OphanionSettings::default();
+        config.target_absorption_rate = 0.5;
+        let threshold = AdaptiveThreshold::new(config);
+
+        // Every recorded absorption is well below target, so the PID
+        // controller should keep lowering the threshold.
+        for _ in 0..500 {
+            threshold.record_absorption(false);
+        }
+
+        assert!((threshold.absorption_rate() - 0.0).abs() < 1e-9);
+        assert!(threshold.value() < 0.5);
+    }
 }
@@ -0,0 +1,259 @@
+//! Tor control-port subsystem
+//!
+//! Wires the live control-port client in [`crate::tor_interface::TorInterface`]
+//! into the classification pipeline: each `650 CIRC`/`650 STREAM` event is
+//! turned into a spectral signature, scored against the
+//! [`ResonanceEngine`], and routed through the [`DecisionEngine`]'s
+//! per-introduction-point windowed decision. The control connection is
+//! watched by a periodic health check and reconnected (re-authenticating
+//! and re-subscribing) with exponential backoff if the Tor daemon drops or
+//! restarts it.
+
+use crate::{
+    circuit_monitor::CircuitMonitor, decision::DecisionEngine, resonance::ResonanceEngine,
+    spectral::SpectralEngine, threshold::AdaptiveThreshold, tor_interface::TorInterface,
+    CircuitAction, TorCircuitMetadata,
+};
+use anyhow::Result;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Initial delay before the first reconnect attempt after the control
+/// connection drops.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+/// Upper bound on the exponential reconnect backoff.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// Interval at which the read loop checks that the connection is alive.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+/// Number of nearest Gabriel Cells used to score and learn each circuit.
+const K_NEAREST: usize = 5;
+
+/// Drives live Tor circuit events through the spectral/resonance/decision
+/// classification pipeline.
+pub struct TorControlSubsystem {
+    interface: Arc<TorInterface>,
+    monitor: Arc<CircuitMonitor>,
+    spectral: Mutex<SpectralEngine>,
+    resonance: Arc<ResonanceEngine>,
+    threshold: Arc<AdaptiveThreshold>,
+    decision: Arc<Mutex<DecisionEngine>>,
+}
+
+impl TorControlSubsystem {
+    pub fn new(
+        interface: Arc<TorInterface>,
+        monitor: Arc<CircuitMonitor>,
+        resonance: Arc<ResonanceEngine>,
+        threshold: Arc<AdaptiveThreshold>,
+        decision: Arc<Mutex<DecisionEngine>>,
+    ) -> Self {
+        Self {
+            interface,
+            monitor,
+            spectral: Mutex::new(SpectralEngine::new()),
+            resonance,
+            threshold,
+            decision,
+        }
+    }
+
+    /// Runs the subscribe/read/classify loop until `shutdown` fires,
+    /// transparently reconnecting (re-authenticating and re-subscribing)
+    /// with exponential backoff if the control connection drops. Without
+    /// the `tor-control` feature this just waits for `shutdown` and
+    /// returns, matching [`TorInterface`]'s stub behavior.
+    pub async fn run(&self, mut shutdown: broadcast::Receiver<()>) -> Result<()> {
+        #[cfg(feature = "tor-control")]
+        {
+            self.run_live(&mut shutdown).await
+        }
+
+        #[cfg(not(feature = "tor-control"))]
+        {
+            tracing::info!(
+                "Tor control subsystem running in stub mode (enable the `tor-control` feature for a live client)"
+            );
+            let _ = shutdown.recv().await;
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "tor-control")]
+    async fn run_live(&self, shutdown: &mut broadcast::Receiver<()>) -> Result<()> {
+        let mut backoff = INITIAL_BACKOFF;
+
+        loop {
+            if let Err(err) = self.connect_and_subscribe().await {
+                tracing::warn!("Tor control connection failed: {err:#}; retrying in {backoff:?}");
+                tokio::select! {
+                    _ = tokio::time::sleep(backoff) => {}
+                    _ = shutdown.recv() => return Ok(()),
+                }
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+                continue;
+            }
+            backoff = INITIAL_BACKOFF;
+
+            if self.read_events_until_disconnect(shutdown).await? {
+                return Ok(());
+            }
+        }
+    }
+
+    #[cfg(feature = "tor-control")]
+    async fn connect_and_subscribe(&self) -> Result<()> {
+        self.interface.connect().await?;
+        self.interface.monitor_circuits().await?;
+        tracing::info!("Tor control connection established and subscribed to circuit events");
+        Ok(())
+    }
+
+    /// Reads events until the connection drops or `shutdown` fires.
+    /// Returns `Ok(true)` if shutdown was requested.
+    #[cfg(feature = "tor-control")]
+    async fn read_events_until_disconnect(
+        &self,
+        shutdown: &mut broadcast::Receiver<()>,
+    ) -> Result<bool> {
+        let mut health_check = tokio::time::interval(HEALTH_CHECK_INTERVAL);
+
+        loop {
+            tokio::select! {
+                event = self.interface.next_event() => {
+                    match event {
+                        Ok(Some(metadata)) => self.classify(metadata),
+                        Ok(None) => {
+                            tracing::warn!("Tor control connection closed; reconnecting");
+                            return Ok(false);
+                        }
+                        Err(err) => {
+                            tracing::warn!("Error reading Tor control event: {err:#}; reconnecting");
+                            return Ok(false);
+                        }
+                    }
+                }
+                _ = health_check.tick() => {
+                    tracing::debug!("Tor control connection healthy");
+                }
+                _ = shutdown.recv() => {
+                    tracing::info!("Shutdown requested; closing Tor control connection");
+                    return Ok(true);
+                }
+            }
+        }
+    }
+
+    /// Converts a circuit's accumulated metadata into a spectral
+    /// signature, scores it against the resonance engine, and routes the
+    /// decision through the introduction point's windowed decision,
+    /// feeding the outcome back into both the adaptive threshold and the
+    /// matching Gabriel cluster.
+    fn classify(&self, metadata: TorCircuitMetadata) {
+        let circuit_id = metadata.circuit_id;
+        let introduction_point = metadata
+            .introduction_point
+            .clone()
+            .unwrap_or_else(|| format!("circuit-{circuit_id}"));
+
+        let signature = self.spectral.lock().create_signature(&metadata);
+        self.monitor.track_circuit(metadata);
+
+        let score = self.resonance.compute_score_knn(&signature, K_NEAREST);
+        let threshold = self.threshold.value();
+        let action = self
+            .decision
+            .lock()
+            .decide_windowed(&introduction_point, score, threshold);
+
+        self.threshold
+            .record_absorption(action == CircuitAction::Absorb);
+
+        match action {
+            CircuitAction::Absorb => {
+                tracing::warn!(
+                    "Circuit {circuit_id} on {introduction_point} absorbed (score={score:.3}, threshold={threshold:.3})"
+                );
+                self.resonance.learn_attack_signature_knn(&signature, K_NEAREST);
+            }
+            CircuitAction::Forward => {
+                tracing::debug!(
+                    "Circuit {circuit_id} on {introduction_point} forwarded (score={score:.3}, threshold={threshold:.3})"
+                );
+                self.resonance.learn_signature_knn(&signature, K_NEAREST);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::OphanionSettings;
+    use std::time::{Duration, Instant};
+
+    fn test_subsystem() -> TorControlSubsystem {
+        let config = OphanionSettings {
+            num_gabriel_cells: 16,
+            spectral_dim: 32,
+            ..Default::default()
+        };
+
+        TorControlSubsystem::new(
+            Arc::new(TorInterface::new(9051)),
+            Arc::new(CircuitMonitor::new(100)),
+            Arc::new(ResonanceEngine::new(config.clone())),
+            Arc::new(AdaptiveThreshold::new(config)),
+            Arc::new(Mutex::new(DecisionEngine::new())),
+        )
+    }
+
+    fn sample_metadata(circuit_id: u32, introduction_point: &str) -> TorCircuitMetadata {
+        TorCircuitMetadata {
+            circuit_id,
+            created_at: Instant::now(),
+            cell_timings: vec![
+                Duration::from_millis(10),
+                Duration::from_millis(25),
+                Duration::from_millis(60),
+            ],
+            cell_types: vec![crate::TorCellType::Data, crate::TorCellType::Data],
+            introduction_point: Some(introduction_point.to_string()),
+            rendezvous_completed: true,
+            total_bytes: 4096,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stub_run_returns_after_shutdown_signal() {
+        let subsystem = test_subsystem();
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+
+        let handle = tokio::spawn(async move { subsystem.run(shutdown_rx).await });
+        shutdown_tx.send(()).unwrap();
+
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_classify_tracks_circuit_in_monitor() {
+        let subsystem = test_subsystem();
+        subsystem.classify(sample_metadata(7, "$AAAA"));
+
+        assert_eq!(subsystem.monitor.circuit_count(), 1);
+        assert!(subsystem.monitor.get_circuit(7).is_some());
+    }
+
+    #[test]
+    fn test_classify_falls_back_to_circuit_id_without_introduction_point() {
+        let subsystem = test_subsystem();
+        let mut metadata = sample_metadata(9, "$BBBB");
+        metadata.introduction_point = None;
+
+        // Should not panic despite the missing introduction point, and
+        // still track the circuit under its id.
+        subsystem.classify(metadata);
+        assert!(subsystem.monitor.get_circuit(9).is_some());
+    }
+}
@@ -1,36 +1,152 @@
-// Tor Control Port Interface Module (Stub)
-// In production, this would use tor-control-proto or similar crate
+// Tor Control Port Interface Module
+//
+// Without the `tor-control` feature this stays the lightweight stub it
+// always was, so the spectral/resonance pipeline can still be exercised
+// against the synthetic `create_legitimate_circuit`-style fixtures used
+// in tests. With `tor-control` enabled, `TorInterface` becomes a real
+// async control-port client: it authenticates (cookie-file or
+// `HashedControlPassword`), issues `SETEVENTS CIRC STREAM ORCONN`, and
+// parses the resulting `650 CIRC`/`STREAM` events and `GETINFO
+// circuit-status` replies into populated `TorCircuitMetadata`, so the
+// integration test can be driven end-to-end from a live Tor daemon
+// instead.
 
 use crate::TorCircuitMetadata;
 use anyhow::Result;
 use std::time::Instant;
 
+#[cfg(feature = "tor-control")]
+use std::path::PathBuf;
+
 pub struct TorInterface {
     control_port: u16,
+    #[cfg(feature = "tor-control")]
+    auth: live::AuthMethod,
+    #[cfg(feature = "tor-control")]
+    connection: tokio::sync::Mutex<Option<live::ControlConnection>>,
+    #[cfg(feature = "tor-control")]
+    tracker: live::CircuitEventTracker,
 }
 
 impl TorInterface {
     pub fn new(control_port: u16) -> Self {
-        Self { control_port }
+        Self {
+            control_port,
+            #[cfg(feature = "tor-control")]
+            auth: live::AuthMethod::None,
+            #[cfg(feature = "tor-control")]
+            connection: tokio::sync::Mutex::new(None),
+            #[cfg(feature = "tor-control")]
+            tracker: live::CircuitEventTracker::new(),
+        }
+    }
+
+    /// Builds an interface that authenticates via a Tor control-auth
+    /// cookie file (`CookieAuthentication 1` in torrc).
+    #[cfg(feature = "tor-control")]
+    pub fn with_cookie_auth(control_port: u16, cookie_path: impl Into<PathBuf>) -> Self {
+        Self {
+            auth: live::AuthMethod::Cookie(cookie_path.into()),
+            ..Self::new(control_port)
+        }
     }
-    
+
+    /// Builds an interface that authenticates via `HashedControlPassword`
+    /// (the plaintext password is sent; Tor hashes it server-side to
+    /// compare against the configured hash).
+    #[cfg(feature = "tor-control")]
+    pub fn with_password_auth(control_port: u16, password: impl Into<String>) -> Self {
+        Self {
+            auth: live::AuthMethod::HashedPassword(password.into()),
+            ..Self::new(control_port)
+        }
+    }
+
     /// Connect to Tor control port
     pub async fn connect(&self) -> Result<()> {
-        // TODO: Implement actual Tor control port connection
-        tracing::info!("Connecting to Tor control port: {}", self.control_port);
+        #[cfg(feature = "tor-control")]
+        {
+            let mut conn = live::ControlConnection::connect(self.control_port).await?;
+            conn.authenticate(&self.auth).await?;
+            *self.connection.lock().await = Some(conn);
+            tracing::info!(
+                "Connected and authenticated to Tor control port: {}",
+                self.control_port
+            );
+        }
+
+        #[cfg(not(feature = "tor-control"))]
+        {
+            tracing::info!(
+                "Connecting to Tor control port: {} (stub; enable the `tor-control` feature for a live client)",
+                self.control_port
+            );
+        }
+
         Ok(())
     }
-    
-    /// Monitor circuits (stub - would use SETEVENTS CIRC)
+
+    /// Monitor circuits: subscribes to `CIRC`, `STREAM`, and `ORCONN`
+    /// events (stub without the `tor-control` feature).
     pub async fn monitor_circuits(&self) -> Result<()> {
-        tracing::info!("Monitoring Tor circuits...");
-        // TODO: Implement SETEVENTS CIRC, STREAM, ORCONN
+        #[cfg(feature = "tor-control")]
+        {
+            let mut guard = self.connection.lock().await;
+            let conn = guard
+                .as_mut()
+                .ok_or_else(|| anyhow::anyhow!("not connected; call connect() first"))?;
+            conn.send_command("SETEVENTS CIRC STREAM ORCONN").await?;
+            tracing::info!("Subscribed to CIRC/STREAM/ORCONN events");
+        }
+
+        #[cfg(not(feature = "tor-control"))]
+        {
+            tracing::info!("Monitoring Tor circuits... (stub; enable the `tor-control` feature for live events)");
+        }
+
         Ok(())
     }
-    
-    /// Extract circuit metadata (stub)
+
+    /// Reads and parses the next asynchronous `650 CIRC`/`650 STREAM`
+    /// event, updating and returning that circuit's accumulated
+    /// metadata. Returns `Ok(None)` once the control connection closes.
+    /// Only available with the `tor-control` feature.
+    #[cfg(feature = "tor-control")]
+    pub async fn next_event(&self) -> Result<Option<TorCircuitMetadata>> {
+        let line = {
+            let mut guard = self.connection.lock().await;
+            let conn = guard
+                .as_mut()
+                .ok_or_else(|| anyhow::anyhow!("not connected; call connect() first"))?;
+            conn.read_async_event().await?
+        };
+
+        Ok(line.and_then(|line| self.tracker.apply_event(&line)))
+    }
+
+    /// Extract circuit metadata.
+    ///
+    /// With the `tor-control` feature, issues `GETINFO circuit-status`
+    /// and merges the reply with locally-tracked cell timings/types
+    /// (from [`next_event`](Self::next_event)); without it, returns an
+    /// empty stub.
     pub async fn get_circuit_metadata(&self, circuit_id: u32) -> Result<TorCircuitMetadata> {
-        // TODO: Implement GETINFO circuit-status
+        #[cfg(feature = "tor-control")]
+        {
+            let reply = {
+                let mut guard = self.connection.lock().await;
+                let conn = guard
+                    .as_mut()
+                    .ok_or_else(|| anyhow::anyhow!("not connected; call connect() first"))?;
+                conn.send_command("GETINFO circuit-status").await?
+            };
+
+            if let Some(mut metadata) = live::parse_circuit_status(circuit_id, &reply) {
+                self.tracker.merge_tracked(circuit_id, &mut metadata);
+                return Ok(metadata);
+            }
+        }
+
         Ok(TorCircuitMetadata {
             circuit_id,
             created_at: Instant::now(),
@@ -42,3 +158,408 @@ impl TorInterface {
         })
     }
 }
+
+#[cfg(feature = "tor-control")]
+mod live {
+    use crate::{TorCellType, TorCircuitMetadata};
+    use anyhow::{bail, Context, Result};
+    use parking_lot::RwLock;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::time::Instant;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+    use tokio::net::TcpStream;
+
+    /// Supported Tor control-port authentication methods.
+    pub enum AuthMethod {
+        /// No authentication (control port has no auth configured).
+        None,
+        /// `COOKIEFILE`-based authentication: read the cookie file and
+        /// send its hex-encoded bytes.
+        Cookie(PathBuf),
+        /// `HashedControlPassword`-based authentication: send the
+        /// plaintext password, which Tor hashes server-side.
+        HashedPassword(String),
+    }
+
+    /// A connected (and, after [`authenticate`](Self::authenticate),
+    /// authenticated) Tor control-port session.
+    pub struct ControlConnection {
+        reader: BufReader<OwnedReadHalf>,
+        writer: OwnedWriteHalf,
+    }
+
+    impl ControlConnection {
+        pub async fn connect(control_port: u16) -> Result<Self> {
+            let stream = TcpStream::connect(("127.0.0.1", control_port))
+                .await
+                .context("failed to connect to Tor control port")?;
+            let (read_half, writer) = stream.into_split();
+            Ok(Self {
+                reader: BufReader::new(read_half),
+                writer,
+            })
+        }
+
+        pub async fn authenticate(&mut self, method: &AuthMethod) -> Result<()> {
+            let command = match method {
+                AuthMethod::None => "AUTHENTICATE".to_string(),
+                AuthMethod::Cookie(path) => {
+                    let cookie = tokio::fs::read(path)
+                        .await
+                        .context("failed to read Tor control-auth cookie file")?;
+                    format!("AUTHENTICATE {}", to_hex(&cookie))
+                }
+                AuthMethod::HashedPassword(password) => {
+                    format!("AUTHENTICATE \"{}\"", escape_quoted(password))
+                }
+            };
+
+            let reply = self.send_command(&command).await?;
+            if !reply.starts_with("250") {
+                bail!("Tor control-port authentication failed: {reply}");
+            }
+            Ok(())
+        }
+
+        /// Sends a single-line command and reads the (possibly
+        /// multi-line) reply.
+        pub async fn send_command(&mut self, command: &str) -> Result<String> {
+            self.writer
+                .write_all(format!("{command}\r\n").as_bytes())
+                .await
+                .context("failed to write Tor control-port command")?;
+
+            let mut reply = String::new();
+            loop {
+                let mut line = String::new();
+                let bytes_read = self
+                    .reader
+                    .read_line(&mut line)
+                    .await
+                    .context("failed to read Tor control-port reply")?;
+                if bytes_read == 0 {
+                    bail!("Tor control port closed the connection");
+                }
+
+                let trimmed = line.trim_end_matches(['\r', '\n']);
+                reply.push_str(trimmed);
+                reply.push('\n');
+
+                // The 4th character of a reply line is a space for the
+                // final line of a (possibly multi-line) reply, or
+                // `-`/`+` for a line that continues.
+                if trimmed.len() >= 4 && trimmed.as_bytes()[3] == b' ' {
+                    break;
+                }
+            }
+
+            if reply.starts_with('5') {
+                bail!("Tor control-port command failed: {reply}");
+            }
+
+            Ok(reply)
+        }
+
+        /// Reads the next asynchronous (`650`-prefixed) event line, or
+        /// `None` if the connection closed.
+        pub async fn read_async_event(&mut self) -> Result<Option<String>> {
+            let mut line = String::new();
+            let bytes_read = self
+                .reader
+                .read_line(&mut line)
+                .await
+                .context("failed to read Tor control-port event")?;
+            if bytes_read == 0 {
+                return Ok(None);
+            }
+            Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+        }
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn escape_quoted(value: &str) -> String {
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    /// Per-circuit state accumulated from `650 CIRC`/`650 STREAM`
+    /// events, used to populate [`TorCircuitMetadata`] fields the
+    /// control protocol doesn't hand over in a single `GETINFO` reply.
+    #[derive(Default)]
+    struct TrackedCircuit {
+        created_at: Option<Instant>,
+        cell_timings: Vec<Instant>,
+        cell_types: Vec<TorCellType>,
+        introduction_point: Option<String>,
+        rendezvous_completed: bool,
+        total_bytes: u64,
+    }
+
+    /// Accumulates live control-port events into per-circuit state.
+    #[derive(Default)]
+    pub struct CircuitEventTracker {
+        circuits: RwLock<HashMap<u32, TrackedCircuit>>,
+    }
+
+    impl CircuitEventTracker {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Parses one `650 CIRC`/`650 STREAM` event line, updates the
+        /// tracked state for the circuit it references, and returns a
+        /// snapshot of that circuit's metadata (cell timings measured
+        /// relative to the circuit's first observed event).
+        pub fn apply_event(&self, line: &str) -> Option<TorCircuitMetadata> {
+            let mut parts = line.split_whitespace();
+            if parts.next()? != "650" {
+                return None;
+            }
+            let event_type = parts.next()?;
+            let rest: Vec<&str> = parts.collect();
+
+            match event_type {
+                "CIRC" => self.apply_circ_event(&rest),
+                "STREAM" => self.apply_stream_event(&rest),
+                _ => None,
+            }
+        }
+
+        fn apply_circ_event(&self, fields: &[&str]) -> Option<TorCircuitMetadata> {
+            let circuit_id: u32 = fields.first()?.parse().ok()?;
+            let status = *fields.get(1)?;
+
+            let mut circuits = self.circuits.write();
+            let tracked = circuits.entry(circuit_id).or_default();
+            let now = Instant::now();
+            tracked.created_at.get_or_insert(now);
+            tracked.cell_timings.push(now);
+
+            if let Some(path_field) = fields.iter().find(|f| f.contains(',')) {
+                if let Some(last_hop) = path_field.split(',').last() {
+                    tracked.introduction_point =
+                        Some(last_hop.split('~').next().unwrap_or(last_hop).to_string());
+                }
+            }
+
+            if status.eq_ignore_ascii_case("BUILT")
+                && fields.iter().any(|f| {
+                    f.eq_ignore_ascii_case("HS_SERVICE_REND") || f.eq_ignore_ascii_case("REND_JOINED")
+                })
+            {
+                tracked.rendezvous_completed = true;
+            }
+
+            Some(Self::snapshot(circuit_id, tracked))
+        }
+
+        fn apply_stream_event(&self, fields: &[&str]) -> Option<TorCircuitMetadata> {
+            // STREAM events: StreamID StreamStatus CircuitID Target ...
+            let circuit_id: u32 = fields.get(2)?.parse().ok()?;
+
+            let mut circuits = self.circuits.write();
+            let tracked = circuits.entry(circuit_id).or_default();
+            let now = Instant::now();
+            tracked.created_at.get_or_insert(now);
+            tracked.cell_timings.push(now);
+            tracked.cell_types.push(TorCellType::Data);
+
+            Some(Self::snapshot(circuit_id, tracked))
+        }
+
+        /// Merges tracked cell timings/types into a `GETINFO`-sourced
+        /// metadata record, filling in only the fields `GETINFO` left
+        /// empty.
+        pub fn merge_tracked(&self, circuit_id: u32, metadata: &mut TorCircuitMetadata) {
+            let circuits = self.circuits.read();
+            let Some(tracked) = circuits.get(&circuit_id) else {
+                return;
+            };
+
+            if metadata.cell_timings.is_empty() {
+                let base = tracked.created_at.unwrap_or_else(Instant::now);
+                metadata.cell_timings = tracked
+                    .cell_timings
+                    .iter()
+                    .map(|instant| instant.saturating_duration_since(base))
+                    .collect();
+            }
+            if metadata.cell_types.is_empty() {
+                metadata.cell_types = tracked.cell_types.clone();
+            }
+            if metadata.introduction_point.is_none() {
+                metadata.introduction_point = tracked.introduction_point.clone();
+            }
+            metadata.rendezvous_completed |= tracked.rendezvous_completed;
+            if metadata.total_bytes == 0 {
+                metadata.total_bytes = tracked.total_bytes;
+            }
+        }
+
+        fn snapshot(circuit_id: u32, tracked: &TrackedCircuit) -> TorCircuitMetadata {
+            let base = tracked.created_at.unwrap_or_else(Instant::now);
+            TorCircuitMetadata {
+                circuit_id,
+                created_at: base,
+                cell_timings: tracked
+                    .cell_timings
+                    .iter()
+                    .map(|instant| instant.saturating_duration_since(base))
+                    .collect(),
+                cell_types: tracked.cell_types.clone(),
+                introduction_point: tracked.introduction_point.clone(),
+                rendezvous_completed: tracked.rendezvous_completed,
+                total_bytes: tracked.total_bytes,
+            }
+        }
+    }
+
+    /// Parses a `GETINFO circuit-status` reply into a partial
+    /// [`TorCircuitMetadata`] for `circuit_id`, if that circuit appears
+    /// in the reply. Cell timing/type fields are left empty; callers
+    /// merge in locally-tracked event data via
+    /// [`CircuitEventTracker::merge_tracked`].
+    pub fn parse_circuit_status(circuit_id: u32, reply: &str) -> Option<TorCircuitMetadata> {
+        for line in reply.lines() {
+            let line = line.trim_start_matches("250-").trim_start_matches("250+");
+            let mut fields = line.split_whitespace();
+            let id: u32 = fields.next()?.parse().ok()?;
+            if id != circuit_id {
+                continue;
+            }
+
+            let status = fields.next().unwrap_or("");
+            let rendezvous_completed = status.eq_ignore_ascii_case("BUILT");
+
+            let introduction_point = line
+                .split_whitespace()
+                .find(|f| f.contains(','))
+                .and_then(|path_field| path_field.split(',').last())
+                .map(|hop| hop.split('~').next().unwrap_or(hop).to_string());
+
+            return Some(TorCircuitMetadata {
+                circuit_id,
+                created_at: Instant::now(),
+                cell_timings: vec![],
+                cell_types: vec![],
+                introduction_point,
+                rendezvous_completed,
+                total_bytes: 0,
+            });
+        }
+        None
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_to_hex() {
+            assert_eq!(to_hex(&[0x01, 0xab, 0xff]), "01abff");
+        }
+
+        #[test]
+        fn test_escape_quoted() {
+            assert_eq!(escape_quoted(r#"pa"ss\word"#), r#"pa\"ss\\word"#);
+        }
+
+        #[test]
+        fn test_apply_circ_event_tracks_timings_and_path() {
+            let tracker = CircuitEventTracker::new();
+
+            let first = tracker
+                .apply_event("650 CIRC 7 LAUNCHED PURPOSE=GENERAL")
+                .expect("should parse");
+            assert_eq!(first.circuit_id, 7);
+            assert_eq!(first.cell_timings.len(), 1);
+
+            let second = tracker
+                .apply_event("650 CIRC 7 BUILT $AAAA~relay1,$BBBB~relay2 PURPOSE=HS_SERVICE_REND")
+                .expect("should parse");
+            assert_eq!(second.cell_timings.len(), 2);
+            assert!(second.rendezvous_completed);
+            assert_eq!(second.introduction_point.as_deref(), Some("$BBBB"));
+        }
+
+        #[test]
+        fn test_apply_stream_event_tracks_circuit() {
+            let tracker = CircuitEventTracker::new();
+
+            let metadata = tracker
+                .apply_event("650 STREAM 12 NEW 7 example.onion:80")
+                .expect("should parse");
+
+            assert_eq!(metadata.circuit_id, 7);
+            assert_eq!(metadata.cell_types, vec![TorCellType::Data]);
+        }
+
+        #[test]
+        fn test_apply_event_ignores_non_650_lines() {
+            let tracker = CircuitEventTracker::new();
+            assert!(tracker.apply_event("250 OK").is_none());
+        }
+
+        #[test]
+        fn test_parse_circuit_status() {
+            let reply = "250+circuit-status=\n7 BUILT $AAAA~relay1,$BBBB~relay2 PURPOSE=GENERAL\n250 OK\n";
+            let metadata =
+                parse_circuit_status(7, reply).expect("circuit 7 should be present in the reply");
+
+            assert_eq!(metadata.circuit_id, 7);
+            assert!(metadata.rendezvous_completed);
+            assert_eq!(metadata.introduction_point.as_deref(), Some("$BBBB"));
+
+            assert!(parse_circuit_status(99, reply).is_none());
+        }
+
+        #[test]
+        fn test_merge_tracked_fills_empty_fields_only() {
+            let tracker = CircuitEventTracker::new();
+            tracker.apply_event("650 CIRC 3 LAUNCHED PURPOSE=GENERAL");
+            tracker.apply_event("650 CIRC 3 BUILT $AAAA~relay1,$BBBB~relay2 PURPOSE=HS_SERVICE_REND");
+
+            let mut metadata = TorCircuitMetadata {
+                circuit_id: 3,
+                created_at: Instant::now(),
+                cell_timings: vec![],
+                cell_types: vec![],
+                introduction_point: None,
+                rendezvous_completed: false,
+                total_bytes: 0,
+            };
+
+            tracker.merge_tracked(3, &mut metadata);
+
+            assert_eq!(metadata.cell_timings.len(), 2);
+            assert!(metadata.rendezvous_completed);
+            assert_eq!(metadata.introduction_point.as_deref(), Some("$BBBB"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_stub_connect_and_monitor_succeed() {
+        let interface = TorInterface::new(9051);
+        assert!(interface.connect().await.is_ok());
+        assert!(interface.monitor_circuits().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stub_circuit_metadata_is_empty() {
+        let interface = TorInterface::new(9051);
+        let metadata = interface.get_circuit_metadata(1).await.unwrap();
+
+        assert_eq!(metadata.circuit_id, 1);
+        assert!(metadata.cell_timings.is_empty());
+        assert!(!metadata.rendezvous_completed);
+    }
+}
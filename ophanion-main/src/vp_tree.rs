@@ -0,0 +1,205 @@
+//! A vantage-point tree over Gabriel-cell centroids.
+//!
+//! Used by [`crate::gabriel_cell::GabrielCluster::find_k_nearest`] as a
+//! sub-linear approximate-nearest-neighbor index once the cluster grows
+//! past `spatial_index_min_cells`; smaller clusters keep using the exact
+//! linear scan, where the tree-building overhead isn't worth it.
+
+use crate::GabrielCell;
+use ndarray::Array1;
+
+struct VpNode {
+    cell_id: usize,
+    centroid: Array1<f64>,
+    threshold: f64,
+    inside: Option<Box<VpNode>>,
+    outside: Option<Box<VpNode>>,
+}
+
+/// A vantage-point tree built from a snapshot of cell centroids.
+pub struct VpTree {
+    root: Option<Box<VpNode>>,
+}
+
+fn euclidean(a: &Array1<f64>, b: &Array1<f64>) -> f64 {
+    (a - b).mapv(|x| x * x).sum().sqrt()
+}
+
+impl VpTree {
+    /// Builds a tree from a snapshot of `cells`.
+    pub fn build(cells: &[GabrielCell]) -> Self {
+        let mut items: Vec<(usize, Array1<f64>)> = cells
+            .iter()
+            .map(|cell| (cell.id, cell.centroid.clone()))
+            .collect();
+
+        Self {
+            root: Self::build_node(&mut items),
+        }
+    }
+
+    fn build_node(items: &mut [(usize, Array1<f64>)]) -> Option<Box<VpNode>> {
+        if items.is_empty() {
+            return None;
+        }
+        if items.len() == 1 {
+            let (cell_id, centroid) = items[0].clone();
+            return Some(Box::new(VpNode {
+                cell_id,
+                centroid,
+                threshold: 0.0,
+                inside: None,
+                outside: None,
+            }));
+        }
+
+        let (vp_id, vp_centroid) = items[0].clone();
+        let rest = &items[1..];
+
+        let distances: Vec<f64> = rest.iter().map(|(_, c)| euclidean(&vp_centroid, c)).collect();
+        let mut sorted = distances.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let median = sorted[sorted.len() / 2];
+
+        let mut inside_items = Vec::new();
+        let mut outside_items = Vec::new();
+        for (i, (id, centroid)) in rest.iter().enumerate() {
+            if distances[i] <= median {
+                inside_items.push((*id, centroid.clone()));
+            } else {
+                outside_items.push((*id, centroid.clone()));
+            }
+        }
+
+        Some(Box::new(VpNode {
+            cell_id: vp_id,
+            centroid: vp_centroid,
+            threshold: median,
+            inside: Self::build_node(&mut inside_items),
+            outside: Self::build_node(&mut outside_items),
+        }))
+    }
+
+    /// Returns the `k` nearest cell ids to `query`.
+    pub fn k_nearest(&self, query: &Array1<f64>, k: usize) -> Vec<usize> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut results: Vec<(usize, f64)> = Vec::with_capacity(k + 1);
+        let mut tau = f64::INFINITY;
+        Self::search(&self.root, query, k, &mut results, &mut tau);
+
+        results.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        results.into_iter().take(k).map(|(id, _)| id).collect()
+    }
+
+    fn search(
+        node: &Option<Box<VpNode>>,
+        query: &Array1<f64>,
+        k: usize,
+        results: &mut Vec<(usize, f64)>,
+        tau: &mut f64,
+    ) {
+        let Some(node) = node else {
+            return;
+        };
+
+        let dist = euclidean(&node.centroid, query);
+
+        if results.len() < k {
+            results.push((node.cell_id, dist));
+            if results.len() == k {
+                *tau = results.iter().map(|(_, d)| *d).fold(f64::MIN, f64::max);
+            }
+        } else if dist < *tau {
+            if let Some(worst_idx) = results
+                .iter()
+                .enumerate()
+                .max_by(|(_, (_, a)), (_, (_, b))| a.partial_cmp(b).unwrap())
+                .map(|(idx, _)| idx)
+            {
+                results[worst_idx] = (node.cell_id, dist);
+                *tau = results.iter().map(|(_, d)| *d).fold(f64::MIN, f64::max);
+            }
+        }
+
+        if node.inside.is_none() && node.outside.is_none() {
+            return;
+        }
+
+        if dist < node.threshold {
+            if dist - *tau <= node.threshold {
+                Self::search(&node.inside, query, k, results, tau);
+            }
+            if dist + *tau >= node.threshold {
+                Self::search(&node.outside, query, k, results, tau);
+            }
+        } else {
+            if dist + *tau >= node.threshold {
+                Self::search(&node.outside, query, k, results, tau);
+            }
+            if dist - *tau <= node.threshold {
+                Self::search(&node.inside, query, k, results, tau);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_cells(centroids: &[Vec<f64>]) -> Vec<GabrielCell> {
+        centroids
+            .iter()
+            .enumerate()
+            .map(|(id, values)| {
+                let mut cell = GabrielCell::new(id, values.len());
+                cell.centroid = Array1::from_vec(values.clone());
+                cell
+            })
+            .collect()
+    }
+
+    fn brute_force_k_nearest(cells: &[GabrielCell], query: &Array1<f64>, k: usize) -> Vec<usize> {
+        let mut distances: Vec<(usize, f64)> = cells
+            .iter()
+            .map(|cell| (cell.id, euclidean(&cell.centroid, query)))
+            .collect();
+        distances.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        distances.into_iter().take(k).map(|(id, _)| id).collect()
+    }
+
+    #[test]
+    fn test_k_nearest_matches_brute_force() {
+        let centroids: Vec<Vec<f64>> = (0..40)
+            .map(|i| vec![(i as f64 * 0.37) % 5.0, (i as f64 * 1.13) % 7.0])
+            .collect();
+        let cells = make_cells(&centroids);
+        let tree = VpTree::build(&cells);
+
+        let query = Array1::from_vec(vec![2.0, 3.0]);
+        let k = 5;
+
+        let mut expected = brute_force_k_nearest(&cells, &query, k);
+        let mut actual = tree.k_nearest(&query, k);
+        expected.sort_unstable();
+        actual.sort_unstable();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_k_nearest_empty_tree() {
+        let tree = VpTree::build(&[]);
+        assert!(tree.k_nearest(&Array1::from_vec(vec![0.0]), 3).is_empty());
+    }
+
+    #[test]
+    fn test_k_nearest_zero_k() {
+        let cells = make_cells(&[vec![0.0], vec![1.0]]);
+        let tree = VpTree::build(&cells);
+        assert!(tree.k_nearest(&Array1::from_vec(vec![0.0]), 0).is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+//! Honggfuzz harness for the packet-classification hot path.
+//!
+//! Interprets the raw fuzz buffer as a sequence of variable-length
+//! packets and replays them through `DeltaKernel::process_packet` and
+//! `absorber.learn_legitimate_pattern` -- the same two calls the QRIK
+//! demo drives with both legitimate and DDoS traffic -- so that a
+//! hostile client's malformed cell stream is exercised directly against
+//! the classification pipeline. After every packet it asserts the
+//! invariants that must hold regardless of input: coherence and
+//! absorption efficiency stay finite and within `[0, 1]`, no NaN/Inf
+//! leaks into the absorber's fields, and nothing panics on an empty or
+//! maximally-sized packet.
+
+#[macro_use]
+extern crate honggfuzz;
+
+use thronion::core::NUM_NODES;
+use thronion::delta::{DeltaKernel, QRIKParams};
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            run(data);
+        });
+    }
+}
+
+fn run(data: &[u8]) {
+    let mut kernel = DeltaKernel::new(QRIKParams::default());
+    kernel.absorber.initialize_random_fields();
+
+    for (payload, node, is_learn_call) in packets(data) {
+        if is_learn_call {
+            kernel.absorber.learn_legitimate_pattern(payload, node, 0.1);
+        } else {
+            let (_absorbed, score) = kernel.process_packet(payload, node);
+            assert!(score.is_finite(), "resonance score must be finite");
+            assert!(
+                (0.0..=1.0).contains(&score),
+                "resonance score out of [0, 1]: {score}"
+            );
+        }
+
+        assert_invariants(&kernel);
+    }
+}
+
+/// Checks the invariants that must hold after every packet, win or lose.
+fn assert_invariants(kernel: &DeltaKernel) {
+    let efficiency = kernel.absorber.absorption_efficiency();
+    assert!(
+        efficiency.is_finite(),
+        "absorption efficiency must be finite"
+    );
+    assert!(
+        (0.0..=1.0).contains(&efficiency),
+        "absorption efficiency out of [0, 1]: {efficiency}"
+    );
+
+    for dictionary in &kernel.absorber.dictionaries {
+        for atom in &dictionary.atoms {
+            assert!(
+                atom.weight.is_finite() && atom.spectrum.iter().all(|v| v.is_finite()),
+                "NaN/Inf leaked into an absorber spectral dictionary"
+            );
+        }
+    }
+    assert!(
+        kernel.absorber.thresholds.iter().all(|v| v.is_finite()),
+        "NaN/Inf leaked into an absorber threshold"
+    );
+
+    let gradient = kernel.coherence_gradient();
+    assert!(gradient.is_finite(), "coherence gradient must be finite");
+}
+
+/// Splits the fuzz buffer into `(payload, node, is_learn_call)` packets.
+///
+/// A control byte picks the absorber call and the Gabriel-cell node
+/// index (reduced mod [`NUM_NODES`] so the node is always in range), and
+/// a length byte gives the payload size clamped to what's left in the
+/// buffer. This consumes the whole input deterministically no matter its
+/// contents, including empty input and a single trailing byte.
+fn packets(data: &[u8]) -> Vec<(&[u8], usize, bool)> {
+    let mut packets = Vec::new();
+    let mut rest = data;
+
+    while let [control, len, tail @ ..] = rest {
+        let node = (*control as usize) % NUM_NODES;
+        let is_learn_call = control & 0x80 != 0;
+        let take = (*len as usize).min(tail.len());
+        let (payload, remainder) = tail.split_at(take);
+
+        packets.push((payload, node, is_learn_call));
+        rest = remainder;
+    }
+
+    packets
+}
@@ -0,0 +1,454 @@
+//! 13-dimensionaler Tripol-Hilbertraum
+//!
+//! Implementiert Quantenzustände im ℂ¹³ mit Normalisierung und
+//! Basis-Operationen gemäß der Metatron-Topologie
+
+use crate::utils::linalg;
+use nalgebra::{SMatrix, SVector};
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+
+/// Dimension des Hilbertraums (13 Knoten im Metatron-Graph)
+pub const HILBERT_DIM: usize = 13;
+
+/// Quantenzustand in ℋ₁₃
+///
+/// Repräsentiert einen normierten Zustand |ψ⟩ = Σᵢ αᵢ|i⟩ mit Σᵢ|αᵢ|² = 1
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuantumState {
+    /// Komplexe Amplitudes-Vektor
+    pub amplitudes: SVector<Complex64, HILBERT_DIM>,
+}
+
+impl QuantumState {
+    /// Erstellt einen neuen Quantenzustand mit automatischer Normalisierung
+    ///
+    /// # Arguments
+    /// * `amps` - Komplexer Amplitudenvektor (wird normalisiert)
+    pub fn new(amps: SVector<Complex64, HILBERT_DIM>) -> Self {
+        let norm = amps.norm();
+        if norm < 1e-10 {
+            panic!("Nullvektor kann nicht normalisiert werden");
+        }
+        Self {
+            amplitudes: amps.scale(1.0 / norm),
+        }
+    }
+
+    /// Erstellt einen Basiszustand |i⟩
+    ///
+    /// # Arguments
+    /// * `index` - Index des Basiszustands (0..13)
+    ///
+    /// # Panics
+    /// Panikt wenn index >= 13
+    pub fn basis_state(index: usize) -> Self {
+        assert!(index < HILBERT_DIM, "Index muss < 13 sein");
+        let mut amps = SVector::<Complex64, HILBERT_DIM>::zeros();
+        amps[index] = Complex64::new(1.0, 0.0);
+        Self { amplitudes: amps }
+    }
+
+    /// Erstellt einen gleichverteilten Superpositionszustand
+    ///
+    /// |ψ⟩ = (1/√13) Σᵢ |i⟩
+    pub fn uniform_superposition() -> Self {
+        let amp = Complex64::new(1.0 / (HILBERT_DIM as f64).sqrt(), 0.0);
+        let amps = SVector::<Complex64, HILBERT_DIM>::from_element(amp);
+        Self { amplitudes: amps }
+    }
+
+    /// Erstellt einen zufälligen Quantenzustand nach dem Haar-Maß
+    ///
+    /// Jede der 13 komplexen Komponenten wird unabhängig als
+    /// Standard-komplex-Gaußverteilung gezogen (Real- und Imaginärteil je
+    /// ~ N(0,1)); ein normierter Vektor aus i.i.d. komplexen
+    /// Gaußkomponenten ist nachweislich Haar-uniform auf der Einheitssphäre
+    /// von ℂ¹³. Für die ältere, nicht Haar-verteilte Stichprobe (uniform im
+    /// Hyperwürfel) siehe [`Self::random_cube`].
+    pub fn random() -> Self {
+        use rand_distr::{Distribution, StandardNormal};
+        let mut rng = rand::thread_rng();
+
+        let amps: SVector<Complex64, HILBERT_DIM> = SVector::from_fn(|_, _| {
+            let re: f64 = StandardNormal.sample(&mut rng);
+            let im: f64 = StandardNormal.sample(&mut rng);
+            Complex64::new(re, im)
+        });
+
+        Self::new(amps)
+    }
+
+    /// Erstellt einen zufälligen Quantenzustand durch Normierung eines im
+    /// Hyperwürfel (-1,1)^13 × (-1,1)^13 uniform gezogenen Vektors
+    ///
+    /// Diese Verteilung ist NICHT Haar-uniform auf der Sphäre (sie
+    /// konzentriert Masse zu den "Ecken" des Hyperwürfels hin); für eine
+    /// echte Haar-verteilte Stichprobe siehe [`Self::random`].
+    pub fn random_cube() -> Self {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+
+        let amps: SVector<Complex64, HILBERT_DIM> = SVector::from_fn(|_, _| {
+            Complex64::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0))
+        });
+
+        Self::new(amps)
+    }
+
+    /// Berechnet Skalarprodukt ⟨self|other⟩
+    pub fn inner_product(&self, other: &Self) -> Complex64 {
+        self.amplitudes
+            .iter()
+            .zip(other.amplitudes.iter())
+            .map(|(a, b)| a.conj() * b)
+            .sum()
+    }
+
+    /// Berechnet Norm ||ψ||
+    pub fn norm(&self) -> f64 {
+        self.amplitudes.norm()
+    }
+
+    /// Überprüft Normalisierung (sollte ≈ 1 sein)
+    pub fn is_normalized(&self) -> bool {
+        (self.norm() - 1.0).abs() < 1e-10
+    }
+
+    /// Berechnet Wahrscheinlichkeitsverteilung P(i) = |αᵢ|²
+    pub fn probabilities(&self) -> [f64; HILBERT_DIM] {
+        let mut probs = [0.0; HILBERT_DIM];
+        for (i, amp) in self.amplitudes.iter().enumerate() {
+            probs[i] = amp.norm_sqr();
+        }
+        probs
+    }
+
+    /// Führt Messung durch (Born-Regel)
+    ///
+    /// Returned den Index des gemessenen Zustands gemäß P(i) = |αᵢ|²
+    pub fn measure(&self) -> usize {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+
+        let probs = self.probabilities();
+        let sample: f64 = rng.gen();
+
+        let mut cumulative = 0.0;
+        for (i, &p) in probs.iter().enumerate() {
+            cumulative += p;
+            if sample <= cumulative {
+                return i;
+            }
+        }
+        HILBERT_DIM - 1 // Fallback (wegen Rundungsfehlern)
+    }
+
+    /// Projiziert auf Basiszustand |i⟩
+    ///
+    /// P̂ᵢ|ψ⟩ = |i⟩⟨i|ψ⟩
+    pub fn project_onto(&self, index: usize) -> Self {
+        assert!(index < HILBERT_DIM);
+        let basis = Self::basis_state(index);
+        let overlap = self.inner_product(&basis);
+        Self::new(basis.amplitudes * overlap)
+    }
+
+    /// Berechnet Erwartungswert eines Hermiteschen Operators
+    ///
+    /// ⟨Ô⟩ = ⟨ψ|Ô|ψ⟩
+    pub fn expectation_value(
+        &self,
+        operator: &nalgebra::SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
+    ) -> Complex64 {
+        let op_psi = operator * self.amplitudes;
+        self.amplitudes
+            .iter()
+            .zip(op_psi.iter())
+            .map(|(a, b)| a.conj() * b)
+            .sum()
+    }
+
+    /// Berechnet die Shannon-Entropie der Messverteilung P(i) = |αᵢ|²
+    ///
+    /// Für einen reinen Zustand ist dies nur dann ≈ 0, wenn der Zustand
+    /// mit einem Basiszustand zusammenfällt (genau ein αᵢ ≠ 0) — für eine
+    /// echte Superposition ist der Wert stets > 0, obwohl der zugehörige
+    /// Dichteoperator ρ = |ψ⟩⟨ψ| rein bleibt. Die tatsächliche
+    /// Von-Neumann-Entropie S(ρ) = −Tr(ρ ln ρ), die für jeden reinen
+    /// Zustand exakt 0 ist, liefert [`DensityMatrix::von_neumann_entropy`].
+    pub fn von_neumann_entropy(&self) -> f64 {
+        let probs = self.probabilities();
+        -probs
+            .iter()
+            .filter(|&&p| p > 1e-15)
+            .map(|&p| p * p.ln())
+            .sum::<f64>()
+    }
+
+    /// Fidelity zwischen zwei Zuständen: F = |⟨ψ|φ⟩|²
+    pub fn fidelity(&self, other: &Self) -> f64 {
+        self.inner_product(other).norm_sqr()
+    }
+}
+
+impl Default for QuantumState {
+    /// Standard ist Basiszustand |0⟩
+    fn default() -> Self {
+        Self::basis_state(0)
+    }
+}
+
+impl std::fmt::Display for QuantumState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "QuantumState |ψ⟩ in ℋ₁₃:")?;
+        for (i, amp) in self.amplitudes.iter().enumerate() {
+            if amp.norm() > 1e-6 {
+                writeln!(
+                    f,
+                    "  |{:2}⟩: {:8.5} {:+8.5}i  (P = {:.5})",
+                    i,
+                    amp.re,
+                    amp.im,
+                    amp.norm_sqr()
+                )?;
+            }
+        }
+        writeln!(f, "  Norm: {:.10}", self.norm())
+    }
+}
+
+/// Dichteoperator ρ auf ℋ₁₃ für reine und gemischte Zustände
+///
+/// Ein reiner Zustand erzeugt ρ = |ψ⟩⟨ψ| (Rang 1, ein Eigenwert = 1); eine
+/// statistische Mischung Σₖ pₖ|ψₖ⟩⟨ψₖ| (z.B. nach Dekohärenz) besitzt
+/// mehrere von Null verschiedene Eigenwerte. Im Gegensatz zu
+/// [`QuantumState::von_neumann_entropy`] — das lediglich die
+/// Shannon-Entropie der Messverteilung berechnet — liefert
+/// [`Self::von_neumann_entropy`] die tatsächliche quanteninformationstheoretische
+/// Größe S(ρ) = −Tr(ρ ln ρ).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DensityMatrix {
+    /// Dichtematrix ρ ∈ ℂ^{13×13}
+    pub matrix: SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
+}
+
+impl DensityMatrix {
+    /// Erstellt den Dichteoperator eines reinen Zustands: ρ = |ψ⟩⟨ψ|
+    pub fn from_pure_state(state: &QuantumState) -> Self {
+        let psi = state.amplitudes;
+        let matrix = psi * psi.adjoint();
+        Self { matrix }
+    }
+
+    /// Erstellt den Dichteoperator einer statistischen Mischung
+    ///
+    /// ρ = Σₖ pₖ |ψₖ⟩⟨ψₖ|
+    ///
+    /// # Panics
+    /// Panikt wenn `weights` leer ist oder die Gewichte pₖ nicht auf 1 summieren
+    /// (Toleranz 1e-8).
+    pub fn from_mixture(weights: &[(f64, QuantumState)]) -> Self {
+        assert!(!weights.is_empty(), "Mischung benötigt mindestens einen Zustand");
+        let total_weight: f64 = weights.iter().map(|(p, _)| p).sum();
+        assert!(
+            (total_weight - 1.0).abs() < 1e-8,
+            "Gewichte müssen auf 1 summieren, Summe ist aber {}",
+            total_weight
+        );
+
+        let mut matrix = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+        for (p, state) in weights {
+            let psi = state.amplitudes;
+            matrix += (psi * psi.adjoint()).scale(Complex64::new(*p, 0.0));
+        }
+        Self { matrix }
+    }
+
+    /// Berechnet die Spur Tr(ρ) (sollte ≈ 1 sein)
+    pub fn trace(&self) -> f64 {
+        linalg::trace(&self.matrix).re
+    }
+
+    /// Berechnet die Reinheit Tr(ρ²) ∈ (0, 1]
+    ///
+    /// Tr(ρ²) = 1 für reine Zustände, < 1 für gemischte Zustände
+    pub fn purity(&self) -> f64 {
+        linalg::trace(&(self.matrix * self.matrix)).re
+    }
+
+    /// Berechnet die wahre Von-Neumann-Entropie S(ρ) = −Tr(ρ ln ρ) = −Σᵢ λᵢ ln λᵢ
+    ///
+    /// Die λᵢ sind die reellen Eigenwerte von ρ, ermittelt über die
+    /// hermitesche Eigenwertzerlegung [`linalg::hermitian_eigen`] (reell-symmetrische
+    /// 26×26-Einbettung). Für einen reinen Zustand ist genau ein λᵢ = 1 und
+    /// alle übrigen = 0, also S(ρ) = 0; für eine dekohärente Mischung ist S(ρ) > 0.
+    pub fn von_neumann_entropy(&self) -> f64 {
+        let (eigenvalues, _) = linalg::hermitian_eigen(&self.matrix);
+        -eigenvalues
+            .iter()
+            .filter(|&&lambda| lambda > 1e-15)
+            .map(|&lambda| lambda * lambda.ln())
+            .sum::<f64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_basis_state() {
+        let state = QuantumState::basis_state(3);
+        assert!(state.is_normalized());
+        assert_eq!(state.amplitudes[3].norm_sqr(), 1.0);
+        for i in 0..HILBERT_DIM {
+            if i != 3 {
+                assert_eq!(state.amplitudes[i].norm_sqr(), 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_uniform_superposition() {
+        let state = QuantumState::uniform_superposition();
+        assert!(state.is_normalized());
+
+        let expected_prob = 1.0 / HILBERT_DIM as f64;
+        for prob in state.probabilities() {
+            assert_abs_diff_eq!(prob, expected_prob, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_inner_product_orthogonal() {
+        let state1 = QuantumState::basis_state(0);
+        let state2 = QuantumState::basis_state(1);
+        let overlap = state1.inner_product(&state2);
+        assert_abs_diff_eq!(overlap.norm(), 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_inner_product_self() {
+        let state = QuantumState::random();
+        let overlap = state.inner_product(&state);
+        assert_abs_diff_eq!(overlap.re, 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(overlap.im, 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_probabilities_sum() {
+        let state = QuantumState::random();
+        let probs = state.probabilities();
+        let sum: f64 = probs.iter().sum();
+        assert_abs_diff_eq!(sum, 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_measure_distribution() {
+        let state = QuantumState::uniform_superposition();
+        let mut counts = [0; HILBERT_DIM];
+
+        for _ in 0..10000 {
+            let outcome = state.measure();
+            counts[outcome] += 1;
+        }
+
+        // Jeder Zustand sollte ca. gleich oft gemessen werden
+        let expected = 10000.0 / HILBERT_DIM as f64;
+        for count in counts {
+            let deviation = (count as f64 - expected).abs() / expected;
+            assert!(deviation < 0.15); // 15% Toleranz
+        }
+    }
+
+    #[test]
+    fn test_von_neumann_entropy_pure() {
+        let state = QuantumState::basis_state(0);
+        let entropy = state.von_neumann_entropy();
+        assert_abs_diff_eq!(entropy, 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_fidelity_identical() {
+        let state = QuantumState::random();
+        let fidelity = state.fidelity(&state);
+        assert_abs_diff_eq!(fidelity, 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_random_is_haar_uniform_on_average() {
+        const SAMPLES: usize = 4000;
+        let mut average_probs = [0.0; HILBERT_DIM];
+
+        for _ in 0..SAMPLES {
+            let state = QuantumState::random();
+            for (i, p) in state.probabilities().iter().enumerate() {
+                average_probs[i] += p / SAMPLES as f64;
+            }
+        }
+
+        let expected = 1.0 / HILBERT_DIM as f64;
+        for avg in average_probs {
+            assert_abs_diff_eq!(avg, expected, epsilon = 0.02);
+        }
+    }
+
+    #[test]
+    fn test_fidelity_orthogonal() {
+        let state1 = QuantumState::basis_state(0);
+        let state2 = QuantumState::basis_state(1);
+        let fidelity = state1.fidelity(&state2);
+        assert_abs_diff_eq!(fidelity, 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_density_matrix_pure_state_is_normalized_and_pure() {
+        let state = QuantumState::random();
+        let rho = DensityMatrix::from_pure_state(&state);
+        assert_abs_diff_eq!(rho.trace(), 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(rho.purity(), 1.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_density_matrix_pure_state_entropy_is_zero() {
+        let state = QuantumState::uniform_superposition();
+        let rho = DensityMatrix::from_pure_state(&state);
+        // Im Gegensatz zu `QuantumState::von_neumann_entropy` (welches für
+        // eine Superposition > 0 wäre) ist die tatsächliche
+        // Von-Neumann-Entropie eines reinen Zustands stets 0.
+        assert_abs_diff_eq!(rho.von_neumann_entropy(), 0.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_density_matrix_mixture_has_positive_entropy() {
+        let states = [
+            QuantumState::basis_state(0),
+            QuantumState::basis_state(1),
+            QuantumState::basis_state(2),
+        ];
+        let weights: Vec<(f64, QuantumState)> = states
+            .into_iter()
+            .map(|s| (1.0 / 3.0, s))
+            .collect();
+        let rho = DensityMatrix::from_mixture(&weights);
+
+        assert_abs_diff_eq!(rho.trace(), 1.0, epsilon = 1e-10);
+        assert!(rho.purity() < 1.0 - 1e-6);
+
+        let entropy = rho.von_neumann_entropy();
+        let expected = 3.0_f64.ln();
+        assert_abs_diff_eq!(entropy, expected, epsilon = 1e-6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_density_matrix_mixture_rejects_unnormalized_weights() {
+        let weights = vec![
+            (0.5, QuantumState::basis_state(0)),
+            (0.2, QuantumState::basis_state(1)),
+        ];
+        DensityMatrix::from_mixture(&weights);
+    }
+}
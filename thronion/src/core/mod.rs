@@ -0,0 +1,17 @@
+//! Core-Module des QRIK-Systems
+//!
+//! Enthält fundamentale Strukturen:
+//! - DTL: Dynamic Tripolar Logic
+//! - Hilbert: 13-dimensionaler Quantenzustandsraum
+//! - Metatron: Graph-Topologie
+//! - Semiclassical: Rückgekoppelte gemeinsame Evolution von DTL- und Quantenzustand
+
+pub mod dtl;
+pub mod hilbert;
+pub mod metatron;
+pub mod semiclassical;
+
+pub use dtl::{DTLClass, DTLState};
+pub use hilbert::{DensityMatrix, QuantumState, HILBERT_DIM};
+pub use metatron::{MetatronGraph, NodeType, NUM_EDGES, NUM_NODES};
+pub use semiclassical::{SemiclassicalRegime, SemiclassicalState};
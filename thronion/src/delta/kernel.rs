@@ -2,10 +2,12 @@
 //!
 //! Unified QRIK-Zustand: Ψ_Δ = Ψ_ℋ₁₃ ⋆ Ψ_Ω₅ ⋆ Ψ_ℛ ⋆ Ψ_ℳ
 
-use crate::core::{MetatronGraph, QuantumState};
+use crate::core::{DensityMatrix, MetatronGraph, QuantumState, HILBERT_DIM};
 use crate::mandorla::MandorlaOperator;
 use crate::operators::HamiltonOperator;
 use crate::resonance::{KuramotoNetwork, ResonantAbsorber};
+use nalgebra::SMatrix;
+use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 
 /// Vereinheitlichter Delta-Kernel
@@ -27,6 +29,18 @@ pub struct DeltaKernel {
     pub graph: MetatronGraph,
     /// System-Parameter
     pub params: QRIKParams,
+    /// Dichteoperator ρ für die Offene-System-Evolution (Lindblad)
+    ///
+    /// Läuft parallel zum reinen `quantum_state`: Während `quantum_state`
+    /// stets rein bleibt (unitäre Hamilton-Evolution), absorbiert
+    /// `density_matrix` über die `collapse_operators` dissipativ
+    /// Population aus geflooteten Knoten und wird dadurch zunehmend
+    /// gemischt (S(ρ) > 0), sobald der `ResonantAbsorber` Flood-Energie
+    /// registriert.
+    pub density_matrix: DensityMatrix,
+    /// Lindblad-Kollapsoperatoren Lₖ, abgeleitet aus den Pro-Knoten-
+    /// Absorptionsraten des [`ResonantAbsorber`] über [`Self::rebuild_collapse_operators`]
+    pub collapse_operators: Vec<SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>>,
 }
 
 impl DeltaKernel {
@@ -38,6 +52,8 @@ impl DeltaKernel {
         let kuramoto = KuramotoNetwork::uniform(params.base_frequency, params.coupling_strength);
         let absorber = ResonantAbsorber::new(params.spectrum_size, params.learning_rate);
         let mandorla = MandorlaOperator::new();
+        let density_matrix = DensityMatrix::from_pure_state(&quantum_state);
+        let collapse_operators = Self::build_collapse_operators(&absorber, &params);
 
         Self {
             quantum_state,
@@ -47,6 +63,8 @@ impl DeltaKernel {
             mandorla,
             graph,
             params,
+            density_matrix,
+            collapse_operators,
         }
     }
 
@@ -66,6 +84,105 @@ impl DeltaKernel {
                 .mandorla
                 .recursive_fusion(&self.quantum_state, self.mandorla.regions.len());
         }
+
+        // 4. Offenes System: Kollapsoperatoren aus aktuellen Flood-Raten
+        //    ableiten und den Dichteoperator über die Lindblad-Gleichung
+        //    dissipativ weiterentwickeln
+        self.rebuild_collapse_operators();
+        self.evolve_open(dt);
+    }
+
+    /// Leitet die Lindblad-Kollapsoperatoren Lₖ aus den aktuellen
+    /// Pro-Knoten-Absorptionsraten des [`ResonantAbsorber`] ab
+    ///
+    /// Für jeden geflooteten Knoten i ≠ `safe_node` entsteht ein
+    /// Sprungoperator Lᵢ = √(λ·rᵢ) |safe_node⟩⟨i|, der Population von i
+    /// nach `safe_node` dissipiert; λ ist `params.dissipation_rate`, rᵢ die
+    /// Absorptionsrate an Knoten i. Knoten ohne Absorption (rᵢ = 0) tragen
+    /// keinen Operator bei.
+    pub fn rebuild_collapse_operators(&mut self) {
+        self.collapse_operators = Self::build_collapse_operators(&self.absorber, &self.params);
+    }
+
+    fn build_collapse_operators(
+        absorber: &ResonantAbsorber,
+        params: &QRIKParams,
+    ) -> Vec<SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>> {
+        let safe_node = params.safe_node.min(HILBERT_DIM - 1);
+        let mut operators = Vec::new();
+
+        for node in 0..HILBERT_DIM {
+            if node == safe_node {
+                continue;
+            }
+            let rate = params.dissipation_rate * absorber.node_absorption_rate(node);
+            if rate <= 0.0 {
+                continue;
+            }
+
+            let mut jump = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+            jump[(safe_node, node)] = Complex64::new(rate.sqrt(), 0.0);
+            operators.push(jump);
+        }
+
+        operators
+    }
+
+    /// Offene-System-Zeitevolution des Dichteoperators via Lindblad-
+    /// Mastergleichung, integriert mit Runge-Kutta 4. Ordnung
+    ///
+    /// dρ/dt = -i[Ĥ,ρ] + Σₖ (Lₖ ρ Lₖ† − ½{Lₖ†Lₖ, ρ})
+    ///
+    /// Spiegelt den RK4-Stepper aus [`KuramotoNetwork::evolve_rk4`].
+    pub fn evolve_open(&mut self, dt: f64) {
+        let hamiltonian = self.hamiltonian.matrix;
+        let rho0 = self.density_matrix.matrix;
+
+        let k1 = Self::lindblad_derivative(&rho0, &hamiltonian, &self.collapse_operators);
+        let k2 = Self::lindblad_derivative(
+            &(rho0 + k1.scale(Complex64::new(0.5 * dt, 0.0))),
+            &hamiltonian,
+            &self.collapse_operators,
+        );
+        let k3 = Self::lindblad_derivative(
+            &(rho0 + k2.scale(Complex64::new(0.5 * dt, 0.0))),
+            &hamiltonian,
+            &self.collapse_operators,
+        );
+        let k4 = Self::lindblad_derivative(
+            &(rho0 + k3.scale(Complex64::new(dt, 0.0))),
+            &hamiltonian,
+            &self.collapse_operators,
+        );
+
+        let step = (k1 + k2.scale(Complex64::new(2.0, 0.0)) + k3.scale(Complex64::new(2.0, 0.0)) + k4)
+            .scale(Complex64::new(dt / 6.0, 0.0));
+
+        self.density_matrix = DensityMatrix {
+            matrix: rho0 + step,
+        };
+    }
+
+    /// Berechnet die rechte Seite der Lindblad-Mastergleichung dρ/dt
+    fn lindblad_derivative(
+        rho: &SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
+        hamiltonian: &SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
+        collapse_operators: &[SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>],
+    ) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        let neg_i = Complex64::new(0.0, -1.0);
+        let commutator = hamiltonian * rho - rho * hamiltonian;
+
+        let mut dissipator = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+        for lindblad_op in collapse_operators {
+            let lindblad_op_dag = lindblad_op.adjoint();
+            let anticommutator_generator = lindblad_op_dag * lindblad_op;
+
+            dissipator += lindblad_op * rho * lindblad_op_dag
+                - (anticommutator_generator * rho + rho * anticommutator_generator)
+                    .scale(Complex64::new(0.5, 0.0));
+        }
+
+        commutator.scale(neg_i) + dissipator
     }
 
     /// Berechnet Delta-Gradient ∇Ψ_Δ
@@ -73,11 +190,12 @@ impl DeltaKernel {
     /// Misst Abweichung vom optimalen Kohärenzzustand
     pub fn coherence_gradient(&self) -> f64 {
         // Gradient basierend auf:
-        // 1. Quantenzustands-Kohärenz
+        // 1. Dekohärenz des Dichteoperators (echte Von-Neumann-Entropie,
+        //    reagiert auf Flood-Last via der dissipativen Lindblad-Evolution)
         // 2. Kuramoto-Ordnungsparameter
         // 3. Mandorla-Dichte
 
-        let quantum_coherence = self.quantum_state.von_neumann_entropy();
+        let quantum_coherence = self.density_matrix.von_neumann_entropy();
         let kuramoto_sync = 1.0 - self.kuramoto.synchronization();
 
         // Gesamtgradient (minimieren!)
@@ -89,6 +207,58 @@ impl DeltaKernel {
         self.coherence_gradient() < epsilon
     }
 
+    /// Treibt das System iterativ in einen stabilen Zustand, beschleunigt
+    /// durch Aitkens Δ²-Extrapolation der Folge `xₙ = coherence_gradient()`.
+    ///
+    /// Nach jedem `evolve(dt)`-Schritt werden die letzten drei
+    /// Gradientenwerte `x₀, x₁, x₂` herangezogen, um die beschleunigte
+    /// Schätzung
+    ///
+    /// x̂ = x₀ − (x₁ − x₀)² / (x₂ − 2x₁ + x₀)
+    ///
+    /// zu bilden; liegt der Nenner nahe Null (Reihe bereits linear bzw.
+    /// stationär), fällt die Schätzung auf den rohen Wert `x₂` zurück.
+    /// Konvergenz gilt als erreicht, sobald `|x̂ − x₂| < epsilon` — ein
+    /// schärferes, schnelleres Abbruchkriterium als das naive
+    /// Schwellwert-Polling über [`is_stable`](Self::is_stable).
+    ///
+    /// # Returns
+    /// `(iterations, accelerated_gradient)`: die Anzahl der durchgeführten
+    /// `evolve`-Schritte und die zuletzt berechnete (beschleunigte oder,
+    /// falls `max_iters` ohne Konvergenz ausgeschöpft wurde, rohe)
+    /// Gradientenschätzung.
+    pub fn stabilize(&mut self, dt: f64, epsilon: f64, max_iters: usize) -> (usize, f64) {
+        let mut history: Vec<f64> = Vec::with_capacity(3);
+        let mut last_gradient = self.coherence_gradient();
+
+        for iteration in 1..=max_iters {
+            self.evolve(dt);
+            last_gradient = self.coherence_gradient();
+
+            history.push(last_gradient);
+            if history.len() > 3 {
+                history.remove(0);
+            }
+
+            if history.len() == 3 {
+                let (x0, x1, x2) = (history[0], history[1], history[2]);
+                let denominator = x2 - 2.0 * x1 + x0;
+
+                let accelerated = if denominator.abs() > 1e-12 {
+                    x0 - (x1 - x0).powi(2) / denominator
+                } else {
+                    x2
+                };
+
+                if (accelerated - x2).abs() < epsilon {
+                    return (iteration, accelerated);
+                }
+            }
+        }
+
+        (max_iters, last_gradient)
+    }
+
     /// Berechnet Systemkohärenz
     pub fn coherence(&self) -> f64 {
         // Durchschnittliche Populationsdichte (vereinfacht)
@@ -129,6 +299,9 @@ impl DeltaKernel {
         let kn = NullpointOperator::new(&self.graph, 10);
         self.quantum_state = kn.apply(&self.quantum_state, &self.graph);
 
+        // Dichteoperator folgt dem zurückgesetzten reinen Zustand: S(ρ) = 0
+        self.density_matrix = DensityMatrix::from_pure_state(&self.quantum_state);
+
         // Reset Kuramoto zu desynchronisiertem Zustand
         self.kuramoto.randomize_phases();
     }
@@ -157,6 +330,14 @@ pub struct QRIKParams {
     pub epsilon_res: f64,
     /// Flood-Penalty-Gewicht
     pub lambda_flood: f64,
+    /// Basis-Dissipationsrate λ für die Lindblad-Kollapsoperatoren
+    ///
+    /// Skaliert die Pro-Knoten-Absorptionsrate des [`ResonantAbsorber`] zur
+    /// Sprungoperator-Stärke: rate = `dissipation_rate` × node_absorption_rate
+    pub dissipation_rate: f64,
+    /// Zielknoten, zu dem geflootete Knoten via Lindblad-Dissipation
+    /// entspannen (Index im Metatron-Graph, < [`crate::core::HILBERT_DIM`])
+    pub safe_node: usize,
 }
 
 impl Default for QRIKParams {
@@ -169,6 +350,8 @@ impl Default for QRIKParams {
             learning_rate: 0.01,
             epsilon_res: 0.3,
             lambda_flood: 1.0,
+            dissipation_rate: 0.5,
+            safe_node: 0,
         }
     }
 }
@@ -237,6 +420,28 @@ mod tests {
         assert!(is_stable == (kernel.coherence_gradient() < 0.001));
     }
 
+    #[test]
+    fn test_stabilize_converges_within_max_iters() {
+        let mut kernel = DeltaKernel::default();
+
+        let (iterations, gradient) = kernel.stabilize(0.01, 0.05, 500);
+
+        assert!(iterations <= 500);
+        assert!(gradient.is_finite());
+        assert!(kernel.is_stable(0.05) || iterations == 500);
+    }
+
+    #[test]
+    fn test_stabilize_matches_coherence_gradient_after_run() {
+        let mut kernel = DeltaKernel::default();
+
+        let (iterations, _gradient) = kernel.stabilize(0.01, 1e-9, 5);
+
+        // Bei Nichtkonvergenz (zu wenige Iterationen für so kleines
+        // epsilon) sollte die volle Schrittzahl ausgeschöpft worden sein.
+        assert_eq!(iterations, 5);
+    }
+
     #[test]
     fn test_packet_processing() {
         let mut kernel = DeltaKernel::default();
@@ -269,5 +474,101 @@ mod tests {
 
         kernel.reset_to_safe_state();
         assert!(kernel.quantum_state.is_normalized());
+        assert_eq!(kernel.density_matrix.von_neumann_entropy(), 0.0);
+    }
+
+    #[test]
+    fn test_no_collapse_operators_without_flood() {
+        let kernel = DeltaKernel::default();
+
+        // Ohne jemals absorbierte Pakete ist jede Pro-Knoten-Absorptionsrate
+        // 0, also entstehen keine Kollapsoperatoren.
+        assert!(kernel.collapse_operators.is_empty());
+        assert_eq!(kernel.density_matrix.trace(), 1.0);
+    }
+
+    #[test]
+    fn test_collapse_operators_grow_with_flood() {
+        let mut kernel = DeltaKernel::default();
+        kernel.absorber.initialize_random_fields();
+
+        // Knoten 1 absorbiert stark geflutete Pakete (ε_res = 2.0 erzwingt
+        // Absorption, da Resonanz-Scores stets in [0,1] liegen) ->
+        // Kollapsoperator Richtung `safe_node` (Standard: Knoten 0) sollte
+        // entstehen.
+        for _ in 0..20 {
+            kernel.absorber.process_packet(b"flood packet payload", 1, 2.0);
+        }
+        kernel.rebuild_collapse_operators();
+
+        assert!(!kernel.collapse_operators.is_empty());
+    }
+
+    #[test]
+    fn test_evolve_open_keeps_density_matrix_valid() {
+        let mut kernel = DeltaKernel::default();
+        kernel.absorber.initialize_random_fields();
+
+        for _ in 0..20 {
+            kernel.absorber.process_packet(b"flood packet payload", 1, 2.0);
+        }
+
+        for _ in 0..10 {
+            kernel.evolve(0.01);
+        }
+
+        // Trace bleibt (bis auf RK4-Diskretisierungsfehler) bei 1, und die
+        // Entropie ist nie negativ.
+        assert!((kernel.density_matrix.trace() - 1.0).abs() < 1e-2);
+        assert!(kernel.density_matrix.von_neumann_entropy() >= -1e-8);
+    }
+
+    #[test]
+    fn test_coherence_gradient_responds_to_decoherence() {
+        let mut kernel = DeltaKernel::default();
+        let gradient_before = kernel.coherence_gradient();
+
+        kernel.density_matrix = DensityMatrix::from_mixture(&[
+            (0.5, QuantumState::basis_state(0)),
+            (0.5, QuantumState::basis_state(1)),
+        ]);
+        let gradient_after = kernel.coherence_gradient();
+
+        // Ein dekohärenter (gemischter) Dichteoperator sollte den Gradienten
+        // erhöhen, da `coherence_gradient` jetzt die echte
+        // Von-Neumann-Entropie von `density_matrix` statt der
+        // Messverteilungs-Entropie von `quantum_state` verwendet.
+        assert!(gradient_after > gradient_before);
+    }
+
+    // `DeltaKernel` has no `optimize_step` method (parameter search lives
+    // in `EvolutionaryOptimizer::optimize` instead, which evolves whole
+    // populations of `QRIKParams` rather than stepping one kernel); these
+    // property tests instead cover `evolve`, the nearest real equivalent,
+    // against randomized `QRIKParams` via the shared proptest strategies
+    // in `crate::proptest_support`.
+    mod proptests {
+        use super::*;
+        use crate::proptest_support::arb_settings;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn prop_evolve_keeps_state_normalized_and_gradient_finite(
+                params in arb_settings(),
+                dt in 0.001f64..0.1,
+            ) {
+                let mut kernel = DeltaKernel::new(params);
+
+                for _ in 0..5 {
+                    kernel.evolve(dt);
+                }
+
+                prop_assert!(kernel.quantum_state.is_normalized());
+                let gradient = kernel.coherence_gradient();
+                prop_assert!(gradient.is_finite());
+                prop_assert!(gradient >= 0.0);
+            }
+        }
     }
 }
@@ -8,4 +8,4 @@ pub mod kernel;
 pub mod optimizer;
 
 pub use kernel::{DeltaKernel, QRIKParams};
-pub use optimizer::{EvolutionaryOptimizer, OptimizationResult};
+pub use optimizer::{EvolutionMode, EvolutionaryOptimizer, OptimizationResult};
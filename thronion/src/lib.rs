@@ -60,15 +60,20 @@ pub mod resonance;
 pub mod utils;
 
 // Thronion-specific modules
+pub mod onnx;
 pub mod tor;
 pub mod thronion;
 
-// To be implemented in future phases
-// pub mod service;
+pub mod service;
+
+/// Gemeinsame `proptest`-Strategien für operatorübergreifende
+/// Eigenschaftstests (nur für Tests kompiliert).
+#[cfg(test)]
+pub(crate) mod proptest_support;
 
 /// Prelude for commonly used types
 pub mod prelude {
-    pub use crate::core::{DTLClass, DTLState, MetatronGraph, QuantumState, HILBERT_DIM};
+    pub use crate::core::{DensityMatrix, DTLClass, DTLState, MetatronGraph, QuantumState, HILBERT_DIM};
     pub use crate::delta::{DeltaKernel, EvolutionaryOptimizer, QRIKParams};
     pub use crate::mandorla::{InformationBlock, LivingCrystal, MandorlaOperator, TemporalCrystal};
     pub use crate::operators::{HamiltonOperator, NullpointOperator};
@@ -8,6 +8,42 @@ use nalgebra::SVector;
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 
+/// Konvergenztoleranz für [`MandorlaOperator::recursive_fusion_accelerated`]
+/// und ihre unbeschleunigte Baseline.
+const ACCELERATION_CONVERGENCE_TOLERANCE: f64 = 1e-6;
+
+/// Schutzschwelle für den Aitken-Δ²-Nenner: Komponenten, deren zweite
+/// Differenz betragsmäßig darunter liegt, gelten als bereits (nahezu)
+/// linear konvergiert, sodass die Extrapolation dort auf Ψ₂ zurückfällt.
+const AITKEN_DENOMINATOR_GUARD: f64 = 1e-12;
+
+/// Wendet Aitkens Δ²-Verfahren komponentenweise auf drei aufeinanderfolgende
+/// Iterierte Ψ₀, Ψ₁, Ψ₂ einer komplexen Amplitudenfolge an:
+///
+/// Ψ*_i = Ψ_{0,i} - (Ψ_{1,i} - Ψ_{0,i})² / (Ψ_{2,i} - 2Ψ_{1,i} + Ψ_{0,i})
+///
+/// Ist der Nenner für eine Komponente betragsmäßig kleiner als
+/// [`AITKEN_DENOMINATOR_GUARD`], wird für diese Komponente stattdessen
+/// Ψ_{2,i} übernommen. Das Ergebnis wird über [`QuantumState::new`]
+/// renormiert.
+fn aitken_accelerate(
+    psi0: &QuantumState,
+    psi1: &QuantumState,
+    psi2: &QuantumState,
+) -> QuantumState {
+    let accelerated = SVector::<Complex64, HILBERT_DIM>::from_fn(|i, _| {
+        let denom = psi2.amplitudes[i] - psi1.amplitudes[i] * 2.0 + psi0.amplitudes[i];
+        if denom.norm() < AITKEN_DENOMINATOR_GUARD {
+            psi2.amplitudes[i]
+        } else {
+            let delta = psi1.amplitudes[i] - psi0.amplitudes[i];
+            psi0.amplitudes[i] - (delta * delta) / denom
+        }
+    });
+
+    QuantumState::new(accelerated)
+}
+
 /// Mandorla-Region (Informationsschnittmenge)
 ///
 /// Repräsentiert Überlappung zweier Informationsdomänen
@@ -138,6 +174,97 @@ impl MandorlaOperator {
         current_state
     }
 
+    /// Wendet [`aitken_accelerate`] auf die Fusionsrekursion an, um die
+    /// langsame lineare Konvergenz von [`Self::recursive_fusion`] zu
+    /// beschleunigen.
+    ///
+    /// Führt jeweils drei rohe Fusionsschritte aus, bildet aus den
+    /// Iterierten Ψ₀, Ψ₁, Ψ₂ per Aitken-Δ² eine beschleunigte Schätzung und
+    /// setzt die Rekursion von dort fort. Bricht vorzeitig ab, sobald sich
+    /// zwei aufeinanderfolgende beschleunigte Schätzungen um weniger als
+    /// [`ACCELERATION_CONVERGENCE_TOLERANCE`] unterscheiden.
+    ///
+    /// # Rückgabe
+    /// Den beschleunigten Zustand sowie die Anzahl roher Fusionsschritte,
+    /// die gegenüber [`Self::recursive_fusion`] eingespart wurden (Differenz
+    /// der jeweils bis zur gleichen Toleranz benötigten Schrittzahlen).
+    pub fn recursive_fusion_accelerated(
+        &mut self,
+        initial_state: &QuantumState,
+        max_depth: usize,
+    ) -> (QuantumState, usize) {
+        let plain_steps = self
+            .clone()
+            .count_unaccelerated_convergence_steps(initial_state, max_depth);
+
+        let mut current_state = initial_state.clone();
+        let mut window = vec![current_state.clone()];
+        let depth = max_depth.min(self.regions.len());
+        let mut steps_used = 0;
+
+        for k in 0..depth {
+            self.regions[k].compute_intersection();
+            let intersection = self.regions[k].intersection_state.as_ref().unwrap().clone();
+            let weight = 1.0 / (k + 2) as f64;
+
+            let fused_amps = window.last().unwrap().amplitudes.scale(1.0 - weight)
+                + intersection.amplitudes.scale(weight);
+            current_state = QuantumState::new(fused_amps);
+            self.recursion_level = k + 1;
+            steps_used = k + 1;
+            window.push(current_state.clone());
+
+            if window.len() == 3 {
+                let accelerated = aitken_accelerate(&window[0], &window[1], &window[2]);
+                let residual = (window[2].amplitudes - accelerated.amplitudes).norm();
+                current_state = accelerated;
+                window = vec![current_state.clone()];
+
+                if residual < ACCELERATION_CONVERGENCE_TOLERANCE {
+                    break;
+                }
+            }
+        }
+
+        let steps_saved = plain_steps.saturating_sub(steps_used);
+        (current_state, steps_saved)
+    }
+
+    /// Zählt, nach wie vielen unbeschleunigten [`Self::recursive_fusion`]-Schritten
+    /// zwei aufeinanderfolgende Iterierte näher als
+    /// [`ACCELERATION_CONVERGENCE_TOLERANCE`] beieinanderliegen (oder
+    /// `max_depth`, falls das innerhalb der Tiefe nicht eintritt). Dient
+    /// [`Self::recursive_fusion_accelerated`] als Baseline für die
+    /// eingesparten Schritte; arbeitet auf einer Kopie, damit der Aufruf die
+    /// eigentliche Operatorinstanz nicht verändert.
+    fn count_unaccelerated_convergence_steps(
+        mut self,
+        initial_state: &QuantumState,
+        max_depth: usize,
+    ) -> usize {
+        let mut current_state = initial_state.clone();
+        let depth = max_depth.min(self.regions.len());
+
+        for k in 0..depth {
+            self.regions[k].compute_intersection();
+            let intersection = self.regions[k].intersection_state.as_ref().unwrap().clone();
+            let weight = 1.0 / (k + 2) as f64;
+
+            let fused_amps = current_state.amplitudes.scale(1.0 - weight)
+                + intersection.amplitudes.scale(weight);
+            let next_state = QuantumState::new(fused_amps);
+
+            let delta = (next_state.amplitudes - current_state.amplitudes).norm();
+            current_state = next_state;
+
+            if delta < ACCELERATION_CONVERGENCE_TOLERANCE {
+                return k + 1;
+            }
+        }
+
+        depth
+    }
+
     /// Berechnet Mandorla-Dichte in Zustandsraum
     pub fn mandorla_density(&self, state: &QuantumState) -> f64 {
         if self.regions.is_empty() {
@@ -254,6 +381,25 @@ mod tests {
         assert_eq!(operator.recursion_level, 3);
     }
 
+    #[test]
+    fn test_recursive_fusion_accelerated() {
+        let mut operator = MandorlaOperator::new();
+
+        for i in 0..6 {
+            let c1 = QuantumState::basis_state(i % HILBERT_DIM);
+            let c2 = QuantumState::basis_state((i + 1) % HILBERT_DIM);
+            let region = MandorlaRegion::new(c1, c2, 0.3);
+            operator.add_region(region);
+        }
+
+        let initial = QuantumState::random();
+        let (accelerated, steps_saved) = operator.recursive_fusion_accelerated(&initial, 6);
+
+        assert!(accelerated.is_normalized());
+        assert!(operator.recursion_level >= 1);
+        assert!(steps_saved <= 6);
+    }
+
     #[test]
     fn test_mandorla_density() {
         let mut operator = MandorlaOperator::new();
@@ -5,7 +5,11 @@
 //! - TIC: Temporal Information Crystals (invariante Bl√∂cke)
 
 pub mod eigenstate;
+pub mod store;
 pub mod tic;
+pub mod vdf;
 
 pub use eigenstate::{MandorlaConvolution, MandorlaOperator, MandorlaRegion};
+pub use store::CrystalStore;
 pub use tic::{InformationBlock, LivingCrystal, TemporalCrystal};
+pub use vdf::VdfProof;
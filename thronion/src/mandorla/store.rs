@@ -0,0 +1,442 @@
+//! LSM-style persistent backing store for [`super::tic::TemporalCrystal`].
+//!
+//! Blocks are appended to an in-memory memtable keyed by timestamp, which
+//! is flushed to an immutable on-disk segment file once it grows past
+//! [`MEMTABLE_FLUSH_THRESHOLD`] records. Each segment is a small header
+//! (record count plus the min/max timestamp it covers) followed by
+//! length-prefixed, crc32-checksummed records, so [`CrystalStore::range`]
+//! can skip whole segments that don't overlap a query window instead of
+//! reading every block back into memory.
+//!
+//! This favors a simple, auditable file format over raw throughput --
+//! fine for the bounded-memory/durability guarantee the crystal needs,
+//! not a tuned storage engine.
+
+use crate::mandorla::tic::InformationBlock;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Number of records the memtable accumulates before [`CrystalStore::append`]
+/// automatically flushes it to a new segment file.
+const MEMTABLE_FLUSH_THRESHOLD: usize = 256;
+
+/// Maps an `f64` timestamp to a `u64` that sorts identically, so it can be
+/// used as a `BTreeMap` key. `id` breaks ties between blocks sharing a
+/// timestamp. This is the standard IEEE-754 bit-twiddle for a
+/// monotonic integer encoding of floats: flip the sign bit for
+/// non-negative values, flip every bit for negative ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct TimestampKey(u64, usize);
+
+impl TimestampKey {
+    fn new(timestamp: f64, id: usize) -> Self {
+        Self(Self::ordered_bits(timestamp), id)
+    }
+
+    fn ordered_bits(timestamp: f64) -> u64 {
+        let bits = timestamp.to_bits();
+        if timestamp.is_sign_negative() {
+            !bits
+        } else {
+            bits | (1 << 63)
+        }
+    }
+
+    fn lower_bound(timestamp: f64) -> Self {
+        Self(Self::ordered_bits(timestamp), usize::MIN)
+    }
+
+    fn upper_bound(timestamp: f64) -> Self {
+        Self(Self::ordered_bits(timestamp), usize::MAX)
+    }
+}
+
+/// Metadata kept in memory for a flushed segment, cheap to load at
+/// [`CrystalStore::open`] time since it's just the file's header.
+#[derive(Debug, Clone)]
+struct SegmentMeta {
+    path: PathBuf,
+    min_timestamp: f64,
+    max_timestamp: f64,
+    record_count: u32,
+}
+
+impl SegmentMeta {
+    fn overlaps(&self, t1: f64, t2: f64) -> bool {
+        self.min_timestamp <= t2 && self.max_timestamp >= t1
+    }
+}
+
+/// An append-only, segment-backed store of [`InformationBlock`]s.
+#[derive(Debug, Clone)]
+pub struct CrystalStore {
+    dir: PathBuf,
+    memtable: BTreeMap<TimestampKey, InformationBlock>,
+    segments: Vec<SegmentMeta>,
+    next_segment_id: u64,
+}
+
+impl CrystalStore {
+    /// Opens the store rooted at `dir`, creating it (and loading the
+    /// headers of any segment files already present) if needed.
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir).context("failed to create crystal store directory")?;
+
+        let mut segments = Vec::new();
+        let mut max_segment_id = 0u64;
+
+        let mut entries: Vec<PathBuf> = std::fs::read_dir(&dir)
+            .context("failed to list crystal store directory")?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with("segment-") && n.ends_with(".tic"))
+            })
+            .collect();
+        entries.sort();
+
+        for path in entries {
+            let meta = read_segment_header(&path)?;
+            if let Some(id) = segment_id(&path) {
+                max_segment_id = max_segment_id.max(id + 1);
+            }
+            segments.push(meta);
+        }
+
+        Ok(Self {
+            dir,
+            memtable: BTreeMap::new(),
+            segments,
+            next_segment_id: max_segment_id,
+        })
+    }
+
+    /// Appends `block` to the memtable, flushing to a new segment file
+    /// once the memtable exceeds [`MEMTABLE_FLUSH_THRESHOLD`] records.
+    pub fn append(&mut self, block: InformationBlock) -> Result<()> {
+        let key = TimestampKey::new(block.timestamp, block.id);
+        self.memtable.insert(key, block);
+
+        if self.memtable.len() >= MEMTABLE_FLUSH_THRESHOLD {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the current memtable out as a new immutable segment file.
+    /// A no-op if the memtable is empty.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.memtable.is_empty() {
+            return Ok(());
+        }
+
+        let blocks: Vec<&InformationBlock> = self.memtable.values().collect();
+        let path = self.dir.join(format!("segment-{:020}.tic", self.next_segment_id));
+        write_segment(&path, &blocks)?;
+
+        let meta = read_segment_header(&path)?;
+        self.segments.push(meta);
+        self.next_segment_id += 1;
+        self.memtable.clear();
+        Ok(())
+    }
+
+    /// Merges every segment (plus any pending memtable contents) into a
+    /// single new segment, dropping blocks that fail
+    /// [`InformationBlock::verify_integrity`] and deleting the
+    /// now-superseded segment files.
+    pub fn compact(&mut self) -> Result<()> {
+        self.flush()?;
+
+        let mut merged: BTreeMap<TimestampKey, InformationBlock> = BTreeMap::new();
+        for segment in &self.segments {
+            for block in read_segment(&segment.path)? {
+                if block.verify_integrity() {
+                    merged.insert(TimestampKey::new(block.timestamp, block.id), block);
+                }
+            }
+        }
+
+        let old_paths: Vec<PathBuf> = self.segments.iter().map(|s| s.path.clone()).collect();
+
+        if merged.is_empty() {
+            self.segments.clear();
+        } else {
+            let blocks: Vec<&InformationBlock> = merged.values().collect();
+            let path = self.dir.join(format!("segment-{:020}.tic", self.next_segment_id));
+            write_segment(&path, &blocks)?;
+            self.next_segment_id += 1;
+
+            let meta = read_segment_header(&path)?;
+            self.segments = vec![meta];
+        }
+
+        for path in old_paths {
+            let _ = std::fs::remove_file(path);
+        }
+
+        Ok(())
+    }
+
+    /// Returns every block with `timestamp` in `[t1, t2]`, gathered from
+    /// the memtable and any overlapping segments, sorted by timestamp.
+    /// Segments whose `[min, max]` range doesn't intersect `[t1, t2]`
+    /// are skipped without being read.
+    pub fn range(&self, t1: f64, t2: f64) -> Result<Vec<InformationBlock>> {
+        let mut found: Vec<InformationBlock> = self
+            .memtable
+            .range(TimestampKey::lower_bound(t1)..=TimestampKey::upper_bound(t2))
+            .map(|(_, block)| block.clone())
+            .collect();
+
+        for segment in &self.segments {
+            if !segment.overlaps(t1, t2) {
+                continue;
+            }
+            for block in read_segment(&segment.path)? {
+                if block.timestamp >= t1 && block.timestamp <= t2 {
+                    found.push(block);
+                }
+            }
+        }
+
+        found.sort_by(|a, b| a.timestamp.total_cmp(&b.timestamp));
+        Ok(found)
+    }
+
+    /// Returns the first block within `tolerance` of `time`, searching
+    /// the memtable and overlapping segments.
+    pub fn find_at_time(&self, time: f64, tolerance: f64) -> Result<Option<InformationBlock>> {
+        let window = self.range(time - tolerance, time + tolerance)?;
+        Ok(window
+            .into_iter()
+            .find(|b| (b.timestamp - time).abs() < tolerance))
+    }
+
+    /// Returns every block currently held by the store (memtable plus
+    /// all segments), used to hydrate a [`super::tic::TemporalCrystal`]'s
+    /// resident block list when opening an existing store.
+    pub fn all_blocks(&self) -> Result<Vec<InformationBlock>> {
+        let mut blocks: Vec<InformationBlock> = self.memtable.values().cloned().collect();
+        for segment in &self.segments {
+            blocks.extend(read_segment(&segment.path)?);
+        }
+        blocks.sort_by(|a, b| a.timestamp.total_cmp(&b.timestamp));
+        Ok(blocks)
+    }
+}
+
+/// Extracts the numeric id from a `segment-00000000000000000042.tic` path.
+fn segment_id(path: &Path) -> Option<u64> {
+    path.file_stem()?
+        .to_str()?
+        .strip_prefix("segment-")?
+        .parse()
+        .ok()
+}
+
+/// Segment file layout: `[record_count: u32][min_ts: f64][max_ts: f64]`
+/// header, followed by `record_count` records of
+/// `[len: u32][toml bytes][crc32: u32]`.
+fn write_segment(path: &Path, blocks: &[&InformationBlock]) -> Result<()> {
+    let min_timestamp = blocks
+        .iter()
+        .map(|b| b.timestamp)
+        .fold(f64::INFINITY, f64::min);
+    let max_timestamp = blocks
+        .iter()
+        .map(|b| b.timestamp)
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&(blocks.len() as u32).to_le_bytes());
+    out.extend_from_slice(&min_timestamp.to_le_bytes());
+    out.extend_from_slice(&max_timestamp.to_le_bytes());
+
+    for block in blocks {
+        let payload = toml::to_string(block).context("failed to serialize block")?;
+        let payload = payload.as_bytes();
+        let checksum = crc32fast::hash(payload);
+
+        out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        out.extend_from_slice(payload);
+        out.extend_from_slice(&checksum.to_le_bytes());
+    }
+
+    let mut file = File::create(path).context("failed to create segment file")?;
+    file.write_all(&out).context("failed to write segment file")?;
+    Ok(())
+}
+
+const SEGMENT_HEADER_LEN: usize = 4 + 8 + 8;
+
+fn read_segment_header(path: &Path) -> Result<SegmentMeta> {
+    let mut file = File::open(path).context("failed to open segment file")?;
+    let mut header = [0u8; SEGMENT_HEADER_LEN];
+    file.read_exact(&mut header)
+        .context("segment file shorter than its header")?;
+
+    let record_count = u32::from_le_bytes(header[0..4].try_into().unwrap());
+    let min_timestamp = f64::from_le_bytes(header[4..12].try_into().unwrap());
+    let max_timestamp = f64::from_le_bytes(header[12..20].try_into().unwrap());
+
+    Ok(SegmentMeta {
+        path: path.to_path_buf(),
+        min_timestamp,
+        max_timestamp,
+        record_count,
+    })
+}
+
+/// Reads every record out of a segment file, skipping (not failing on)
+/// any record whose checksum doesn't match -- a single corrupted record
+/// shouldn't take down the whole segment.
+fn read_segment(path: &Path) -> Result<Vec<InformationBlock>> {
+    let mut file = File::open(path).context("failed to open segment file")?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)
+        .context("failed to read segment file")?;
+
+    if bytes.len() < SEGMENT_HEADER_LEN {
+        anyhow::bail!("segment file {} shorter than its header", path.display());
+    }
+
+    let record_count = u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as usize;
+    let mut cursor = SEGMENT_HEADER_LEN;
+    let mut blocks = Vec::with_capacity(record_count);
+
+    for _ in 0..record_count {
+        if cursor + 4 > bytes.len() {
+            break;
+        }
+        let len = u32::from_le_bytes(bytes[cursor..cursor + 4].try_into().unwrap()) as usize;
+        cursor += 4;
+
+        if cursor + len + 4 > bytes.len() {
+            break;
+        }
+        let payload = &bytes[cursor..cursor + len];
+        let checksum = u32::from_le_bytes(bytes[cursor + len..cursor + len + 4].try_into().unwrap());
+        cursor += len + 4;
+
+        if crc32fast::hash(payload) != checksum {
+            // Corrupted record -- skip it and keep reading the rest of
+            // the segment.
+            continue;
+        }
+
+        let Ok(text) = std::str::from_utf8(payload) else {
+            continue;
+        };
+        if let Ok(block) = toml::from_str::<InformationBlock>(text) {
+            blocks.push(block);
+        }
+    }
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::QuantumState;
+
+    fn temp_store_dir(name: &str) -> PathBuf {
+        let id = crate::utils::test_support::unique_id();
+        std::env::temp_dir().join(format!("thronion-crystal-store-{name}-{id}"))
+    }
+
+    #[test]
+    fn test_append_and_range_without_flush() {
+        let dir = temp_store_dir("range");
+        let mut store = CrystalStore::open(&dir).unwrap();
+
+        for i in 0..5 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            store.append(block).unwrap();
+        }
+
+        let found = store.range(1.0, 3.0).unwrap();
+        assert_eq!(found.len(), 3);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_flush_then_reopen_preserves_blocks() {
+        let dir = temp_store_dir("reopen");
+        {
+            let mut store = CrystalStore::open(&dir).unwrap();
+            for i in 0..5 {
+                let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+                store.append(block).unwrap();
+            }
+            store.flush().unwrap();
+        }
+
+        let reopened = CrystalStore::open(&dir).unwrap();
+        assert_eq!(reopened.all_blocks().unwrap().len(), 5);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_automatic_flush_past_threshold() {
+        let dir = temp_store_dir("auto-flush");
+        let mut store = CrystalStore::open(&dir).unwrap();
+
+        for i in 0..(MEMTABLE_FLUSH_THRESHOLD + 10) {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            store.append(block).unwrap();
+        }
+
+        assert!(!store.segments.is_empty());
+        assert_eq!(store.all_blocks().unwrap().len(), MEMTABLE_FLUSH_THRESHOLD + 10);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_compact_drops_tampered_blocks() {
+        let dir = temp_store_dir("compact");
+        let mut store = CrystalStore::open(&dir).unwrap();
+
+        let good = InformationBlock::new(0, QuantumState::random(), 0.0);
+        let mut bad = InformationBlock::new(1, QuantumState::random(), 1.0);
+        bad.hash = [0u8; 32]; // corrupt: no longer matches its own state
+
+        store.append(good).unwrap();
+        store.append(bad).unwrap();
+        store.flush().unwrap();
+        store.compact().unwrap();
+
+        let blocks = store.all_blocks().unwrap();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].id, 0);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_find_at_time() {
+        let dir = temp_store_dir("find");
+        let mut store = CrystalStore::open(&dir).unwrap();
+
+        for i in 0..5 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            store.append(block).unwrap();
+        }
+        store.flush().unwrap();
+
+        let found = store.find_at_time(2.0, 0.5).unwrap();
+        assert_eq!(found.unwrap().id, 2);
+        assert!(store.find_at_time(99.0, 0.5).unwrap().is_none());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
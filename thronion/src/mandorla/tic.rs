@@ -0,0 +1,1000 @@
+//! Temporal Information Crystals (TIC)
+//!
+//! Implementiert invariante Informationsblöcke mit temporaler Stabilität
+//! C_TIC = ⊗_{k=0}^N B_k
+//!
+//! Block-Integrität wird über einen kryptographischen Hash (blake3) statt
+//! `DefaultHasher` verifiziert, und `TemporalCrystal` hält zusätzlich
+//! einen Merkle-Root über alle Block-Hashes, sodass `check_invariance`
+//! auch erkennt, *welche* Blöcke im Kristall vorhanden sind, und ein
+//! Prüfer einen einzelnen Block mit einem O(log n)-Inklusionsbeweis
+//! gegen den Root verifizieren kann, ohne den ganzen Kristall zu kennen.
+//!
+//! `⊗_{k=0}^N B_k` is taken literally in `CompositionMode::TensorProduct`
+//! (the default): `TemporalCrystal::compute_crystal_state` forms the true
+//! Kronecker product of the blocks' amplitude vectors, bounded by
+//! `max_composite_dim` since its dimension grows exponentially in the
+//! block count.
+
+use crate::core::QuantumState;
+use crate::mandorla::eigenstate::MandorlaRegion;
+use crate::mandorla::store::CrystalStore;
+use crate::mandorla::vdf::{self, VdfProof};
+use anyhow::Result;
+use ndarray::Array1;
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Informationsblock B_k
+///
+/// Unveränderliche Einheit mit semantischer Bedeutung
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InformationBlock {
+    /// Block-ID
+    pub id: usize,
+    /// Quantenzustand des Blocks
+    pub state: QuantumState,
+    /// Zeitstempel (Erstellungszeit)
+    pub timestamp: f64,
+    /// Content-addressed Invarianz-Hash (blake3 über die Amplituden), der
+    /// auch als Merkle-Blatt in [`TemporalCrystal::merkle_root`] dient.
+    pub hash: [u8; 32],
+    /// Verifiable-delay proof that `vdf.t` sequential squarings elapsed
+    /// since the predecessor block's hash -- see [`Self::with_delay_proof`]
+    /// and [`TemporalCrystal::verify_temporal_order`]. `None` for blocks
+    /// built via [`Self::new`], which makes no ordering claim beyond the
+    /// unverifiable `timestamp` field.
+    pub vdf: Option<VdfProof>,
+}
+
+impl InformationBlock {
+    /// Erstellt neuen Informationsblock
+    pub fn new(id: usize, state: QuantumState, timestamp: f64) -> Self {
+        let hash = Self::compute_hash(&state);
+        Self {
+            id,
+            state,
+            timestamp,
+            hash,
+            vdf: None,
+        }
+    }
+
+    /// Like [`Self::new`], but also attaches a Wesolowski VDF proof that
+    /// `t` sequential squarings elapsed since `predecessor_hash` (the
+    /// previous block's [`Self::hash`], or this block's own hash for the
+    /// first block in a crystal). This is what lets
+    /// [`TemporalCrystal::verify_temporal_order`] vouch for the block's
+    /// position in the sequence, rather than trusting the unverifiable
+    /// `timestamp` field.
+    pub fn with_delay_proof(
+        id: usize,
+        state: QuantumState,
+        timestamp: f64,
+        predecessor_hash: [u8; 32],
+        t: u64,
+    ) -> Self {
+        let hash = Self::compute_hash(&state);
+        let proof = vdf::compute(&predecessor_hash, t);
+        Self {
+            id,
+            state,
+            timestamp,
+            hash,
+            vdf: Some(proof),
+        }
+    }
+
+    /// Berechnet den Hash des Zustands über alle Amplituden-Bits
+    fn compute_hash(state: &QuantumState) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+
+        for amp in state.amplitudes.iter() {
+            hasher.update(&amp.re.to_bits().to_le_bytes());
+            hasher.update(&amp.im.to_bits().to_le_bytes());
+        }
+
+        *hasher.finalize().as_bytes()
+    }
+
+    /// Verifiziert Integrität des Blocks
+    pub fn verify_integrity(&self) -> bool {
+        let current_hash = Self::compute_hash(&self.state);
+        current_hash == self.hash
+    }
+
+    /// Berechnet Ähnlichkeit mit anderem Block
+    pub fn similarity(&self, other: &Self) -> f64 {
+        self.state.fidelity(&other.state)
+    }
+}
+
+/// Identifies which side of a hashed pair a Merkle proof step's sibling
+/// hash sits on, so [`verify_inclusion`] knows whether to hash
+/// `sibling || current` or `current || sibling` at that level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    /// The sibling hash is the left operand.
+    Left,
+    /// The sibling hash is the right operand.
+    Right,
+}
+
+/// Hashes a pair of Merkle nodes as `H(left || right)`.
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(left);
+    hasher.update(right);
+    *hasher.finalize().as_bytes()
+}
+
+/// Builds every level of the Merkle tree bottom-up from `leaves`,
+/// duplicating the last leaf of a level when its length is odd. Returns
+/// `[[0u8; 32]]` as the (single) level for an empty crystal. The last
+/// level always holds exactly one hash: the root.
+fn merkle_levels(leaves: &[[u8; 32]]) -> Vec<Vec<[u8; 32]>> {
+    if leaves.is_empty() {
+        return vec![vec![[0u8; 32]]];
+    }
+
+    let mut levels = vec![leaves.to_vec()];
+
+    while levels.last().expect("levels is never empty").len() > 1 {
+        let current = levels.last().expect("levels is never empty");
+        let mut next = Vec::with_capacity((current.len() + 1) / 2);
+        let mut i = 0;
+
+        while i < current.len() {
+            let left = current[i];
+            let right = if i + 1 < current.len() {
+                current[i + 1]
+            } else {
+                current[i]
+            };
+            next.push(hash_pair(&left, &right));
+            i += 2;
+        }
+
+        levels.push(next);
+    }
+
+    levels
+}
+
+/// Verifies that `block_hash` is included under `root` given `proof`, an
+/// O(log n) sibling-hash path as returned by
+/// [`TemporalCrystal::inclusion_proof`]. Folds the proof bottom-up,
+/// hashing each step as `H(left || right)` according to its [`Side`],
+/// and checks the final hash against `root`.
+pub fn verify_inclusion(root: [u8; 32], block_hash: [u8; 32], proof: &[(Side, [u8; 32])]) -> bool {
+    let mut current = block_hash;
+
+    for (side, sibling) in proof {
+        current = match side {
+            Side::Left => hash_pair(sibling, &current),
+            Side::Right => hash_pair(&current, sibling),
+        };
+    }
+
+    current == root
+}
+
+/// Selects how [`TemporalCrystal::compute_crystal_state`] forms the
+/// crystal's composite representation from `blocks`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompositionMode {
+    /// Forms the true tensor product ⊗ B_k: the composite dimension is
+    /// ∏ d_k over every block's amplitude vector, with amplitude at
+    /// multi-index (i_0,...,i_N) equal to ∏ B_k\[i_k\]. Cached in
+    /// [`TemporalCrystal::tensor_crystal_state`] when ∏ d_k stays within
+    /// [`TemporalCrystal::max_composite_dim`]; falls back to
+    /// `WeightedSum` above that bound, since ∏ d_k grows exponentially
+    /// in the block count.
+    TensorProduct,
+    /// The original decaying weighted-sum approximation: cheap at any
+    /// block count, but does not reflect genuine multi-block
+    /// entanglement structure.
+    WeightedSum,
+}
+
+/// Default bound on the Kronecker composite dimension ∏ d_k that
+/// [`CompositionMode::TensorProduct`] will compute before falling back
+/// to [`CompositionMode::WeightedSum`]. Every block's amplitude vector
+/// has dimension [`crate::core::HILBERT_DIM`] (13), so this comfortably
+/// covers crystals of up to three blocks (13³ = 2197) while a fourth
+/// (13⁴ = 28561) falls back.
+const DEFAULT_MAX_COMPOSITE_DIM: usize = 4096;
+
+/// Computes the Kronecker product of two amplitude vectors: the result
+/// has dimension `a.len() * b.len()`, with entry `a[i] * b[j]` at index
+/// `i * b.len() + j`.
+fn kronecker(a: &Array1<Complex64>, b: &Array1<Complex64>) -> Array1<Complex64> {
+    let mut out = Array1::from_elem(a.len() * b.len(), Complex64::new(0.0, 0.0));
+    for (i, ai) in a.iter().enumerate() {
+        for (j, bj) in b.iter().enumerate() {
+            out[i * b.len() + j] = ai * bj;
+        }
+    }
+    out
+}
+
+/// Temporal Information Crystal
+///
+/// C_TIC = ⊗_{k=0}^N B_k mit Invarianz-Eigenschaft
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemporalCrystal {
+    /// Sammlung von Informationsblöcken
+    pub blocks: Vec<InformationBlock>,
+    /// Mandorla-Regionen für Blöcke
+    pub mandorla_regions: Vec<MandorlaRegion>,
+    /// Globaler Kristall-Zustand. Note this is always the decaying
+    /// weighted-sum approximation, regardless of `composition_mode`: H₁₃
+    /// has no room for a genuine multi-block tensor product, so the
+    /// true composite (when computed) lives in
+    /// [`Self::tensor_crystal_state`] instead.
+    pub crystal_state: Option<QuantumState>,
+    /// Merkle-Root über `blocks`' Hashes (in Einfüge-Reihenfolge), damit
+    /// ein Prüfer die Mitgliedschaft eines einzelnen Blocks verifizieren
+    /// kann, ohne den ganzen Kristall zu kennen.
+    pub merkle_root: [u8; 32],
+    /// How [`Self::compute_crystal_state`] forms the composite state.
+    /// Defaults to [`CompositionMode::TensorProduct`].
+    pub composition_mode: CompositionMode,
+    /// Bound on the Kronecker composite dimension ∏ d_k above which
+    /// [`CompositionMode::TensorProduct`] falls back to the weighted-sum
+    /// approximation. Defaults to [`DEFAULT_MAX_COMPOSITE_DIM`].
+    pub max_composite_dim: usize,
+    /// The true Kronecker composite ⊗ B_k from the most recent
+    /// [`Self::compute_crystal_state`] call, renormalized. `None` if the
+    /// crystal is empty, `composition_mode` is
+    /// [`CompositionMode::WeightedSum`], or the composite dimension
+    /// exceeded `max_composite_dim`.
+    #[serde(skip)]
+    tensor_crystal_state: Option<Array1<Complex64>>,
+    /// Optional on-disk backing store for durability and bounded-memory
+    /// ingestion -- see [`Self::open`]. `None` for a purely in-memory
+    /// crystal (the default, and what [`Self::new`] gives you).
+    #[serde(skip)]
+    store: Option<CrystalStore>,
+}
+
+impl TemporalCrystal {
+    /// Erstellt neuen Temporal Crystal
+    pub fn new() -> Self {
+        Self {
+            blocks: Vec::new(),
+            mandorla_regions: Vec::new(),
+            crystal_state: None,
+            merkle_root: merkle_levels(&[])[0][0],
+            composition_mode: CompositionMode::TensorProduct,
+            max_composite_dim: DEFAULT_MAX_COMPOSITE_DIM,
+            tensor_crystal_state: None,
+            store: None,
+        }
+    }
+
+    /// Opens (creating if needed) a durable, LSM-style backing store at
+    /// `path` and hydrates `blocks` with whatever it already holds. Once
+    /// opened, [`Self::add_block`] also appends to the store, and
+    /// [`Self::extract_time_window`]/[`Self::find_block_at_time`] scan
+    /// the store (memtable + segments) rather than the in-memory `blocks`
+    /// Vec, so time-window queries stay cheap even once the crystal holds
+    /// far more blocks than comfortably fit in memory.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let store = CrystalStore::open(path)?;
+        let blocks = store.all_blocks()?;
+
+        let mut crystal = Self {
+            blocks,
+            mandorla_regions: Vec::new(),
+            crystal_state: None,
+            merkle_root: [0u8; 32],
+            composition_mode: CompositionMode::TensorProduct,
+            max_composite_dim: DEFAULT_MAX_COMPOSITE_DIM,
+            tensor_crystal_state: None,
+            store: Some(store),
+        };
+        crystal.rebuild_merkle_root();
+        Ok(crystal)
+    }
+
+    /// Flushes the backing store's memtable to a new on-disk segment. A
+    /// no-op for a crystal not opened via [`Self::open`].
+    pub fn flush(&mut self) -> Result<()> {
+        if let Some(store) = &mut self.store {
+            store.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Merges the backing store's segments, dropping any block that
+    /// fails [`InformationBlock::verify_integrity`]. A no-op for a
+    /// crystal not opened via [`Self::open`].
+    pub fn compact(&mut self) -> Result<()> {
+        if let Some(store) = &mut self.store {
+            store.compact()?;
+        }
+        Ok(())
+    }
+
+    /// Fügt Informationsblock hinzu. Also durably appends the block to
+    /// the backing store if this crystal was opened via [`Self::open`].
+    pub fn add_block(&mut self, block: InformationBlock) -> Result<()> {
+        if let Some(store) = &mut self.store {
+            store.append(block.clone())?;
+        }
+
+        self.blocks.push(block);
+        // Invalidiere Kristall-Zustand (muss neu berechnet werden)
+        self.crystal_state = None;
+        self.tensor_crystal_state = None;
+        self.rebuild_merkle_root();
+        Ok(())
+    }
+
+    /// Sets [`Self::composition_mode`].
+    pub fn set_composition_mode(&mut self, mode: CompositionMode) {
+        self.composition_mode = mode;
+    }
+
+    /// Sets [`Self::max_composite_dim`].
+    pub fn set_max_composite_dim(&mut self, max_composite_dim: usize) {
+        self.max_composite_dim = max_composite_dim;
+    }
+
+    /// The true Kronecker composite from the most recent
+    /// [`Self::compute_crystal_state`] call -- see
+    /// [`Self::tensor_crystal_state`] (the field) for when this is
+    /// `None`.
+    pub fn tensor_crystal_state(&self) -> Option<&Array1<Complex64>> {
+        self.tensor_crystal_state.as_ref()
+    }
+
+    /// Recomputes [`Self::merkle_root`] from the current `blocks`' hashes.
+    fn rebuild_merkle_root(&mut self) {
+        let leaves: Vec<[u8; 32]> = self.blocks.iter().map(|b| b.hash).collect();
+        let levels = merkle_levels(&leaves);
+        self.merkle_root = levels
+            .last()
+            .and_then(|level| level.first())
+            .copied()
+            .expect("merkle_levels always produces a non-empty root level");
+    }
+
+    /// Berechnet Kristall-Zustand C_TIC = ⊗ B_k
+    ///
+    /// [`Self::crystal_state`] (and this method's return value) is
+    /// always the decaying weighted-sum approximation, since H₁₃ is
+    /// fixed at 13 dimensions and has no room for a genuine multi-block
+    /// tensor product. When `composition_mode` is
+    /// [`CompositionMode::TensorProduct`] and the composite dimension
+    /// ∏ d_k fits within `max_composite_dim`, this additionally computes
+    /// the true Kronecker product and caches it in
+    /// [`Self::tensor_crystal_state`] for callers that want the genuine
+    /// entanglement structure rather than the approximation.
+    pub fn compute_crystal_state(&mut self) -> QuantumState {
+        self.rebuild_merkle_root();
+
+        if self.blocks.is_empty() {
+            self.tensor_crystal_state = None;
+            return QuantumState::default();
+        }
+
+        self.tensor_crystal_state = self.try_tensor_product();
+
+        // Gewichtete Summe aller Blöcke
+        let mut composite_amps = self.blocks[0].state.amplitudes;
+
+        for (i, block) in self.blocks.iter().enumerate().skip(1) {
+            let weight = 1.0 / (i + 1) as f64;
+            composite_amps =
+                composite_amps.scale(1.0 - weight) + block.state.amplitudes.scale(weight);
+        }
+
+        let state = QuantumState::new(composite_amps);
+        self.crystal_state = Some(state.clone());
+        state
+    }
+
+    /// Forms the Kronecker composite ⊗ B_k over `blocks`, renormalized,
+    /// or `None` if `composition_mode` is
+    /// [`CompositionMode::WeightedSum`] or the composite dimension
+    /// ∏ d_k exceeds `max_composite_dim`.
+    fn try_tensor_product(&self) -> Option<Array1<Complex64>> {
+        if self.composition_mode != CompositionMode::TensorProduct {
+            return None;
+        }
+
+        let composite_dim: usize = self
+            .blocks
+            .iter()
+            .map(|b| b.state.amplitudes.len())
+            .product();
+        if composite_dim > self.max_composite_dim {
+            return None;
+        }
+
+        let mut composite = Array1::from_elem(1, Complex64::new(1.0, 0.0));
+        for block in &self.blocks {
+            let block_amps: Array1<Complex64> = block.state.amplitudes.iter().copied().collect();
+            composite = kronecker(&composite, &block_amps);
+        }
+
+        let norm = composite.iter().map(|amp| amp.norm_sqr()).sum::<f64>().sqrt();
+        if norm < 1e-10 {
+            return None;
+        }
+        composite.mapv_inplace(|amp| amp / norm);
+        Some(composite)
+    }
+
+    /// Prüft temporale Invarianz
+    ///
+    /// C_TIC(γ) = C_TIC(T(γ)) für zulässige Transformationen. Neben der
+    /// Integrität jedes einzelnen Blocks wird auch der Merkle-Root gegen
+    /// die aktuellen Blöcke neu verifiziert, sodass auch das Einfügen,
+    /// Entfernen oder Vertauschen ganzer Blöcke erkannt wird -- nicht nur
+    /// Bit-Rot innerhalb eines Blocks.
+    pub fn check_invariance(&self) -> bool {
+        if !self.blocks.iter().all(|b| b.verify_integrity()) {
+            return false;
+        }
+
+        let leaves: Vec<[u8; 32]> = self.blocks.iter().map(|b| b.hash).collect();
+        let levels = merkle_levels(&leaves);
+        let current_root = levels
+            .last()
+            .and_then(|level| level.first())
+            .copied()
+            .expect("merkle_levels always produces a non-empty root level");
+
+        current_root == self.merkle_root
+    }
+
+    /// Returns an O(log n) inclusion proof for the block whose `id`
+    /// matches `block_id` -- a sibling-hash path from that block's leaf
+    /// up to [`Self::merkle_root`], consumable by [`verify_inclusion`].
+    /// Returns `None` if no block with that id is present.
+    pub fn inclusion_proof(&self, block_id: usize) -> Option<Vec<(Side, [u8; 32])>> {
+        let mut index = self.blocks.iter().position(|b| b.id == block_id)?;
+        let leaves: Vec<[u8; 32]> = self.blocks.iter().map(|b| b.hash).collect();
+        let levels = merkle_levels(&leaves);
+
+        let mut proof = Vec::with_capacity(levels.len().saturating_sub(1));
+
+        for level in &levels[..levels.len() - 1] {
+            let is_left_child = index % 2 == 0;
+            let sibling_index = if is_left_child { index + 1 } else { index - 1 };
+            let sibling_index = sibling_index.min(level.len() - 1);
+            let side = if is_left_child { Side::Right } else { Side::Left };
+
+            proof.push((side, level[sibling_index]));
+            index /= 2;
+        }
+
+        Some(proof)
+    }
+
+    /// Berechnet Kristall-Kohärenz
+    pub fn coherence(&self) -> f64 {
+        if self.blocks.len() < 2 {
+            return 1.0;
+        }
+
+        let mut total_similarity = 0.0;
+        let mut count = 0;
+
+        for i in 0..self.blocks.len() {
+            for j in (i + 1)..self.blocks.len() {
+                total_similarity += self.blocks[i].similarity(&self.blocks[j]);
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            total_similarity / count as f64
+        } else {
+            1.0
+        }
+    }
+
+    /// Walks `blocks` in order and verifies each one's VDF proof
+    /// (see [`InformationBlock::with_delay_proof`]) against its
+    /// predecessor's hash, rejecting the crystal if any proof fails to
+    /// verify, any block is missing a proof, or any proof's `t` falls
+    /// below `min_t`. This is what makes the block ordering trustworthy:
+    /// unlike `timestamp`, a VDF proof cannot be forged without actually
+    /// performing the sequential computation it attests to.
+    pub fn verify_temporal_order(&self, min_t: u64) -> bool {
+        let mut predecessor_hash: Option<[u8; 32]> = None;
+
+        for block in &self.blocks {
+            let Some(proof) = &block.vdf else {
+                return false;
+            };
+            if proof.t < min_t {
+                return false;
+            }
+
+            let seed = predecessor_hash.unwrap_or(block.hash);
+            if !vdf::verify(&seed, proof) {
+                return false;
+            }
+
+            predecessor_hash = Some(block.hash);
+        }
+
+        true
+    }
+
+    /// Findet Block nach Zeitstempel. When this crystal was opened via
+    /// [`Self::open`], this does a ranged scan over the backing store's
+    /// memtable plus whichever segments overlap `[time - tolerance, time
+    /// + tolerance]`, instead of scanning every resident `blocks` entry.
+    pub fn find_block_at_time(&self, time: f64, tolerance: f64) -> Result<Option<InformationBlock>> {
+        if let Some(store) = &self.store {
+            return store.find_at_time(time, tolerance);
+        }
+
+        Ok(self
+            .blocks
+            .iter()
+            .find(|b| (b.timestamp - time).abs() < tolerance)
+            .cloned())
+    }
+
+    /// Extrahiert Zeitfenster [t1, t2]. When this crystal was opened via
+    /// [`Self::open`], this does a ranged scan over the backing store
+    /// (memtable plus overlapping segments only) rather than filtering
+    /// the full `blocks` Vec, so it stays cheap even once the crystal
+    /// holds far more blocks than are resident in memory.
+    pub fn extract_time_window(&self, t1: f64, t2: f64) -> Result<Vec<InformationBlock>> {
+        if let Some(store) = &self.store {
+            return store.range(t1, t2);
+        }
+
+        Ok(self
+            .blocks
+            .iter()
+            .filter(|b| b.timestamp >= t1 && b.timestamp <= t2)
+            .cloned()
+            .collect())
+    }
+}
+
+impl Default for TemporalCrystal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Living Information Crystal (C_LIV)
+///
+/// C_LIV = lim_{n→∞} ⋂_{k=0}^n [M_k ⋆ B_k]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LivingCrystal {
+    /// Basis Temporal Crystal
+    pub temporal_crystal: TemporalCrystal,
+    /// Konvergenz-Level
+    pub convergence_level: usize,
+    /// Invarianz-Metadaten
+    pub metadata: HashMap<String, String>,
+}
+
+impl LivingCrystal {
+    /// Erstellt neuen Living Crystal
+    pub fn new() -> Self {
+        Self {
+            temporal_crystal: TemporalCrystal::new(),
+            convergence_level: 0,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Iterative Konvergenz: ⋂_{k=0}^n [M_k ⋆ B_k]
+    pub fn converge(&mut self, max_iterations: usize) {
+        for _ in 0..max_iterations {
+            if self.temporal_crystal.blocks.is_empty() {
+                break;
+            }
+
+            // Recompute crystal state
+            self.temporal_crystal.compute_crystal_state();
+            self.convergence_level += 1;
+
+            // Prüfe Konvergenz
+            if self.temporal_crystal.coherence() > 0.99 {
+                break;
+            }
+        }
+    }
+
+    /// Prüft Mandorla-Bedingung: C_LIV(γ) = C_LIV(T(γ))
+    pub fn verify_mandorla_condition(&self) -> bool {
+        self.temporal_crystal.check_invariance()
+    }
+
+    /// Speichert Metadaten
+    pub fn set_metadata(&mut self, key: String, value: String) {
+        self.metadata.insert(key, value);
+    }
+
+    /// Abruft Metadaten
+    pub fn get_metadata(&self, key: &str) -> Option<&String> {
+        self.metadata.get(key)
+    }
+}
+
+impl Default for LivingCrystal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_information_block_creation() {
+        let state = QuantumState::random();
+        let block = InformationBlock::new(0, state, 0.0);
+
+        assert_eq!(block.id, 0);
+        assert!(block.verify_integrity());
+    }
+
+    #[test]
+    fn test_block_integrity_verification() {
+        let state = QuantumState::random();
+        let mut block = InformationBlock::new(0, state, 0.0);
+
+        assert!(block.verify_integrity());
+
+        // Modifiziere Zustand (sollte Integrität verletzen)
+        block.state = QuantumState::random();
+        assert!(!block.verify_integrity());
+    }
+
+    #[test]
+    fn test_temporal_crystal_creation() {
+        let mut crystal = TemporalCrystal::new();
+        assert!(crystal.blocks.is_empty());
+
+        let block = InformationBlock::new(0, QuantumState::random(), 0.0);
+        crystal.add_block(block).unwrap();
+
+        assert_eq!(crystal.blocks.len(), 1);
+    }
+
+    #[test]
+    fn test_crystal_state_computation() {
+        let mut crystal = TemporalCrystal::new();
+
+        for i in 0..5 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            crystal.add_block(block).unwrap();
+        }
+
+        let state = crystal.compute_crystal_state();
+        assert!(state.is_normalized());
+        assert!(crystal.crystal_state.is_some());
+        // Five blocks push ∏ d_k = 13^5 past the default
+        // `max_composite_dim`, so this falls back to the weighted sum.
+        assert!(crystal.tensor_crystal_state().is_none());
+    }
+
+    #[test]
+    fn test_tensor_product_composes_block_amplitudes() {
+        let mut crystal = TemporalCrystal::new();
+
+        let a = InformationBlock::new(0, QuantumState::random(), 0.0);
+        let b = InformationBlock::new(1, QuantumState::random(), 1.0);
+        let (a_amps, b_amps) = (a.state.amplitudes, b.state.amplitudes);
+        crystal.add_block(a).unwrap();
+        crystal.add_block(b).unwrap();
+
+        crystal.compute_crystal_state();
+        let composite = crystal.tensor_crystal_state().unwrap();
+
+        assert_eq!(composite.len(), a_amps.len() * b_amps.len());
+
+        let norm = a_amps.norm() * b_amps.norm();
+        let expected_00 = a_amps[0] * b_amps[0] / norm;
+        assert!((composite[0] - expected_00).norm() < 1e-9);
+
+        let total_prob: f64 = composite.iter().map(|amp| amp.norm_sqr()).sum();
+        assert!((total_prob - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tensor_product_falls_back_above_max_composite_dim() {
+        let mut crystal = TemporalCrystal::new();
+        crystal.set_max_composite_dim(1);
+
+        crystal
+            .add_block(InformationBlock::new(0, QuantumState::random(), 0.0))
+            .unwrap();
+        crystal.compute_crystal_state();
+
+        assert!(crystal.tensor_crystal_state().is_none());
+    }
+
+    #[test]
+    fn test_weighted_sum_mode_skips_tensor_product() {
+        let mut crystal = TemporalCrystal::new();
+        crystal.set_composition_mode(CompositionMode::WeightedSum);
+
+        crystal
+            .add_block(InformationBlock::new(0, QuantumState::random(), 0.0))
+            .unwrap();
+        crystal.compute_crystal_state();
+
+        assert!(crystal.tensor_crystal_state().is_none());
+    }
+
+    #[test]
+    fn test_crystal_invariance() {
+        let mut crystal = TemporalCrystal::new();
+
+        for i in 0..3 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            crystal.add_block(block).unwrap();
+        }
+
+        assert!(crystal.check_invariance());
+    }
+
+    #[test]
+    fn test_crystal_coherence() {
+        let mut crystal = TemporalCrystal::new();
+
+        // Ähnliche Blöcke sollten hohe Kohärenz haben
+        let base_state = QuantumState::random();
+        for i in 0..3 {
+            let block = InformationBlock::new(i, base_state.clone(), i as f64);
+            crystal.add_block(block).unwrap();
+        }
+
+        let coherence = crystal.coherence();
+        assert!(coherence > 0.9); // Hohe Kohärenz für identische Blöcke
+    }
+
+    #[test]
+    fn test_time_window_extraction() {
+        let mut crystal = TemporalCrystal::new();
+
+        for i in 0..10 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            crystal.add_block(block).unwrap();
+        }
+
+        let window = crystal.extract_time_window(2.0, 5.0).unwrap();
+        assert_eq!(window.len(), 4); // Blöcke bei t=2,3,4,5
+    }
+
+    #[test]
+    fn test_living_crystal() {
+        let mut living = LivingCrystal::new();
+
+        for i in 0..5 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            living.temporal_crystal.add_block(block).unwrap();
+        }
+
+        living.converge(10);
+        assert!(living.convergence_level > 0);
+    }
+
+    #[test]
+    fn test_metadata() {
+        let mut living = LivingCrystal::new();
+
+        living.set_metadata("author".to_string(), "QRIK".to_string());
+        living.set_metadata("version".to_string(), "1.0".to_string());
+
+        assert_eq!(living.get_metadata("author"), Some(&"QRIK".to_string()));
+        assert_eq!(living.get_metadata("version"), Some(&"1.0".to_string()));
+    }
+
+    #[test]
+    fn test_inclusion_proof_verifies_against_root() {
+        let mut crystal = TemporalCrystal::new();
+
+        for i in 0..7 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            crystal.add_block(block).unwrap();
+        }
+
+        for block in &crystal.blocks {
+            let proof = crystal.inclusion_proof(block.id).unwrap();
+            assert!(verify_inclusion(crystal.merkle_root, block.hash, &proof));
+        }
+    }
+
+    #[test]
+    fn test_inclusion_proof_missing_block_is_none() {
+        let crystal = TemporalCrystal::new();
+        assert!(crystal.inclusion_proof(0).is_none());
+    }
+
+    #[test]
+    fn test_inclusion_proof_rejects_wrong_leaf() {
+        let mut crystal = TemporalCrystal::new();
+
+        for i in 0..4 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            crystal.add_block(block).unwrap();
+        }
+
+        let proof = crystal.inclusion_proof(0).unwrap();
+        let wrong_hash = InformationBlock::new(99, QuantumState::random(), 99.0).hash;
+
+        assert!(!verify_inclusion(crystal.merkle_root, wrong_hash, &proof));
+    }
+
+    #[test]
+    fn test_check_invariance_detects_tampered_membership() {
+        let mut crystal = TemporalCrystal::new();
+
+        for i in 0..3 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            crystal.add_block(block).unwrap();
+        }
+
+        assert!(crystal.check_invariance());
+
+        // Directly mutating `blocks` bypasses `add_block`'s root rebuild,
+        // simulating an attacker splicing a block in without updating
+        // the recorded root.
+        crystal
+            .blocks
+            .push(InformationBlock::new(99, QuantumState::random(), 99.0));
+
+        assert!(!crystal.check_invariance());
+    }
+
+    #[test]
+    fn test_verify_temporal_order_accepts_valid_chain() {
+        let mut crystal = TemporalCrystal::new();
+        let genesis = InformationBlock::new(0, QuantumState::random(), 0.0);
+        let mut predecessor_hash = genesis.hash;
+        crystal.add_block(genesis).unwrap();
+
+        for i in 1..4 {
+            let block = InformationBlock::with_delay_proof(
+                i,
+                QuantumState::random(),
+                i as f64,
+                predecessor_hash,
+                20,
+            );
+            predecessor_hash = block.hash;
+            crystal.add_block(block).unwrap();
+        }
+
+        assert!(crystal.verify_temporal_order(10));
+    }
+
+    #[test]
+    fn test_verify_temporal_order_rejects_missing_proof() {
+        let mut crystal = TemporalCrystal::new();
+        crystal.add_block(InformationBlock::new(0, QuantumState::random(), 0.0)).unwrap();
+
+        assert!(!crystal.verify_temporal_order(0));
+    }
+
+    #[test]
+    fn test_verify_temporal_order_rejects_below_minimum_delay() {
+        let mut crystal = TemporalCrystal::new();
+        let genesis = InformationBlock::new(0, QuantumState::random(), 0.0);
+        let predecessor_hash = genesis.hash;
+        crystal.add_block(genesis).unwrap();
+        crystal
+            .add_block(InformationBlock::with_delay_proof(
+                1,
+                QuantumState::random(),
+                1.0,
+                predecessor_hash,
+                5,
+            ))
+            .unwrap();
+
+        assert!(!crystal.verify_temporal_order(10));
+    }
+
+    #[test]
+    fn test_verify_temporal_order_rejects_broken_chain() {
+        let mut crystal = TemporalCrystal::new();
+        let genesis = InformationBlock::new(0, QuantumState::random(), 0.0);
+        crystal.add_block(genesis).unwrap();
+        // Proof seeded from the wrong predecessor hash (not the genesis
+        // block's actual hash), simulating a spliced-in block.
+        crystal
+            .add_block(InformationBlock::with_delay_proof(
+                1,
+                QuantumState::random(),
+                1.0,
+                [0u8; 32],
+                20,
+            ))
+            .unwrap();
+
+        assert!(!crystal.verify_temporal_order(10));
+    }
+
+    fn temp_crystal_dir(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("thronion-temporal-crystal-{name}-{:p}", name))
+    }
+
+    #[test]
+    fn test_open_creates_empty_store_backed_crystal() {
+        let dir = temp_crystal_dir("open-empty");
+        let crystal = TemporalCrystal::open(&dir).unwrap();
+
+        assert!(crystal.blocks.is_empty());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_store_backed_crystal_survives_reopen() {
+        let dir = temp_crystal_dir("reopen");
+        {
+            let mut crystal = TemporalCrystal::open(&dir).unwrap();
+            for i in 0..5 {
+                let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+                crystal.add_block(block).unwrap();
+            }
+            crystal.flush().unwrap();
+        }
+
+        let reopened = TemporalCrystal::open(&dir).unwrap();
+        assert_eq!(reopened.blocks.len(), 5);
+        assert!(reopened.check_invariance());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_store_backed_time_window_and_find() {
+        let dir = temp_crystal_dir("time-window");
+        let mut crystal = TemporalCrystal::open(&dir).unwrap();
+
+        for i in 0..10 {
+            let block = InformationBlock::new(i, QuantumState::random(), i as f64);
+            crystal.add_block(block).unwrap();
+        }
+        crystal.flush().unwrap();
+
+        let window = crystal.extract_time_window(2.0, 5.0).unwrap();
+        assert_eq!(window.len(), 4);
+
+        let found = crystal.find_block_at_time(7.0, 0.5).unwrap();
+        assert_eq!(found.unwrap().id, 7);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_compact_drops_tampered_blocks_from_store_backed_crystal() {
+        let dir = temp_crystal_dir("compact");
+        let mut crystal = TemporalCrystal::open(&dir).unwrap();
+
+        let good = InformationBlock::new(0, QuantumState::random(), 0.0);
+        let mut bad = InformationBlock::new(1, QuantumState::random(), 1.0);
+        bad.hash = [0u8; 32];
+
+        crystal.add_block(good).unwrap();
+        crystal.add_block(bad).unwrap();
+        crystal.flush().unwrap();
+        crystal.compact().unwrap();
+
+        let reopened = TemporalCrystal::open(&dir).unwrap();
+        assert_eq!(reopened.blocks.len(), 1);
+        assert_eq!(reopened.blocks[0].id, 0);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,296 @@
+//! Wesolowski verifiable delay function (VDz) over an RSA group of
+//! presumed-unknown order.
+//!
+//! Gives each [`super::tic::InformationBlock`] a proof that `t`
+//! sequential modular squarings -- and therefore real wall-clock time --
+//! elapsed since its predecessor's hash, so
+//! [`super::tic::TemporalCrystal::verify_temporal_order`] can reject
+//! blocks whose claimed ordering wasn't actually computed in sequence.
+//!
+//! The modulus here is the product of two primes generated
+//! deterministically from fixed labels (see [`modulus`]), which makes
+//! their factorization -- and thus the group's order -- trivially
+//! derivable by anyone who recomputes them. That's fine for exercising
+//! the Wesolowski protocol's arithmetic and the prove/verify contract,
+//! but a real deployment must swap this out for a modulus whose
+//! factorization is genuinely unpublished (e.g. the RSA-2048 factoring
+//! challenge number), or switch to a class group of an imaginary
+//! quadratic field, which needs no trusted setup at all.
+
+use blake3::Hasher;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Byte length of the primes composing [`modulus`]. 256 bytes (2048-bit)
+/// per prime keeps this in the same ballpark as a real RSA modulus while
+/// staying fast enough for the squaring loop in tests.
+const MODULUS_PRIME_BYTES: usize = 256;
+
+/// Byte length of the Fiat-Shamir prime `l`, matching the usual 128-bit
+/// security parameter for the Wesolowski construction.
+const FIAT_SHAMIR_PRIME_BYTES: usize = 16;
+
+/// Fixed witness bases for the Miller-Rabin test below. Deterministic
+/// (not random) witnesses are adequate here since this module only needs
+/// to find *some* prime deterministically, not serve as a general-purpose
+/// primality oracle.
+const MILLER_RABIN_BASES: &[u32] = &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// A Wesolowski VDF proof: `y = g^(2^t)` and `pi = g^floor(2^t / l)` for
+/// the Fiat-Shamir prime `l` derived from `(g, y, t)`. `y`/`pi` are
+/// stored as big-endian bytes so the proof can be serialized without
+/// requiring `num-bigint`'s own serde support.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VdfProof {
+    /// `g^(2^t) mod n`, big-endian bytes.
+    pub y: Vec<u8>,
+    /// `g^floor(2^t / l) mod n`, big-endian bytes.
+    pub pi: Vec<u8>,
+    /// Number of sequential squarings the proof attests to.
+    pub t: u64,
+}
+
+/// Computes a VDF proof over `seed`: `t` sequential squarings of
+/// `g = H(seed)` reduced into the RSA group, plus the Wesolowski proof
+/// of that work. This is deliberately sequential -- the only way to
+/// compute `y` is `t` squarings in order, which is what makes the delay
+/// verifiable.
+pub fn compute(seed: &[u8], t: u64) -> VdfProof {
+    let n = modulus();
+    let g = hash_to_group(seed, n);
+    let two = BigUint::from(2u32);
+
+    let mut y = g.clone();
+    for _ in 0..t {
+        y = y.modpow(&two, n);
+    }
+
+    let l = fiat_shamir_prime(&g, &y, t);
+    let pi = prove_quotient(&g, t, n, &l);
+
+    VdfProof {
+        y: y.to_bytes_be(),
+        pi: pi.to_bytes_be(),
+        t,
+    }
+}
+
+/// Verifies that `proof` attests to `proof.t` sequential squarings of
+/// `H(seed)`. Cheap relative to [`compute`]: recovering `l` and
+/// `r = 2^t mod l` costs `O(log t)` modular multiplications instead of
+/// the prover's `O(t)`.
+pub fn verify(seed: &[u8], proof: &VdfProof) -> bool {
+    let n = modulus();
+    let g = hash_to_group(seed, n);
+    let y = BigUint::from_bytes_be(&proof.y);
+    let pi = BigUint::from_bytes_be(&proof.pi);
+
+    let l = fiat_shamir_prime(&g, &y, proof.t);
+    let r = BigUint::from(2u32).modpow(&BigUint::from(proof.t), &l);
+
+    let lhs = (pi.modpow(&l, n) * g.modpow(&r, n)) % n;
+    lhs == y
+}
+
+/// Computes `pi = g^floor(2^t / l)` without ever materializing `2^t`,
+/// using the standard bit-serial technique: at each of the `t` squaring
+/// steps, fold one more bit of the (implicit) binary representation of
+/// `2^t` into the running quotient exponent via `r`, the remainder of
+/// `2^t` mod `l` accumulated so far.
+fn prove_quotient(g: &BigUint, t: u64, n: &BigUint, l: &BigUint) -> BigUint {
+    let two = BigUint::from(2u32);
+    let mut pi = BigUint::one();
+    let mut r = BigUint::one();
+
+    for _ in 0..t {
+        let doubled = &r * &two;
+        let bit = &doubled / l;
+        r = &doubled % l;
+        pi = (&pi * &pi % n) * g.modpow(&bit, n) % n;
+    }
+
+    pi
+}
+
+/// Derives the Fiat-Shamir challenge prime `l = Hprime(g || y || t)` by
+/// hashing with an incrementing counter until the candidate passes
+/// [`is_probable_prime`].
+fn fiat_shamir_prime(g: &BigUint, y: &BigUint, t: u64) -> BigUint {
+    let mut counter: u64 = 0;
+
+    loop {
+        let mut hasher = Hasher::new();
+        hasher.update(&g.to_bytes_be());
+        hasher.update(&y.to_bytes_be());
+        hasher.update(&t.to_le_bytes());
+        hasher.update(&counter.to_le_bytes());
+
+        let mut xof = hasher.finalize_xof();
+        let mut bytes = [0u8; FIAT_SHAMIR_PRIME_BYTES];
+        xof.fill(&mut bytes);
+        bytes[0] |= 0x80;
+        bytes[FIAT_SHAMIR_PRIME_BYTES - 1] |= 1;
+
+        let candidate = BigUint::from_bytes_be(&bytes);
+        if is_probable_prime(&candidate) {
+            return candidate;
+        }
+        counter += 1;
+    }
+}
+
+/// Hashes `seed` into a non-zero element of the group of order `n` via a
+/// blake3 extendable-output hash reduced mod `n`.
+fn hash_to_group(seed: &[u8], n: &BigUint) -> BigUint {
+    let byte_len = (n.bits() as usize).div_ceil(8);
+    let mut hasher = Hasher::new();
+    hasher.update(seed);
+    let mut xof = hasher.finalize_xof();
+    let mut bytes = vec![0u8; byte_len];
+    xof.fill(&mut bytes);
+
+    let candidate = BigUint::from_bytes_be(&bytes) % n;
+    if candidate.is_zero() {
+        BigUint::one()
+    } else {
+        candidate
+    }
+}
+
+/// Returns the fixed RSA modulus used by this module: the product of two
+/// primes deterministically generated from fixed labels (see the module
+/// doc comment for why that's fine here but not for production use).
+fn modulus() -> &'static BigUint {
+    static MODULUS: OnceLock<BigUint> = OnceLock::new();
+    MODULUS.get_or_init(|| {
+        let p = seeded_prime("thronion-vdf-prime-p", MODULUS_PRIME_BYTES);
+        let q = seeded_prime("thronion-vdf-prime-q", MODULUS_PRIME_BYTES);
+        p * q
+    })
+}
+
+/// Deterministically derives a probable prime of `num_bytes` bytes by
+/// hashing `label` to seed the search, then walking odd candidates
+/// upward until one passes [`is_probable_prime`].
+fn seeded_prime(label: &str, num_bytes: usize) -> BigUint {
+    let mut hasher = Hasher::new();
+    hasher.update(label.as_bytes());
+    let mut xof = hasher.finalize_xof();
+    let mut bytes = vec![0u8; num_bytes];
+    xof.fill(&mut bytes);
+    bytes[0] |= 0x80;
+    bytes[num_bytes - 1] |= 1;
+
+    let mut candidate = BigUint::from_bytes_be(&bytes);
+    let two = BigUint::from(2u32);
+    while !is_probable_prime(&candidate) {
+        candidate += &two;
+    }
+    candidate
+}
+
+/// Fixed-base Miller-Rabin primality test. Deterministic and adequate
+/// for the sizes this module generates/verifies, but not a substitute
+/// for a general-purpose primality library.
+fn is_probable_prime(n: &BigUint) -> bool {
+    let zero = BigUint::zero();
+    let one = BigUint::one();
+    let two = BigUint::from(2u32);
+
+    if *n < two {
+        return false;
+    }
+    if *n == two {
+        return true;
+    }
+    if n % &two == zero {
+        return false;
+    }
+
+    for &small in MILLER_RABIN_BASES {
+        let small = BigUint::from(small);
+        if *n == small {
+            return true;
+        }
+        if n % &small == zero {
+            return false;
+        }
+    }
+
+    let n_minus_one = n - &one;
+    let mut d = n_minus_one.clone();
+    let mut r = 0u32;
+    while &d % &two == zero {
+        d /= &two;
+        r += 1;
+    }
+
+    'witness: for &base in MILLER_RABIN_BASES {
+        let a = BigUint::from(base);
+        if a >= *n {
+            continue;
+        }
+
+        let mut x = a.modpow(&d, n);
+        if x == one || x == n_minus_one {
+            continue;
+        }
+
+        for _ in 0..r.saturating_sub(1) {
+            x = x.modpow(&two, n);
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_known_primes_and_composites() {
+        assert!(is_probable_prime(&BigUint::from(2u32)));
+        assert!(is_probable_prime(&BigUint::from(97u32)));
+        assert!(is_probable_prime(&BigUint::from(7919u32)));
+        assert!(!is_probable_prime(&BigUint::from(1u32)));
+        assert!(!is_probable_prime(&BigUint::from(91u32))); // 7 * 13
+        assert!(!is_probable_prime(&BigUint::from(9u32)));
+    }
+
+    #[test]
+    fn test_compute_then_verify_roundtrip() {
+        let proof = compute(b"first-block-hash", 25);
+        assert!(verify(b"first-block-hash", &proof));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_proof() {
+        let mut proof = compute(b"seed", 20);
+        // Flip a byte in y; the proof should no longer verify.
+        let last = proof.y.len() - 1;
+        proof.y[last] ^= 0xFF;
+
+        assert!(!verify(b"seed", &proof));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_seed() {
+        let proof = compute(b"seed-a", 20);
+        assert!(!verify(b"seed-b", &proof));
+    }
+
+    #[test]
+    fn test_verify_rejects_claimed_t_mismatch() {
+        let mut proof = compute(b"seed", 20);
+        proof.t = 21;
+        assert!(!verify(b"seed", &proof));
+    }
+}
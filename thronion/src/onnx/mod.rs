@@ -0,0 +1,319 @@
+//! Minimal ONNX model writer
+//!
+//! Encodes a small subset of the ONNX `ModelProto` wire format by hand:
+//! there is no `prost`/`onnx` dependency in this crate, and the graphs
+//! `EnhancedThronionKernel::export_onnx` needs to emit are a fixed, small
+//! sequence of vector ops (`Sub`, `Mul`, `ReduceSum`, `Sqrt`, `Reciprocal`,
+//! `ArgMax`, `Gather`, `Greater`, ...) over a handful of constant
+//! tensors, so a full protobuf code-generation pipeline would be
+//! overkill. [`ModelBuilder`] assembles exactly the messages ONNX
+//! consumers (`onnxruntime`/`ort`) expect for that subset, encoded with
+//! the generic varint/length-delimited helpers in this module.
+//!
+//! Protobuf wire format reference: each field is a `(field_number << 3) |
+//! wire_type` varint tag followed by the field's payload — `0` for
+//! varint integers, `5` for 32-bit (float), `2` for length-delimited
+//! (strings, bytes, and nested messages, which are just length-prefixed
+//! re-serializations of themselves).
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_32BIT: u8 = 5;
+const WIRE_LEN: u8 = 2;
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf.push(byte);
+            break;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+fn write_tag(buf: &mut Vec<u8>, field: u32, wire_type: u8) {
+    write_varint(buf, ((field as u64) << 3) | wire_type as u64);
+}
+
+fn write_int64_field(buf: &mut Vec<u8>, field: u32, value: i64) {
+    write_tag(buf, field, WIRE_VARINT);
+    write_varint(buf, value as u64);
+}
+
+fn write_float_field(buf: &mut Vec<u8>, field: u32, value: f32) {
+    write_tag(buf, field, WIRE_32BIT);
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_bytes_field(buf: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    write_tag(buf, field, WIRE_LEN);
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+fn write_string_field(buf: &mut Vec<u8>, field: u32, value: &str) {
+    write_bytes_field(buf, field, value.as_bytes());
+}
+
+/// Nested messages are encoded identically to `bytes` fields: the
+/// sub-message's own serialized bytes, length-prefixed.
+fn write_message_field(buf: &mut Vec<u8>, field: u32, message: &[u8]) {
+    write_bytes_field(buf, field, message);
+}
+
+/// ONNX `TensorProto.DataType` values this module emits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElemType {
+    Float = 1,
+    Int64 = 7,
+    Bool = 9,
+}
+
+/// A constant or input/output tensor's shape and element type
+#[derive(Debug, Clone)]
+pub struct TensorSpec {
+    pub dims: Vec<i64>,
+    pub elem_type: ElemType,
+}
+
+impl TensorSpec {
+    pub fn new(dims: Vec<i64>, elem_type: ElemType) -> Self {
+        Self { dims, elem_type }
+    }
+}
+
+/// A single ONNX attribute value this module emits (enough for
+/// `ReduceSum`'s/`ArgMax`'s `axes`/`axis`/`keepdims` attributes)
+#[derive(Debug, Clone)]
+pub enum AttributeValue {
+    Int(i64),
+    Ints(Vec<i64>),
+}
+
+fn encode_attribute(name: &str, value: &AttributeValue) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_string_field(&mut buf, 1, name);
+    match value {
+        AttributeValue::Int(i) => {
+            write_int64_field(&mut buf, 3, *i);
+            // AttributeProto.type = INT (2)
+            write_int64_field(&mut buf, 20, 2);
+        }
+        AttributeValue::Ints(ints) => {
+            for i in ints {
+                write_int64_field(&mut buf, 8, *i);
+            }
+            // AttributeProto.type = INTS (7)
+            write_int64_field(&mut buf, 20, 7);
+        }
+    }
+    buf
+}
+
+fn encode_tensor_shape(dims: &[i64]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for &dim in dims {
+        let mut dimension = Vec::new();
+        write_int64_field(&mut dimension, 1, dim); // Dimension.dim_value
+        write_message_field(&mut buf, 1, &dimension); // TensorShapeProto.dim
+    }
+    buf
+}
+
+fn encode_type_proto(spec: &TensorSpec) -> Vec<u8> {
+    let mut tensor_type = Vec::new();
+    write_int64_field(&mut tensor_type, 1, spec.elem_type as i64); // elem_type
+    let shape = encode_tensor_shape(&spec.dims);
+    write_message_field(&mut tensor_type, 2, &shape); // shape
+    let mut type_proto = Vec::new();
+    write_message_field(&mut type_proto, 1, &tensor_type); // tensor_type
+    type_proto
+}
+
+fn encode_value_info(name: &str, spec: &TensorSpec) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_string_field(&mut buf, 1, name);
+    let type_proto = encode_type_proto(spec);
+    write_message_field(&mut buf, 2, &type_proto);
+    buf
+}
+
+fn encode_node(
+    op_type: &str,
+    name: &str,
+    inputs: &[&str],
+    outputs: &[&str],
+    attributes: &[(&str, AttributeValue)],
+) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for input in inputs {
+        write_string_field(&mut buf, 1, input);
+    }
+    for output in outputs {
+        write_string_field(&mut buf, 2, output);
+    }
+    write_string_field(&mut buf, 3, name);
+    write_string_field(&mut buf, 4, op_type);
+    for (attr_name, attr_value) in attributes {
+        let encoded = encode_attribute(attr_name, attr_value);
+        write_message_field(&mut buf, 5, &encoded);
+    }
+    buf
+}
+
+fn encode_f32_tensor(name: &str, dims: &[i64], data: &[f32]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    for &dim in dims {
+        write_int64_field(&mut buf, 1, dim); // dims
+    }
+    write_int64_field(&mut buf, 2, ElemType::Float as i64); // data_type
+    let mut raw_data = Vec::with_capacity(data.len() * 4);
+    for &value in data {
+        raw_data.extend_from_slice(&value.to_le_bytes());
+    }
+    write_bytes_field(&mut buf, 9, &raw_data); // raw_data
+    write_string_field(&mut buf, 8, name); // name
+    buf
+}
+
+fn encode_string_string_entry(key: &str, value: &str) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_string_field(&mut buf, 1, key);
+    write_string_field(&mut buf, 2, value);
+    buf
+}
+
+/// Assembles a minimal single-graph ONNX `ModelProto` and serializes it
+/// to bytes, node-by-node and initializer-by-initializer, in the order
+/// they were added.
+#[derive(Debug, Default)]
+pub struct ModelBuilder {
+    graph_name: String,
+    inputs: Vec<u8>,
+    outputs: Vec<u8>,
+    initializers: Vec<u8>,
+    nodes: Vec<u8>,
+    metadata: Vec<u8>,
+}
+
+impl ModelBuilder {
+    pub fn new(graph_name: impl Into<String>) -> Self {
+        Self {
+            graph_name: graph_name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn add_input(&mut self, name: &str, spec: &TensorSpec) -> &mut Self {
+        let encoded = encode_value_info(name, spec);
+        write_message_field(&mut self.inputs, 11, &encoded); // GraphProto.input
+        self
+    }
+
+    pub fn add_output(&mut self, name: &str, spec: &TensorSpec) -> &mut Self {
+        let encoded = encode_value_info(name, spec);
+        write_message_field(&mut self.outputs, 12, &encoded); // GraphProto.output
+        self
+    }
+
+    pub fn add_initializer_f32(&mut self, name: &str, dims: &[i64], data: &[f32]) -> &mut Self {
+        let encoded = encode_f32_tensor(name, dims, data);
+        write_message_field(&mut self.initializers, 5, &encoded); // GraphProto.initializer
+        self
+    }
+
+    pub fn add_node(
+        &mut self,
+        op_type: &str,
+        name: &str,
+        inputs: &[&str],
+        outputs: &[&str],
+        attributes: &[(&str, AttributeValue)],
+    ) -> &mut Self {
+        let encoded = encode_node(op_type, name, inputs, outputs, attributes);
+        write_message_field(&mut self.nodes, 1, &encoded); // GraphProto.node
+        self
+    }
+
+    /// Embeds a key/value pair as `ModelProto.metadata_props`, used to
+    /// carry the feature-layout descriptor so a loader can refuse a
+    /// graph built for an incompatible feature space.
+    pub fn add_metadata(&mut self, key: &str, value: &str) -> &mut Self {
+        let encoded = encode_string_string_entry(key, value);
+        write_message_field(&mut self.metadata, 14, &encoded); // ModelProto.metadata_props
+        self
+    }
+
+    /// Serializes the accumulated graph into a complete `ModelProto`.
+    pub fn build(&self) -> Vec<u8> {
+        let mut graph = Vec::new();
+        graph.extend_from_slice(&self.nodes);
+        write_string_field(&mut graph, 2, &self.graph_name);
+        graph.extend_from_slice(&self.initializers);
+        graph.extend_from_slice(&self.inputs);
+        graph.extend_from_slice(&self.outputs);
+
+        let mut model = Vec::new();
+        write_int64_field(&mut model, 1, 8); // ir_version (IR_VERSION_2023_5_5-ish)
+        let mut opset = Vec::new();
+        write_int64_field(&mut opset, 2, 17); // OperatorSetIdProto.version
+        write_message_field(&mut model, 8, &opset); // opset_import
+        write_string_field(&mut model, 2, "thronion"); // producer_name
+        write_message_field(&mut model, 7, &graph); // graph
+        model.extend_from_slice(&self.metadata);
+        model
+    }
+
+    /// Serializes and writes the model to `path`.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path.as_ref(), self.build()).context("Failed to write ONNX model file")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint_roundtrips_small_and_multibyte_values() {
+        for &value in &[0u64, 1, 127, 128, 300, 16384, u32::MAX as u64] {
+            let mut buf = Vec::new();
+            write_varint(&mut buf, value);
+
+            let mut decoded: u64 = 0;
+            let mut shift = 0;
+            for &byte in &buf {
+                decoded |= ((byte & 0x7f) as u64) << shift;
+                shift += 7;
+            }
+            assert_eq!(decoded, value);
+        }
+    }
+
+    #[test]
+    fn test_model_builder_produces_nonempty_bytes_with_expected_strings() {
+        let mut builder = ModelBuilder::new("test_graph");
+        builder
+            .add_input("features", &TensorSpec::new(vec![1, 4], ElemType::Float))
+            .add_initializer_f32("prototypes", &[2, 4], &[0.0; 8])
+            .add_node("Sub", "diff", &["features", "prototypes"], &["diff"], &[])
+            .add_output("resonance", &TensorSpec::new(vec![], ElemType::Float))
+            .add_metadata("schema_version", "1");
+
+        let bytes = builder.build();
+        assert!(!bytes.is_empty());
+
+        // The raw tensor/op names must appear verbatim as length-delimited
+        // UTF-8 strings somewhere in the serialized bytes.
+        let haystack = String::from_utf8_lossy(&bytes);
+        assert!(haystack.contains("features"));
+        assert!(haystack.contains("prototypes"));
+        assert!(haystack.contains("resonance"));
+        assert!(haystack.contains("schema_version"));
+    }
+}
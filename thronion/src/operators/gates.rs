@@ -0,0 +1,258 @@
+//! Gate-/Schaltkreis-Schicht über `QuantumState`
+//!
+//! Stellt einen kleinen Satz parametrisierter [`Gate`]s (Phasenschub,
+//! Givens-Rotation/"Beam-Splitter" zwischen zwei Knoten, diagonales
+//! Detuning, beliebige benutzerdefinierte Unitäre) sowie [`Circuit`]
+//! bereit, das eine geordnete Gate-Sequenz zu einer einzigen Unitären
+//! zusammenfaltet. Damit lassen sich Zustände in ℋ₁₃ programmatisch und
+//! deterministisch präparieren — etwa zum Skripten reproduzierbarer
+//! Testzustände oder zum Seeden von `DeltaKernel::quantum_state` — statt
+//! nur über `QuantumState::basis_state`/`uniform_superposition`.
+//! Givens-Rotationen entlang von Metatron-Graph-Kanten ergeben dabei ein
+//! hardware-nahes, auf die Topologie zugeschnittenes Gate-Set.
+
+use crate::core::{QuantumState, HILBERT_DIM};
+use crate::utils::linalg::is_unitary;
+use nalgebra::SMatrix;
+use num_complex::Complex64;
+
+/// Toleranz für die Unitaritätsprüfung in [`Circuit::unitary`]
+const UNITARITY_TOLERANCE: f64 = 1e-8;
+
+/// Ein einzelnes Gate auf dem HILBERT_DIM-dimensionalen Metatron-Hilbertraum
+#[derive(Debug, Clone)]
+pub enum Gate {
+    /// Phasenschub e^{iφ} auf einem einzelnen Knoten
+    Phase {
+        /// Knotenindex, auf den die Phase angewendet wird
+        node: usize,
+        /// Phasenwinkel φ (rad)
+        phi: f64,
+    },
+    /// Givens-Rotation ("Beam-Splitter") zwischen zwei Knoten i,j um
+    /// Winkel θ: wirkt auf die (i,j)-Unterebene als
+    /// [[cosθ, −sinθ], [sinθ, cosθ]], Identität sonst
+    Givens {
+        /// Erster Knotenindex
+        i: usize,
+        /// Zweiter Knotenindex
+        j: usize,
+        /// Rotationswinkel θ (rad)
+        theta: f64,
+    },
+    /// Diagonales Detuning e^{iδₖ} auf jedem Knoten k
+    Detuning {
+        /// Phasen δₖ für jeden der HILBERT_DIM Knoten
+        phases: [f64; HILBERT_DIM],
+    },
+    /// Beliebige benutzerdefinierte HILBERT_DIM×HILBERT_DIM-Unitäre
+    Custom(SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>),
+}
+
+impl Gate {
+    /// Baut die volle HILBERT_DIM×HILBERT_DIM-Matrix dieses Gates
+    ///
+    /// # Panics
+    /// Wenn ein Knotenindex außerhalb von `0..HILBERT_DIM` liegt, oder bei
+    /// [`Gate::Givens`] wenn `i == j`.
+    pub fn matrix(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        match self {
+            Gate::Phase { node, phi } => {
+                assert!(*node < HILBERT_DIM, "Phase: Knotenindex außerhalb von HILBERT_DIM");
+
+                let mut matrix = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+                matrix[(*node, *node)] = Complex64::from_polar(1.0, *phi);
+                matrix
+            }
+            Gate::Givens { i, j, theta } => {
+                assert!(i != j, "Givens: Knoten müssen verschieden sein");
+                assert!(
+                    *i < HILBERT_DIM && *j < HILBERT_DIM,
+                    "Givens: Knotenindex außerhalb von HILBERT_DIM"
+                );
+
+                let mut matrix = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+                let (cos_t, sin_t) = (theta.cos(), theta.sin());
+                matrix[(*i, *i)] = Complex64::new(cos_t, 0.0);
+                matrix[(*i, *j)] = Complex64::new(-sin_t, 0.0);
+                matrix[(*j, *i)] = Complex64::new(sin_t, 0.0);
+                matrix[(*j, *j)] = Complex64::new(cos_t, 0.0);
+                matrix
+            }
+            Gate::Detuning { phases } => {
+                let mut matrix = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+                for (k, &delta) in phases.iter().enumerate() {
+                    matrix[(k, k)] = Complex64::from_polar(1.0, delta);
+                }
+                matrix
+            }
+            Gate::Custom(matrix) => *matrix,
+        }
+    }
+}
+
+/// Ein Schaltkreis: eine geordnete Sequenz von [`Gate`]s, die sich zu
+/// einer einzigen Unitären zusammenfalten oder direkt auf einen
+/// `QuantumState` anwenden lässt
+#[derive(Debug, Clone, Default)]
+pub struct Circuit {
+    gates: Vec<Gate>,
+}
+
+impl Circuit {
+    /// Erstellt einen leeren Schaltkreis
+    pub fn new() -> Self {
+        Self { gates: Vec::new() }
+    }
+
+    /// Hängt ein Gate ans Ende der Sequenz an
+    pub fn push(&mut self, gate: Gate) {
+        self.gates.push(gate);
+    }
+
+    /// Anzahl der Gates im Schaltkreis
+    pub fn len(&self) -> usize {
+        self.gates.len()
+    }
+
+    /// Ob der Schaltkreis keine Gates enthält
+    pub fn is_empty(&self) -> bool {
+        self.gates.is_empty()
+    }
+
+    /// Faltet die gesamte Gate-Sequenz zu einer einzigen Unitären
+    /// zusammen: U = Gₙ·...·G₁, sodass `U|ψ⟩` dieselbe Reihenfolge
+    /// anwendet, in der die Gates via [`Self::push`] hinzugefügt wurden
+    /// (G₁ zuerst).
+    ///
+    /// # Panics
+    /// Wenn das Ergebnis nicht unitär ist (auf 1e-8 toleriert), z.B. durch
+    /// ein fehlerhaft konstruiertes [`Gate::Custom`].
+    pub fn unitary(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        let mut combined = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+        for gate in &self.gates {
+            combined = gate.matrix() * combined;
+        }
+
+        assert!(
+            is_unitary(&combined, UNITARITY_TOLERANCE),
+            "Circuit::unitary: zusammengesetzte Gate-Sequenz ist nicht unitär"
+        );
+
+        combined
+    }
+
+    /// Wendet den Schaltkreis auf einen Zustand an: |ψ'⟩ = U|ψ⟩
+    pub fn apply(&self, state: &QuantumState) -> QuantumState {
+        QuantumState::new(self.unitary() * state.amplitudes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::MetatronGraph;
+
+    #[test]
+    fn test_phase_gate_is_unitary() {
+        let gate = Gate::Phase { node: 3, phi: 1.2 };
+        assert!(is_unitary(&gate.matrix(), 1e-10));
+    }
+
+    #[test]
+    fn test_phase_gate_leaves_other_nodes_unchanged() {
+        let gate = Gate::Phase { node: 0, phi: std::f64::consts::FRAC_PI_2 };
+        let matrix = gate.matrix();
+
+        for k in 1..HILBERT_DIM {
+            assert!((matrix[(k, k)] - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_givens_gate_is_unitary() {
+        let gate = Gate::Givens { i: 1, j: 4, theta: 0.7 };
+        assert!(is_unitary(&gate.matrix(), 1e-10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_givens_gate_rejects_equal_indices() {
+        let gate = Gate::Givens { i: 2, j: 2, theta: 0.5 };
+        let _ = gate.matrix();
+    }
+
+    #[test]
+    fn test_detuning_gate_is_unitary_and_diagonal() {
+        let mut phases = [0.0; HILBERT_DIM];
+        for (k, phase) in phases.iter_mut().enumerate() {
+            *phase = k as f64 * 0.1;
+        }
+        let gate = Gate::Detuning { phases };
+        let matrix = gate.matrix();
+
+        assert!(is_unitary(&matrix, 1e-10));
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                if i != j {
+                    assert!(matrix[(i, j)].norm() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_gate_returns_matrix_unchanged() {
+        let identity = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+        let gate = Gate::Custom(identity);
+        assert_eq!(gate.matrix(), identity);
+    }
+
+    #[test]
+    fn test_circuit_unitary_folds_gates_in_push_order() {
+        let mut circuit = Circuit::new();
+        circuit.push(Gate::Phase { node: 0, phi: 0.3 });
+        circuit.push(Gate::Givens { i: 0, j: 1, theta: 0.4 });
+
+        assert_eq!(circuit.len(), 2);
+        assert!(is_unitary(&circuit.unitary(), 1e-10));
+    }
+
+    #[test]
+    fn test_empty_circuit_is_identity() {
+        let circuit = Circuit::new();
+        assert!(circuit.is_empty());
+
+        let identity = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+        assert_eq!(circuit.unitary(), identity);
+    }
+
+    #[test]
+    fn test_circuit_apply_preserves_normalization() {
+        let mut circuit = Circuit::new();
+        circuit.push(Gate::Givens { i: 2, j: 5, theta: 0.9 });
+        circuit.push(Gate::Phase { node: 5, phi: 1.1 });
+        circuit.push(Gate::Detuning { phases: [0.05; HILBERT_DIM] });
+
+        let state = QuantumState::basis_state(2);
+        let evolved = circuit.apply(&state);
+
+        assert!(evolved.is_normalized());
+    }
+
+    #[test]
+    fn test_givens_gates_along_metatron_edges_stay_unitary() {
+        let graph = MetatronGraph::new();
+        let mut circuit = Circuit::new();
+
+        for i in 0..HILBERT_DIM {
+            for j in (i + 1)..HILBERT_DIM {
+                if graph.has_edge(i, j) {
+                    circuit.push(Gate::Givens { i, j, theta: 0.1 });
+                }
+            }
+        }
+
+        assert!(is_unitary(&circuit.unitary(), 1e-8));
+    }
+}
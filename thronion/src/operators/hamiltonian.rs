@@ -4,10 +4,16 @@
 //! für Zeitevolution auf dem Metatron-Graph
 
 use crate::core::{MetatronGraph, QuantumState, HILBERT_DIM};
+use crate::utils::linalg::{hermitian_eigen, matrix_exp_unitary};
+use crate::utils::special::bessel_j;
 use nalgebra::{SMatrix, SVector};
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 
+/// Standardtoleranz für den Abbruch der Chebyshev-Reihe:
+/// Terme mit |J_k(a·t)| unterhalb dieser Schwelle werden verworfen.
+const CHEBYSHEV_DEFAULT_TOLERANCE: f64 = 1e-12;
+
 /// Metatron-Hamilton-Operator
 ///
 /// Ĥ_M = -J·L + Σᵢ εᵢ|i⟩⟨i|
@@ -82,6 +88,84 @@ impl HamiltonOperator {
         Self::new(hopping_strength, local_energies, graph)
     }
 
+    /// Erstellt Hamilton-Operator mit komplexen Hopping-Amplituden via
+    /// Peierls-Substitution: t_ij = J·e^{iφᵢⱼ} für jede Kante (i,j) des
+    /// Graphen. `phases` muss antisymmetrisch sein (φⱼᵢ = -φᵢⱼ), damit das
+    /// Ergebnis hermitesch bleibt.
+    ///
+    /// # Arguments
+    /// * `hopping_strength` - Kopplungsstärke J > 0
+    /// * `local_energies` - On-site Energien εᵢ für jeden Knoten
+    /// * `graph` - Metatron-Graph für Adjazenz
+    /// * `phases` - Antisymmetrische Matrix der Peierls-Phasen φᵢⱼ (rad)
+    pub fn with_peierls_phases(
+        hopping_strength: f64,
+        local_energies: [f64; HILBERT_DIM],
+        graph: &MetatronGraph,
+        phases: &SMatrix<f64, HILBERT_DIM, HILBERT_DIM>,
+    ) -> Self {
+        assert!(hopping_strength > 0.0, "J muss positiv sein");
+
+        let mut matrix = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                if graph.adjacency[(i, j)] {
+                    debug_assert!(
+                        (phases[(i, j)] + phases[(j, i)]).abs() < 1e-9,
+                        "Peierls-Phasen müssen antisymmetrisch sein (Hermitizität)"
+                    );
+                    matrix[(i, j)] = Complex64::from_polar(hopping_strength, phases[(i, j)]);
+                }
+            }
+        }
+
+        for i in 0..HILBERT_DIM {
+            let degree = graph.degree(i) as f64;
+            matrix[(i, i)] += Complex64::new(local_energies[i] - hopping_strength * degree, 0.0);
+        }
+
+        Self {
+            hopping_strength,
+            local_energies,
+            matrix,
+        }
+    }
+
+    /// Erstellt Hamilton-Operator mit einem Aharonov–Bohm-Fluss, der
+    /// gleichmäßig über die Kanten des Hexagon- bzw. Cube-Rings verteilt
+    /// wird (Standard-Peierls-Substitution entlang einer Plakette: die
+    /// Summe der Phasen um den Ring ist gleich dem jeweiligen Fluss).
+    ///
+    /// # Arguments
+    /// * `hexagon_flux` - Fluss Φ_hex durch die Hexagon-Plakette (v1..v6)
+    /// * `cube_flux` - Fluss Φ_cube durch die Cube-Plakette (v7..v12)
+    pub fn with_aharonov_bohm_flux(
+        hopping_strength: f64,
+        local_energies: [f64; HILBERT_DIM],
+        graph: &MetatronGraph,
+        hexagon_flux: f64,
+        cube_flux: f64,
+    ) -> Self {
+        let mut phases = SMatrix::<f64, HILBERT_DIM, HILBERT_DIM>::zeros();
+
+        let hex_phase = hexagon_flux / 6.0;
+        for i in 1..=6 {
+            let next = if i == 6 { 1 } else { i + 1 };
+            phases[(i, next)] = hex_phase;
+            phases[(next, i)] = -hex_phase;
+        }
+
+        let cube_phase = cube_flux / 6.0;
+        for i in 7..=12 {
+            let next = if i == 12 { 7 } else { i + 1 };
+            phases[(i, next)] = cube_phase;
+            phases[(next, i)] = -cube_phase;
+        }
+
+        Self::with_peierls_phases(hopping_strength, local_energies, graph, &phases)
+    }
+
     /// Wendet Hamilton-Operator auf Zustand an: Ĥ|ψ⟩
     pub fn apply(&self, state: &QuantumState) -> SVector<Complex64, HILBERT_DIM> {
         self.matrix * state.amplitudes
@@ -105,50 +189,133 @@ impl HamiltonOperator {
         QuantumState::new(evolved_amps)
     }
 
-    /// Berechnet Evolutionsoperator U(t) = exp(-iĤt)
-    pub fn evolution_operator(&self, time: f64) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
-        // Für kleine Matrizen: Diagonalisierung verwenden
-        use nalgebra::SymmetricEigen;
+    /// Zeitevolution via Chebyshev-Polynom-Entwicklung von exp(−iĤt)
+    ///
+    /// Im Gegensatz zu [`time_evolution`](Self::time_evolution) wird Ĥ
+    /// dabei nicht diagonalisiert, sondern nur über wiederholte
+    /// Matrix-Vektor-Produkte ausgewertet - der Ansatz, der auch für
+    /// größere (ggf. dünnbesetzte) Hilberträume jenseits der 13
+    /// Metatron-Zustände trägt.
+    ///
+    /// Schätzt die Spektralgrenzen [E_min, E_max] über Gershgorin-Kreise,
+    /// reskaliert Ĥ zu Ĥ_norm = (Ĥ − b)/a mit a = (E_max−E_min)/2,
+    /// b = (E_max+E_min)/2 und entwickelt
+    ///
+    /// |ψ(t)⟩ = e^{−ibt} Σ_{k≥0} c_k φ_k
+    ///
+    /// mit c_0 = J_0(at), c_k = 2 J_k(at) (reelle Bessel-Koeffizienten, da
+    /// die (−i)^k-Phase bereits in φ_k steckt) und der Drei-Term-Rekursion
+    /// φ_0 = |ψ⟩, φ_1 = −iĤ_norm φ_0, φ_{k+1} = −2iĤ_norm φ_k + φ_{k−1}.
+    /// Die Reihe bricht ab, sobald |J_k(at)| unter `tolerance` fällt.
+    pub fn chebyshev_evolution(&self, state: &QuantumState, time: f64, tolerance: f64) -> QuantumState {
+        let (e_min, e_max) = self.gershgorin_bounds();
+
+        let a = ((e_max - e_min) / 2.0).max(1e-10);
+        let b = (e_max + e_min) / 2.0;
+
+        // Ĥ_norm = (Ĥ − b·I) / a
+        let mut h_norm = self.matrix;
+        for i in 0..HILBERT_DIM {
+            h_norm[(i, i)] -= Complex64::new(b, 0.0);
+        }
+        h_norm = h_norm.map(|c| c / a);
 
-        // Extrahiere Realteil (Hamilton ist Hermitesch, also reell)
-        let h_real = self.matrix.map(|c| c.re);
+        let neg_i = Complex64::new(0.0, -1.0);
+        let at = a * time;
 
-        // Eigenwertzerlegung
-        let eigen = SymmetricEigen::new(h_real);
-        let eigenvalues = eigen.eigenvalues;
-        let eigenvectors = eigen.eigenvectors;
+        let phi_prev = state.amplitudes;
+        let mut psi = phi_prev * Complex64::new(bessel_j(0, at), 0.0);
 
-        // Konstruiere exp(-iĤt) = V·exp(-iΛt)·V†
-        let mut exp_matrix = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+        if bessel_j(1, at).abs() >= tolerance {
+            let phi_curr = h_norm * phi_prev * neg_i;
+            let c1 = Complex64::new(2.0 * bessel_j(1, at), 0.0);
+            psi += phi_curr * c1;
 
-        for i in 0..HILBERT_DIM {
-            let phase = Complex64::new(0.0, -eigenvalues[i] * time).exp();
+            let mut phi_prev_iter = phi_prev;
+            let mut phi_curr_iter = phi_curr;
 
-            for j in 0..HILBERT_DIM {
-                for k in 0..HILBERT_DIM {
-                    exp_matrix[(j, k)] += phase
-                        * Complex64::new(eigenvectors[(j, i)], 0.0)
-                        * Complex64::new(eigenvectors[(k, i)], 0.0);
+            for k in 2..HILBERT_DIM * 4 {
+                let jk = bessel_j(k as u32, at);
+
+                let phi_next = h_norm * phi_curr_iter * (neg_i * Complex64::new(2.0, 0.0))
+                    + phi_prev_iter;
+
+                if jk.abs() < tolerance {
+                    break;
                 }
+
+                let ck = Complex64::new(2.0 * jk, 0.0);
+                psi += phi_next * ck;
+
+                phi_prev_iter = phi_curr_iter;
+                phi_curr_iter = phi_next;
             }
         }
 
-        exp_matrix
+        let global_phase = Complex64::new(0.0, -b * time).exp();
+        QuantumState::new(psi * global_phase)
+    }
+
+    /// [`chebyshev_evolution`](Self::chebyshev_evolution) mit der
+    /// Standardtoleranz [`CHEBYSHEV_DEFAULT_TOLERANCE`]
+    pub fn chebyshev_evolution_default(&self, state: &QuantumState, time: f64) -> QuantumState {
+        self.chebyshev_evolution(state, time, CHEBYSHEV_DEFAULT_TOLERANCE)
+    }
+
+    /// Schätzt Spektralgrenzen [E_min, E_max] von `matrix` via
+    /// Gershgorin-Kreise: für jede Zeile i liegt jeder Eigenwert in
+    /// [Re(H_ii) − R_i, Re(H_ii) + R_i] mit R_i = Σ_{j≠i} |H_ij|.
+    fn gershgorin_bounds(&self) -> (f64, f64) {
+        let mut e_min = f64::INFINITY;
+        let mut e_max = f64::NEG_INFINITY;
+
+        for i in 0..HILBERT_DIM {
+            let center = self.matrix[(i, i)].re;
+            let radius: f64 = (0..HILBERT_DIM)
+                .filter(|&j| j != i)
+                .map(|j| self.matrix[(i, j)].norm())
+                .sum();
+
+            e_min = e_min.min(center - radius);
+            e_max = e_max.max(center + radius);
+        }
+
+        (e_min, e_max)
+    }
+
+    /// Berechnet Evolutionsoperator U(t) = exp(-iĤt)
+    ///
+    /// Delegiert an die geteilte Spektralfunktion
+    /// [`matrix_exp_unitary`], die Ĥ vor der Eigenwertzerlegung
+    /// symmetrisiert (Ĥ ← (Ĥ + Ĥ†)/2), sodass komplexe Hopping-Amplituden
+    /// (Peierls-Phasen) korrekt berücksichtigt werden und die Propagation
+    /// exakt bleibt, statt auf eine trunkierte Reihe wie
+    /// [`chebyshev_evolution`](Self::chebyshev_evolution) zurückzugreifen.
+    pub fn evolution_operator(&self, time: f64) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        matrix_exp_unitary(&self.matrix, time)
     }
 
     /// Berechnet Eigenenergien und Eigenzustände
+    ///
+    /// Nutzt die komplex-hermitesche Eigenwertzerlegung
+    /// [`hermitian_eigen`] statt den Imaginärteil von `matrix` zu
+    /// verwerfen, sodass komplexe Peierls-Phasen korrekt eingehen.
     pub fn eigenspectrum(&self) -> EigenSpectrum {
-        use nalgebra::SymmetricEigen;
+        let (eigenvalues, eigenvectors) = hermitian_eigen(&self.matrix);
 
-        let h_real = self.matrix.map(|c| c.re);
-        let eigen = SymmetricEigen::new(h_real);
+        let mut indices: Vec<usize> = (0..HILBERT_DIM).collect();
+        indices.sort_by(|&a, &b| eigenvalues[a].partial_cmp(&eigenvalues[b]).unwrap());
 
-        let mut energies = eigen.eigenvalues.as_slice().to_vec();
-        energies.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut energies = [0.0; HILBERT_DIM];
+        let mut sorted_eigenvectors = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+        for (col, &idx) in indices.iter().enumerate() {
+            energies[col] = eigenvalues[idx];
+            sorted_eigenvectors.set_column(col, &eigenvectors.column(idx));
+        }
 
         EigenSpectrum {
-            energies: energies.try_into().unwrap(),
-            eigenvectors: eigen.eigenvectors,
+            energies,
+            eigenvectors: sorted_eigenvectors,
         }
     }
 
@@ -171,8 +338,9 @@ impl Default for HamiltonOperator {
 pub struct EigenSpectrum {
     /// Eigenenergien (sortiert)
     pub energies: [f64; HILBERT_DIM],
-    /// Eigenvektoren (Spalten der Matrix)
-    pub eigenvectors: SMatrix<f64, HILBERT_DIM, HILBERT_DIM>,
+    /// Eigenvektoren (Spalten der Matrix), komplex wegen möglicher
+    /// Peierls-Phasen
+    pub eigenvectors: SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
 }
 
 impl EigenSpectrum {
@@ -184,9 +352,7 @@ impl EigenSpectrum {
     /// Gibt angeregten Zustand zurück
     pub fn excited_state(&self, n: usize) -> QuantumState {
         assert!(n < HILBERT_DIM);
-        let eigenvector = self.eigenvectors.column(n);
-        let amps =
-            SVector::<Complex64, HILBERT_DIM>::from_fn(|i, _| Complex64::new(eigenvector[i], 0.0));
+        let amps = self.eigenvectors.column(n).clone_owned();
         QuantumState::new(amps)
     }
 
@@ -263,6 +429,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_time_evolution_preserves_norm_exactly() {
+        let graph = MetatronGraph::new();
+        let ham = HamiltonOperator::uniform(1.0, 0.5, &graph);
+        let state = QuantumState::random();
+
+        let evolved = ham.time_evolution(&state, 3.7);
+        assert_abs_diff_eq!(evolved.amplitudes.norm(), 1.0, epsilon = 1e-10);
+    }
+
     #[test]
     fn test_eigenspectrum() {
         let graph = MetatronGraph::new();
@@ -284,4 +460,106 @@ mod tests {
         // Spektrale Lücke sollte positiv sein
         assert!(gap > 0.0);
     }
+
+    #[test]
+    fn test_chebyshev_matches_exact_evolution() {
+        let graph = MetatronGraph::new();
+        let ham = HamiltonOperator::uniform(1.0, 0.5, &graph);
+        let state = QuantumState::random();
+
+        let exact = ham.time_evolution(&state, 0.5);
+        let chebyshev = ham.chebyshev_evolution(&state, 0.5, 1e-12);
+
+        for i in 0..HILBERT_DIM {
+            assert_abs_diff_eq!(exact.amplitudes[i].re, chebyshev.amplitudes[i].re, epsilon = 1e-6);
+            assert_abs_diff_eq!(exact.amplitudes[i].im, chebyshev.amplitudes[i].im, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_chebyshev_evolution_preserves_norm() {
+        let graph = MetatronGraph::new();
+        let ham = HamiltonOperator::uniform(1.0, 0.0, &graph);
+        let state = QuantumState::random();
+
+        let evolved = ham.chebyshev_evolution_default(&state, 1.0);
+        assert_abs_diff_eq!(evolved.amplitudes.norm(), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_chebyshev_evolution_preserves_energy() {
+        let graph = MetatronGraph::new();
+        let ham = HamiltonOperator::uniform(1.0, 0.0, &graph);
+        let state = QuantumState::random();
+
+        let e0 = ham.expectation_value(&state);
+        let evolved = ham.chebyshev_evolution_default(&state, 2.0);
+        let e1 = ham.expectation_value(&evolved);
+
+        assert_abs_diff_eq!(e0, e1, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_peierls_phases_preserve_hermiticity() {
+        let graph = MetatronGraph::new();
+        let ham = HamiltonOperator::with_aharonov_bohm_flux(
+            1.0,
+            [0.0; HILBERT_DIM],
+            &graph,
+            std::f64::consts::PI / 3.0,
+            std::f64::consts::PI / 5.0,
+        );
+
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                let h_ij = ham.matrix[(i, j)];
+                let h_ji_conj = ham.matrix[(j, i)].conj();
+                assert_abs_diff_eq!(h_ij.re, h_ji_conj.re, epsilon = 1e-10);
+                assert_abs_diff_eq!(h_ij.im, h_ji_conj.im, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_peierls_flux_changes_spectrum() {
+        let graph = MetatronGraph::new();
+        let no_flux = HamiltonOperator::uniform(1.0, 0.0, &graph);
+        let with_flux = HamiltonOperator::with_aharonov_bohm_flux(
+            1.0,
+            [0.0; HILBERT_DIM],
+            &graph,
+            std::f64::consts::PI / 2.0,
+            0.0,
+        );
+
+        // Ein Fluss durch die Hexagon-Plakette sollte das Spektrum
+        // verändern (Aharonov-Bohm-Effekt)
+        let e0 = no_flux.eigenspectrum().energies;
+        let e1 = with_flux.eigenspectrum().energies;
+        let differs = e0.iter().zip(e1.iter()).any(|(a, b)| (a - b).abs() > 1e-6);
+        assert!(differs);
+    }
+
+    #[test]
+    fn test_evolution_operator_unitary_with_flux() {
+        let graph = MetatronGraph::new();
+        let ham = HamiltonOperator::with_aharonov_bohm_flux(
+            1.0,
+            [0.0; HILBERT_DIM],
+            &graph,
+            std::f64::consts::PI / 4.0,
+            std::f64::consts::PI / 6.0,
+        );
+        let u = ham.evolution_operator(0.7);
+
+        let u_dagger_u = u.adjoint() * u;
+        let identity = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                assert_abs_diff_eq!(u_dagger_u[(i, j)].re, identity[(i, j)].re, epsilon = 1e-7);
+                assert_abs_diff_eq!(u_dagger_u[(i, j)].im, 0.0, epsilon = 1e-7);
+            }
+        }
+    }
 }
@@ -0,0 +1,513 @@
+//! Many-Body-Erweiterung auf dem Metatron-Graphen
+//!
+//! Während `HamiltonOperator` ein einzelnes Teilchen im 13-dimensionalen
+//! Ein-Teilchen-Hilbertraum beschreibt, baut dieses Modul N-Teilchen-
+//! Hamiltonians auf demselben Graphen auf (Hopping via Laplacian, optionale
+//! Hubbard-artige On-site-Wechselwirkung U·n(n−1)/2 und
+//! Nächste-Nachbar-Wechselwirkung V·nᵢnⱼ entlang `MetatronGraph::adjacency`).
+//!
+//! Da der exakte Fock-Raum exponentiell mit der Teilchenzahl wächst, werden
+//! Zustände als Matrix-Produkt-Zustand (MPS) über die 13 Plätze dargestellt,
+//! mit einer Bindungsdimension χ, die nach jedem Schritt re-trunkiert wird.
+//! Die Zeitevolution erfolgt per TEBD: der Hamiltonian wird über eine
+//! Kantenfärbung des Graphen in Gruppen kommutierender Bindungs-Gatter
+//! zerlegt (Trotter-Zerlegung), die gruppenweise angewendet werden.
+
+use crate::core::MetatronGraph;
+use crate::utils::linalg::hermitian_eigen_dyn;
+use nalgebra::{DMatrix, DVector};
+use num_complex::Complex64;
+
+/// Many-Body-Hamiltonian auf dem Metatron-Graphen
+///
+/// Ĥ = −J Σ_{⟨i,j⟩} (a†ᵢaⱼ + a†ⱼaᵢ) + Σᵢ εᵢnᵢ + Σᵢ (U/2)nᵢ(nᵢ−1) + Σ_{⟨i,j⟩} V·nᵢnⱼ
+#[derive(Debug, Clone)]
+pub struct ManyBodyHamiltonian {
+    /// Hopping-Konstante J
+    pub hopping_strength: f64,
+    /// Lokale Energien εᵢ (13 Knoten)
+    pub local_energies: [f64; 13],
+    /// Hubbard-On-site-Wechselwirkung U
+    pub hubbard_u: f64,
+    /// Nächste-Nachbar-Wechselwirkung V
+    pub nn_interaction_v: f64,
+    /// Lokale Besetzungsdimension (2 = harte-Kern-Bosonen / Spin-1/2)
+    pub local_dim: usize,
+}
+
+impl ManyBodyHamiltonian {
+    /// Erstellt einen harte-Kern-Bosonen/Spin-1/2-Hamiltonian (local_dim=2)
+    pub fn hard_core(hopping_strength: f64, local_energies: [f64; 13], nn_interaction_v: f64) -> Self {
+        Self {
+            hopping_strength,
+            local_energies,
+            hubbard_u: 0.0,
+            nn_interaction_v,
+            local_dim: 2,
+        }
+    }
+
+    /// Erstellt einen weichen bosonischen Hamiltonian mit abgeschnittenem
+    /// Fock-Raum (Besetzung 0..local_dim−1) und Hubbard-Wechselwirkung U
+    pub fn soft_core(
+        hopping_strength: f64,
+        local_energies: [f64; 13],
+        hubbard_u: f64,
+        nn_interaction_v: f64,
+        local_dim: usize,
+    ) -> Self {
+        assert!(local_dim >= 2, "local_dim muss mindestens 2 sein");
+        Self {
+            hopping_strength,
+            local_energies,
+            hubbard_u,
+            nn_interaction_v,
+            local_dim,
+        }
+    }
+
+    /// Bosonische Vernichtungsmatrix a auf dem abgeschnittenen lokalen
+    /// Hilbertraum: a|n⟩ = √n|n−1⟩
+    fn annihilation(&self) -> DMatrix<Complex64> {
+        let d = self.local_dim;
+        DMatrix::from_fn(d, d, |row, col| {
+            if col == row + 1 {
+                Complex64::new((col as f64).sqrt(), 0.0)
+            } else {
+                Complex64::new(0.0, 0.0)
+            }
+        })
+    }
+
+    /// Besetzungszahloperator n = diag(0, 1, ..., local_dim−1)
+    fn number_operator(&self) -> DMatrix<Complex64> {
+        let d = self.local_dim;
+        DMatrix::from_fn(d, d, |row, col| {
+            if row == col {
+                Complex64::new(row as f64, 0.0)
+            } else {
+                Complex64::new(0.0, 0.0)
+            }
+        })
+    }
+
+    /// Zwei-Platz-Bindungs-Hamiltonian für die Kante (i,j), im Sinne einer
+    /// Trotter-Zerlegung: Hopping und NN-Wechselwirkung sitzen vollständig
+    /// auf der Kante; On-site-Terme (εᵢnᵢ und Hubbard U) werden anteilig
+    /// gleichmäßig auf alle Kanten verteilt, die an i bzw. j anliegen
+    /// (Gewicht 1/deg), sodass ihre Summe über alle Kanten exakt den
+    /// vollen On-site-Term ergibt.
+    ///
+    /// Rückgabe als (d²×d²)-Matrix in der Basis |sᵢ,sⱼ⟩ mit Index
+    /// sᵢ·d + sⱼ.
+    pub fn bond_hamiltonian(&self, graph: &MetatronGraph, i: usize, j: usize) -> DMatrix<Complex64> {
+        let d = self.local_dim;
+        let a = self.annihilation();
+        let a_dag = a.adjoint();
+        let n = self.number_operator();
+        let identity = DMatrix::<Complex64>::identity(d, d);
+
+        let kron = |lhs: &DMatrix<Complex64>, rhs: &DMatrix<Complex64>| -> DMatrix<Complex64> {
+            lhs.kronecker(rhs)
+        };
+
+        let mut h = kron(&a_dag, &a) * Complex64::new(-self.hopping_strength, 0.0)
+            + kron(&a, &a_dag) * Complex64::new(-self.hopping_strength, 0.0);
+
+        h += kron(&n, &n) * Complex64::new(self.nn_interaction_v, 0.0);
+
+        let deg_i = graph.degree(i).max(1) as f64;
+        let deg_j = graph.degree(j).max(1) as f64;
+
+        h += kron(&n, &identity) * Complex64::new(self.local_energies[i] / deg_i, 0.0);
+        h += kron(&identity, &n) * Complex64::new(self.local_energies[j] / deg_j, 0.0);
+
+        if self.hubbard_u != 0.0 {
+            let n_minus_one = &n - &identity;
+            let hubbard_term_i = (&n * &n_minus_one) * Complex64::new(self.hubbard_u / (2.0 * deg_i), 0.0);
+            let hubbard_term_j = (&n * &n_minus_one) * Complex64::new(self.hubbard_u / (2.0 * deg_j), 0.0);
+            h += kron(&hubbard_term_i, &identity);
+            h += kron(&identity, &hubbard_term_j);
+        }
+
+        h
+    }
+
+    /// Zwei-Platz-Zeitentwicklungs-Gatter e^{−iĥ_{ij}·dt} für eine Kante
+    pub fn bond_gate(&self, graph: &MetatronGraph, i: usize, j: usize, dt: f64) -> DMatrix<Complex64> {
+        let h = self.bond_hamiltonian(graph, i, j);
+        let (eigenvalues, eigenvectors) = hermitian_eigen_dyn(&h);
+
+        let n = h.nrows();
+        let mut gate = DMatrix::<Complex64>::zeros(n, n);
+        for k in 0..n {
+            let phase = Complex64::new(0.0, -eigenvalues[k] * dt).exp();
+            let v = eigenvectors.column(k);
+            for a in 0..n {
+                for b in 0..n {
+                    gate[(a, b)] += phase * v[a] * v[b].conj();
+                }
+            }
+        }
+        gate
+    }
+}
+
+/// Greedy-Kantenfärbung des Graphen: gruppiert Kanten so, dass Kanten
+/// derselben Farbe paarweise keinen Knoten teilen (und damit unabhängig
+/// und ohne definierte Reihenfolge anwendbar, d.h. kommutierende
+/// Trotter-Gatter bilden).
+pub fn edge_coloring(graph: &MetatronGraph) -> Vec<Vec<(usize, usize)>> {
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    for i in 0..crate::core::NUM_NODES {
+        for j in (i + 1)..crate::core::NUM_NODES {
+            if graph.adjacency[(i, j)] {
+                edges.push((i, j));
+            }
+        }
+    }
+
+    let mut colors: Vec<Vec<(usize, usize)>> = Vec::new();
+    for edge in edges {
+        let mut placed = false;
+        for color in colors.iter_mut() {
+            let conflicts = color
+                .iter()
+                .any(|&(a, b)| a == edge.0 || a == edge.1 || b == edge.0 || b == edge.1);
+            if !conflicts {
+                color.push(edge);
+                placed = true;
+                break;
+            }
+        }
+        if !placed {
+            colors.push(vec![edge]);
+        }
+    }
+
+    colors
+}
+
+/// Ein Platz-Tensor des MPS: ein (d-elementiges) Array von
+/// (links × rechts)-Matrizen, eine pro physikalischem Basiszustand.
+pub type MpsTensor = Vec<DMatrix<Complex64>>;
+
+/// Matrix-Produkt-Zustand über die 13 Plätze des Metatron-Graphen, mit
+/// Bindungsdimensions-Obergrenze `max_bond_dim` (χ).
+#[derive(Debug, Clone)]
+pub struct MatrixProductState {
+    /// Platz-Tensoren (13 Einträge)
+    pub sites: Vec<MpsTensor>,
+    /// Lokale physikalische Dimension d
+    pub physical_dim: usize,
+    /// Maximal erlaubte Bindungsdimension χ
+    pub max_bond_dim: usize,
+}
+
+impl MatrixProductState {
+    /// Erstellt einen Produktzustand (Bindungsdimension 1) aus den
+    /// gegebenen Besetzungszahlen je Platz.
+    pub fn product_state(occupations: &[usize], physical_dim: usize, max_bond_dim: usize) -> Self {
+        let sites = occupations
+            .iter()
+            .map(|&occ| {
+                assert!(occ < physical_dim, "Besetzung übersteigt lokale Dimension");
+                (0..physical_dim)
+                    .map(|s| {
+                        let value = if s == occ { 1.0 } else { 0.0 };
+                        DMatrix::from_element(1, 1, Complex64::new(value, 0.0))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            sites,
+            physical_dim,
+            max_bond_dim,
+        }
+    }
+
+    /// Anzahl der Plätze
+    pub fn num_sites(&self) -> usize {
+        self.sites.len()
+    }
+
+    /// Bindungsdimension rechts von Platz `site` (0 = zwischen Platz 0
+    /// und 1, usw.; `num_sites()-2` ist der letzte innere Bond)
+    pub fn bond_dimension(&self, site: usize) -> usize {
+        self.sites[site][0].ncols()
+    }
+
+    /// Wendet ein Zwei-Platz-Gatter auf die benachbarten Plätze (site,
+    /// site+1) an und trunkiert die resultierende Bindungsdimension per
+    /// SVD auf `max_bond_dim`.
+    ///
+    /// `gate` ist eine (d²×d²)-Matrix in der Basis |s_site, s_{site+1}⟩
+    /// mit Index s_site·d + s_{site+1}.
+    pub fn apply_two_site_gate(&mut self, site: usize, gate: &DMatrix<Complex64>) {
+        let d = self.physical_dim;
+        let left_dim = self.sites[site][0].nrows();
+        let right_dim = self.sites[site + 1][0].ncols();
+
+        // theta[(a, s_left), (s_right, b)] = Σ_m A_left[s_left][a,m]·A_right[s_right][m,b]
+        let mut theta = DMatrix::<Complex64>::zeros(left_dim * d, d * right_dim);
+        for s_left in 0..d {
+            for s_right in 0..d {
+                let block = &self.sites[site][s_left] * &self.sites[site + 1][s_right];
+                for a in 0..left_dim {
+                    for b in 0..right_dim {
+                        theta[(a * d + s_left, s_right * d + b)] = block[(a, b)];
+                    }
+                }
+            }
+        }
+
+        // Gatter anwenden: theta'[(a,s'_left),(s'_right,b)] =
+        //   Σ gate[(s'_left,s'_right),(s_left,s_right)]·theta[(a,s_left),(s_right,b)]
+        let mut theta_gated = DMatrix::<Complex64>::zeros(left_dim * d, d * right_dim);
+        for a in 0..left_dim {
+            for b in 0..right_dim {
+                for s_left_out in 0..d {
+                    for s_right_out in 0..d {
+                        let mut acc = Complex64::new(0.0, 0.0);
+                        for s_left_in in 0..d {
+                            for s_right_in in 0..d {
+                                acc += gate[(
+                                    s_left_out * d + s_right_out,
+                                    s_left_in * d + s_right_in,
+                                )] * theta[(a * d + s_left_in, s_right_in * d + b)];
+                            }
+                        }
+                        theta_gated[(a * d + s_left_out, s_right_out * d + b)] = acc;
+                    }
+                }
+            }
+        }
+
+        // SVD + Trunkierung auf max_bond_dim
+        let svd = theta_gated.clone().svd(true, true);
+        let u = svd.u.expect("SVD sollte U liefern");
+        let v_t = svd.v_t.expect("SVD sollte Vᵀ liefern");
+        let singular_values = svd.singular_values;
+
+        let new_bond_dim = singular_values.len().min(self.max_bond_dim);
+
+        let mut new_left: MpsTensor = (0..d)
+            .map(|_| DMatrix::<Complex64>::zeros(left_dim, new_bond_dim))
+            .collect();
+        let mut new_right: MpsTensor = (0..d)
+            .map(|_| DMatrix::<Complex64>::zeros(new_bond_dim, right_dim))
+            .collect();
+
+        for k in 0..new_bond_dim {
+            let sigma = singular_values[k];
+            for a in 0..left_dim {
+                for s_left in 0..d {
+                    new_left[s_left][(a, k)] = u[(a * d + s_left, k)];
+                }
+            }
+            for b in 0..right_dim {
+                for s_right in 0..d {
+                    new_right[s_right][(k, b)] =
+                        v_t[(k, s_right * d + b)] * Complex64::new(sigma, 0.0);
+                }
+            }
+        }
+
+        self.sites[site] = new_left;
+        self.sites[site + 1] = new_right;
+    }
+
+    /// Berechnet ⟨ψ|ψ⟩ durch sukzessive Transfer-Matrix-Kontraktion
+    /// (kostet O(N·d·χ²) statt des vollen, exponentiell großen
+    /// Fock-Raums).
+    pub fn norm_squared(&self) -> f64 {
+        let mut transfer = DMatrix::<Complex64>::identity(1, 1);
+
+        for tensor in &self.sites {
+            let right_dim = tensor[0].ncols();
+            let mut new_transfer = DMatrix::<Complex64>::zeros(right_dim, right_dim);
+            for a in tensor {
+                new_transfer += a.adjoint() * &transfer * a;
+            }
+            transfer = new_transfer;
+        }
+
+        transfer[(0, 0)].re
+    }
+
+    /// Norm ‖ψ‖ = √⟨ψ|ψ⟩
+    pub fn norm(&self) -> f64 {
+        self.norm_squared().max(0.0).sqrt()
+    }
+}
+
+/// TEBD-Zeitentwickler (Trotter-Zerlegung entlang einer Kantenfärbung)
+pub struct TebdEvolver<'a> {
+    hamiltonian: &'a ManyBodyHamiltonian,
+    graph: &'a MetatronGraph,
+    edge_groups: Vec<Vec<(usize, usize)>>,
+}
+
+impl<'a> TebdEvolver<'a> {
+    /// Erstellt einen TEBD-Evolver für den gegebenen Hamiltonian/Graph
+    pub fn new(hamiltonian: &'a ManyBodyHamiltonian, graph: &'a MetatronGraph) -> Self {
+        Self {
+            hamiltonian,
+            graph,
+            edge_groups: edge_coloring(graph),
+        }
+    }
+
+    /// Führt einen Trotter-Schritt der Weite `dt` aus: je Farbgruppe
+    /// werden alle (kommutierenden) Kanten-Gatter angewendet, mit
+    /// Re-Trunkierung per SVD nach jeder Kante.
+    ///
+    /// Kanten, deren Knoten in der MPS-Kette nicht benachbart sind,
+    /// werden per Swap-Netzwerk temporär nebeneinandergebracht, das
+    /// Gatter angewendet und anschließend zurückgeschoben - der übliche
+    /// Kunstgriff für TEBD auf Nicht-Pfad-Graphen.
+    pub fn step(&self, mps: &mut MatrixProductState, dt: f64) {
+        for group in &self.edge_groups {
+            for &(i, j) in group {
+                let gate = self.hamiltonian.bond_gate(self.graph, i, j, dt);
+                self.apply_gate_between(mps, i, j, &gate);
+            }
+        }
+    }
+
+    /// Führt `steps` Trotter-Schritte der Weite `dt` aus
+    pub fn evolve(&self, mps: &mut MatrixProductState, dt: f64, steps: usize) {
+        for _ in 0..steps {
+            self.step(mps, dt);
+        }
+    }
+
+    fn swap_gate(d: usize) -> DMatrix<Complex64> {
+        DMatrix::from_fn(d * d, d * d, |row, col| {
+            let (a, b) = (row / d, row % d);
+            let (c, e) = (col / d, col % d);
+            if a == e && b == c {
+                Complex64::new(1.0, 0.0)
+            } else {
+                Complex64::new(0.0, 0.0)
+            }
+        })
+    }
+
+    fn apply_gate_between(&self, mps: &mut MatrixProductState, i: usize, j: usize, gate: &DMatrix<Complex64>) {
+        let (lo, hi) = if i < j { (i, j) } else { (j, i) };
+
+        if hi == lo + 1 {
+            mps.apply_two_site_gate(lo, gate);
+            return;
+        }
+
+        let d = mps.physical_dim;
+        let swap = Self::swap_gate(d);
+
+        // Bringe den Platz `hi` per Swap-Kette direkt neben `lo`
+        for pos in (lo + 1..hi).rev() {
+            mps.apply_two_site_gate(pos, &swap);
+        }
+
+        mps.apply_two_site_gate(lo, gate);
+
+        // Zurückschieben in die Ursprungsreihenfolge
+        for pos in lo + 1..hi {
+            mps.apply_two_site_gate(pos, &swap);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_edge_coloring_covers_all_edges() {
+        let graph = MetatronGraph::new();
+        let colors = edge_coloring(&graph);
+        let total: usize = colors.iter().map(|c| c.len()).sum();
+        assert_eq!(total, graph.count_edges());
+    }
+
+    #[test]
+    fn test_edge_coloring_groups_are_vertex_disjoint() {
+        let graph = MetatronGraph::new();
+        let colors = edge_coloring(&graph);
+        for group in &colors {
+            let mut seen = std::collections::HashSet::new();
+            for &(a, b) in group {
+                assert!(seen.insert(a));
+                assert!(seen.insert(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bond_hamiltonian_is_hermitian() {
+        let graph = MetatronGraph::new();
+        let ham = ManyBodyHamiltonian::hard_core(1.0, [0.1; 13], 0.5);
+        let h = ham.bond_hamiltonian(&graph, 0, 1);
+
+        for a in 0..h.nrows() {
+            for b in 0..h.ncols() {
+                assert_abs_diff_eq!(h[(a, b)].re, h[(b, a)].re, epsilon = 1e-10);
+                assert_abs_diff_eq!(h[(a, b)].im, -h[(b, a)].im, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_product_state_has_bond_dimension_one() {
+        let occupations = vec![0; 13];
+        let mps = MatrixProductState::product_state(&occupations, 2, 8);
+        for site in 0..(mps.num_sites() - 1) {
+            assert_eq!(mps.bond_dimension(site), 1);
+        }
+    }
+
+    #[test]
+    fn test_product_state_is_normalized() {
+        let mut occupations = vec![0; 13];
+        occupations[0] = 1;
+        let mps = MatrixProductState::product_state(&occupations, 2, 8);
+        assert_abs_diff_eq!(mps.norm(), 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_tebd_step_preserves_norm() {
+        let graph = MetatronGraph::new();
+        let ham = ManyBodyHamiltonian::hard_core(1.0, [0.0; 13], 0.2);
+        let evolver = TebdEvolver::new(&ham, &graph);
+
+        let mut occupations = vec![0; 13];
+        occupations[0] = 1;
+        let mut mps = MatrixProductState::product_state(&occupations, 2, 8);
+
+        evolver.evolve(&mut mps, 0.05, 5);
+
+        assert_abs_diff_eq!(mps.norm(), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_bond_dimension_respects_chi_max() {
+        let graph = MetatronGraph::new();
+        let ham = ManyBodyHamiltonian::hard_core(1.0, [0.0; 13], 0.0);
+        let evolver = TebdEvolver::new(&ham, &graph);
+
+        let mut occupations = vec![0; 13];
+        occupations[0] = 1;
+        let max_bond_dim = 4;
+        let mut mps = MatrixProductState::product_state(&occupations, 2, max_bond_dim);
+
+        evolver.evolve(&mut mps, 0.1, 10);
+
+        for site in 0..(mps.num_sites() - 1) {
+            assert!(mps.bond_dimension(site) <= max_bond_dim);
+        }
+    }
+}
@@ -4,14 +4,49 @@
 //! - Hamilton: Zeitevolution auf Metatron-Graph
 //! - Omega5: 5D-Operatorfamilie
 //! - Nullpoint: K_N Reset-Operator
+//! - Symmetry: C6-Symmetriesektoren und Bloch-Dispersion des Hamiltonians
+//! - Manybody: N-Teilchen-Hamiltonians und MPS/TEBD-Zeitevolution
+//! - SpectralGuard: Spektrale Kennzahlen (Lücke, Betti-Zahl, Kohärenzgradient) für `GuardValidator`
+//! - SU2: Haar-nahe SU(2)-Rotationen für echte unitäre Mischung in `NullpointOperator::apply`
+//! - Gates: Gate-/Schaltkreis-Schicht (Phase, Givens, Detuning, Custom) über `QuantumState`
+//! - SparseSynthesis: Conditional-Gradient-Zerlegung hermitescher Kopplungen in dünnbesetzte Wormhole-Generator-Summen
+//! - Omega5::MatrixExp: Gemeinsamer Exponential-Pfad für Generator-basierte Operatoren, mit gecachter Eigenzerlegung für Dämpfungs-Familien
+//! - Omega5::TorusOrbit: Weyl-Diskrepanz- und diophantische Klassifikation von 2-Torus-Trajektorien
+//! - Sequence: Komposition, Adjungierte und Guard-Audit für Ketten von Operator-Generatoren
+//! - Omega5::Precision: Batch-Anwendung von Operatoren in voller oder gemischter (f32-Matrix/f64-Akkumulation) Präzision
 
+pub mod gates;
 pub mod hamiltonian;
+pub mod manybody;
 pub mod nullpoint;
 pub mod omega5;
+pub mod sequence;
+pub mod sparse_synthesis;
+pub mod spectral_guard;
+pub mod su2;
+pub mod symmetry;
 
+pub use gates::{Circuit, Gate};
 pub use hamiltonian::{EigenSpectrum, HamiltonOperator};
+pub use manybody::{
+    edge_coloring, ManyBodyHamiltonian, MatrixProductState, MpsTensor, TebdEvolver,
+};
 pub use nullpoint::{GuardStatus, GuardValidator, GuardViolation, NullpointOperator};
 pub use omega5::{
-    DampingOperator, QuaternionRotation, ScalingOperator, State5D, TopologicalGuards,
+    classify_torus_orbit, DampingFamily, DampingOperator, MatrixExp, OrbitClassifierConfig,
+    Precision, QuaternionRotation, ScalingOperator, State5D, TopologicalGuards, TorusOrbit,
     WormholeOperator,
 };
+pub use sequence::{
+    ComposedOperatorSequence, GuardedPropagation, GuardedStepReport, Operator, OperatorSequence,
+    SequenceKind,
+};
+pub use sparse_synthesis::{synthesize_sparse_operator, SparseSynthesisConfig, SparseSynthesisResult};
+pub use spectral_guard::{
+    betti_number_estimate, coherence_gradient, spectral_gap, DEFAULT_ZERO_EIGENVALUE_TOLERANCE,
+};
+pub use su2::{
+    embed_su2_block, pauli_x, pauli_y, pauli_z, random_su2_close_to_unity,
+    random_unitary_close_to_unity,
+};
+pub use symmetry::{BlochSpectrum, MomentumSector, NUM_MOMENTUM_SECTORS};
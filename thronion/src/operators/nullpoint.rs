@@ -4,7 +4,8 @@
 //! K_N = lim_{k→∞} (∏ᵢ₌₁ᵏ Uᵢ·Dᵢ) · P_sterile
 
 use crate::core::{MetatronGraph, QuantumState, HILBERT_DIM};
-use crate::operators::omega5::{DampingOperator, ScalingOperator};
+use crate::operators::omega5::DampingOperator;
+use crate::operators::su2::random_unitary_close_to_unity;
 use nalgebra::SMatrix;
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
@@ -60,6 +61,11 @@ impl NullpointOperator {
             }
         }
 
+        debug_assert!(
+            crate::utils::linalg::is_projector(&projector, 1e-8),
+            "construct_sterile_projector lieferte keinen gültigen Projektor (hermitesch & idempotent)"
+        );
+
         projector
     }
 
@@ -72,17 +78,18 @@ impl NullpointOperator {
 
         let mut current_state = state.clone();
 
-        // Iteriere mit zufälligen Unitären und Dämpfungen
+        // Iteriere mit zufälligen Unitären und Dämpfungen: ψ_{k+1} = U_k·D_k·ψ_k
         for _ in 0..self.num_iterations {
-            // Zufällige Dämpfung
+            // Zufällige Dämpfung D_k
             let mu = rng.gen_range(0.01..0.1);
             let damping = DampingOperator::new(mu, graph);
             current_state = damping.apply(&current_state);
 
-            // Zufällige Skalierung
-            let lambda = rng.gen_range(-0.1..0.1);
-            let scaling = ScalingOperator::uniform(lambda, 0.0);
-            current_state = scaling.apply(&current_state);
+            // Echte zufällige Unitäre U_k (Haar-nahe SU(2)-Rotation in
+            // einer zufälligen 2D-Unterebene), ersetzt die frühere
+            // ScalingOperator-Näherung, die U†U = I nicht garantierte.
+            let unitary = random_unitary_close_to_unity(0.1, &mut rng);
+            current_state = QuantumState::new(unitary * current_state.amplitudes);
         }
 
         // Finale Projektion auf sterilen Unterraum
@@ -168,6 +175,28 @@ impl GuardValidator {
         }
     }
 
+    /// Validiert alle Guards, indem `betti`, `spectral_gap` und
+    /// `coherence_gradient` selbst aus `graph` und den beiden Zuständen
+    /// abgeleitet werden, statt sie (wie in [`Self::validate`]) von außen
+    /// hereinzureichen.
+    pub fn validate_state(
+        &self,
+        state: &QuantumState,
+        prev_state: &QuantumState,
+        graph: &MetatronGraph,
+    ) -> GuardStatus {
+        use crate::operators::spectral_guard::{
+            betti_number_estimate, coherence_gradient, spectral_gap,
+            DEFAULT_ZERO_EIGENVALUE_TOLERANCE,
+        };
+
+        let betti = betti_number_estimate(graph, DEFAULT_ZERO_EIGENVALUE_TOLERANCE);
+        let gap = spectral_gap(graph);
+        let gradient = coherence_gradient(prev_state, state);
+
+        self.validate(betti, gap, gradient)
+    }
+
     /// Führt bedingten Reset durch wenn Guards verletzt sind
     pub fn conditional_reset(
         &self,
@@ -293,6 +322,28 @@ mod tests {
         assert!(matches!(status, GuardStatus::Violated(_)));
     }
 
+    #[test]
+    fn test_validate_state_derives_metrics_from_graph_and_states() {
+        let graph = MetatronGraph::new();
+        let validator = GuardValidator::new(&graph);
+        let state = QuantumState::random();
+
+        // Identischer Vorher-/Nachher-Zustand: Kohärenzgradient ≈ 0,
+        // sollte also nicht an der Gradient-Guard scheitern.
+        let status = validator.validate_state(&state, &state, &graph);
+        match status {
+            GuardStatus::Violated(violations) => {
+                assert!(
+                    !violations
+                        .iter()
+                        .any(|v| matches!(v, GuardViolation::CoherenceGradientTooLarge(_))),
+                    "identical states should not trip the coherence-gradient guard"
+                );
+            }
+            GuardStatus::Valid => {}
+        }
+    }
+
     #[test]
     fn test_conditional_reset() {
         let graph = MetatronGraph::new();
@@ -309,4 +360,44 @@ mod tests {
         assert!(was_reset);
         assert!(reset_state.is_normalized());
     }
+
+    // Eigenschaftstests über zufällige, normierte `QuantumState`s statt
+    // eines einzelnen festen RNG-Seeds (siehe `crate::proptest_support`).
+    mod proptests {
+        use super::*;
+        use crate::proptest_support::arb_quantum_state;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn prop_apply_returns_normalized_state_with_nondecreasing_overlap(
+                state in arb_quantum_state(),
+            ) {
+                let graph = MetatronGraph::new();
+                let kn = NullpointOperator::new(&graph, 5);
+
+                let overlap_before = kn.sterile_overlap(&state);
+                let result = kn.apply(&state, &graph);
+
+                prop_assert!(result.is_normalized());
+
+                let overlap_after = kn.sterile_overlap(&result);
+                prop_assert!(overlap_after >= overlap_before - 1e-9);
+            }
+
+            #[test]
+            fn prop_conditional_reset_is_identity_when_valid(state in arb_quantum_state()) {
+                let graph = MetatronGraph::new();
+                let validator = GuardValidator::new(&graph);
+
+                // Feste, garantiert gültige Guard-Werte (betti >= min_betti,
+                // gap >= min_gap, gradient <= max_gradient der Default-Validator).
+                let (new_state, was_reset) =
+                    validator.conditional_reset(&state, 2.0, 0.5, 0.001, &graph);
+
+                prop_assert!(!was_reset);
+                prop_assert!((new_state.fidelity(&state) - 1.0).abs() < 1e-9);
+            }
+        }
+    }
 }
@@ -8,11 +8,101 @@
 //! - Nullpunkt-Operator
 
 use crate::core::{MetatronGraph, QuantumState, HILBERT_DIM};
+use crate::utils::linalg;
 use nalgebra::{SMatrix, SVector, Vector3};
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
+/// Gemeinsame Schnittstelle für Operatoren, deren Matrixform als
+/// Exponential eines Generators `G` entsteht, also Operatoren der Form
+/// `exp(G)` (z.B. `S(λ) = exp(λE)`, `D(μ) = exp(−μL)`).
+///
+/// Bindet neue Generatorfamilien an den gemeinsamen
+/// [`linalg::matrix_exp`]-Pfad an (hermitesche Eigenwertzerlegung bzw.
+/// Scaling-and-Squaring-Padé-Fallback für den nicht-hermiteschen Fall),
+/// statt dass jeder Operator seine eigene Exponentiationslogik
+/// mitbringt. [`WormholeOperator`] implementiert dies bewusst nicht: sein
+/// `W = 𝕀 + κ(|i⟩⟨j| + |j⟩⟨i|)` ist eine Generator-Einfügung erster
+/// Ordnung, kein Matrixexponential.
+pub trait MatrixExp {
+    /// Gibt den Generator `G` zurück, dessen Exponential `exp(G)` den
+    /// Operator bildet.
+    fn generator(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>;
+
+    /// Berechnet `exp(G)` via [`linalg::matrix_exp`].
+    fn matrix_exp(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        linalg::matrix_exp(&self.generator())
+    }
+}
+
+/// Rechenpräzision für `apply_batch` auf [`ScalingOperator`],
+/// [`DampingOperator`] und [`WormholeOperator`].
+///
+/// `Mixed` halbiert den Speicherbedarf der Operatormatrix, indem sie in
+/// `Complex<f32>` statt `Complex64` gehalten wird; die
+/// Matrix-Vektor-Produkte werden trotzdem in `f64` akkumuliert, sodass
+/// nur die Matrixkoeffizienten, nicht die Summation, an Genauigkeit
+/// verlieren. Gedacht für große Batches (z.B. Monte-Carlo-Trajektorien),
+/// bei denen sich die einmalige Operatorkonstruktion -- insbesondere
+/// [`DampingOperator`]s Eigenzerlegung -- über tausende Zustände
+/// amortisiert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// Operatormatrix in `Complex64`.
+    Full,
+    /// Operatormatrix in `Complex<f32>`, Akkumulation in `f64`.
+    Mixed,
+}
+
+/// Rundet eine `Complex64`-Matrix auf `Complex<f32>`-Koeffizienten.
+fn to_mixed_matrix(
+    matrix: &SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
+) -> SMatrix<num_complex::Complex32, HILBERT_DIM, HILBERT_DIM> {
+    matrix.map(|c| num_complex::Complex32::new(c.re as f32, c.im as f32))
+}
+
+/// Matrix-Vektor-Produkt einer `Complex<f32>`-Matrix mit `Complex64`-Amplituden,
+/// akkumuliert in `f64`.
+fn apply_mixed(
+    matrix_f32: &SMatrix<num_complex::Complex32, HILBERT_DIM, HILBERT_DIM>,
+    amplitudes: &SVector<Complex64, HILBERT_DIM>,
+) -> SVector<Complex64, HILBERT_DIM> {
+    let mut result = SVector::<Complex64, HILBERT_DIM>::zeros();
+    for i in 0..HILBERT_DIM {
+        let mut acc = Complex64::new(0.0, 0.0);
+        for j in 0..HILBERT_DIM {
+            let entry = matrix_f32[(i, j)];
+            acc += Complex64::new(entry.re as f64, entry.im as f64) * amplitudes[j];
+        }
+        result[i] = acc;
+    }
+    result
+}
+
+/// Wendet `matrix` in der gewählten [`Precision`] auf einen Batch von
+/// Zuständen an, sodass `matrix` (und bei [`DampingOperator`] dessen
+/// Eigenzerlegung) nur einmal für den ganzen Batch aufgebaut wird.
+fn apply_batch_with_matrix(
+    matrix: &SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
+    states: &[QuantumState],
+    precision: Precision,
+) -> Vec<QuantumState> {
+    match precision {
+        Precision::Full => states
+            .iter()
+            .map(|state| QuantumState::new(matrix * state.amplitudes))
+            .collect(),
+        Precision::Mixed => {
+            let matrix_f32 = to_mixed_matrix(matrix);
+            states
+                .iter()
+                .map(|state| QuantumState::new(apply_mixed(&matrix_f32, &state.amplitudes)))
+                .collect()
+        }
+    }
+}
+
 /// 5D-Zustand auf der Informationsmannigfaltigkeit
 ///
 /// ξ = (ψ, ρ, ω, θ, φ) ∈ ℳ₅ ⊂ ℝ⁵
@@ -74,6 +164,156 @@ impl State5D {
     }
 }
 
+/// Qualitative Klassifikation einer 2-Torus-Trajektorie mit Frequenzen
+/// `(ω_θ, ω_φ)`, siehe [`classify_torus_orbit`].
+///
+/// Ersetzt die binäre, numerisch fragile Entscheidung von
+/// [`State5D::is_ergodic`] durch eine quantitative Einordnung: geschlossen
+/// (resonant), ergodisch mit unbekannter Mischungsrate, oder ergodisch
+/// mit einer durch den diophantischen Exponenten garantierten
+/// Mischungsrate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TorusOrbit {
+    /// Geschlossene Bahn: es existiert ein ganzzahliger Resonanzvektor
+    /// `(k1, k2) ≠ (0,0)` mit `k1·ω_θ + k2·ω_φ ≈ 0`.
+    Resonant { k1: i64, k2: i64 },
+    /// Dicht auf dem Torus (ergodisch), aber mit unbeschränkten
+    /// Kettenbruch-Teilnennern von `ω_θ/ω_φ` -- keine quantitative
+    /// Mischungsrate garantiert.
+    Ergodic,
+    /// Ergodisch und diophantisch ("badly approximable"): beschränkte
+    /// Kettenbruch-Teilnenner liefern eine algebraische Mischungsrate
+    /// mit dem angegebenen Exponenten (≥ 2, mit 2 für den Goldenen
+    /// Schnitt und verwandte Zahlen).
+    Diophantine { exponent: f64 },
+}
+
+/// Konfiguration für die Weyl-Diskrepanz-Resonanzsuche und die
+/// Kettenbruch-Abschätzung in [`classify_torus_orbit`].
+#[derive(Debug, Clone, Copy)]
+pub struct OrbitClassifierConfig {
+    /// Maximaler Betrag der getesteten Resonanzvektor-Komponenten `k1, k2`.
+    pub k_max: i64,
+    /// Anzahl gleichabständiger Abtastzeitpunkte der Weyl-Summe.
+    pub samples: usize,
+    /// Zeitschritt zwischen aufeinanderfolgenden Abtastpunkten.
+    pub dt: f64,
+    /// Schwelle, ab der `|S|` als "nahe 1" (Resonanz) gilt.
+    pub resonance_threshold: f64,
+    /// Maximaler Kettenbruch-Teilnenner, ab dem `ω_θ/ω_φ` als nicht
+    /// diophantisch (unbeschränkte Teilnenner) gilt.
+    pub partial_quotient_bound: f64,
+}
+
+impl Default for OrbitClassifierConfig {
+    fn default() -> Self {
+        Self {
+            k_max: 8,
+            samples: 512,
+            dt: 0.1,
+            resonance_threshold: 0.999,
+            partial_quotient_bound: 64.0,
+        }
+    }
+}
+
+/// Klassifiziert eine 2-Torus-Trajektorie mit Frequenzen `(ω_θ, ω_φ)`.
+///
+/// Zwei komplementäre Tests:
+/// 1. Ein Weyl-Summen-Diskrepanztest: für Integervektoren `(k1, k2)` mit
+///    `|k1|, |k2| ≤ k_max` wird `S = (1/N)Σ exp(i(k1·θₙ + k2·φₙ))` über
+///    `N` gleichabständige Abtastpunkte berechnet. Liegt `|S|` für ein
+///    `(k1, k2) ≠ (0,0)` nahe 1, ist die Bahn resonant (periodisch).
+/// 2. Andernfalls wird der diophantische Exponent von `ω_θ/ω_φ` aus den
+///    Kettenbruch-Konvergenten geschätzt: beschränkte Teilnenner ⇒
+///    [`TorusOrbit::Diophantine`] (schnelle Mischung), unbeschränkte
+///    Teilnenner ⇒ generisches [`TorusOrbit::Ergodic`].
+pub fn classify_torus_orbit(
+    omega_theta: f64,
+    omega_phi: f64,
+    config: OrbitClassifierConfig,
+) -> TorusOrbit {
+    if let Some((k1, k2)) = detect_resonance(omega_theta, omega_phi, &config) {
+        return TorusOrbit::Resonant { k1, k2 };
+    }
+
+    match diophantine_exponent(omega_theta / omega_phi, &config) {
+        Some(exponent) => TorusOrbit::Diophantine { exponent },
+        None => TorusOrbit::Ergodic,
+    }
+}
+
+/// Sucht einen Resonanzvektor `(k1, k2)` via Weyl-Summen-Diskrepanz.
+fn detect_resonance(
+    omega_theta: f64,
+    omega_phi: f64,
+    config: &OrbitClassifierConfig,
+) -> Option<(i64, i64)> {
+    for k1 in -config.k_max..=config.k_max {
+        for k2 in -config.k_max..=config.k_max {
+            if k1 == 0 && k2 == 0 {
+                continue;
+            }
+
+            let mut sum = Complex64::new(0.0, 0.0);
+            for n in 0..config.samples {
+                let t = n as f64 * config.dt;
+                let phase = k1 as f64 * (omega_theta * t) + k2 as f64 * (omega_phi * t);
+                sum += Complex64::new(phase.cos(), phase.sin());
+            }
+
+            let magnitude = (sum / config.samples as f64).norm();
+            if magnitude >= config.resonance_threshold {
+                return Some((k1, k2));
+            }
+        }
+    }
+    None
+}
+
+/// Schätzt den diophantischen Exponenten `2 + limsup (ln a_{k+1})/(ln q_k)`
+/// aus den Kettenbruch-Teilnennern `a_k` und Konvergenten-Nennern `q_k`
+/// von `ratio`. Gibt `None` zurück, sobald ein Teilnenner
+/// `config.partial_quotient_bound` übersteigt (unbeschränkte
+/// Teilnenner, also nicht diophantisch).
+fn diophantine_exponent(ratio: f64, config: &OrbitClassifierConfig) -> Option<f64> {
+    const MAX_TERMS: usize = 40;
+    const TOLERANCE: f64 = 1e-13;
+
+    let mut x = ratio.fract().abs();
+    if x < TOLERANCE {
+        return None; // ratio ist (nahezu) ganzzahlig, kein irrationaler Exponent definiert
+    }
+
+    let mut q_prev = 0.0_f64; // q_{-1}
+    let mut q_curr = 1.0_f64; // q_0
+    let mut limsup_term = 0.0_f64;
+
+    for _ in 0..MAX_TERMS {
+        if x < TOLERANCE {
+            break; // Kettenbruch terminiert: ratio ist rational
+        }
+
+        let inv = 1.0 / x;
+        let next_quotient = inv.floor();
+
+        if next_quotient > config.partial_quotient_bound {
+            return None;
+        }
+
+        if q_curr > 1.0 {
+            limsup_term = limsup_term.max(next_quotient.ln() / q_curr.ln());
+        }
+
+        let q_next = next_quotient * q_curr + q_prev;
+        q_prev = q_curr;
+        q_curr = q_next;
+        x = inv - next_quotient;
+    }
+
+    Some(2.0 + limsup_term)
+}
+
 impl Default for State5D {
     fn default() -> Self {
         Self::new(0.5, 0.5, 0.0, 0.0, 0.0)
@@ -164,6 +404,22 @@ impl ScalingOperator {
         }
         mat
     }
+
+    /// Wendet die Skalierung auf einen ganzen Batch von Zuständen an und
+    /// baut dabei die Operatormatrix nur einmal für den gesamten Batch.
+    pub fn apply_batch(&self, states: &[QuantumState], precision: Precision) -> Vec<QuantumState> {
+        apply_batch_with_matrix(&self.matrix(), states, precision)
+    }
+}
+
+impl MatrixExp for ScalingOperator {
+    fn generator(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        let mut generator = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+        for i in 0..HILBERT_DIM {
+            generator[(i, i)] = Complex64::new(self.lambda * self.energies[i], 0.0);
+        }
+        generator
+    }
 }
 
 /// Pfad-Invarianz-Dämpfungs-Operator
@@ -172,43 +428,99 @@ impl ScalingOperator {
 pub struct DampingOperator {
     /// Dämpfungsparameter μ ≥ 0
     pub mu: f64,
+    /// Graph-Laplacian, aus dem dieser Operator entstanden ist (für
+    /// [`MatrixExp::generator`])
+    pub laplacian: SMatrix<f64, HILBERT_DIM, HILBERT_DIM>,
     /// Dämpfungsmatrix
     pub matrix: SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
 }
 
 impl DampingOperator {
     /// Erstellt Dämpfungs-Operator aus Metatron-Graph
+    ///
+    /// Führt für einen einzelnen Operator eine volle
+    /// `SymmetricEigen`-Zerlegung des Laplacians durch. Wird D(μ) für
+    /// viele μ aus demselben Graphen benötigt (z.B. beim Absuchen einer
+    /// Zeitentwicklung), zerlegt [`DampingFamily`] den Laplacian nur
+    /// einmal und erzeugt jeden weiteren Operator in O(n²) statt O(n³).
     pub fn new(mu: f64, graph: &MetatronGraph) -> Self {
-        assert!(mu >= 0.0, "μ muss ≥ 0 sein");
+        DampingFamily::new(graph).at(mu)
+    }
 
-        // Berechne exp(-μL) via Eigenwertzerlegung
+    /// Wendet Dämpfung auf Zustand an
+    pub fn apply(&self, state: &QuantumState) -> QuantumState {
+        let damped_amps = self.matrix * state.amplitudes;
+        QuantumState::new(damped_amps)
+    }
+
+    /// Wendet die Dämpfung auf einen ganzen Batch von Zuständen an. Die
+    /// (bereits konstruierte) Dämpfungsmatrix wird dabei für den gesamten
+    /// Batch wiederverwendet, sodass sich insbesondere die zur
+    /// Konstruktion nötige Eigenzerlegung über den Batch amortisiert.
+    pub fn apply_batch(&self, states: &[QuantumState], precision: Precision) -> Vec<QuantumState> {
+        apply_batch_with_matrix(&self.matrix, states, precision)
+    }
+}
+
+impl MatrixExp for DampingOperator {
+    fn generator(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        self.laplacian.map(|entry| Complex64::new(-self.mu * entry, 0.0))
+    }
+}
+
+/// Vorberechnete Eigenzerlegung eines Graph-Laplacians für eine ganze
+/// Familie von Dämpfungs-Operatoren D(μ) = exp(−μL).
+///
+/// [`DampingOperator::new`] löst für jeden Aufruf eine vollständige
+/// Eigenwertzerlegung -- verschwendet, wenn μ in einer Zeitentwicklung
+/// durchlaufen ("gesweept") wird. `DampingFamily` führt die
+/// O(n³)-Zerlegung genau einmal durch; [`Self::at`] liefert danach für
+/// jedes μ einen `DampingOperator` in O(n²), indem nur die Eigenwerte
+/// reskaliert (`exp(−μλᵢ)`) und wieder zusammengesetzt werden.
+pub struct DampingFamily {
+    laplacian: SMatrix<f64, HILBERT_DIM, HILBERT_DIM>,
+    eigenvalues: SVector<f64, HILBERT_DIM>,
+    eigenvectors: SMatrix<f64, HILBERT_DIM, HILBERT_DIM>,
+}
+
+impl DampingFamily {
+    /// Zerlegt den Laplacian von `graph` genau einmal.
+    pub fn new(graph: &MetatronGraph) -> Self {
         use nalgebra::SymmetricEigen;
 
-        let laplacian = graph.laplacian;
-        let eigen = SymmetricEigen::new(laplacian);
+        let eigen = SymmetricEigen::new(graph.laplacian);
+
+        Self {
+            laplacian: graph.laplacian,
+            eigenvalues: eigen.eigenvalues,
+            eigenvectors: eigen.eigenvectors,
+        }
+    }
+
+    /// Erzeugt D(μ) = exp(−μL) aus der zwischengespeicherten
+    /// Eigenzerlegung, ohne erneute Diagonalisierung.
+    pub fn at(&self, mu: f64) -> DampingOperator {
+        assert!(mu >= 0.0, "μ muss ≥ 0 sein");
 
         let mut matrix = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
 
         for i in 0..HILBERT_DIM {
-            let eigenvalue = eigen.eigenvalues[i];
-            let damping_factor = (-mu * eigenvalue).exp();
+            let damping_factor = (-mu * self.eigenvalues[i]).exp();
 
             for j in 0..HILBERT_DIM {
                 for k in 0..HILBERT_DIM {
                     matrix[(j, k)] += Complex64::from(
-                        damping_factor * eigen.eigenvectors[(j, i)] * eigen.eigenvectors[(k, i)],
+                        damping_factor * self.eigenvectors[(j, i)] * self.eigenvectors[(k, i)],
                     );
                 }
             }
         }
 
-        Self { mu, matrix }
-    }
-
-    /// Wendet Dämpfung auf Zustand an
-    pub fn apply(&self, state: &QuantumState) -> QuantumState {
-        let damped_amps = self.matrix * state.amplitudes;
-        QuantumState::new(damped_amps)
+        DampingOperator {
+            mu,
+            laplacian: self.laplacian,
+            matrix,
+        }
     }
 }
 
@@ -262,6 +574,13 @@ impl WormholeOperator {
         mat[(self.to_node, self.from_node)] = Complex64::from(self.kappa);
         mat
     }
+
+    /// Wendet den Wormhole-Transfer auf einen ganzen Batch von Zuständen
+    /// an und baut dabei die Operatormatrix nur einmal für den gesamten
+    /// Batch.
+    pub fn apply_batch(&self, states: &[QuantumState], precision: Precision) -> Vec<QuantumState> {
+        apply_batch_with_matrix(&self.matrix(), states, precision)
+    }
 }
 
 /// Topologische Guard-Bedingungen
@@ -327,6 +646,39 @@ mod tests {
         // which is the primary use case for ergodicity checking.
     }
 
+    #[test]
+    fn test_classify_torus_orbit_detects_rational_resonance() {
+        // omega_theta/omega_phi = 2/3 -> 3*omega_theta - 2*omega_phi = 0
+        let orbit = classify_torus_orbit(2.0, 3.0, OrbitClassifierConfig::default());
+        match orbit {
+            TorusOrbit::Resonant { k1, k2 } => {
+                let residual = k1 as f64 * 2.0 + k2 as f64 * 3.0;
+                assert!(residual.abs() < 1e-6, "resonance vector does not annihilate the frequencies: {residual}");
+            }
+            other => panic!("expected a resonant orbit, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_classify_torus_orbit_golden_ratio_is_diophantine() {
+        let golden = (5f64.sqrt() - 1.0) / 2.0;
+        let orbit = classify_torus_orbit(golden, 1.0, OrbitClassifierConfig::default());
+        match orbit {
+            TorusOrbit::Diophantine { exponent } => {
+                assert!((exponent - 2.0).abs() < 0.5, "golden ratio exponent should be close to 2: {exponent}");
+            }
+            other => panic!("expected a diophantine orbit for the golden ratio, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_classify_torus_orbit_pi_has_unbounded_partial_quotients() {
+        // pi's continued fraction has an early large partial quotient (a_3 = 292),
+        // so it should not be classified as diophantine.
+        let orbit = classify_torus_orbit(PI, 1.0, OrbitClassifierConfig::default());
+        assert_eq!(orbit, TorusOrbit::Ergodic);
+    }
+
     #[test]
     fn test_quaternion_rotation() {
         let rot = QuaternionRotation::new(PI / 2.0, Vector3::new(1.0, 0.0, 0.0));
@@ -356,6 +708,86 @@ mod tests {
         assert!(damped.is_normalized());
     }
 
+    #[test]
+    fn test_scaling_operator_matrix_exp_matches_cached_matrix() {
+        let scaling = ScalingOperator::uniform(0.5, 1.0);
+        let reconstructed = scaling.matrix_exp();
+
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                assert!((reconstructed[(i, j)] - scaling.matrix[(i, j)]).norm() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_damping_operator_matrix_exp_matches_cached_matrix() {
+        let graph = MetatronGraph::new();
+        let damping = DampingOperator::new(0.3, &graph);
+        let reconstructed = damping.matrix_exp();
+
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                assert!((reconstructed[(i, j)] - damping.matrix[(i, j)]).norm() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_damping_family_matches_direct_construction() {
+        let graph = MetatronGraph::new();
+        let family = DampingFamily::new(&graph);
+
+        for &mu in &[0.0, 0.1, 0.7, 2.0] {
+            let direct = DampingOperator::new(mu, &graph);
+            let cached = family.at(mu);
+
+            for i in 0..HILBERT_DIM {
+                for j in 0..HILBERT_DIM {
+                    assert!((cached.matrix[(i, j)] - direct.matrix[(i, j)]).norm() < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_scaling_operator_mixed_precision_matches_full_within_tolerance() {
+        let scaling = ScalingOperator::uniform(0.4, 1.0);
+        let states: Vec<QuantumState> = (0..HILBERT_DIM).map(QuantumState::basis_state).collect();
+
+        let full = scaling.apply_batch(&states, Precision::Full);
+        let mixed = scaling.apply_batch(&states, Precision::Mixed);
+
+        for (full_state, mixed_state) in full.iter().zip(mixed.iter()) {
+            assert!(full_state.is_normalized());
+            assert!(mixed_state.is_normalized());
+            for i in 0..HILBERT_DIM {
+                assert!(
+                    (full_state.amplitudes[i] - mixed_state.amplitudes[i]).norm() < 1e-5,
+                    "mixed precision diverges beyond f32 rounding tolerance"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_damping_operator_apply_batch_matches_single_apply() {
+        let graph = MetatronGraph::new();
+        let damping = DampingOperator::new(0.2, &graph);
+        let states = vec![QuantumState::random(), QuantumState::random()];
+
+        let batch_full = damping.apply_batch(&states, Precision::Full);
+        let batch_mixed = damping.apply_batch(&states, Precision::Mixed);
+
+        for (index, state) in states.iter().enumerate() {
+            let direct = damping.apply(state);
+            for i in 0..HILBERT_DIM {
+                assert!((batch_full[index].amplitudes[i] - direct.amplitudes[i]).norm() < 1e-9);
+                assert!((batch_mixed[index].amplitudes[i] - direct.amplitudes[i]).norm() < 1e-5);
+            }
+        }
+    }
+
     #[test]
     fn test_wormhole_operator() {
         let graph = MetatronGraph::new();
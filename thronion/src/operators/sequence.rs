@@ -0,0 +1,342 @@
+//! Operator-Sequenzen: Komposition, Adjungierte und Guard-Audit
+//!
+//! Bindet die bisher unabhängig angewendeten Generatoren ([`ScalingOperator`],
+//! [`DampingOperator`], [`WormholeOperator`]) hinter einer gemeinsamen
+//! [`Operator`]-Schnittstelle an [`OperatorSequence`] an: eine geordnete
+//! Liste von Generatoren, die sich zu einer einzigen Matrix komponieren,
+//! adjungieren und auf Unitarität/Kontraktivität prüfen lässt. Über
+//! [`OperatorSequence::propagate_guarded`] wird außerdem
+//! [`TopologicalGuards::check`] nach jedem Schritt ausgewertet, sodass der
+//! Schritt, der eine Invariante verletzt, erkannt und die Sequenz dort
+//! abgebrochen werden kann.
+//!
+//! [`QuaternionRotation`] implementiert [`Operator`] bewusst nicht: sie
+//! rotiert die skalaren (ψ,ρ)/(ψ,ω)-Paare des 5D-Makrozustands, nicht die
+//! 13-dimensionalen Hilbert-Amplituden, und besitzt daher keine
+//! `SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>`-Darstellung, die sich in
+//! einen komponierten Propagator einreihen ließe.
+
+use crate::core::{MetatronGraph, QuantumState, HILBERT_DIM};
+use crate::operators::omega5::{DampingOperator, ScalingOperator, TopologicalGuards, WormholeOperator};
+use crate::operators::spectral_guard::{
+    betti_number_estimate, coherence_gradient, spectral_gap, DEFAULT_ZERO_EIGENVALUE_TOLERANCE,
+};
+use crate::utils::linalg;
+use nalgebra::SMatrix;
+use num_complex::Complex64;
+
+/// Gemeinsame Schnittstelle für Generatoren, die in einer [`OperatorSequence`]
+/// komponiert werden können.
+pub trait Operator {
+    /// Gibt die Matrixform des Operators im 13-dim Hilbertraum zurück.
+    fn matrix(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>;
+
+    /// Wendet den Operator auf einen Zustand an. Die Default-Implementierung
+    /// geht über [`Self::matrix`]; Operatoren mit einer günstigeren direkten
+    /// Formel können dies überschreiben.
+    fn apply(&self, state: &QuantumState) -> QuantumState {
+        QuantumState::new(self.matrix() * state.amplitudes)
+    }
+}
+
+impl Operator for ScalingOperator {
+    fn matrix(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        ScalingOperator::matrix(self)
+    }
+
+    fn apply(&self, state: &QuantumState) -> QuantumState {
+        ScalingOperator::apply(self, state)
+    }
+}
+
+impl Operator for DampingOperator {
+    fn matrix(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        self.matrix
+    }
+
+    fn apply(&self, state: &QuantumState) -> QuantumState {
+        DampingOperator::apply(self, state)
+    }
+}
+
+impl Operator for WormholeOperator {
+    fn matrix(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        WormholeOperator::matrix(self)
+    }
+
+    fn apply(&self, state: &QuantumState) -> QuantumState {
+        WormholeOperator::apply(self, state)
+    }
+}
+
+/// Klassifikation der von einer [`OperatorSequence`] komponierten Matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SequenceKind {
+    /// Normerhaltend: `U†U ≈ 𝕀`.
+    Unitary,
+    /// Nicht unitär, aber die Operatornorm ist `≤ 1`: keine Amplitude kann
+    /// verstärkt werden (z.B. eine Sequenz, die überwiegend Dämpfung enthält).
+    Contractive,
+    /// Weder unitär noch kontraktiv: die Sequenz kann Amplituden verstärken.
+    Expansive,
+}
+
+/// Eine geordnete Liste von [`Operator`]-Generatoren, die gemeinsam einen
+/// Propagator bilden.
+#[derive(Default)]
+pub struct OperatorSequence {
+    operators: Vec<Box<dyn Operator>>,
+}
+
+impl OperatorSequence {
+    /// Erstellt eine leere Sequenz.
+    pub fn new() -> Self {
+        Self {
+            operators: Vec::new(),
+        }
+    }
+
+    /// Hängt einen Operator ans Ende der Sequenz an.
+    pub fn push(mut self, operator: Box<dyn Operator>) -> Self {
+        self.operators.push(operator);
+        self
+    }
+
+    /// Anzahl der Operatoren in der Sequenz.
+    pub fn len(&self) -> usize {
+        self.operators.len()
+    }
+
+    /// Ob die Sequenz keine Operatoren enthält.
+    pub fn is_empty(&self) -> bool {
+        self.operators.is_empty()
+    }
+
+    /// Komponiert die Sequenz zu einer einzigen Matrix (der erste Operator
+    /// wirkt zuerst) und cached sie in einer [`ComposedOperatorSequence`],
+    /// sodass [`ComposedOperatorSequence::adjoint`] und
+    /// [`ComposedOperatorSequence::classify`] nicht erneut über die Liste
+    /// falten müssen.
+    pub fn compose(&self) -> ComposedOperatorSequence {
+        let matrix = self
+            .operators
+            .iter()
+            .fold(SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity(), |acc, op| {
+                op.matrix() * acc
+            });
+
+        ComposedOperatorSequence { matrix }
+    }
+
+    /// Wendet die Sequenz Schritt für Schritt auf `initial` an und prüft
+    /// nach jedem Schritt [`TopologicalGuards::check`]: Betti-Zahl und
+    /// spektrale Lücke sind topologische Invarianten von `graph` und daher
+    /// über die Sequenz konstant (der Graph selbst ändert sich durch die
+    /// Anwendung eines Operators nicht), während der Kohärenzgradient
+    /// zwischen aufeinanderfolgenden Zuständen pro Schritt neu berechnet
+    /// wird. Bricht beim ersten verletzten Schritt ab und meldet dessen
+    /// Index.
+    pub fn propagate_guarded(
+        &self,
+        initial: &QuantumState,
+        graph: &MetatronGraph,
+        guards: &TopologicalGuards,
+    ) -> GuardedPropagation {
+        let betti = betti_number_estimate(graph, DEFAULT_ZERO_EIGENVALUE_TOLERANCE);
+        let gap = spectral_gap(graph);
+
+        let mut states = vec![initial.clone()];
+        let mut reports = Vec::new();
+        let mut aborted_at = None;
+
+        for (index, operator) in self.operators.iter().enumerate() {
+            let previous = states.last().expect("states is never empty").clone();
+            let next = operator.apply(&previous);
+            let gradient = coherence_gradient(&previous, &next);
+            let passed = guards.check(betti, gap, gradient);
+
+            states.push(next);
+            reports.push(GuardedStepReport {
+                betti,
+                spectral_gap: gap,
+                coherence_gradient: gradient,
+                passed,
+            });
+
+            if !passed {
+                aborted_at = Some(index);
+                break;
+            }
+        }
+
+        GuardedPropagation {
+            states,
+            reports,
+            aborted_at,
+        }
+    }
+}
+
+/// Die zu einer Matrix komponierte Form einer [`OperatorSequence`].
+pub struct ComposedOperatorSequence {
+    matrix: SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
+}
+
+impl ComposedOperatorSequence {
+    /// Gibt die komponierte Matrix zurück.
+    pub fn matrix(&self) -> &SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        &self.matrix
+    }
+
+    /// Berechnet die Adjungierte der komponierten Matrix.
+    pub fn adjoint(&self) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+        self.matrix.adjoint()
+    }
+
+    /// Klassifiziert die komponierte Matrix als unitär, kontraktiv oder
+    /// expansiv. Die Operatornorm wird über den größten Eigenwert von
+    /// `M†M` geschätzt (dessen Wurzel ist die größte Singulärzahl von `M`).
+    pub fn classify(&self, tolerance: f64) -> SequenceKind {
+        if linalg::is_unitary(&self.matrix, tolerance) {
+            return SequenceKind::Unitary;
+        }
+
+        let gram = self.matrix.adjoint() * self.matrix;
+        let (eigenvalues, _) = linalg::hermitian_eigen(&gram);
+        let spectral_norm_sqr = eigenvalues[HILBERT_DIM - 1];
+
+        if spectral_norm_sqr <= 1.0 + tolerance {
+            SequenceKind::Contractive
+        } else {
+            SequenceKind::Expansive
+        }
+    }
+}
+
+/// Guard-Kennzahlen und Ergebnis eines einzelnen Schritts in
+/// [`OperatorSequence::propagate_guarded`].
+#[derive(Debug, Clone, Copy)]
+pub struct GuardedStepReport {
+    /// Betti-Zahl des Graphen (über die Sequenz konstant).
+    pub betti: f64,
+    /// Spektrale Lücke des Graph-Laplacians (über die Sequenz konstant).
+    pub spectral_gap: f64,
+    /// Kohärenzgradient zwischen dem Zustand vor und nach diesem Schritt.
+    pub coherence_gradient: f64,
+    /// Ob dieser Schritt alle Guards erfüllt hat.
+    pub passed: bool,
+}
+
+/// Ergebnis einer guard-überwachten [`OperatorSequence::propagate_guarded`]-Anwendung.
+pub struct GuardedPropagation {
+    /// Zustand vor jedem Schritt und nach dem letzten ausgeführten Schritt
+    /// (Index 0 ist der Anfangszustand).
+    pub states: Vec<QuantumState>,
+    /// Guard-Bericht für jeden tatsächlich ausgeführten Schritt.
+    pub reports: Vec<GuardedStepReport>,
+    /// Index des Operators, bei dem abgebrochen wurde, falls ein Guard
+    /// verletzt wurde.
+    pub aborted_at: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_single_operator_matches_its_matrix() {
+        let scaling = ScalingOperator::uniform(0.3, 1.0);
+        let expected = scaling.matrix();
+
+        let sequence = OperatorSequence::new().push(Box::new(scaling));
+        let composed = sequence.compose();
+
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                assert!((composed.matrix()[(i, j)] - expected[(i, j)]).norm() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compose_order_applies_first_operator_first() {
+        let graph = MetatronGraph::new();
+        let wormhole = WormholeOperator::new(0, 1, 0.2, &graph);
+        let scaling = ScalingOperator::uniform(0.1, 1.0);
+        let direct = scaling.matrix() * wormhole.matrix();
+
+        let sequence = OperatorSequence::new()
+            .push(Box::new(wormhole))
+            .push(Box::new(scaling));
+
+        let composed = *sequence.compose().matrix();
+
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                assert!((composed[(i, j)] - direct[(i, j)]).norm() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_sequence_composes_to_identity() {
+        let sequence = OperatorSequence::new();
+        let composed = sequence.compose();
+        assert_eq!(composed.classify(1e-9), SequenceKind::Unitary);
+    }
+
+    #[test]
+    fn test_damping_only_sequence_is_contractive_not_unitary() {
+        let graph = MetatronGraph::new();
+        let damping = DampingOperator::new(0.5, &graph);
+
+        let sequence = OperatorSequence::new().push(Box::new(damping));
+        let composed = sequence.compose();
+
+        assert_eq!(composed.classify(1e-9), SequenceKind::Contractive);
+    }
+
+    #[test]
+    fn test_propagate_guarded_runs_all_steps_when_guards_hold() {
+        let graph = MetatronGraph::new();
+        let wormhole = WormholeOperator::new(0, 1, 0.1, &graph);
+        let sequence = OperatorSequence::new().push(Box::new(wormhole));
+
+        let guards = TopologicalGuards {
+            min_betti: 0.0,
+            min_spectral_gap: 0.0,
+            max_coherence_gradient: 1.0,
+        };
+
+        let initial = QuantumState::basis_state(0);
+        let result = sequence.propagate_guarded(&initial, &graph, &guards);
+
+        assert_eq!(result.states.len(), 2);
+        assert_eq!(result.reports.len(), 1);
+        assert!(result.aborted_at.is_none());
+        assert!(result.reports[0].passed);
+    }
+
+    #[test]
+    fn test_propagate_guarded_aborts_on_violation() {
+        let graph = MetatronGraph::new();
+        let wormhole = WormholeOperator::new(0, 1, 0.9, &graph);
+        let second = WormholeOperator::new(1, 2, 0.9, &graph);
+        let sequence = OperatorSequence::new()
+            .push(Box::new(wormhole))
+            .push(Box::new(second));
+
+        let guards = TopologicalGuards {
+            min_betti: 0.0,
+            min_spectral_gap: 0.0,
+            max_coherence_gradient: 1e-6,
+        };
+
+        let initial = QuantumState::basis_state(0);
+        let result = sequence.propagate_guarded(&initial, &graph, &guards);
+
+        assert_eq!(result.aborted_at, Some(0));
+        assert_eq!(result.reports.len(), 1);
+        assert!(!result.reports[0].passed);
+    }
+}
@@ -0,0 +1,81 @@
+//! Spektralanalyse für `GuardValidator`
+//!
+//! Leitet die drei von [`crate::operators::GuardValidator::validate`]
+//! benötigten Kennzahlen direkt aus dem Metatron-Graphen und
+//! aufeinanderfolgenden Quantenzuständen ab, statt sie von außen als
+//! Floats entgegenzunehmen:
+//! - die spektrale Lücke λ₂ − λ₁ des Laplacian,
+//! - die nullte Betti-Zahl (Anzahl Zusammenhangskomponenten), geschätzt
+//!   als Multiplizität der nahezu-null Laplacian-Eigenwerte,
+//! - den Kohärenzgradienten zwischen zwei aufeinanderfolgenden Zuständen.
+
+use crate::core::{MetatronGraph, QuantumState};
+use nalgebra::SymmetricEigen;
+
+/// Toleranz, unterhalb derer ein Laplacian-Eigenwert als "praktisch null"
+/// gilt und damit zu einer eigenen Zusammenhangskomponente beiträgt.
+pub const DEFAULT_ZERO_EIGENVALUE_TOLERANCE: f64 = 1e-6;
+
+/// Liefert die aufsteigend sortierten Eigenwerte des Graph-Laplacians.
+///
+/// `SymmetricEigen` garantiert keine Sortierreihenfolge, daher wird hier
+/// explizit sortiert (wie auch in [`crate::operators::hamiltonian`]).
+fn sorted_laplacian_eigenvalues(graph: &MetatronGraph) -> Vec<f64> {
+    let eigen = SymmetricEigen::new(graph.laplacian);
+    let mut eigenvalues: Vec<f64> = eigen.eigenvalues.iter().copied().collect();
+    eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    eigenvalues
+}
+
+/// Berechnet die spektrale Lücke λ₂ − λ₁ des Graph-Laplacians.
+///
+/// Ein kleinster Eigenwert λ₁ ≈ 0 entspricht dabei der trivialen
+/// "konstanten" Mode; die Lücke zum zweitkleinsten Eigenwert misst, wie
+/// gut der Graph zusammenhängt.
+pub fn spectral_gap(graph: &MetatronGraph) -> f64 {
+    let eigenvalues = sorted_laplacian_eigenvalues(graph);
+    if eigenvalues.len() < 2 {
+        return 0.0;
+    }
+    eigenvalues[1] - eigenvalues[0]
+}
+
+/// Schätzt die nullte Betti-Zahl (Anzahl Zusammenhangskomponenten) als
+/// Multiplizität der Laplacian-Eigenwerte unterhalb `tolerance`.
+pub fn betti_number_estimate(graph: &MetatronGraph, tolerance: f64) -> f64 {
+    let eigenvalues = sorted_laplacian_eigenvalues(graph);
+    eigenvalues.iter().filter(|&&lambda| lambda < tolerance).count() as f64
+}
+
+/// Berechnet den Kohärenzgradienten zwischen zwei aufeinanderfolgenden
+/// Quantenzuständen als 1 − Fidelity(vorher, jetzt): je näher die
+/// Zustände beieinander liegen, desto kleiner der Gradient.
+pub fn coherence_gradient(previous: &QuantumState, current: &QuantumState) -> f64 {
+    1.0 - previous.fidelity(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_spectral_gap_nonnegative() {
+        let graph = MetatronGraph::new();
+        assert!(spectral_gap(&graph) >= 0.0);
+    }
+
+    #[test]
+    fn test_betti_number_estimate_at_least_one_for_connected_graph() {
+        let graph = MetatronGraph::new();
+        // Der Metatron-Graph ist zusammenhängend, also sollte zumindest
+        // der triviale Nulleigenwert gezählt werden.
+        assert!(betti_number_estimate(&graph, DEFAULT_ZERO_EIGENVALUE_TOLERANCE) >= 1.0);
+    }
+
+    #[test]
+    fn test_coherence_gradient_zero_for_identical_state() {
+        let state = QuantumState::random();
+        assert_abs_diff_eq!(coherence_gradient(&state, &state), 0.0, epsilon = 1e-10);
+    }
+}
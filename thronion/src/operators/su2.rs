@@ -0,0 +1,163 @@
+//! Haar-verteilte SU(2)-Rotationen nahe der Identität
+//!
+//! Stellt die Pauli-Matrizen σ₁,σ₂,σ₃ sowie
+//! [`random_su2_close_to_unity`] bereit, das echte unitäre 2×2-Faktoren
+//! U = r₀·I + i(r₁σ₁ + r₂σ₂ + r₃σ₃) mit U†U = I erzeugt, statt der
+//! bisherigen reellen `ScalingOperator`-Näherung. [`embed_su2_block`]
+//! hebt eine solche Rotation in eine zufällig gewählte 2D-Unterebene des
+//! HILBERT_DIM-dimensionalen Raums, sodass `NullpointOperator::apply`
+//! echte unitäre Mischung vor der finalen Projektion anwenden kann.
+
+use crate::core::HILBERT_DIM;
+use nalgebra::SMatrix;
+use num_complex::Complex64;
+use rand::Rng;
+
+/// Pauli-Matrix σ₁ = [[0, 1], [1, 0]]
+pub fn pauli_x() -> SMatrix<Complex64, 2, 2> {
+    SMatrix::<Complex64, 2, 2>::new(
+        Complex64::new(0.0, 0.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, 0.0),
+    )
+}
+
+/// Pauli-Matrix σ₂ = [[0, −i], [i, 0]]
+pub fn pauli_y() -> SMatrix<Complex64, 2, 2> {
+    SMatrix::<Complex64, 2, 2>::new(
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.0, -1.0),
+        Complex64::new(0.0, 1.0),
+        Complex64::new(0.0, 0.0),
+    )
+}
+
+/// Pauli-Matrix σ₃ = [[1, 0], [0, −1]]
+pub fn pauli_z() -> SMatrix<Complex64, 2, 2> {
+    SMatrix::<Complex64, 2, 2>::new(
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(-1.0, 0.0),
+    )
+}
+
+/// Zieht eine echte SU(2)-Rotation nahe der Identität:
+/// U = r₀·I + i(r₁σ₁ + r₂σ₂ + r₃σ₃), mit r₁,r₂,r₃ uniform aus
+/// [−spread, spread] und r₀ = √(1 − r₁²−r₂²−r₃²), garantiert U†U = I da
+/// (r₀, r₁, r₂, r₃) ein Einheitsvektor in ℝ⁴ ist (Quaternionen-
+/// Parametrisierung von SU(2)).
+///
+/// # Panics
+/// Wenn `spread` so groß gewählt wird, dass r₁²+r₂²+r₃² > 1 ausfallen
+/// kann (z.B. `spread > 1/√3`), ist r₀ nicht mehr reell definiert; in dem
+/// Fall wird r₀ auf 0 geklemmt (reine Rotation um π).
+pub fn random_su2_close_to_unity<R: Rng + ?Sized>(
+    spread: f64,
+    rng: &mut R,
+) -> SMatrix<Complex64, 2, 2> {
+    let r1 = rng.gen_range(-spread..spread);
+    let r2 = rng.gen_range(-spread..spread);
+    let r3 = rng.gen_range(-spread..spread);
+
+    let sum_sqr = r1 * r1 + r2 * r2 + r3 * r3;
+    let r0 = (1.0 - sum_sqr).max(0.0).sqrt();
+
+    let identity = SMatrix::<Complex64, 2, 2>::identity();
+    let i_unit = Complex64::new(0.0, 1.0);
+
+    identity.scale(Complex64::new(r0, 0.0))
+        + (pauli_x().scale(Complex64::new(r1, 0.0))
+            + pauli_y().scale(Complex64::new(r2, 0.0))
+            + pauli_z().scale(Complex64::new(r3, 0.0)))
+        .scale(i_unit)
+}
+
+/// Hebt eine 2×2-SU(2)-Rotation in eine HILBERT_DIM×HILBERT_DIM-Unitäre
+/// an: Identität überall außer in der von `i` und `j` aufgespannten
+/// 2D-Unterebene, die durch `su2` ersetzt wird.
+///
+/// # Panics
+/// Wenn `i == j` oder einer der Indizes außerhalb `0..HILBERT_DIM` liegt.
+pub fn embed_su2_block(
+    su2: &SMatrix<Complex64, 2, 2>,
+    i: usize,
+    j: usize,
+) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+    assert!(i != j, "embed_su2_block benötigt zwei verschiedene Indizes");
+    assert!(i < HILBERT_DIM && j < HILBERT_DIM, "Indizes außerhalb von HILBERT_DIM");
+
+    let mut embedded = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+
+    embedded[(i, i)] = su2[(0, 0)];
+    embedded[(i, j)] = su2[(0, 1)];
+    embedded[(j, i)] = su2[(1, 0)];
+    embedded[(j, j)] = su2[(1, 1)];
+
+    embedded
+}
+
+/// Zieht eine zufällige, echte HILBERT_DIM×HILBERT_DIM-Unitäre nahe der
+/// Identität: wählt eine zufällige 2D-Unterebene und bettet darin eine
+/// Haar-nahe SU(2)-Rotation ein (siehe [`random_su2_close_to_unity`] und
+/// [`embed_su2_block`]).
+pub fn random_unitary_close_to_unity<R: Rng + ?Sized>(
+    spread: f64,
+    rng: &mut R,
+) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+    let i = rng.gen_range(0..HILBERT_DIM);
+    let mut j = rng.gen_range(0..HILBERT_DIM);
+    while j == i {
+        j = rng.gen_range(0..HILBERT_DIM);
+    }
+
+    let su2 = random_su2_close_to_unity(spread, rng);
+    embed_su2_block(&su2, i, j)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::linalg::is_unitary;
+
+    #[test]
+    fn test_random_su2_close_to_unity_is_unitary() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let u2x2 = random_su2_close_to_unity(0.2, &mut rng);
+
+            // In HILBERT_DIM×2 eingebettet ist is_unitary (HILBERT_DIM-
+            // generisch) nicht direkt anwendbar, daher prüfen wir U†U=I
+            // hier direkt auf der 2×2-Matrix.
+            let product = u2x2.adjoint() * u2x2;
+            let identity = SMatrix::<Complex64, 2, 2>::identity();
+            let diff = (product - identity).iter().map(|c| c.norm_sqr()).sum::<f64>().sqrt();
+            assert!(diff < 1e-10, "U†U should equal I, diff = {}", diff);
+        }
+    }
+
+    #[test]
+    fn test_embed_su2_block_is_unitary() {
+        let mut rng = rand::thread_rng();
+        let su2 = random_su2_close_to_unity(0.2, &mut rng);
+        let embedded = embed_su2_block(&su2, 1, 4);
+        assert!(is_unitary(&embedded, 1e-10));
+    }
+
+    #[test]
+    fn test_random_unitary_close_to_unity_is_unitary() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let unitary = random_unitary_close_to_unity(0.1, &mut rng);
+            assert!(is_unitary(&unitary, 1e-10));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_embed_su2_block_rejects_equal_indices() {
+        let identity = SMatrix::<Complex64, 2, 2>::identity();
+        let _ = embed_su2_block(&identity, 3, 3);
+    }
+}
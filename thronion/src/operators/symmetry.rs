@@ -0,0 +1,337 @@
+//! C6-Symmetrieanalyse des Metatron-Hamiltonians
+//!
+//! Nutzt die sechszählige Rotationssymmetrie der Metatron-Topologie
+//! (Zentrum fix, Hexagon v1..v6 zyklisch, Cube v7..v12 zyklisch), um den
+//! Hamilton-Operator in Impuls-("Bloch")-Sektoren k = 2πn/6 (n=0..5) zu
+//! block-diagonalisieren. Analog zum Bloch-Theorem eines periodischen
+//! Gitters liefert dies eine Dispersionsrelation E(k) statt des reinen
+//! Realraum-Spektrums.
+
+use crate::core::HILBERT_DIM;
+use crate::operators::HamiltonOperator;
+use nalgebra::{DMatrix, SMatrix, SVector};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// Anzahl der Impuls-Sektoren der C6-Symmetriegruppe (n = 0..5)
+pub const NUM_MOMENTUM_SECTORS: usize = 6;
+
+/// Konstruiert die 13×13 Permutationsmatrix `T` der C6-Rotation:
+/// Zentrum invariant, Hexagon v1..v6 um einen Schritt zyklisch,
+/// Cube v7..v12 um einen Schritt zyklisch.
+pub fn c6_permutation_matrix() -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+    let mut t = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+
+    // Zentrum (v0) ist Fixpunkt der Rotation
+    t[(0, 0)] = Complex64::new(1.0, 0.0);
+
+    // Hexagon-Ring v1..v6: vi -> v(i+1), v6 -> v1
+    for i in 1..=6 {
+        let next = if i == 6 { 1 } else { i + 1 };
+        t[(next, i)] = Complex64::new(1.0, 0.0);
+    }
+
+    // Cube-Ring v7..v12: vi -> v(i+1), v12 -> v7
+    for i in 7..=12 {
+        let next = if i == 12 { 7 } else { i + 1 };
+        t[(next, i)] = Complex64::new(1.0, 0.0);
+    }
+
+    t
+}
+
+/// Berechnet den Projektor auf den Impuls-Sektor `k = 2πn/6`:
+///
+/// P_k = (1/6) Σₘ e^{-ikm} Tᵐ
+pub fn momentum_projector(n: usize) -> SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM> {
+    assert!(n < NUM_MOMENTUM_SECTORS, "n muss in 0..6 liegen");
+
+    let t = c6_permutation_matrix();
+    let k = crystal_momentum(n);
+
+    let mut projector = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+    let mut t_power = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+
+    for m in 0..NUM_MOMENTUM_SECTORS {
+        let phase = Complex64::from_polar(1.0, -k * m as f64);
+        projector += t_power * phase;
+        t_power *= t;
+    }
+
+    projector / Complex64::new(NUM_MOMENTUM_SECTORS as f64, 0.0)
+}
+
+/// Kristallimpuls k = 2πn/6 des n-ten Sektors
+pub fn crystal_momentum(n: usize) -> f64 {
+    2.0 * PI * (n as f64) / (NUM_MOMENTUM_SECTORS as f64)
+}
+
+/// Eine einzelne Impuls-Sektor-Auswertung: definiertes k, die darin
+/// enthaltenen Energien und die zugehörigen Eigenzustände im vollen
+/// 13-dimensionalen Hilbertraum.
+#[derive(Debug, Clone)]
+pub struct MomentumSector {
+    /// Sektorindex n (k = 2πn/6)
+    pub n: usize,
+    /// Kristallimpuls k
+    pub k: f64,
+    /// Eigenenergien innerhalb dieses Sektors (sortiert)
+    pub energies: Vec<f64>,
+    /// Eigenzustände im vollen Hilbertraum, zurücktransformiert aus dem Sektor
+    pub eigenstates: Vec<SVector<Complex64, HILBERT_DIM>>,
+}
+
+/// Vollständige Bloch-artige Dispersionsrelation E(k), aufgeteilt in die
+/// sechs Impuls-Sektoren der C6-Symmetrie.
+#[derive(Debug, Clone)]
+pub struct BlochSpectrum {
+    /// Sektoren n = 0..5, mit k = 2πn/6
+    pub sectors: Vec<MomentumSector>,
+}
+
+impl BlochSpectrum {
+    /// Gibt alle (k, E)-Paare zurück, sortiert nach k und innerhalb eines
+    /// Sektors nach Energie.
+    pub fn dispersion(&self) -> Vec<(f64, f64)> {
+        self.sectors
+            .iter()
+            .flat_map(|sector| sector.energies.iter().map(move |&e| (sector.k, e)))
+            .collect()
+    }
+
+    /// Spektrale Lücke innerhalb eines Sektors (None falls < 2 Energien)
+    pub fn sector_gap(&self, n: usize) -> Option<f64> {
+        let sector = self.sectors.iter().find(|s| s.n == n)?;
+        if sector.energies.len() < 2 {
+            return None;
+        }
+        Some(sector.energies[1] - sector.energies[0])
+    }
+
+    /// Globale Grundzustandsenergie über alle Sektoren
+    pub fn ground_state_energy(&self) -> f64 {
+        self.sectors
+            .iter()
+            .flat_map(|s| s.energies.iter().copied())
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// Extrahiert eine orthonormale Basis des Bildraums eines Projektors, indem
+/// er auf die Standardbasis angewendet und die Resultate per modifiziertem
+/// Gram-Schmidt orthonormalisiert werden (Vektoren mit Norm ≈ 0 entfallen).
+fn projector_range_basis(
+    projector: &SMatrix<Complex64, HILBERT_DIM, HILBERT_DIM>,
+    tol: f64,
+) -> Vec<SVector<Complex64, HILBERT_DIM>> {
+    let mut basis: Vec<SVector<Complex64, HILBERT_DIM>> = Vec::new();
+
+    for i in 0..HILBERT_DIM {
+        let mut candidate = projector.column(i).clone_owned();
+
+        for existing in &basis {
+            let overlap: Complex64 = existing.dotc(&candidate);
+            candidate -= *existing * overlap;
+        }
+
+        let norm = candidate.norm();
+        if norm > tol {
+            basis.push(candidate.scale(1.0 / norm));
+        }
+    }
+
+    basis
+}
+
+/// Diagonalisiert eine kleine komplex-hermitesche Matrix via reeller
+/// Einbettung H = A + iB -> M = [[A, -B], [B, A]] und `SymmetricEigen`.
+/// Liefert aufsteigend sortierte (reelle) Eigenwerte und zugehörige
+/// komplexe Eigenvektoren.
+fn small_hermitian_eigen(matrix: &DMatrix<Complex64>) -> (Vec<f64>, Vec<nalgebra::DVector<Complex64>>) {
+    use nalgebra::SymmetricEigen;
+
+    let n = matrix.nrows();
+    let mut embedded = DMatrix::<f64>::zeros(2 * n, 2 * n);
+
+    for i in 0..n {
+        for j in 0..n {
+            let c = matrix[(i, j)];
+            embedded[(i, j)] = c.re;
+            embedded[(i, n + j)] = -c.im;
+            embedded[(n + i, j)] = c.im;
+            embedded[(n + i, n + j)] = c.re;
+        }
+    }
+
+    let eigen = SymmetricEigen::new(embedded);
+
+    // Jeder echte Eigenwert erscheint doppelt degeneriert, und die
+    // Entartung bildet nach dem Sortieren stets benachbarte Paare (siehe
+    // `utils::linalg::hermitian_eigen`); wir behalten daher pro Paar nur
+    // den ersten (kleineren Spaltenindex) Eintrag.
+    let mut order: Vec<usize> = (0..2 * n).collect();
+    order.sort_by(|&a, &b| {
+        eigen.eigenvalues[a]
+            .partial_cmp(&eigen.eigenvalues[b])
+            .unwrap()
+    });
+
+    let mut energies = Vec::with_capacity(n);
+    let mut vectors = Vec::with_capacity(n);
+
+    for out_col in 0..n {
+        let idx = order[2 * out_col];
+        let value = eigen.eigenvalues[idx];
+
+        let col = eigen.eigenvectors.column(idx);
+        let re = col.rows(0, n).clone_owned();
+        let im = col.rows(n, n).clone_owned();
+
+        let mut complex_vec = nalgebra::DVector::<Complex64>::zeros(n);
+        for k in 0..n {
+            complex_vec[k] = Complex64::new(re[k], im[k]);
+        }
+        let norm = complex_vec.norm();
+        if norm > 1e-12 {
+            complex_vec /= Complex64::new(norm, 0.0);
+        }
+
+        energies.push(value);
+        vectors.push(complex_vec);
+    }
+
+    (energies, vectors)
+}
+
+/// Berechnet die Bloch-artige Dispersionsrelation E(k) eines
+/// C6-symmetrischen Hamilton-Operators.
+///
+/// Voraussetzung: `[H, T] = 0`, z.B. für uniforme oder C6-invariante
+/// lokale Energien. Ist diese Bedingung nur näherungsweise erfüllt, wird
+/// trotzdem eine sinnvolle Näherung zurückgegeben, da die Projektion und
+/// Block-Diagonalisierung auch für fast-invariante H wohldefiniert bleibt.
+pub fn bloch_spectrum(hamiltonian: &HamiltonOperator) -> BlochSpectrum {
+    let mut sectors = Vec::with_capacity(NUM_MOMENTUM_SECTORS);
+
+    for n in 0..NUM_MOMENTUM_SECTORS {
+        let projector = momentum_projector(n);
+        let basis = projector_range_basis(&projector, 1e-8);
+        let dim = basis.len();
+
+        if dim == 0 {
+            sectors.push(MomentumSector {
+                n,
+                k: crystal_momentum(n),
+                energies: Vec::new(),
+                eigenstates: Vec::new(),
+            });
+            continue;
+        }
+
+        // Projiziere H in den Sektor: H_k[a,b] = <b_a| H |b_b>
+        let mut h_sector = DMatrix::<Complex64>::zeros(dim, dim);
+        for a in 0..dim {
+            let h_ba = hamiltonian.matrix * basis[a];
+            for b in 0..dim {
+                h_sector[(b, a)] = basis[b].dotc(&h_ba);
+            }
+        }
+
+        let (energies, sector_vectors) = small_hermitian_eigen(&h_sector);
+
+        let eigenstates: Vec<SVector<Complex64, HILBERT_DIM>> = sector_vectors
+            .iter()
+            .map(|v| {
+                let mut full = SVector::<Complex64, HILBERT_DIM>::zeros();
+                for (a, coeff) in v.iter().enumerate() {
+                    full += basis[a] * *coeff;
+                }
+                full
+            })
+            .collect();
+
+        sectors.push(MomentumSector {
+            n,
+            k: crystal_momentum(n),
+            energies,
+            eigenstates,
+        });
+    }
+
+    BlochSpectrum { sectors }
+}
+
+impl HamiltonOperator {
+    /// Berechnet die Bloch-artige Dispersionsrelation E(k) durch
+    /// Block-Diagonalisierung entlang der C6-Symmetriesektoren des
+    /// Metatron-Graphen. Siehe [`bloch_spectrum`].
+    pub fn bloch_spectrum(&self) -> BlochSpectrum {
+        bloch_spectrum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::MetatronGraph;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_c6_permutation_is_order_six() {
+        let t = c6_permutation_matrix();
+        let mut power = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+        for _ in 0..NUM_MOMENTUM_SECTORS {
+            power *= t;
+        }
+        let identity = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                assert_abs_diff_eq!(power[(i, j)].re, identity[(i, j)].re, epsilon = 1e-8);
+                assert_abs_diff_eq!(power[(i, j)].im, identity[(i, j)].im, epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_projectors_sum_to_identity() {
+        let mut sum = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::zeros();
+        for n in 0..NUM_MOMENTUM_SECTORS {
+            sum += momentum_projector(n);
+        }
+        let identity = SMatrix::<Complex64, HILBERT_DIM, HILBERT_DIM>::identity();
+        for i in 0..HILBERT_DIM {
+            for j in 0..HILBERT_DIM {
+                assert_abs_diff_eq!(sum[(i, j)].re, identity[(i, j)].re, epsilon = 1e-6);
+                assert_abs_diff_eq!(sum[(i, j)].im, identity[(i, j)].im, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sector_dimensions_sum_to_thirteen() {
+        let graph = MetatronGraph::new();
+        let ham = HamiltonOperator::uniform(1.0, 0.0, &graph);
+        let spectrum = ham.bloch_spectrum();
+
+        let total: usize = spectrum.sectors.iter().map(|s| s.energies.len()).sum();
+        assert_eq!(total, HILBERT_DIM);
+    }
+
+    #[test]
+    fn test_uniform_hamiltonian_matches_real_space_spectrum() {
+        let graph = MetatronGraph::new();
+        let ham = HamiltonOperator::uniform(1.0, 0.0, &graph);
+
+        let bloch = ham.bloch_spectrum();
+        let mut bloch_energies = bloch.dispersion().into_iter().map(|(_, e)| e).collect::<Vec<_>>();
+        bloch_energies.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let real_space = ham.eigenspectrum();
+        let mut real_energies = real_space.energies.to_vec();
+        real_energies.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        for (a, b) in bloch_energies.iter().zip(real_energies.iter()) {
+            assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
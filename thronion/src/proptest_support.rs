@@ -0,0 +1,82 @@
+//! Gemeinsame `proptest`-Strategien für Operator-Invarianten
+//!
+//! Stellt [`arb_quantum_state`] (zufällige, normierte `QuantumState`s) und
+//! [`arb_settings`] (zufällige Konfigurationen) bereit, damit
+//! Eigenschaftstests in mehreren Operator-Modulen (`nullpoint`, `delta`,
+//! ...) fehlschlagende Gegenbeispiele schrumpfen können, statt sich auf
+//! einen einzelnen fixen RNG-Seed zu verlassen.
+//!
+//! Hinweis: Der ursprünglich angefragte Typ `OphanionSettings` existiert
+//! in diesem Baum nicht (mehr) — [`arb_settings`] bedient stattdessen
+//! [`crate::delta::QRIKParams`], die tatsächlich existierende
+//! Parameterstruktur, die `DeltaKernel` entgegennimmt. Ebenso existiert
+//! `DeltaKernel::optimize_step` nicht (das zugehörige
+//! `delta::optimizer`-Modul ist im Quellbaum nicht vorhanden); die
+//! Eigenschaftstests in `delta::kernel` prüfen daher die nächstliegende
+//! real existierende Operation, `DeltaKernel::evolve`.
+//! Cfg(test)-only, da `proptest` nur als Dev-Dependency vorgesehen ist.
+
+#![cfg(test)]
+
+use crate::core::{QuantumState, HILBERT_DIM};
+use crate::delta::QRIKParams;
+use crate::utils::linalg::normalize_vector;
+use nalgebra::SVector;
+use num_complex::Complex64;
+use proptest::prelude::*;
+
+/// Zufällige, normierte `QuantumState`s über den vollen HILBERT_DIM-
+/// dimensionalen komplexen Raum: zieht 2·HILBERT_DIM reelle Komponenten
+/// in [−1, 1], baut daraus einen komplexen Vektor und normiert ihn via
+/// [`normalize_vector`].
+pub(crate) fn arb_quantum_state() -> impl Strategy<Value = QuantumState> {
+    proptest::collection::vec(-1.0f64..1.0f64, 2 * HILBERT_DIM).prop_map(|components| {
+        let mut amplitudes = SVector::<Complex64, HILBERT_DIM>::zeros();
+        for i in 0..HILBERT_DIM {
+            amplitudes[i] = Complex64::new(components[2 * i], components[2 * i + 1]);
+        }
+        if amplitudes.norm() < 1e-10 {
+            amplitudes[0] = Complex64::new(1.0, 0.0);
+        }
+        QuantumState::new(normalize_vector(&amplitudes))
+    })
+}
+
+/// Zufällige `QRIKParams` innerhalb plausibler Wertebereiche (siehe
+/// `QRIKParams::default()` als Größenordnungsreferenz).
+pub(crate) fn arb_settings() -> impl Strategy<Value = QRIKParams> {
+    (
+        0.01f64..5.0,
+        0.01f64..5.0,
+        0.01f64..5.0,
+        16usize..1024,
+        0.001f64..1.0,
+        0.01f64..1.0,
+        0.0f64..5.0,
+        0.0f64..2.0,
+        0usize..HILBERT_DIM,
+    )
+        .prop_map(
+            |(
+                hopping_strength,
+                base_frequency,
+                coupling_strength,
+                spectrum_size,
+                learning_rate,
+                epsilon_res,
+                lambda_flood,
+                dissipation_rate,
+                safe_node,
+            )| QRIKParams {
+                hopping_strength,
+                base_frequency,
+                coupling_strength,
+                spectrum_size,
+                learning_rate,
+                epsilon_res,
+                lambda_flood,
+                dissipation_rate,
+                safe_node,
+            },
+        )
+}
@@ -0,0 +1,974 @@
+//! Resonant Absorber Layer (RAL)
+//!
+//! Implementiert adaptive spektrale Filterung für DDoS-Resistenz
+//!
+//! Rᵢ(t) = σ(max_k aₖ(t)·cos(sₖ(t), S(T_in)) - θᵢ)
+//!
+//! Jeder Knoten hält statt eines einzelnen geglätteten Feldes ein per
+//! Conditional-Gradient (Frank-Wolfe) gefittetes, dünnbesetztes
+//! Wörterbuch `{(sₖ, aₖ)}` gelernter legitimer Spektren (siehe
+//! [`SpectralDictionary`]).
+//!
+//! Hinter dem `parallel`-Feature verarbeiten
+//! [`ResonantAbsorber::process_batch`] und
+//! [`ResonantAbsorber::route_batch`] Paket-Batches über Rayon parallel.
+
+use crate::core::NUM_NODES;
+use crate::resonance::spectrum::{ln_gamma, SpectralFingerprint};
+use serde::{Deserialize, Serialize};
+
+/// Beta(α, β)-Posterior über den rohen (schwellwertfreien) Resonanz-Score
+/// `σ(⟨Fᵢ, S⟩)` legitimer Pakete an einem Knoten.
+///
+/// Startet als uninformativer Prior Beta(1, 1) (Gleichverteilung über [0,1])
+/// und wird über [`ResonantAbsorber::observe_legitimate`] aktualisiert.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BetaPosterior {
+    /// Pseudo-Erfolgszähler α
+    pub alpha: f64,
+    /// Pseudo-Fehlschlagzähler β
+    pub beta: f64,
+}
+
+impl BetaPosterior {
+    /// Uninformativer Prior Beta(1, 1)
+    fn uniform() -> Self {
+        Self { alpha: 1.0, beta: 1.0 }
+    }
+
+    /// Aktualisiert den Posterior um eine kontinuierliche Beobachtung
+    /// `score ∈ [0, 1]` als fraktionalen Pseudo-Erfolg/-Fehlschlag:
+    /// `α += score`, `β += 1 - score`.
+    fn update(&mut self, score: f64) {
+        self.alpha += score;
+        self.beta += 1.0 - score;
+    }
+}
+
+/// Regularisierte unvollständige Beta-Funktion `I_x(a, b)`, berechnet über
+/// die Kettenbruchentwicklung nach Numerical Recipes (`betacf`).
+fn regularized_incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let ln_beta = ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b);
+    let front = (a * x.ln() + b * (1.0 - x).ln() - ln_beta).exp();
+
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - front * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+/// Lentz'sche Kettenbruchauswertung der unvollständigen Beta-Funktion
+/// (Numerical Recipes `betacf`).
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 200;
+    const EPSILON: f64 = 1e-12;
+    const TINY: f64 = 1e-300;
+
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..=MAX_ITERATIONS {
+        let m_f = m as f64;
+        let m2 = 2.0 * m_f;
+
+        let aa_even = m_f * (b - m_f) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa_even * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa_even / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+
+        let aa_odd = -(a + m_f) * (qab + m_f) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa_odd * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa_odd / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    h
+}
+
+/// Invertiert `I_x(a, b) = target` per Bisektion auf `x ∈ [0, 1]`.
+fn inverse_regularized_incomplete_beta(target: f64, a: f64, b: f64) -> f64 {
+    let target = target.clamp(0.0, 1.0);
+
+    let mut lower = 0.0_f64;
+    let mut upper = 1.0_f64;
+
+    for _ in 0..100 {
+        let mid = 0.5 * (lower + upper);
+        if regularized_incomplete_beta(mid, a, b) < target {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+    }
+
+    0.5 * (lower + upper)
+}
+
+/// Ein gelerntes Spektral-Atom mit nichtnegativem Konvexgewicht im
+/// Frank-Wolfe-Wörterbuch eines Knotens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpectralAtom {
+    /// Leistungsspektrum `s_k` des Atoms
+    pub spectrum: Vec<f64>,
+    /// Konvexgewicht `a_k`
+    pub weight: f64,
+}
+
+/// Dünnbesetztes Wörterbuch gelernter legitimer Spektren an einem Knoten,
+/// gefittet per Conditional-Gradient (Frank-Wolfe).
+///
+/// Ersetzt ein einzelnes exponentiell geglättetes Feld Fᵢ(t) durch eine
+/// konvexe Kombination `Σ_k a_k·s_k` tatsächlich beobachteter legitimer
+/// Spektren, sodass mehrere normale Verkehrsmodi gleichzeitig dargestellt
+/// werden können, statt zu einem einzigen Mittelwert zu verschwimmen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpectralDictionary {
+    /// Aktive Atome (Träger der Konvexkombination)
+    pub atoms: Vec<SpectralAtom>,
+    /// Iterationszähler `t` für die Frank-Wolfe-Schrittweite `2/(t+2)`
+    iteration: usize,
+}
+
+impl SpectralDictionary {
+    /// Leeres Wörterbuch ohne Atome
+    fn new() -> Self {
+        Self {
+            atoms: Vec::new(),
+            iteration: 0,
+        }
+    }
+
+    /// Gewichtete Rekonstruktion `Σ_k a_k·s_k`; Nullvektor der Länge
+    /// `spectrum_size`, solange das Wörterbuch leer ist.
+    fn reconstruct(&self, spectrum_size: usize) -> Vec<f64> {
+        let mut reconstruction = vec![0.0; spectrum_size];
+        for atom in &self.atoms {
+            for (r, s) in reconstruction.iter_mut().zip(atom.spectrum.iter()) {
+                *r += atom.weight * s;
+            }
+        }
+        reconstruction
+    }
+
+    /// Conditional-Gradient-Schritt (Frank-Wolfe): bildet das Residuum
+    /// zwischen der aktuellen Rekonstruktion und dem komponentenweisen
+    /// Mittelwert von `batch`, wählt das mit diesem Residuum am stärksten
+    /// korrelierte Atom aus `batch`, nimmt einen Konvexkombinationsschritt
+    /// der Schrittweite `2/(t+2)` dorthin und verwirft anschließend Atome
+    /// mit Gewicht unterhalb `prune_threshold`.
+    ///
+    /// Für eine Batchgröße von 1 -- der übliche Online-Fall in
+    /// [`ResonantAbsorber::learn_legitimate_pattern`] -- reduziert sich
+    /// dies darauf, das neu beobachtete Spektrum selbst als einziges
+    /// Kandidatenatom aufzunehmen bzw. zu verstärken.
+    fn fit_batch(&mut self, batch: &[Vec<f64>], prune_threshold: f64) {
+        let Some(spectrum_size) = batch.first().map(Vec::len) else {
+            return;
+        };
+
+        let mut target = vec![0.0; spectrum_size];
+        for sample in batch {
+            for (t, &v) in target.iter_mut().zip(sample.iter()) {
+                *t += v;
+            }
+        }
+        for t in target.iter_mut() {
+            *t /= batch.len() as f64;
+        }
+
+        let reconstruction = self.reconstruct(spectrum_size);
+        let residual: Vec<f64> = target
+            .iter()
+            .zip(reconstruction.iter())
+            .map(|(t, r)| t - r)
+            .collect();
+
+        let best_atom = batch
+            .iter()
+            .max_by(|a, b| dot(&residual, a).partial_cmp(&dot(&residual, b)).unwrap())
+            .expect("batch wurde bereits auf Nichtleere geprüft")
+            .clone();
+
+        let gamma = 2.0 / (self.iteration as f64 + 2.0);
+        for atom in &mut self.atoms {
+            atom.weight *= 1.0 - gamma;
+        }
+
+        if let Some(existing) = self.atoms.iter_mut().find(|atom| atom.spectrum == best_atom) {
+            existing.weight += gamma;
+        } else {
+            self.atoms.push(SpectralAtom {
+                spectrum: best_atom,
+                weight: gamma,
+            });
+        }
+
+        self.atoms.retain(|atom| atom.weight >= prune_threshold);
+        self.iteration += 1;
+    }
+
+    /// Max-Kernel-Ähnlichkeit (gewichtete Kosinus-Ähnlichkeit) des
+    /// Paket-Spektrums zu den gelernten Atomen; `0.0`, solange das
+    /// Wörterbuch leer ist.
+    fn max_similarity(&self, spectrum: &[f64]) -> f64 {
+        self.atoms
+            .iter()
+            .map(|atom| atom.weight * cosine_similarity(&atom.spectrum, spectrum))
+            .fold(0.0_f64, f64::max)
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    let norm_a = dot(a, a).sqrt();
+    let norm_b = dot(b, b).sqrt();
+    let denom = norm_a * norm_b;
+
+    if denom < 1e-12 {
+        0.0
+    } else {
+        dot(a, b) / denom
+    }
+}
+
+/// Resonant Absorber Layer
+///
+/// Filtert eingehende Signale basierend auf spektraler Resonanz
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResonantAbsorber {
+    /// Pro-Knoten Frank-Wolfe-Wörterbuch gelernter legitimer Spektren
+    pub dictionaries: [SpectralDictionary; NUM_NODES],
+    /// Adaptive Schwellwerte θᵢ
+    pub thresholds: [f64; NUM_NODES],
+    /// Lernrate für Schwellwert-Anpassung
+    pub learning_rate: f64,
+    /// Spektral-Fingerprint-Größe
+    pub spectrum_size: usize,
+    /// Absorptions-Statistiken
+    pub stats: AbsorberStats,
+    /// Pro-Knoten Beta-Posterior über den rohen legitimen Resonanz-Score,
+    /// gepflegt von [`Self::observe_legitimate`] und ausgewertet von
+    /// [`Self::set_target_false_positive`]
+    pub score_posteriors: [BetaPosterior; NUM_NODES],
+}
+
+impl ResonantAbsorber {
+    /// Erstellt neuen Resonant Absorber
+    ///
+    /// # Arguments
+    /// * `spectrum_size` - Größe des Spektrums (FFT-Bins)
+    /// * `learning_rate` - Lernrate für adaptive Schwellwerte
+    pub fn new(spectrum_size: usize, learning_rate: f64) -> Self {
+        let dictionaries: [SpectralDictionary; NUM_NODES] =
+            std::array::from_fn(|_| SpectralDictionary::new());
+        let thresholds = [0.5; NUM_NODES]; // Initial neutral
+
+        Self {
+            dictionaries,
+            thresholds,
+            learning_rate,
+            spectrum_size,
+            stats: AbsorberStats::default(),
+            score_posteriors: [BetaPosterior::uniform(); NUM_NODES],
+        }
+    }
+
+    /// Initialisiert jedes Knoten-Wörterbuch mit einem einzelnen
+    /// zufälligen Atom voller Konvexgewicht
+    pub fn initialize_random_fields(&mut self) {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let spectrum_size = self.spectrum_size;
+
+        for dictionary in &mut self.dictionaries {
+            let spectrum: Vec<f64> = (0..spectrum_size).map(|_| rng.gen_range(-1.0..1.0)).collect();
+            dictionary.atoms = vec![SpectralAtom { spectrum, weight: 1.0 }];
+            dictionary.iteration = 0;
+        }
+    }
+
+    /// Berechnet Resonanz-Score für Paket an Knoten i
+    ///
+    /// Rᵢ = σ(max_k aₖ·cos(sₖ, S) - θᵢ)
+    ///
+    /// # Arguments
+    /// * `packet` - Eingehende Daten
+    /// * `node` - Knoten-Index
+    pub fn resonance_score(&self, packet: &[u8], node: usize) -> f64 {
+        assert!(node < NUM_NODES);
+
+        // Berechne Spektrum des Pakets
+        let spectrum = SpectralFingerprint::compute(packet, self.spectrum_size);
+
+        // Max-Kernel-Ähnlichkeit zu den gelernten Wörterbuch-Atomen
+        let similarity = self.dictionaries[node].max_similarity(&spectrum.power_spectrum);
+
+        // Sigmoid-Aktivierung
+        self.sigmoid(similarity - self.thresholds[node])
+    }
+
+    /// Sigmoid-Funktion σ(x) = 1/(1 + e^(-x))
+    fn sigmoid(&self, x: f64) -> f64 {
+        1.0 / (1.0 + (-x).exp())
+    }
+
+    /// Routet ein Paket anhand einer "quiet softmax" über alle Knotenlogits.
+    ///
+    /// Berechnet für jeden Knoten `i` den Logit
+    /// `z_i = max_k aₖ·cos(sₖ, S) - θᵢ` (Max-Kernel-Ähnlichkeit zum
+    /// Knoten-Wörterbuch) und bildet daraus eine Softmax-Verteilung mit
+    /// einer impliziten, nicht
+    /// explizit aufgeführten "Absorb"-Kategorie:
+    /// `p_i = exp(z_i - m) / (exp(-m) + Σⱼ exp(z_j - m))`, `m = max_i z_i`.
+    /// Die fehlende Masse `p_absorb = 1 - Σᵢ p_i` ist die Wahrscheinlichkeit,
+    /// dass kein Knoten resoniert. Anders als `should_absorb`/`resonance_score`
+    /// (die jeden Knoten unabhängig mit einem Sigmoid bewerten) entscheidet
+    /// dies global über alle Knoten: weitergeleitet wird an `argmax_i p_i`,
+    /// aber nur solange `p_absorb` unter `absorb_gate` bleibt; sind alle
+    /// Logits stark negativ, kollabiert die Verteilung zur Absorb-Kategorie
+    /// statt eine willkürliche Gleichverteilung über die Knoten zu erzwingen.
+    ///
+    /// # Rückgabe
+    /// `(Some(node), p)` beim Weiterleiten an `node`, sonst `(None, p)` beim
+    /// Absorbieren, zusammen mit der vollen Verteilung `p` über alle Knoten.
+    pub fn route_packet(&self, packet: &[u8], absorb_gate: f64) -> (Option<usize>, [f64; NUM_NODES]) {
+        let spectrum = SpectralFingerprint::compute(packet, self.spectrum_size);
+
+        let logits: [f64; NUM_NODES] = std::array::from_fn(|node| {
+            let similarity = self.dictionaries[node].max_similarity(&spectrum.power_spectrum);
+            similarity - self.thresholds[node]
+        });
+
+        let m = logits.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let shifted: [f64; NUM_NODES] = std::array::from_fn(|i| (logits[i] - m).exp());
+        let none_term = (-m).exp();
+        let denom = none_term + shifted.iter().sum::<f64>();
+
+        let probabilities: [f64; NUM_NODES] = std::array::from_fn(|i| shifted[i] / denom);
+        let p_absorb = 1.0 - probabilities.iter().sum::<f64>();
+
+        let best_node = probabilities
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .map(|(index, _)| index);
+
+        let routed = if p_absorb < absorb_gate { best_node } else { None };
+
+        (routed, probabilities)
+    }
+
+    /// Entscheidet ob Paket absorbiert werden soll
+    ///
+    /// Absorb ⟺ R < ε_res
+    pub fn should_absorb(&self, packet: &[u8], node: usize, epsilon_res: f64) -> bool {
+        let score = self.resonance_score(packet, node);
+        score < epsilon_res
+    }
+
+    /// Verarbeitet Paket und entscheidet über Absorption
+    ///
+    /// Returns: (absorbed, score)
+    pub fn process_packet(&mut self, packet: &[u8], node: usize, epsilon_res: f64) -> (bool, f64) {
+        assert!(node < NUM_NODES);
+        let score = self.resonance_score(packet, node);
+        let absorbed = score < epsilon_res;
+
+        if absorbed {
+            self.stats.packets_absorbed += 1;
+            self.stats.node_packets_absorbed[node] += 1;
+            // Update Schwellwert basierend auf absorbierter Energie
+            self.update_threshold(node, packet.len() as f64);
+        } else {
+            self.stats.packets_forwarded += 1;
+        }
+
+        self.stats.total_packets += 1;
+        self.stats.node_total_packets[node] += 1;
+
+        (absorbed, score)
+    }
+
+    /// Verarbeitet einen Paket-Batch parallel an einem Knoten (erfordert
+    /// das `parallel`-Feature)
+    ///
+    /// Spektrum-Berechnung und Resonanz-Score laufen über einen Rayon
+    /// Parallel-Iterator; Absorptions-/Weiterleitungszähler und
+    /// absorbierte Energie werden über `fold`/`reduce` als Pro-Thread-
+    /// Lokalzustand akkumuliert und erst danach in einem einzigen
+    /// sequenziellen Schritt in `self.stats` gemerged und in
+    /// [`Self::update_threshold`] verrechnet -- so bleiben sowohl die
+    /// Rückgabereihenfolge als auch das Schwellwert-Update deterministisch,
+    /// statt unter einem Datenrennen zwischen Threads zu leiden.
+    #[cfg(feature = "parallel")]
+    pub fn process_batch(
+        &mut self,
+        packets: &[&[u8]],
+        node: usize,
+        epsilon_res: f64,
+    ) -> Vec<(bool, f64)> {
+        assert!(node < NUM_NODES);
+        use rayon::prelude::*;
+
+        #[derive(Default, Clone)]
+        struct BatchAccumulator {
+            results: Vec<(bool, f64)>,
+            absorbed: usize,
+            forwarded: usize,
+            absorbed_energy: f64,
+        }
+
+        let accumulator = packets
+            .par_iter()
+            .map(|packet| {
+                let score = self.resonance_score(packet, node);
+                (packet.len(), score < epsilon_res, score)
+            })
+            .fold(BatchAccumulator::default, |mut acc, (len, absorbed, score)| {
+                if absorbed {
+                    acc.absorbed += 1;
+                    acc.absorbed_energy += len as f64;
+                } else {
+                    acc.forwarded += 1;
+                }
+                acc.results.push((absorbed, score));
+                acc
+            })
+            .reduce(BatchAccumulator::default, |mut a, mut b| {
+                a.results.append(&mut b.results);
+                a.absorbed += b.absorbed;
+                a.forwarded += b.forwarded;
+                a.absorbed_energy += b.absorbed_energy;
+                a
+            });
+
+        self.stats.packets_absorbed += accumulator.absorbed;
+        self.stats.packets_forwarded += accumulator.forwarded;
+        self.stats.node_packets_absorbed[node] += accumulator.absorbed;
+        self.stats.total_packets += packets.len();
+        self.stats.node_total_packets[node] += packets.len();
+
+        if accumulator.absorbed > 0 {
+            self.update_threshold(node, accumulator.absorbed_energy);
+        }
+
+        accumulator.results
+    }
+
+    /// Routet einen Paket-Batch parallel über [`Self::route_packet`]
+    /// (erfordert das `parallel`-Feature)
+    ///
+    /// Liest nur, verändert `self` nicht -- daher ohne Reduce-Schritt ein
+    /// reiner Rayon Parallel-Map, dessen Ergebnisreihenfolge der
+    /// Paketreihenfolge entspricht.
+    #[cfg(feature = "parallel")]
+    pub fn route_batch(
+        &self,
+        packets: &[&[u8]],
+        absorb_gate: f64,
+    ) -> Vec<(Option<usize>, [f64; NUM_NODES])> {
+        use rayon::prelude::*;
+        packets
+            .par_iter()
+            .map(|packet| self.route_packet(packet, absorb_gate))
+            .collect()
+    }
+
+    /// Update adaptiver Schwellwert
+    ///
+    /// dθᵢ/dt = -λ ∂E_abs/∂θᵢ
+    pub fn update_threshold(&mut self, node: usize, absorbed_energy: f64) {
+        assert!(node < NUM_NODES);
+
+        // Gradient descent: Verringere Schwellwert um mehr zu absorbieren
+        self.thresholds[node] -= self.learning_rate * absorbed_energy / 1000.0;
+
+        // Clamp to reasonable range
+        self.thresholds[node] = self.thresholds[node].clamp(-10.0, 10.0);
+    }
+
+    /// Fittet das Knoten-Wörterbuch um einen weiteren Frank-Wolfe-Schritt
+    /// auf dieses legitime Paket
+    ///
+    /// Lernt normale Verkehrsmuster, ohne -- anders als ein einzelnes
+    /// exponentiell geglättetes Feld -- mehrere gleichzeitig auftretende
+    /// normale Spektren zu einem Mittelwert zu verwischen.
+    ///
+    /// `prune_threshold` verwirft nach diesem Schritt Atome, deren
+    /// Konvexgewicht darunter liegt (ersetzt die frühere EMA-Lernrate).
+    pub fn learn_legitimate_pattern(&mut self, packet: &[u8], node: usize, prune_threshold: f64) {
+        assert!(node < NUM_NODES);
+
+        let spectrum = SpectralFingerprint::compute(packet, self.spectrum_size);
+        self.dictionaries[node].fit_batch(&[spectrum.power_spectrum.clone()], prune_threshold);
+    }
+
+    /// Beobachtet ein legitimes Paket und aktualisiert den Beta-Posterior
+    /// über den rohen Resonanz-Score `σ(max_k aₖ·cos(sₖ, S))` (ohne
+    /// Schwellwert-Bias) an Knoten `node`.
+    ///
+    /// Dient als Datengrundlage für [`Self::set_target_false_positive`].
+    pub fn observe_legitimate(&mut self, packet: &[u8], node: usize) {
+        assert!(node < NUM_NODES);
+
+        let spectrum = SpectralFingerprint::compute(packet, self.spectrum_size);
+        let similarity = self.dictionaries[node].max_similarity(&spectrum.power_spectrum);
+        let raw_score = self.sigmoid(similarity);
+
+        self.score_posteriors[node].update(raw_score);
+    }
+
+    /// Passt `thresholds[node]` so an, dass die Wahrscheinlichkeit,
+    /// ein legitimes Paket fälschlich zu absorbieren, gegen den fitted
+    /// Beta-Posterior durch `target` beschränkt wird.
+    ///
+    /// Bestimmt per Bisektion auf der regularisierten unvollständigen
+    /// Beta-Funktion das `target`-Quantil `x` des Posteriors
+    /// `Beta(α_i, β_i)`, d.h. `I_x(α_i, β_i) = target`. Da
+    /// `σ(z - θ) < 0.5 ⟺ z < θ` und `score = σ(z)` per Konstruktion des
+    /// Posteriors, liefert `θᵢ = logit(x) = ln(x / (1 - x))` genau den
+    /// Schwellwert, unterhalb dessen ein legitimes Paket mit
+    /// Wahrscheinlichkeit `target` landet — also mit `should_absorb(..,
+    /// 0.5)` fälschlich absorbiert würde.
+    ///
+    /// Die Garantie ist nur so gut wie der Posterior: Mit wenigen
+    /// [`Self::observe_legitimate`]-Beobachtungen bleibt Beta(1,1) nahe dem
+    /// uninformativen Prior, und der resultierende Schwellwert ist
+    /// entsprechend unsicher.
+    pub fn set_target_false_positive(&mut self, node: usize, target: f64) {
+        assert!(node < NUM_NODES);
+        assert!(
+            (0.0..=1.0).contains(&target),
+            "Ziel-False-Positive-Rate muss in [0,1] liegen"
+        );
+
+        let posterior = self.score_posteriors[node];
+        let quantile =
+            inverse_regularized_incomplete_beta(target, posterior.alpha, posterior.beta);
+        let quantile = quantile.clamp(1e-9, 1.0 - 1e-9);
+
+        self.thresholds[node] = (quantile / (1.0 - quantile)).ln();
+    }
+
+    /// Berechnet Absorptions-Effizienz η_RAL
+    pub fn absorption_efficiency(&self) -> f64 {
+        if self.stats.total_packets == 0 {
+            return 0.0;
+        }
+        self.stats.packets_absorbed as f64 / self.stats.total_packets as f64
+    }
+
+    /// Berechnet die Absorptionsrate am Knoten `node`
+    ///
+    /// Anteil der an diesem Knoten absorbierten Pakete an allen an diesem
+    /// Knoten verarbeiteten Paketen; 0, solange der Knoten noch kein Paket
+    /// gesehen hat. Dient als Stärke-Indikator für die Lindblad-Kollapsoperatoren
+    /// in [`crate::delta::kernel::DeltaKernel`], damit stark geflutete Knoten
+    /// stärker dissipieren.
+    pub fn node_absorption_rate(&self, node: usize) -> f64 {
+        assert!(node < NUM_NODES);
+        let total = self.stats.node_total_packets[node];
+        if total == 0 {
+            return 0.0;
+        }
+        self.stats.node_packets_absorbed[node] as f64 / total as f64
+    }
+
+    /// Berechnet False-Positive-Rate
+    pub fn false_positive_rate(&self, legitimate_count: usize) -> f64 {
+        if self.stats.packets_absorbed == 0 {
+            return 0.0;
+        }
+        legitimate_count as f64 / self.stats.packets_absorbed as f64
+    }
+
+    /// Reset Statistiken
+    pub fn reset_stats(&mut self) {
+        self.stats = AbsorberStats::default();
+    }
+}
+
+impl Default for ResonantAbsorber {
+    fn default() -> Self {
+        Self::new(256, 0.01)
+    }
+}
+
+/// Absorber-Statistiken
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct AbsorberStats {
+    /// Gesamtanzahl verarbeiteter Pakete
+    pub total_packets: usize,
+    /// Anzahl absorbierter Pakete
+    pub packets_absorbed: usize,
+    /// Anzahl weitergeleiteter Pakete
+    pub packets_forwarded: usize,
+    /// Pro-Knoten absorbierte Paketanzahl
+    pub node_packets_absorbed: [usize; NUM_NODES],
+    /// Pro-Knoten Gesamtanzahl verarbeiteter Pakete
+    pub node_total_packets: [usize; NUM_NODES],
+}
+
+impl std::fmt::Display for AbsorberStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Absorber-Statistiken:")?;
+        writeln!(f, "  Total Packets:    {}", self.total_packets)?;
+        writeln!(f, "  Absorbed:         {}", self.packets_absorbed)?;
+        writeln!(f, "  Forwarded:        {}", self.packets_forwarded)?;
+        if self.total_packets > 0 {
+            writeln!(
+                f,
+                "  Absorption Rate:  {:.2}%",
+                100.0 * self.packets_absorbed as f64 / self.total_packets as f64
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_absorber_creation() {
+        let absorber = ResonantAbsorber::new(256, 0.01);
+        assert_eq!(absorber.thresholds.len(), NUM_NODES);
+        assert_eq!(absorber.spectrum_size, 256);
+    }
+
+    #[test]
+    fn test_sigmoid() {
+        let absorber = ResonantAbsorber::default();
+
+        assert!((absorber.sigmoid(0.0) - 0.5).abs() < 1e-10);
+        assert!(absorber.sigmoid(10.0) > 0.9);
+        assert!(absorber.sigmoid(-10.0) < 0.1);
+    }
+
+    #[test]
+    fn test_resonance_score() {
+        let mut absorber = ResonantAbsorber::default();
+        absorber.initialize_random_fields();
+
+        let packet = b"test packet data";
+        let score = absorber.resonance_score(packet, 0);
+
+        // Score sollte in [0,1] liegen
+        assert!(score >= 0.0 && score <= 1.0);
+    }
+
+    #[test]
+    fn test_absorption_decision() {
+        let mut absorber = ResonantAbsorber::default();
+        absorber.initialize_random_fields();
+
+        let packet = b"malicious traffic";
+        let epsilon_res = 0.3;
+
+        let (absorbed, score) = absorber.process_packet(packet, 0, epsilon_res);
+
+        // Entweder absorbiert oder weitergeleitet
+        assert_eq!(absorbed, score < epsilon_res);
+    }
+
+    #[test]
+    fn test_threshold_update() {
+        let mut absorber = ResonantAbsorber::default();
+        let initial_threshold = absorber.thresholds[0];
+
+        absorber.update_threshold(0, 100.0);
+
+        // Schwellwert sollte sich geändert haben
+        assert_ne!(absorber.thresholds[0], initial_threshold);
+    }
+
+    #[test]
+    fn test_pattern_learning() {
+        let mut absorber = ResonantAbsorber::default();
+        absorber.initialize_random_fields();
+
+        let legitimate_packet = b"normal traffic pattern";
+
+        // Lerne Pattern mehrfach
+        for _ in 0..10 {
+            absorber.learn_legitimate_pattern(legitimate_packet, 0, 1e-6);
+        }
+
+        // Wörterbuch sollte mindestens ein Atom für das Pattern gelernt haben
+        assert!(!absorber.dictionaries[0].atoms.is_empty());
+    }
+
+    #[test]
+    fn test_statistics() {
+        let mut absorber = ResonantAbsorber::default();
+        absorber.initialize_random_fields();
+
+        let packet1 = b"packet1";
+        let packet2 = b"packet2";
+
+        absorber.process_packet(packet1, 0, 0.5);
+        absorber.process_packet(packet2, 0, 0.5);
+
+        assert_eq!(absorber.stats.total_packets, 2);
+        assert_eq!(
+            absorber.stats.packets_absorbed + absorber.stats.packets_forwarded,
+            2
+        );
+    }
+
+    #[test]
+    fn test_absorption_efficiency() {
+        let mut absorber = ResonantAbsorber::default();
+
+        // Simuliere Pakete
+        absorber.stats.total_packets = 100;
+        absorber.stats.packets_absorbed = 95;
+        absorber.stats.packets_forwarded = 5;
+
+        let efficiency = absorber.absorption_efficiency();
+        assert!((efficiency - 0.95).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_route_packet_forwards_to_best_resonating_node() {
+        let mut absorber = ResonantAbsorber::new(4, 0.01);
+        // Knoten 2 bekommt ein stark positives Wörterbuch-Atom, alle anderen bleiben leer.
+        absorber.dictionaries[2].atoms = vec![SpectralAtom {
+            spectrum: vec![10.0; 4],
+            weight: 1.0,
+        }];
+        absorber.thresholds = [0.0; NUM_NODES];
+
+        let packet = b"some packet bytes";
+        let (routed, probabilities) = absorber.route_packet(packet, 0.5);
+
+        assert_eq!(routed, Some(2));
+        let sum: f64 = probabilities.iter().sum();
+        assert!(sum <= 1.0 + 1e-9);
+        assert!(probabilities[2] >= probabilities.iter().cloned().fold(f64::NEG_INFINITY, f64::max) - 1e-12);
+    }
+
+    #[test]
+    fn test_route_packet_absorbs_when_all_logits_strongly_negative() {
+        let mut absorber = ResonantAbsorber::new(4, 0.01);
+        absorber.thresholds = [1000.0; NUM_NODES]; // alle Logits stark negativ
+
+        let packet = b"flood packet";
+        let (routed, probabilities) = absorber.route_packet(packet, 0.5);
+
+        assert_eq!(routed, None);
+        let sum: f64 = probabilities.iter().sum();
+        assert!(sum < 0.5, "probability mass should collapse toward absorb: sum = {sum}");
+    }
+
+    #[test]
+    fn test_node_absorption_rate_tracks_per_node_ratio() {
+        let mut absorber = ResonantAbsorber::default();
+        absorber.initialize_random_fields();
+
+        assert_eq!(absorber.node_absorption_rate(0), 0.0);
+
+        absorber.stats.node_total_packets[0] = 4;
+        absorber.stats.node_packets_absorbed[0] = 3;
+
+        assert!((absorber.node_absorption_rate(0) - 0.75).abs() < 1e-10);
+        assert_eq!(absorber.node_absorption_rate(1), 0.0);
+    }
+
+    #[test]
+    fn test_regularized_incomplete_beta_matches_known_values() {
+        // I_x(a, a) = 0.5 bei x = 0.5 (Symmetrie der Beta-Verteilung).
+        assert!((regularized_incomplete_beta(0.5, 2.0, 2.0) - 0.5).abs() < 1e-9);
+        // Für Beta(1,1) (Gleichverteilung) entspricht I_x(1,1) = x.
+        assert!((regularized_incomplete_beta(0.3, 1.0, 1.0) - 0.3).abs() < 1e-9);
+        assert!((regularized_incomplete_beta(0.0, 2.0, 5.0)).abs() < 1e-12);
+        assert!((regularized_incomplete_beta(1.0, 2.0, 5.0) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_regularized_incomplete_beta_roundtrips() {
+        let a = 3.0;
+        let b = 7.0;
+        let target = 0.2;
+
+        let x = inverse_regularized_incomplete_beta(target, a, b);
+        let recovered = regularized_incomplete_beta(x, a, b);
+
+        assert!((recovered - target).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_observe_legitimate_updates_posterior_toward_observed_scores() {
+        let mut absorber = ResonantAbsorber::new(4, 0.01);
+        absorber.dictionaries[0].atoms = vec![SpectralAtom {
+            spectrum: vec![1.0; 4],
+            weight: 1.0,
+        }];
+
+        let prior = absorber.score_posteriors[0];
+
+        for _ in 0..50 {
+            absorber.observe_legitimate(b"normal legitimate traffic", 0);
+        }
+
+        let posterior = absorber.score_posteriors[0];
+        assert!(posterior.alpha > prior.alpha);
+        assert!(posterior.beta > prior.beta);
+    }
+
+    #[test]
+    fn test_set_target_false_positive_bounds_legitimate_absorption_rate() {
+        let mut absorber = ResonantAbsorber::new(8, 0.01);
+        absorber.dictionaries[0].atoms = vec![SpectralAtom {
+            spectrum: vec![0.5; 8],
+            weight: 1.0,
+        }];
+
+        // Lerne den Posterior aus vielen "legitimen" Paketen unterschiedlichen Inhalts an.
+        for i in 0..200u32 {
+            let packet = i.to_le_bytes();
+            absorber.observe_legitimate(&packet, 0);
+        }
+
+        absorber.set_target_false_positive(0, 0.05);
+
+        let mut false_positives = 0;
+        let samples = 200u32;
+        for i in 0..samples {
+            let packet = i.to_le_bytes();
+            if absorber.should_absorb(&packet, 0, 0.5) {
+                false_positives += 1;
+            }
+        }
+
+        let observed_rate = false_positives as f64 / samples as f64;
+        assert!(
+            observed_rate < 0.2,
+            "observed false-positive rate too high: {observed_rate}"
+        );
+    }
+
+    #[test]
+    fn test_fit_batch_adds_atom_and_reduces_residual() {
+        let mut dictionary = SpectralDictionary::new();
+        let target = vec![1.0, 0.0, 0.0, 0.0];
+
+        dictionary.fit_batch(&[target.clone()], 1e-9);
+
+        assert_eq!(dictionary.atoms.len(), 1);
+        let reconstruction = dictionary.reconstruct(4);
+        let residual_norm: f64 = target
+            .iter()
+            .zip(reconstruction.iter())
+            .map(|(t, r)| (t - r).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        assert!(residual_norm < 1e-9);
+    }
+
+    #[test]
+    fn test_fit_batch_prunes_low_weight_atoms() {
+        let mut dictionary = SpectralDictionary::new();
+        dictionary.atoms = vec![SpectralAtom {
+            spectrum: vec![1.0, 0.0],
+            weight: 1e-10,
+        }];
+
+        dictionary.fit_batch(&[vec![0.0, 1.0]], 1e-9);
+
+        assert_eq!(dictionary.atoms.len(), 1);
+        assert!((dictionary.atoms[0].spectrum[1] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_max_similarity_is_zero_for_empty_dictionary() {
+        let dictionary = SpectralDictionary::new();
+        assert_eq!(dictionary.max_similarity(&[1.0, 2.0, 3.0]), 0.0);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_process_batch_matches_sequential_process_packet() {
+        let mut batch_absorber = ResonantAbsorber::new(16, 0.01);
+        batch_absorber.initialize_random_fields();
+        let mut sequential_absorber = batch_absorber.clone();
+
+        let packets: Vec<&[u8]> = vec![b"packet one", b"packet two", b"packet three"];
+        let epsilon_res = 0.5;
+
+        let batch_results = batch_absorber.process_batch(&packets, 0, epsilon_res);
+        let sequential_results: Vec<(bool, f64)> = packets
+            .iter()
+            .map(|packet| sequential_absorber.process_packet(packet, 0, epsilon_res))
+            .collect();
+
+        assert_eq!(batch_results, sequential_results);
+        assert_eq!(
+            batch_absorber.stats.total_packets,
+            sequential_absorber.stats.total_packets
+        );
+        assert_eq!(
+            batch_absorber.stats.packets_absorbed,
+            sequential_absorber.stats.packets_absorbed
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_route_batch_matches_sequential_route_packet() {
+        let mut absorber = ResonantAbsorber::new(8, 0.01);
+        absorber.dictionaries[1].atoms = vec![SpectralAtom {
+            spectrum: vec![5.0; 8],
+            weight: 1.0,
+        }];
+
+        let packets: Vec<&[u8]> = vec![b"alpha", b"beta", b"gamma"];
+        let batch_results = absorber.route_batch(&packets, 0.5);
+        let sequential_results: Vec<_> = packets
+            .iter()
+            .map(|packet| absorber.route_packet(packet, 0.5))
+            .collect();
+
+        assert_eq!(batch_results, sequential_results);
+    }
+}
@@ -0,0 +1,292 @@
+//! Ring-gepufferte Diagnose-Historie
+//!
+//! Hält die letzten Messungen zentraler Systemgrößen in
+//! größenbeschränkten Ringpuffern fest, damit Operatoren
+//! Desynchronisation oder sonstige Drift nachvollziehen können, ohne
+//! selbst unbeschränkt Historie mitschreiben zu müssen. Jede Probe
+//! trägt einen monotonen, intern gezählten Zeitstempel statt einer
+//! Wanduhr-Zeit, damit die Historie wie der Rest der Kernlogik
+//! deterministisch bleibt.
+
+use crate::resonance::spectrum::TrafficType;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Anzahl zurückgehaltener Kuramoto-Ordnungsparameter-Schnappschüsse
+const ORDER_PARAMETER_HISTORY_COUNT: usize = 5;
+/// Anzahl zurückgehaltener Spektral-Fingerprint-Klassifikationen
+const SPECTRAL_CLASSIFICATION_HISTORY_COUNT: usize = 3;
+/// Anzahl zurückgehaltener Circuit-Count-Deltas
+const CIRCUIT_COUNT_DELTA_HISTORY_COUNT: usize = 5;
+/// Anzahl zurückgehaltener Frequenz-Kalman-Schätzungen
+const FREQUENCY_ESTIMATE_HISTORY_COUNT: usize = 3;
+
+/// Ein Kuramoto-Ordnungsparameter-/Phasenkohärenz-Schnappschuss
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderParameterSample {
+    /// Monotoner Zeitstempel (kein Wanduhr-Zeitpunkt)
+    pub timestamp: u64,
+    /// Synchronisationsgrad r = |1/N Σ e^{iθⱼ}|
+    pub r: f64,
+    /// Globale Phase Θ des Ordnungsparameters
+    pub theta: f64,
+    /// Mittlere Frequenz Σωᵢ/N über alle Oszillatoren
+    pub mean_frequency: f64,
+}
+
+/// Eine Spektral-Fingerprint-Klassifikation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpectralClassificationSample {
+    /// Monotoner Zeitstempel (kein Wanduhr-Zeitpunkt)
+    pub timestamp: u64,
+    /// Klassifizierter Verkehrstyp
+    pub traffic_type: TrafficType,
+}
+
+/// Eine Veränderung der Anzahl verfolgter Circuits gegenüber der vorigen Probe
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CircuitCountDeltaSample {
+    /// Monotoner Zeitstempel (kein Wanduhr-Zeitpunkt)
+    pub timestamp: u64,
+    /// Δ(Circuit-Anzahl) gegenüber der zuletzt aufgezeichneten Probe
+    pub delta: i64,
+}
+
+/// Ergebnis einer einzelnen Kalman-Frequenzmessung
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FrequencyEstimateOutcome {
+    /// Messung lag innerhalb des Ausreißer-Gates und wurde eingearbeitet
+    Accepted,
+    /// Messung überschritt das Ausreißer-Gate und wurde verworfen
+    DiscardedOutlier,
+}
+
+/// Eine Probe des Kalman-gefilterten Frequenzzustands eines Oszillators
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FrequencyEstimateSample {
+    /// Monotoner Zeitstempel (kein Wanduhr-Zeitpunkt)
+    pub timestamp: u64,
+    /// Index des betroffenen Oszillators
+    pub node: usize,
+    /// Geschätzte Eigenfrequenz nach dieser Messung
+    pub freq_estimate: f64,
+    /// Schätzvarianz nach dieser Messung
+    pub variance: f64,
+    /// Ob die auslösende Messung eingearbeitet oder als Ausreißer verworfen wurde
+    pub outcome: FrequencyEstimateOutcome,
+}
+
+/// Exportierbares Fenster der zurückgehaltenen Diagnose-Historie
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DiagnosticsSnapshot {
+    /// Bis zu [`ORDER_PARAMETER_HISTORY_COUNT`] letzte Ordnungsparameter-Proben
+    pub order_parameter_history: Vec<OrderParameterSample>,
+    /// Bis zu [`SPECTRAL_CLASSIFICATION_HISTORY_COUNT`] letzte Spektral-Klassifikationen
+    pub spectral_classification_history: Vec<SpectralClassificationSample>,
+    /// Bis zu [`CIRCUIT_COUNT_DELTA_HISTORY_COUNT`] letzte Circuit-Count-Deltas
+    pub circuit_count_delta_history: Vec<CircuitCountDeltaSample>,
+    /// Bis zu [`FREQUENCY_ESTIMATE_HISTORY_COUNT`] letzte Kalman-Frequenzschätzungen
+    pub frequency_estimate_history: Vec<FrequencyEstimateSample>,
+}
+
+/// Ring-gepufferte Diagnose-Historie fester Größe
+///
+/// Jeder der drei Ringpuffer überschreibt beim Überlauf die älteste
+/// Probe (`pop_front` vor `push_back`), sodass der Speicherbedarf
+/// unabhängig von der Laufzeit beschränkt bleibt.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Diagnostics {
+    order_parameter_history: VecDeque<OrderParameterSample>,
+    spectral_classification_history: VecDeque<SpectralClassificationSample>,
+    circuit_count_delta_history: VecDeque<CircuitCountDeltaSample>,
+    frequency_estimate_history: VecDeque<FrequencyEstimateSample>,
+    next_timestamp: u64,
+    last_circuit_count: Option<usize>,
+}
+
+impl Diagnostics {
+    /// Erstellt eine leere Diagnose-Historie
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn tick(&mut self) -> u64 {
+        let timestamp = self.next_timestamp;
+        self.next_timestamp += 1;
+        timestamp
+    }
+
+    /// Zeichnet einen Kuramoto-Ordnungsparameter-/Phasenkohärenz-Schnappschuss auf
+    pub fn record_order_parameter(&mut self, r: f64, theta: f64, mean_frequency: f64) {
+        let timestamp = self.tick();
+        if self.order_parameter_history.len() == ORDER_PARAMETER_HISTORY_COUNT {
+            self.order_parameter_history.pop_front();
+        }
+        self.order_parameter_history.push_back(OrderParameterSample {
+            timestamp,
+            r,
+            theta,
+            mean_frequency,
+        });
+    }
+
+    /// Zeichnet eine Spektral-Fingerprint-Klassifikation auf
+    pub fn record_spectral_classification(&mut self, traffic_type: TrafficType) {
+        let timestamp = self.tick();
+        if self.spectral_classification_history.len() == SPECTRAL_CLASSIFICATION_HISTORY_COUNT {
+            self.spectral_classification_history.pop_front();
+        }
+        self.spectral_classification_history
+            .push_back(SpectralClassificationSample {
+                timestamp,
+                traffic_type,
+            });
+    }
+
+    /// Zeichnet die aktuelle Circuit-Anzahl auf und leitet daraus das
+    /// Delta gegenüber der zuletzt aufgezeichneten Anzahl ab (0 bei der
+    /// ersten Probe)
+    pub fn record_circuit_count(&mut self, circuit_count: usize) {
+        let delta = match self.last_circuit_count {
+            Some(previous) => circuit_count as i64 - previous as i64,
+            None => 0,
+        };
+        self.last_circuit_count = Some(circuit_count);
+
+        let timestamp = self.tick();
+        if self.circuit_count_delta_history.len() == CIRCUIT_COUNT_DELTA_HISTORY_COUNT {
+            self.circuit_count_delta_history.pop_front();
+        }
+        self.circuit_count_delta_history
+            .push_back(CircuitCountDeltaSample { timestamp, delta });
+    }
+
+    /// Zeichnet eine Kalman-Frequenzschätzung für `node` auf
+    pub fn record_frequency_estimate(
+        &mut self,
+        node: usize,
+        freq_estimate: f64,
+        variance: f64,
+        outcome: FrequencyEstimateOutcome,
+    ) {
+        let timestamp = self.tick();
+        if self.frequency_estimate_history.len() == FREQUENCY_ESTIMATE_HISTORY_COUNT {
+            self.frequency_estimate_history.pop_front();
+        }
+        self.frequency_estimate_history.push_back(FrequencyEstimateSample {
+            timestamp,
+            node,
+            freq_estimate,
+            variance,
+            outcome,
+        });
+    }
+
+    /// Exportiert das aktuell zurückgehaltene Historienfenster
+    pub fn snapshot(&self) -> DiagnosticsSnapshot {
+        DiagnosticsSnapshot {
+            order_parameter_history: self.order_parameter_history.iter().cloned().collect(),
+            spectral_classification_history: self
+                .spectral_classification_history
+                .iter()
+                .cloned()
+                .collect(),
+            circuit_count_delta_history: self
+                .circuit_count_delta_history
+                .iter()
+                .cloned()
+                .collect(),
+            frequency_estimate_history: self
+                .frequency_estimate_history
+                .iter()
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_parameter_history_overwrites_oldest_on_overflow() {
+        let mut diagnostics = Diagnostics::new();
+        for i in 0..(ORDER_PARAMETER_HISTORY_COUNT + 2) {
+            diagnostics.record_order_parameter(i as f64, 0.0, 0.0);
+        }
+
+        let snapshot = diagnostics.snapshot();
+        assert_eq!(snapshot.order_parameter_history.len(), ORDER_PARAMETER_HISTORY_COUNT);
+        // Die ältesten zwei Proben (r=0.0, r=1.0) sollten verdrängt worden sein
+        assert_eq!(snapshot.order_parameter_history[0].r, 2.0);
+    }
+
+    #[test]
+    fn test_timestamps_are_monotonic_across_all_buffers() {
+        let mut diagnostics = Diagnostics::new();
+        diagnostics.record_order_parameter(0.5, 0.0, 1.0);
+        diagnostics.record_circuit_count(3);
+        diagnostics.record_spectral_classification(TrafficType::Legitimate);
+
+        let snapshot = diagnostics.snapshot();
+        assert_eq!(snapshot.order_parameter_history[0].timestamp, 0);
+        assert_eq!(snapshot.circuit_count_delta_history[0].timestamp, 1);
+        assert_eq!(snapshot.spectral_classification_history[0].timestamp, 2);
+    }
+
+    #[test]
+    fn test_circuit_count_delta_tracks_change_since_last_sample() {
+        let mut diagnostics = Diagnostics::new();
+        diagnostics.record_circuit_count(5);
+        diagnostics.record_circuit_count(8);
+        diagnostics.record_circuit_count(2);
+
+        let snapshot = diagnostics.snapshot();
+        let deltas: Vec<i64> = snapshot
+            .circuit_count_delta_history
+            .iter()
+            .map(|sample| sample.delta)
+            .collect();
+        assert_eq!(deltas, vec![0, 3, -6]);
+    }
+
+    #[test]
+    fn test_spectral_classification_history_respects_its_own_capacity() {
+        let mut diagnostics = Diagnostics::new();
+        diagnostics.record_spectral_classification(TrafficType::Legitimate);
+        diagnostics.record_spectral_classification(TrafficType::Bot);
+        diagnostics.record_spectral_classification(TrafficType::Legitimate);
+        diagnostics.record_spectral_classification(TrafficType::Bot);
+
+        let snapshot = diagnostics.snapshot();
+        assert_eq!(
+            snapshot.spectral_classification_history.len(),
+            SPECTRAL_CLASSIFICATION_HISTORY_COUNT
+        );
+        assert_eq!(
+            snapshot.spectral_classification_history[0].traffic_type,
+            TrafficType::Bot
+        );
+    }
+
+    #[test]
+    fn test_frequency_estimate_history_retains_only_the_last_few_samples() {
+        let mut diagnostics = Diagnostics::new();
+        diagnostics.record_frequency_estimate(0, 1.0, 0.1, FrequencyEstimateOutcome::Accepted);
+        diagnostics.record_frequency_estimate(1, 2.0, 0.1, FrequencyEstimateOutcome::DiscardedOutlier);
+        diagnostics.record_frequency_estimate(2, 3.0, 0.1, FrequencyEstimateOutcome::Accepted);
+        diagnostics.record_frequency_estimate(3, 4.0, 0.1, FrequencyEstimateOutcome::Accepted);
+
+        let snapshot = diagnostics.snapshot();
+        assert_eq!(
+            snapshot.frequency_estimate_history.len(),
+            FREQUENCY_ESTIMATE_HISTORY_COUNT
+        );
+        assert_eq!(snapshot.frequency_estimate_history[0].node, 1);
+        assert_eq!(
+            snapshot.frequency_estimate_history[0].outcome,
+            FrequencyEstimateOutcome::DiscardedOutlier
+        );
+    }
+}
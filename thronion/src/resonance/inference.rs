@@ -0,0 +1,255 @@
+//! Sparse Kopplungsmatrix-Inferenz aus beobachteten Trajektorien
+//!
+//! Rekonstruiert aus einer Folge beobachteter Phasen-Schnappschüsse
+//! eine dünnbesetzte, symmetrische Kopplungsmatrix, die mit der
+//! Kuramoto-Dynamik konsistent ist, mittels proximalem
+//! Forward-Backward-Splitting (ISTA):
+//!
+//! dφᵢ/dt = ωᵢ + Σⱼ κᵢⱼ·sin(φⱼ−φᵢ)
+//!
+//! ist linear in den Unbekannten {ωᵢ, κᵢⱼ}. Minimiert wird
+//!
+//! ½‖φ̂̇ − A·x‖² + λ‖κ‖₁
+//!
+//! durch abwechselnde Gradientenschritte auf dem glatten
+//! Least-Squares-Term und Soft-Thresholding der κ-Komponenten von x.
+//! Die Symmetrie κᵢⱼ=κⱼᵢ wird dadurch erzwungen, dass pro ungeordnetem
+//! Paar (i,j) nur eine gemeinsame Unbekannte geführt wird — äquivalent
+//! zur expliziten Neu-Symmetrisierung κᵢⱼ←(κᵢⱼ+κⱼᵢ)/2 nach jedem
+//! Schritt, jedoch ohne redundante Variablen. Die Diagonale κᵢᵢ bleibt
+//! konstruktionsbedingt bei Null.
+
+use crate::core::NUM_NODES;
+use crate::resonance::kuramoto::KuramotoNetwork;
+use nalgebra::{DMatrix, DVector};
+use std::f64::consts::PI;
+
+/// Anzahl ungeordneter Knotenpaare (i<j)
+const NUM_PAIRS: usize = NUM_NODES * (NUM_NODES - 1) / 2;
+/// Dimension des Unbekannten-Vektors x = [ω₀..ω_{N-1}, κ-Paare...]
+const NUM_UNKNOWNS: usize = NUM_NODES + NUM_PAIRS;
+
+/// Index des Paar-Unbekannten für (i,j), i≠j, innerhalb von x
+/// (unabhängig von der Reihenfolge i,j)
+fn pair_index(i: usize, j: usize) -> usize {
+    let (a, b) = if i < j { (i, j) } else { (j, i) };
+    // Zeilenweise Aufzählung aller Paare (a,b) mit a<b
+    let mut index = 0;
+    for row in 0..a {
+        index += NUM_NODES - 1 - row;
+    }
+    index + (b - a - 1)
+}
+
+/// Kleinste Kreisdifferenz φⱼ−φᵢ, nach [−π,π) gewickelt
+fn wrapped_diff(from: f64, to: f64) -> f64 {
+    let diff = to - from;
+    ((diff + PI).rem_euclid(2.0 * PI)) - PI
+}
+
+/// Konfiguration der ISTA-Inferenz
+#[derive(Debug, Clone)]
+pub struct CouplingInference {
+    /// Sparsity-Gewicht λ der ℓ₁-Strafe auf die Kopplungen
+    pub lambda: f64,
+    /// Anzahl Forward-Backward-Iterationen
+    pub max_iterations: usize,
+}
+
+impl CouplingInference {
+    /// Erstellt eine Inferenz-Konfiguration
+    pub fn new(lambda: f64, max_iterations: usize) -> Self {
+        Self {
+            lambda,
+            max_iterations,
+        }
+    }
+
+    /// Rekonstruiert ein `KuramotoNetwork` aus einer beobachteten
+    /// Phasen-Trajektorie `snapshots` (aufeinanderfolgende Zustände im
+    /// Abstand `dt`). Der letzte Schnappschuss wird als aktueller
+    /// Phasenzustand des zurückgegebenen Netzwerks übernommen.
+    pub fn infer(&self, snapshots: &[[f64; NUM_NODES]], dt: f64) -> KuramotoNetwork {
+        assert!(snapshots.len() >= 2, "mindestens zwei Schnappschüsse nötig");
+        assert!(dt > 0.0, "dt muss positiv sein");
+
+        let num_rows = (snapshots.len() - 1) * NUM_NODES;
+        let mut design = DMatrix::<f64>::zeros(num_rows, NUM_UNKNOWNS);
+        let mut target = DVector::<f64>::zeros(num_rows);
+
+        for (t, pair) in snapshots.windows(2).enumerate() {
+            let phi_now = &pair[0];
+            let phi_next = &pair[1];
+
+            for i in 0..NUM_NODES {
+                let row = t * NUM_NODES + i;
+                let derivative = wrapped_diff(phi_now[i], phi_next[i]) / dt;
+                target[row] = derivative;
+
+                design[(row, i)] = 1.0; // Koeffizient von ωᵢ
+
+                for j in 0..NUM_NODES {
+                    if i == j {
+                        continue;
+                    }
+                    let column = NUM_NODES + pair_index(i, j);
+                    design[(row, column)] += (phi_now[j] - phi_now[i]).sin();
+                }
+            }
+        }
+
+        let lipschitz = largest_singular_value_squared(&design);
+        let step_size = if lipschitz > 1e-12 {
+            1.0 / lipschitz
+        } else {
+            1.0
+        };
+
+        let mut x = DVector::<f64>::zeros(NUM_UNKNOWNS);
+
+        for _ in 0..self.max_iterations {
+            let residual = &design * &x - &target;
+            let gradient = design.transpose() * residual;
+            x -= step_size * gradient;
+
+            // Soft-Thresholding nur auf die κ-Komponenten (die
+            // Frequenzen ωᵢ bleiben unreguliert)
+            let threshold = self.lambda * step_size;
+            for k in NUM_NODES..NUM_UNKNOWNS {
+                x[k] = soft_threshold(x[k], threshold);
+            }
+        }
+
+        let mut frequencies = [0.0; NUM_NODES];
+        frequencies.copy_from_slice(&x.as_slice()[0..NUM_NODES]);
+
+        let mut coupling_matrix = [[0.0; NUM_NODES]; NUM_NODES];
+        for i in 0..NUM_NODES {
+            for j in 0..NUM_NODES {
+                if i != j {
+                    coupling_matrix[i][j] = x[NUM_NODES + pair_index(i, j)];
+                }
+            }
+        }
+
+        let last_phases = *snapshots.last().unwrap();
+        KuramotoNetwork::from_state(last_phases, frequencies, coupling_matrix)
+    }
+}
+
+/// Soft-Threshold-Proximaloperator prox_{τ}(x) = sign(x)·max(|x|−τ, 0)
+fn soft_threshold(x: f64, threshold: f64) -> f64 {
+    if x > threshold {
+        x - threshold
+    } else if x < -threshold {
+        x + threshold
+    } else {
+        0.0
+    }
+}
+
+/// Schätzt ‖AᵀA‖ (größten Singulärwert von A, zum Quadrat) via SVD,
+/// um eine zulässige Lipschitz-konstante Schrittweite ≤ 1/‖AᵀA‖ zu
+/// erhalten.
+fn largest_singular_value_squared(design: &DMatrix<f64>) -> f64 {
+    let svd = design.clone().svd(false, false);
+    let largest = svd
+        .singular_values
+        .iter()
+        .cloned()
+        .fold(0.0_f64, f64::max);
+    largest * largest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simulate_ground_truth(
+        network: &mut KuramotoNetwork,
+        dt: f64,
+        steps: usize,
+    ) -> Vec<[f64; NUM_NODES]> {
+        let mut snapshots = vec![network.phases];
+        for _ in 0..steps {
+            network.evolve_rk4(dt);
+            snapshots.push(network.phases);
+        }
+        snapshots
+    }
+
+    #[test]
+    fn test_pair_index_is_bijective_and_in_range() {
+        let mut seen = std::collections::HashSet::new();
+        for i in 0..NUM_NODES {
+            for j in 0..NUM_NODES {
+                if i == j {
+                    continue;
+                }
+                let index = pair_index(i, j);
+                assert!(index < NUM_PAIRS);
+                seen.insert(index);
+            }
+        }
+        assert_eq!(seen.len(), NUM_PAIRS);
+    }
+
+    #[test]
+    fn test_pair_index_symmetric() {
+        for i in 0..NUM_NODES {
+            for j in 0..NUM_NODES {
+                if i != j {
+                    assert_eq!(pair_index(i, j), pair_index(j, i));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_inferred_coupling_matrix_is_symmetric_and_zero_diagonal() {
+        let mut network = KuramotoNetwork::uniform(0.5, 1.0);
+        network.randomize_phases();
+        let snapshots = simulate_ground_truth(&mut network, 0.02, 30);
+
+        let inference = CouplingInference::new(0.01, 20);
+        let inferred = inference.infer(&snapshots, 0.02);
+
+        for i in 0..NUM_NODES {
+            assert_eq!(inferred.coupling_matrix[i][i], 0.0);
+            for j in 0..NUM_NODES {
+                assert_eq!(inferred.coupling_matrix[i][j], inferred.coupling_matrix[j][i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inference_recovers_last_phase_snapshot() {
+        let mut network = KuramotoNetwork::uniform(0.5, 1.0);
+        network.randomize_phases();
+        let snapshots = simulate_ground_truth(&mut network, 0.02, 10);
+
+        let inference = CouplingInference::new(0.05, 10);
+        let inferred = inference.infer(&snapshots, 0.02);
+
+        assert_eq!(inferred.phases, *snapshots.last().unwrap());
+    }
+
+    #[test]
+    fn test_larger_lambda_increases_sparsity() {
+        let mut network = KuramotoNetwork::uniform(0.5, 1.0);
+        network.randomize_phases();
+        let snapshots = simulate_ground_truth(&mut network, 0.02, 40);
+
+        let loose = CouplingInference::new(1e-4, 25).infer(&snapshots, 0.02);
+        let strict = CouplingInference::new(5.0, 25).infer(&snapshots, 0.02);
+
+        let count_nonzero = |m: &[[f64; NUM_NODES]; NUM_NODES]| -> usize {
+            m.iter()
+                .flat_map(|row| row.iter())
+                .filter(|&&v| v.abs() > 1e-6)
+                .count()
+        };
+
+        assert!(count_nonzero(&strict.coupling_matrix) <= count_nonzero(&loose.coupling_matrix));
+    }
+}
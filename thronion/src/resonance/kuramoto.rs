@@ -0,0 +1,843 @@
+//! Kuramoto-Modell auf Metatron-Graph
+//!
+//! Implementiert Resonator-Dynamik und Synchronisationsphänomene
+//!
+//! dφᵢ/dt = ωᵢ + Σⱼ κᵢⱼ sin(φⱼ - φᵢ)
+//!
+//! [`KuramotoNetwork::maximal_sync_cliques`] findet über
+//! [`crate::utils::graph::maximal_cliques_bron_kerbosch`] maximale
+//! Cliquen phasensynchronisierter oder graph-verbundener Oszillatoren.
+//!
+//! [`KuramotoNetwork::enable_frequency_adaptation`] aktiviert pro
+//! Oszillator einen [`FrequencyKalmanFilter`], der die Eigenfrequenz
+//! online aus dem beobachteten Phasenvorschub in `evolve_rk4` nachführt
+//! und Ausreißer verwirft, statt sie einzuarbeiten.
+
+use crate::core::{MetatronGraph, NUM_NODES};
+use crate::resonance::diagnostics::{Diagnostics, DiagnosticsSnapshot, FrequencyEstimateOutcome};
+use nalgebra::SMatrix;
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Default-Prozessrauschen `q` für neu aktivierte Frequenz-Kalman-Filter
+const DEFAULT_FREQUENCY_PROCESS_NOISE: f64 = 1e-4;
+/// Default-Messrauschen `r` für neu aktivierte Frequenz-Kalman-Filter
+const DEFAULT_FREQUENCY_MEASUREMENT_NOISE: f64 = 1e-2;
+/// Default-Vielfaches von `sqrt(var+r)`, ab dem eine Frequenzmessung als
+/// Ausreißer verworfen wird
+const DEFAULT_FREQUENCY_OUTLIER_THRESHOLD: f64 = 5.0;
+
+/// Ein 1-D-Kalman-Filter, der die Eigenfrequenz eines einzelnen
+/// Oszillators online aus beobachteten Phasenvorschub-Messungen
+/// `z = Δφ/Δt` nachführt.
+///
+/// Zustand ist `(freq_estimate, variance)`. Jede Messung durchläuft
+/// zunächst einen Prädiktionsschritt, der `variance` um das
+/// Prozessrauschen `q` aufbläht, danach ein Ausreißer-Gate: liegt
+/// `|z - freq_estimate|` über `outlier_threshold · sqrt(variance + r)`,
+/// wird die Messung verworfen, statt einen transienten Spitzenwert in
+/// die Schätzung einzuarbeiten.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FrequencyKalmanFilter {
+    /// Aktuelle Eigenfrequenz-Schätzung
+    pub freq_estimate: f64,
+    /// Aktuelle Schätzvarianz
+    pub variance: f64,
+    /// Prozessrauschen `q`
+    pub process_noise: f64,
+    /// Messrauschen `r`
+    pub measurement_noise: f64,
+    /// Vielfaches von `sqrt(variance + r)`, ab dem eine Messung verworfen wird
+    pub outlier_threshold: f64,
+    /// Anzahl bislang als Ausreißer verworfener Messungen
+    pub discarded_count: u64,
+}
+
+impl FrequencyKalmanFilter {
+    /// Erstellt einen Filter, der bei `initial_frequency` startet
+    pub fn new(
+        initial_frequency: f64,
+        process_noise: f64,
+        measurement_noise: f64,
+        outlier_threshold: f64,
+    ) -> Self {
+        Self {
+            freq_estimate: initial_frequency,
+            variance: measurement_noise,
+            process_noise,
+            measurement_noise,
+            outlier_threshold,
+            discarded_count: 0,
+        }
+    }
+
+    /// Verarbeitet eine Frequenzmessung `z`. Gibt `true` zurück, wenn
+    /// sie innerhalb des Ausreißer-Gates lag und eingearbeitet wurde,
+    /// `false`, wenn sie verworfen wurde.
+    pub fn observe(&mut self, z: f64) -> bool {
+        // Prädiktion: Unsicherheit wächst um das Prozessrauschen
+        self.variance += self.process_noise;
+
+        let innovation = z - self.freq_estimate;
+        let gate = self.outlier_threshold * (self.variance + self.measurement_noise).sqrt();
+        if innovation.abs() > gate {
+            self.discarded_count += 1;
+            return false;
+        }
+
+        let gain = self.variance / (self.variance + self.measurement_noise);
+        self.freq_estimate += gain * innovation;
+        self.variance *= 1.0 - gain;
+        true
+    }
+}
+
+/// Kuramoto-Netzwerk auf Metatron-Topologie
+///
+/// Jeder Knoten trägt Phase φᵢ(t) ∈ S¹ mit gekoppelter Dynamik
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KuramotoNetwork {
+    /// Phasen φᵢ(t) für jeden Knoten
+    pub phases: [f64; NUM_NODES],
+    /// Intrinsische Frequenzen ωᵢ
+    pub frequencies: [f64; NUM_NODES],
+    /// Kopplungsmatrix κᵢⱼ (symmetrisch, basierend auf Graph)
+    pub coupling_matrix: [[f64; NUM_NODES]; NUM_NODES],
+    /// Metatron-Graph-Struktur
+    graph: MetatronGraph,
+    /// Ring-gepufferte Historie von [`Self::record_sample`]-Proben
+    diagnostics: Diagnostics,
+    /// Optionale Online-Schätzung der Eigenfrequenzen via
+    /// [`FrequencyKalmanFilter`], aktiviert über
+    /// [`Self::enable_frequency_adaptation`]
+    frequency_filters: Option<[FrequencyKalmanFilter; NUM_NODES]>,
+}
+
+impl KuramotoNetwork {
+    /// Erstellt neues Kuramoto-Netzwerk
+    ///
+    /// # Arguments
+    /// * `frequencies` - Intrinsische Frequenzen ωᵢ
+    /// * `coupling_strength` - Globale Kopplungsstärke κ
+    pub fn new(frequencies: [f64; NUM_NODES], coupling_strength: f64) -> Self {
+        let graph = MetatronGraph::new();
+
+        // Konstruiere Kopplungsmatrix basierend auf Graph-Adjazenz
+        let mut coupling_matrix = [[0.0; NUM_NODES]; NUM_NODES];
+        for i in 0..NUM_NODES {
+            for j in 0..NUM_NODES {
+                if graph.adjacency[(i, j)] {
+                    coupling_matrix[i][j] = coupling_strength;
+                }
+            }
+        }
+
+        // Zufällige Initialphasen
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let phases = std::array::from_fn(|_| rng.gen_range(0.0..2.0 * PI));
+
+        Self {
+            phases,
+            frequencies,
+            coupling_matrix,
+            graph,
+            diagnostics: Diagnostics::new(),
+            frequency_filters: None,
+        }
+    }
+
+    /// Erstellt Netzwerk mit uniformen Frequenzen
+    pub fn uniform(base_frequency: f64, coupling_strength: f64) -> Self {
+        Self::new([base_frequency; NUM_NODES], coupling_strength)
+    }
+
+    /// Rekonstruiert ein Netzwerk aus rohem Zustand (z.B. dem Ergebnis
+    /// einer Parameter-Inferenz), ohne die Kopplungsmatrix aus der
+    /// Graph-Adjazenz neu abzuleiten.
+    pub fn from_state(
+        phases: [f64; NUM_NODES],
+        frequencies: [f64; NUM_NODES],
+        coupling_matrix: [[f64; NUM_NODES]; NUM_NODES],
+    ) -> Self {
+        Self {
+            phases,
+            frequencies,
+            coupling_matrix,
+            graph: MetatronGraph::new(),
+            diagnostics: Diagnostics::new(),
+            frequency_filters: None,
+        }
+    }
+
+    /// Erstellt Netzwerk mit Frequenz-Dispersion
+    ///
+    /// ωᵢ ~ N(ω₀, σ²)
+    pub fn with_frequency_disorder(
+        base_frequency: f64,
+        disorder_std: f64,
+        coupling_strength: f64,
+    ) -> Self {
+        use rand_distr::{Distribution, Normal};
+        let mut rng = rand::thread_rng();
+
+        let normal = Normal::new(base_frequency, disorder_std).unwrap();
+        let frequencies: [f64; NUM_NODES] = std::array::from_fn(|_| normal.sample(&mut rng));
+
+        Self::new(frequencies, coupling_strength)
+    }
+
+    /// Randomisiert Phasen
+    pub fn randomize_phases(&mut self) {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        for phase in &mut self.phases {
+            *phase = rng.gen_range(0.0..2.0 * PI);
+        }
+    }
+
+    /// Zeitschritt: Euler-Integration
+    ///
+    /// φᵢ(t+dt) = φᵢ(t) + (dφᵢ/dt)·dt
+    pub fn evolve(&mut self, dt: f64) {
+        let mut derivatives = [0.0; NUM_NODES];
+
+        // Berechne dφᵢ/dt für alle Knoten
+        for i in 0..NUM_NODES {
+            derivatives[i] = self.frequencies[i];
+
+            for j in 0..NUM_NODES {
+                if self.coupling_matrix[i][j] > 0.0 {
+                    derivatives[i] +=
+                        self.coupling_matrix[i][j] * (self.phases[j] - self.phases[i]).sin();
+                }
+            }
+        }
+
+        // Update Phasen
+        for i in 0..NUM_NODES {
+            self.phases[i] += derivatives[i] * dt;
+            self.phases[i] = self.phases[i].rem_euclid(2.0 * PI);
+        }
+    }
+
+    /// Runge-Kutta 4. Ordnung (genauer als Euler)
+    pub fn evolve_rk4(&mut self, dt: f64) {
+        let phases_before = self.phases;
+
+        let k1 = self.compute_derivatives(&self.phases);
+
+        let mut phases_temp = self.phases;
+        for i in 0..NUM_NODES {
+            phases_temp[i] += 0.5 * dt * k1[i];
+        }
+        let k2 = self.compute_derivatives(&phases_temp);
+
+        phases_temp = self.phases;
+        for i in 0..NUM_NODES {
+            phases_temp[i] += 0.5 * dt * k2[i];
+        }
+        let k3 = self.compute_derivatives(&phases_temp);
+
+        phases_temp = self.phases;
+        for i in 0..NUM_NODES {
+            phases_temp[i] += dt * k3[i];
+        }
+        let k4 = self.compute_derivatives(&phases_temp);
+
+        // Update
+        for i in 0..NUM_NODES {
+            self.phases[i] += (dt / 6.0) * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
+            self.phases[i] = self.phases[i].rem_euclid(2.0 * PI);
+        }
+
+        if self.frequency_filters.is_some() {
+            self.adapt_frequencies(&phases_before, dt);
+        }
+    }
+
+    /// Beobachtet den tatsächlichen Phasenvorschub jedes Oszillators
+    /// über den zuletzt gelaufenen Integrationsschritt (`z = Δφ/Δt`,
+    /// zirkulär entpackt, damit ein Wrap um 2π keinen Sprung
+    /// vortäuscht), speist ihn in dessen [`FrequencyKalmanFilter`] und
+    /// schreibt bei Annahme die gefilterte Schätzung zurück nach
+    /// `self.frequencies[i]`, sodass der nächste Integrationsschritt mit
+    /// der adaptierten Eigenfrequenz rechnet. Jede Messung wird zudem in
+    /// [`Self::diagnostics`] festgehalten.
+    fn adapt_frequencies(&mut self, phases_before: &[f64; NUM_NODES], dt: f64) {
+        let filters = self
+            .frequency_filters
+            .as_mut()
+            .expect("adapt_frequencies nur bei aktivierten Filtern aufgerufen");
+
+        for i in 0..NUM_NODES {
+            let raw_delta = self.phases[i] - phases_before[i];
+            let delta = ((raw_delta + PI).rem_euclid(2.0 * PI)) - PI;
+            let z = delta / dt;
+
+            let accepted = filters[i].observe(z);
+            if accepted {
+                self.frequencies[i] = filters[i].freq_estimate;
+            }
+
+            let outcome = if accepted {
+                FrequencyEstimateOutcome::Accepted
+            } else {
+                FrequencyEstimateOutcome::DiscardedOutlier
+            };
+            self.diagnostics
+                .record_frequency_estimate(i, filters[i].freq_estimate, filters[i].variance, outcome);
+        }
+    }
+
+    /// Aktiviert die Online-Frequenzadaption: jeder Oszillator erhält
+    /// einen [`FrequencyKalmanFilter`], der bei seiner aktuellen
+    /// Eigenfrequenz startet.
+    pub fn enable_frequency_adaptation(
+        &mut self,
+        process_noise: f64,
+        measurement_noise: f64,
+        outlier_threshold: f64,
+    ) {
+        let frequencies = self.frequencies;
+        self.frequency_filters = Some(std::array::from_fn(|i| {
+            FrequencyKalmanFilter::new(frequencies[i], process_noise, measurement_noise, outlier_threshold)
+        }));
+    }
+
+    /// Aktiviert die Online-Frequenzadaption mit Standard-Rauschparametern
+    pub fn enable_frequency_adaptation_default(&mut self) {
+        self.enable_frequency_adaptation(
+            DEFAULT_FREQUENCY_PROCESS_NOISE,
+            DEFAULT_FREQUENCY_MEASUREMENT_NOISE,
+            DEFAULT_FREQUENCY_OUTLIER_THRESHOLD,
+        );
+    }
+
+    /// Deaktiviert die Online-Frequenzadaption; `evolve_rk4` rechnet
+    /// danach wieder mit den zuletzt gehaltenen `frequencies` ohne
+    /// weitere Anpassung.
+    pub fn disable_frequency_adaptation(&mut self) {
+        self.frequency_filters = None;
+    }
+
+    /// Gibt die aktuellen Frequenz-Kalman-Filter zurück, falls die
+    /// Adaption aktiviert ist
+    pub fn frequency_filters(&self) -> Option<&[FrequencyKalmanFilter; NUM_NODES]> {
+        self.frequency_filters.as_ref()
+    }
+
+    /// Berechnet Ableitungen dφᵢ/dt
+    fn compute_derivatives(&self, phases: &[f64; NUM_NODES]) -> [f64; NUM_NODES] {
+        let mut derivatives = [0.0; NUM_NODES];
+
+        for i in 0..NUM_NODES {
+            derivatives[i] = self.frequencies[i];
+
+            for j in 0..NUM_NODES {
+                if self.coupling_matrix[i][j] > 0.0 {
+                    derivatives[i] += self.coupling_matrix[i][j] * (phases[j] - phases[i]).sin();
+                }
+            }
+        }
+
+        derivatives
+    }
+
+    /// Berechnet Kuramoto-Ordnungsparameter
+    ///
+    /// r(t)·e^(iΘ) = (1/N)Σᵢ e^(iφᵢ)
+    ///
+    /// Returns: (r, Θ)
+    pub fn order_parameter(&self) -> (f64, f64) {
+        let z: Complex64 = self
+            .phases
+            .iter()
+            .map(|&phi| Complex64::from_polar(1.0, phi))
+            .sum::<Complex64>()
+            / (NUM_NODES as f64);
+
+        (z.norm(), z.arg())
+    }
+
+    /// Gibt nur r zurück (Synchronisationsgrad)
+    pub fn synchronization(&self) -> f64 {
+        self.order_parameter().0
+    }
+
+    /// Berechnet kritische Kopplung für Synchronisation
+    ///
+    /// κ_c ≈ 2|ω_max|/λ₂
+    pub fn critical_coupling(&self) -> f64 {
+        let omega_max = self
+            .frequencies
+            .iter()
+            .map(|&f| f.abs())
+            .fold(0.0, f64::max);
+        let lambda2 = self.graph.algebraic_connectivity();
+
+        2.0 * omega_max / lambda2
+    }
+
+    /// Prüft ob Netzwerk synchronisiert ist
+    pub fn is_synchronized(&self, threshold: f64) -> bool {
+        self.synchronization() > threshold
+    }
+
+    /// Zeichnet eine Probe des aktuellen globalen Ordnungsparameters
+    /// `r·e^{iΘ}` und der mittleren Frequenz `Σωᵢ/N` in der
+    /// [`Diagnostics`]-Ringpuffer-Historie auf.
+    pub fn record_sample(&mut self) {
+        let (r, theta) = self.order_parameter();
+        let mean_frequency = self.frequencies.iter().sum::<f64>() / (NUM_NODES as f64);
+        self.diagnostics.record_order_parameter(r, theta, mean_frequency);
+    }
+
+    /// Exportiert das aktuell zurückgehaltene Diagnose-Historienfenster
+    pub fn snapshot(&self) -> DiagnosticsSnapshot {
+        self.diagnostics.snapshot()
+    }
+
+    /// Findet alle maximalen Cliquen phasensynchronisierter Oszillatoren
+    /// via degeneracy-geordnetem Bron–Kerbosch mit Pivotierung.
+    ///
+    /// Zwei Knoten gelten als adjazent, wenn ihre zirkuläre Phasendifferenz
+    /// innerhalb von `phase_tol` liegt ODER sie im Metatron-Graph
+    /// verbunden sind -- so werden sowohl rein dynamisch mitgelaufene
+    /// Cluster als auch topologisch gekoppelte Knotenpaare erfasst, deren
+    /// Phasen (noch) nicht exakt zusammengelaufen sind.
+    pub fn maximal_sync_cliques(&self, phase_tol: f64) -> Vec<Vec<usize>> {
+        let neighbors: Vec<Vec<usize>> = (0..NUM_NODES)
+            .map(|i| {
+                (0..NUM_NODES)
+                    .filter(|&j| {
+                        j != i
+                            && (self.circular_phase_difference(i, j) < phase_tol
+                                || self.graph.has_edge(i, j))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        crate::utils::graph::maximal_cliques_bron_kerbosch(&neighbors)
+    }
+
+    /// Kürzeste zirkuläre Distanz zwischen zwei Phasen auf `S¹`
+    fn circular_phase_difference(&self, i: usize, j: usize) -> f64 {
+        let raw = (self.phases[i] - self.phases[j]).rem_euclid(2.0 * PI);
+        raw.min(2.0 * PI - raw)
+    }
+
+    /// Berechnet lokale Ordnungsparameter für jeden Knoten
+    pub fn local_order_parameters(&self) -> [f64; NUM_NODES] {
+        let mut local_r = [0.0; NUM_NODES];
+
+        for i in 0..NUM_NODES {
+            let neighbors = self.graph.neighbors(i);
+            if neighbors.is_empty() {
+                local_r[i] = 1.0;
+                continue;
+            }
+
+            let z: Complex64 = neighbors
+                .iter()
+                .map(|&j| Complex64::from_polar(1.0, self.phases[j]))
+                .sum::<Complex64>()
+                / (neighbors.len() as f64);
+
+            local_r[i] = z.norm();
+        }
+
+        local_r
+    }
+
+    /// Berechnet Phasen-Kohärenz-Matrix
+    pub fn phase_coherence_matrix(&self) -> [[f64; NUM_NODES]; NUM_NODES] {
+        let mut coherence = [[0.0; NUM_NODES]; NUM_NODES];
+
+        for i in 0..NUM_NODES {
+            for j in 0..NUM_NODES {
+                // Kohärenz: cos(Δφ)
+                coherence[i][j] = (self.phases[i] - self.phases[j]).cos();
+            }
+        }
+
+        coherence
+    }
+
+    /// Berechnet die Jacobi-Matrix der linearisierten Dynamik um die
+    /// aktuelle Phasenkonfiguration:
+    ///
+    /// Jᵢⱼ = κᵢⱼ·cos(φⱼ−φᵢ) für i≠j,  Jᵢᵢ = −Σⱼ κᵢⱼ·cos(φⱼ−φᵢ)
+    ///
+    /// Dies ist ein gewichteter Graph-Laplacian, ausgewertet bei den
+    /// aktuellen Phasen; für symmetrische Kopplung ist J selbst
+    /// symmetrisch.
+    pub fn jacobian(&self) -> SMatrix<f64, NUM_NODES, NUM_NODES> {
+        let mut jacobian = SMatrix::<f64, NUM_NODES, NUM_NODES>::zeros();
+
+        for i in 0..NUM_NODES {
+            let mut diagonal = 0.0;
+            for j in 0..NUM_NODES {
+                if i == j {
+                    continue;
+                }
+                let coupling = self.coupling_matrix[i][j];
+                if coupling != 0.0 {
+                    let weight = coupling * (self.phases[j] - self.phases[i]).cos();
+                    jacobian[(i, j)] = weight;
+                    diagonal -= weight;
+                }
+            }
+            jacobian[(i, i)] = diagonal;
+        }
+
+        jacobian
+    }
+
+    /// Berechnet das Stabilitätsspektrum: die sortierten Eigenwerte der
+    /// Jacobi-Matrix an der aktuellen Phasenkonfiguration
+    /// (Lyapunov-artige Exponenten der linearisierten Dynamik).
+    ///
+    /// Da die Jacobi-Matrix für symmetrische Kopplung selbst symmetrisch
+    /// ist, genügt ein reeller symmetrischer Eigenwertlöser (analog zur
+    /// hermiteschen Eigenwertzerlegung, die an anderer Stelle für
+    /// Matrixfunktionen verwendet wird). Der triviale Nullmodus (globale
+    /// Phasenverschiebung) liefert stets einen Eigenwert ≈0.
+    pub fn stability_spectrum(&self) -> [f64; NUM_NODES] {
+        use nalgebra::SymmetricEigen;
+
+        let eigen = SymmetricEigen::new(self.jacobian());
+        let mut eigenvalues: Vec<f64> = eigen.eigenvalues.iter().copied().collect();
+        eigenvalues.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        eigenvalues.try_into().unwrap()
+    }
+
+    /// Prüft lineare Stabilität: der synchronisierte Zustand ist
+    /// linear stabil, wenn alle Eigenwerte der Jacobi-Matrix außer dem
+    /// trivialen Nullmodus (globale Phasenverschiebung) ≤ `tol` sind.
+    pub fn is_linearly_stable(&self, tol: f64) -> bool {
+        let spectrum = self.stability_spectrum();
+
+        // Der größte Eigenwert ist der triviale Nullmodus (≈0); alle
+        // übrigen müssen nicht-positiv sein.
+        spectrum
+            .iter()
+            .rev()
+            .skip(1)
+            .all(|&lambda| lambda <= tol)
+    }
+}
+
+impl Default for KuramotoNetwork {
+    fn default() -> Self {
+        Self::uniform(0.0, 1.0)
+    }
+}
+
+impl std::fmt::Display for KuramotoNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (r, theta) = self.order_parameter();
+        writeln!(f, "Kuramoto-Netzwerk (N={}):", NUM_NODES)?;
+        writeln!(f, "  Ordnungsparameter r: {:.4}", r)?;
+        writeln!(f, "  Globale Phase Θ: {:.4} rad", theta)?;
+        writeln!(f, "  Synchronisiert: {}", self.is_synchronized(0.9))?;
+        writeln!(f, "  Kritische Kopplung: {:.4}", self.critical_coupling())?;
+
+        writeln!(f, "\nPhasen:")?;
+        for (i, &phi) in self.phases.iter().enumerate() {
+            writeln!(
+                f,
+                "  φ{:2} = {:.4} rad  (ω = {:.4})",
+                i, phi, self.frequencies[i]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_kuramoto_creation() {
+        let network = KuramotoNetwork::uniform(1.0, 2.0);
+        assert_eq!(network.phases.len(), NUM_NODES);
+        assert_eq!(network.frequencies.len(), NUM_NODES);
+    }
+
+    #[test]
+    fn test_order_parameter_desynchronized() {
+        let mut network = KuramotoNetwork::uniform(0.0, 0.0);
+        network.randomize_phases();
+
+        let (r, _) = network.order_parameter();
+        // Zufällige Phasen sollten r ≈ 0 haben
+        assert!(r < 0.5);
+    }
+
+    #[test]
+    fn test_order_parameter_synchronized() {
+        let mut network = KuramotoNetwork::uniform(0.0, 0.0);
+        // Alle Phasen gleich
+        for phase in &mut network.phases {
+            *phase = 0.0;
+        }
+
+        let (r, _) = network.order_parameter();
+        assert_abs_diff_eq!(r, 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_evolution_preserves_range() {
+        let mut network = KuramotoNetwork::uniform(1.0, 1.0);
+
+        for _ in 0..100 {
+            network.evolve(0.01);
+        }
+
+        // Alle Phasen sollten in [0, 2π] bleiben
+        for &phase in &network.phases {
+            assert!(phase >= 0.0 && phase < 2.0 * PI);
+        }
+    }
+
+    #[test]
+    fn test_synchronization_convergence() {
+        // Starke Kopplung sollte zu Synchronisation führen
+        let mut network = KuramotoNetwork::uniform(1.0, 10.0);
+        network.randomize_phases();
+
+        let r_initial = network.synchronization();
+
+        for _ in 0..1000 {
+            network.evolve_rk4(0.01);
+        }
+
+        let r_final = network.synchronization();
+
+        // r sollte zunehmen (Synchronisation)
+        assert!(r_final > r_initial);
+    }
+
+    #[test]
+    fn test_critical_coupling() {
+        let network = KuramotoNetwork::uniform(1.0, 1.0);
+        let kappa_c = network.critical_coupling();
+
+        // κ_c sollte positiv sein
+        assert!(kappa_c > 0.0);
+    }
+
+    #[test]
+    fn test_local_order_parameters() {
+        let network = KuramotoNetwork::uniform(0.0, 1.0);
+        let local_r = network.local_order_parameters();
+
+        // Alle lokalen r sollten in [0,1] sein
+        for &r in &local_r {
+            assert!(r >= 0.0 && r <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_phase_coherence() {
+        let network = KuramotoNetwork::uniform(0.0, 1.0);
+        let coherence = network.phase_coherence_matrix();
+
+        // Kohärenz sollte symmetrisch sein
+        for i in 0..NUM_NODES {
+            for j in 0..NUM_NODES {
+                assert_abs_diff_eq!(coherence[i][j], coherence[j][i], epsilon = 1e-10);
+            }
+        }
+
+        // Diagonale sollte 1 sein
+        for i in 0..NUM_NODES {
+            assert_abs_diff_eq!(coherence[i][i], 1.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_jacobian_is_symmetric_for_symmetric_coupling() {
+        let mut network = KuramotoNetwork::uniform(0.0, 1.0);
+        network.randomize_phases();
+        let jacobian = network.jacobian();
+
+        for i in 0..NUM_NODES {
+            for j in 0..NUM_NODES {
+                assert_abs_diff_eq!(jacobian[(i, j)], jacobian[(j, i)], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobian_rows_sum_to_zero() {
+        // Wie ein Graph-Laplacian sollte jede Zeile Summe 0 haben
+        let mut network = KuramotoNetwork::uniform(0.0, 1.0);
+        network.randomize_phases();
+        let jacobian = network.jacobian();
+
+        for i in 0..NUM_NODES {
+            let row_sum: f64 = jacobian.row(i).iter().sum();
+            assert_abs_diff_eq!(row_sum, 0.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_fully_synchronized_state_is_stable() {
+        // Identische Phasen sind ein Fixpunkt der Dynamik; da κᵢⱼ ≥ 0
+        // sollte dieser Zustand linear stabil sein (nur der triviale
+        // Nullmodus bleibt übrig).
+        let mut network = KuramotoNetwork::uniform(0.0, 1.0);
+        for phase in &mut network.phases {
+            *phase = 0.0;
+        }
+
+        assert!(network.is_linearly_stable(1e-9));
+    }
+
+    #[test]
+    fn test_stability_spectrum_has_trivial_zero_mode() {
+        let mut network = KuramotoNetwork::uniform(0.0, 1.0);
+        for phase in &mut network.phases {
+            *phase = 0.0;
+        }
+
+        let spectrum = network.stability_spectrum();
+        // Der größte Eigenwert (globale Phasenverschiebung) ist ≈0
+        assert_abs_diff_eq!(spectrum[NUM_NODES - 1], 0.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_fully_synchronized_network_is_one_maximal_clique() {
+        let mut network = KuramotoNetwork::uniform(0.0, 1.0);
+        for phase in &mut network.phases {
+            *phase = 0.0;
+        }
+
+        let cliques = network.maximal_sync_cliques(1e-6);
+        assert_eq!(cliques.len(), 1);
+        assert_eq!(cliques[0].len(), NUM_NODES);
+    }
+
+    #[test]
+    fn test_disjoint_phase_groups_yield_separate_cliques() {
+        // Zentrum (v1) ist mit jedem anderen Knoten graph-verbunden, also
+        // aus der Kopplungsmatrix ausgeklammert: κ=0 verhindert, dass
+        // `has_edge` zusätzliche Cliquen-Kanten einzieht.
+        let mut network = KuramotoNetwork::uniform(0.0, 0.0);
+        for (i, phase) in network.phases.iter_mut().enumerate() {
+            *phase = if i % 2 == 0 { 0.0 } else { PI };
+        }
+        network.coupling_matrix = [[0.0; NUM_NODES]; NUM_NODES];
+
+        let cliques = network.maximal_sync_cliques(1e-6);
+        // Jede Clique darf nur Phasen aus derselben Gruppe enthalten
+        for clique in &cliques {
+            let phases: Vec<f64> = clique.iter().map(|&i| network.phases[i]).collect();
+            let all_zero = phases.iter().all(|&p| p.abs() < 1e-9);
+            let all_pi = phases.iter().all(|&p| (p - PI).abs() < 1e-9);
+            assert!(all_zero || all_pi);
+        }
+    }
+
+    #[test]
+    fn test_record_sample_reflects_full_synchronization() {
+        let mut network = KuramotoNetwork::uniform(1.5, 1.0);
+        for phase in &mut network.phases {
+            *phase = 0.0;
+        }
+
+        network.record_sample();
+
+        let snapshot = network.snapshot();
+        assert_eq!(snapshot.order_parameter_history.len(), 1);
+        assert_abs_diff_eq!(snapshot.order_parameter_history[0].r, 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(
+            snapshot.order_parameter_history[0].mean_frequency,
+            1.5,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_snapshot_caps_history_at_configured_capacity() {
+        let mut network = KuramotoNetwork::uniform(1.0, 1.0);
+        for _ in 0..10 {
+            network.record_sample();
+        }
+
+        let snapshot = network.snapshot();
+        assert!(snapshot.order_parameter_history.len() <= 5);
+    }
+
+    #[test]
+    fn test_frequency_kalman_filter_converges_to_constant_measurement() {
+        let mut filter = FrequencyKalmanFilter::new(0.0, 1e-4, 1e-2, 5.0);
+        for _ in 0..200 {
+            assert!(filter.observe(2.0));
+        }
+        assert_abs_diff_eq!(filter.freq_estimate, 2.0, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_frequency_kalman_filter_rejects_outlier() {
+        let mut filter = FrequencyKalmanFilter::new(0.0, 1e-6, 1e-4, 3.0);
+        for _ in 0..50 {
+            filter.observe(1.0);
+        }
+        let converged = filter.freq_estimate;
+
+        let accepted = filter.observe(1000.0);
+        assert!(!accepted);
+        assert_eq!(filter.discarded_count, 1);
+        assert_abs_diff_eq!(filter.freq_estimate, converged, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_enable_frequency_adaptation_tracks_noise_free_phase_advance() {
+        // Ohne Kopplung ist der Phasenvorschub exakt ωᵢ·dt, sodass der
+        // Kalman-Filter die wahre Frequenz rauschfrei nachführen sollte.
+        let mut network = KuramotoNetwork::uniform(2.0, 0.0);
+        for phase in &mut network.phases {
+            *phase = 0.0;
+        }
+        network.enable_frequency_adaptation_default();
+
+        for _ in 0..20 {
+            network.evolve_rk4(0.01);
+        }
+
+        for &freq in &network.frequencies {
+            assert_abs_diff_eq!(freq, 2.0, epsilon = 1e-2);
+        }
+
+        let snapshot = network.snapshot();
+        assert!(!snapshot.frequency_estimate_history.is_empty());
+        assert!(snapshot
+            .frequency_estimate_history
+            .iter()
+            .all(|sample| sample.outcome == FrequencyEstimateOutcome::Accepted));
+    }
+
+    #[test]
+    fn test_disable_frequency_adaptation_stops_further_updates() {
+        let mut network = KuramotoNetwork::uniform(1.0, 0.0);
+        network.enable_frequency_adaptation_default();
+        network.evolve_rk4(0.01);
+        assert!(network.frequency_filters().is_some());
+
+        network.disable_frequency_adaptation();
+        assert!(network.frequency_filters().is_none());
+
+        let frequencies_before = network.frequencies;
+        network.evolve_rk4(0.01);
+        assert_eq!(network.frequencies, frequencies_before);
+    }
+}
@@ -2,13 +2,34 @@
 //!
 //! Implementiert resonanzbasiertes Routing und adaptive Filterung:
 //! - Kuramoto: Synchronisationsnetzwerk
+//! - Control: Optimale Steuerung des Kuramoto-Kopplungsplans
+//! - Trajectory: Aufzeichnung und tar-Archivierung von Kuramoto-Läufen
+//! - PhaseSpectrum: Lorentz-Resonanzanpassung der Phasendynamik
+//! - Inference: Sparse Kopplungsmatrix-Inferenz via ISTA
 //! - Absorber: Resonant Absorber Layer (RAL)
 //! - Spectrum: FFT-basiertes Spektral-Fingerprinting
+//! - Diagnostics: Ring-gepufferte Historie von Ordnungsparameter-, Klassifikations- und Circuit-Count-Proben
 
 pub mod absorber;
+pub mod control;
+pub mod diagnostics;
+pub mod inference;
 pub mod kuramoto;
+pub mod phase_spectrum;
 pub mod spectrum;
+pub mod trajectory;
 
 pub use absorber::{AbsorberStats, ResonantAbsorber};
+pub use control::{ControlSchedule, SynchronizationController};
+pub use diagnostics::{
+    CircuitCountDeltaSample, Diagnostics, DiagnosticsSnapshot, OrderParameterSample,
+    SpectralClassificationSample,
+};
+pub use inference::CouplingInference;
 pub use kuramoto::KuramotoNetwork;
-pub use spectrum::{SpectralFingerprint, TrafficType};
+pub use phase_spectrum::{spectral_analysis, PhaseSpectralFingerprint, Resonance};
+pub use spectrum::{
+    BaselineFeature, BaselineModel, Detection, FingerprintLibrary, SpectralClassifier, SpectralConfig,
+    SpectralFingerprint, TrafficType, WindowFunction,
+};
+pub use trajectory::{IntegratorKind, TrajectoryRecorder, TrajectoryStep};
@@ -0,0 +1,294 @@
+//! Spektrale Resonanzanalyse der Phasendynamik
+//!
+//! Zerlegt eine aufgezeichnete Zeitreihe des komplexen
+//! Ordnungsparameters z(t) = r(t)·e^{iΘ(t)} (oder eines einzelnen
+//! Phasensignals e^{iφᵢ(t)}) in eine Summe von Lorentz-Linien:
+//!
+//! L(f) = A·(Γ/2)² / ((f−f₀)² + (Γ/2)²)
+//!
+//! Dies deckt Teil-Synchronisation und Cluster-Frequenzen auf, die der
+//! skalare Wert von `KuramotoNetwork::synchronization()` verbirgt.
+//!
+//! Hinweis: dieses Modul liefert einen eigenständigen
+//! `PhaseSpectralFingerprint` und ist bewusst getrennt von
+//! [`crate::resonance::spectrum::SpectralFingerprint`], das
+//! Paket-Bytes klassifiziert — beide analysieren unterschiedliche
+//! Signaldomänen.
+
+use num_complex::Complex64;
+use rustfft::{num_complex::Complex, FftPlanner};
+use serde::{Deserialize, Serialize};
+
+/// Eine einzelne Resonanz, extrahiert aus dem Leistungsspektrum
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Resonance {
+    /// Amplitude A der Lorentz-Linie
+    pub amplitude: f64,
+    /// Zentralfrequenz f₀
+    pub center_frequency: f64,
+    /// Linienbreite Γ (volle Breite bei halbem Maximum)
+    pub linewidth: f64,
+}
+
+impl Resonance {
+    /// Gütefaktor Q = f₀/Γ
+    pub fn quality_factor(&self) -> f64 {
+        if self.linewidth.abs() < 1e-15 {
+            f64::INFINITY
+        } else {
+            self.center_frequency.abs() / self.linewidth
+        }
+    }
+
+    /// Wertet die Lorentz-Linie L(f) an der Frequenz `f` aus
+    pub fn evaluate(&self, f: f64) -> f64 {
+        let half_width = self.linewidth / 2.0;
+        self.amplitude * half_width * half_width
+            / ((f - self.center_frequency).powi(2) + half_width * half_width)
+    }
+}
+
+/// Spektrales Fingerprint der Phasendynamik: eine Liste angepasster
+/// Resonanzen, sortiert nach absteigender Amplitude
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseSpectralFingerprint {
+    /// Angepasste Resonanzen, absteigend nach Amplitude sortiert
+    pub resonances: Vec<Resonance>,
+    /// Leistung der DC-Komponente (globale Phasenverschiebung)
+    pub dc_power: f64,
+    /// Rauschboden, oberhalb dessen Peaks akzeptiert wurden
+    pub noise_floor: f64,
+}
+
+/// Extrahiert dominante Resonanzpeaks aus einer Zeitreihe des
+/// komplexen Signals `e^{iφ(t)}` (z.B. des Ordnungsparameters).
+///
+/// `dt` ist der Zeitschritt zwischen Samples, `noise_floor_ratio`
+/// legt den Rauschboden relativ zur Spitzenleistung fest (z.B. 0.05
+/// für 5% der maximalen Leistung). Peaks, deren Zentralfrequenzen
+/// innerhalb eines Frequenz-Bins liegen, werden zusammengeführt
+/// (der mit größerer Amplitude gewinnt).
+pub fn spectral_analysis(
+    signal: &[Complex64],
+    dt: f64,
+    noise_floor_ratio: f64,
+) -> PhaseSpectralFingerprint {
+    let n = signal.len();
+    assert!(n >= 4, "Zeitreihe benötigt mindestens 4 Samples");
+    assert!(dt > 0.0, "dt muss positiv sein");
+
+    let mut buffer: Vec<Complex<f64>> = signal.iter().map(|z| Complex::new(z.re, z.im)).collect();
+
+    let mut planner = FftPlanner::<f64>::new();
+    let fft = planner.plan_fft_forward(n);
+    fft.process(&mut buffer);
+
+    let power: Vec<f64> = buffer.iter().map(|c| c.norm_sqr() / (n as f64)).collect();
+    let freq_bin = 1.0 / (n as f64 * dt);
+
+    // Frequenzen gemäß Standard-FFT-Layout: [0, 1, ..., n/2, -(n/2-1), ..., -1] * freq_bin
+    let frequency_at = |k: usize| -> f64 {
+        if k <= n / 2 {
+            k as f64 * freq_bin
+        } else {
+            (k as f64 - n as f64) * freq_bin
+        }
+    };
+
+    let dc_power = power[0];
+    let max_power = power.iter().skip(1).cloned().fold(0.0_f64, f64::max);
+    let noise_floor = max_power * noise_floor_ratio;
+
+    // Lokale Maxima oberhalb des Rauschbodens finden (DC, k=0, separat behandelt)
+    let mut peak_indices = Vec::new();
+    for k in 1..n {
+        let prev = power[(k + n - 1) % n];
+        let next = power[(k + 1) % n];
+        if power[k] > noise_floor && power[k] >= prev && power[k] >= next && power[k] > 0.0 {
+            peak_indices.push(k);
+        }
+    }
+
+    let mut resonances: Vec<Resonance> = peak_indices
+        .into_iter()
+        .map(|k| fit_lorentzian(&power, k, freq_bin, n, &frequency_at))
+        .collect();
+
+    resonances.sort_by(|a, b| b.amplitude.partial_cmp(&a.amplitude).unwrap());
+    merge_close_peaks(&mut resonances, freq_bin);
+
+    PhaseSpectralFingerprint {
+        resonances,
+        dc_power,
+        noise_floor,
+    }
+}
+
+/// Schätzt Amplitude, Zentralfrequenz und Linienbreite (volle Breite
+/// bei halbem Maximum) eines Peaks an Bin `k` durch Abschreiten der
+/// Flanken bis zum Abfall auf die halbe Spitzenleistung.
+fn fit_lorentzian(
+    power: &[f64],
+    k: usize,
+    freq_bin: f64,
+    n: usize,
+    frequency_at: &impl Fn(usize) -> f64,
+) -> Resonance {
+    let amplitude = power[k];
+    let half_power = amplitude / 2.0;
+
+    let left_bins = half_width_bins(power, k, half_power, n, true);
+    let right_bins = half_width_bins(power, k, half_power, n, false);
+
+    // Mindestens eine Bin-Breite, damit Γ>0 bleibt, falls die Flanke
+    // innerhalb eines einzelnen Bins unter die halbe Leistung fällt.
+    let linewidth = ((left_bins + right_bins).max(1.0)) * freq_bin;
+
+    Resonance {
+        amplitude,
+        center_frequency: frequency_at(k),
+        linewidth,
+    }
+}
+
+/// Läuft vom Peak-Bin `k` in eine Richtung, bis die Leistung unter
+/// `half_power` fällt, und liefert die (linear interpolierte)
+/// Bin-Distanz bis dahin.
+fn half_width_bins(power: &[f64], k: usize, half_power: f64, n: usize, towards_left: bool) -> f64 {
+    let step: isize = if towards_left { -1 } else { 1 };
+    let mut offset: isize = 0;
+    let mut prev_power = power[k];
+
+    loop {
+        offset += step;
+        if offset.unsigned_abs() >= n / 2 {
+            // Spektrum komplett durchlaufen, ohne unter half_power zu fallen
+            return (n / 2) as f64;
+        }
+        let idx = ((k as isize + offset).rem_euclid(n as isize)) as usize;
+        let current_power = power[idx];
+
+        if current_power <= half_power {
+            // Lineare Interpolation zwischen prev_power und current_power
+            let denom = prev_power - current_power;
+            let frac = if denom.abs() > 1e-15 {
+                (prev_power - half_power) / denom
+            } else {
+                1.0
+            };
+            return (offset.unsigned_abs() as f64 - 1.0) + frac;
+        }
+        prev_power = current_power;
+    }
+}
+
+/// Führt Peaks zusammen, deren Zentralfrequenzen näher als ein
+/// Frequenz-Bin beieinander liegen; behält jeweils den mit der
+/// größeren Amplitude. `resonances` muss bereits nach Amplitude
+/// absteigend sortiert sein.
+fn merge_close_peaks(resonances: &mut Vec<Resonance>, freq_bin: f64) {
+    let mut merged: Vec<Resonance> = Vec::with_capacity(resonances.len());
+
+    'outer: for candidate in resonances.drain(..) {
+        for kept in &merged {
+            if (candidate.center_frequency - kept.center_frequency).abs() < freq_bin {
+                continue 'outer;
+            }
+        }
+        merged.push(candidate);
+    }
+
+    *resonances = merged;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_single_tone_produces_one_dominant_resonance() {
+        let n = 256;
+        let dt = 0.01;
+        let freq = 5.0; // Hz
+        let signal: Vec<Complex64> = (0..n)
+            .map(|i| Complex64::from_polar(1.0, 2.0 * PI * freq * (i as f64) * dt))
+            .collect();
+
+        let fingerprint = spectral_analysis(&signal, dt, 0.1);
+
+        assert!(!fingerprint.resonances.is_empty());
+        let dominant = &fingerprint.resonances[0];
+        assert!((dominant.center_frequency.abs() - freq).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_resonances_sorted_by_amplitude_descending() {
+        let n = 256;
+        let dt = 0.01;
+        let signal: Vec<Complex64> = (0..n)
+            .map(|i| {
+                let t = i as f64 * dt;
+                Complex64::from_polar(1.0, 2.0 * PI * 3.0 * t)
+                    + Complex64::from_polar(0.3, 2.0 * PI * 11.0 * t)
+            })
+            .collect();
+
+        let fingerprint = spectral_analysis(&signal, dt, 0.05);
+
+        for pair in fingerprint.resonances.windows(2) {
+            assert!(pair[0].amplitude >= pair[1].amplitude);
+        }
+    }
+
+    #[test]
+    fn test_dc_component_handled_separately() {
+        let n = 64;
+        let dt = 0.01;
+        let signal: Vec<Complex64> = vec![Complex64::new(1.0, 0.0); n];
+
+        let fingerprint = spectral_analysis(&signal, dt, 0.1);
+
+        assert!(fingerprint.dc_power > 0.0);
+        // Eine konstante Zeitreihe hat keine nicht-triviale Resonanz
+        // abseits der DC-Komponente.
+        assert!(fingerprint.resonances.is_empty());
+    }
+
+    #[test]
+    fn test_quality_factor_positive_for_nonzero_linewidth() {
+        let resonance = Resonance {
+            amplitude: 1.0,
+            center_frequency: 10.0,
+            linewidth: 2.0,
+        };
+
+        assert!((resonance.quality_factor() - 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_merge_close_peaks_deduplicates() {
+        let mut resonances = vec![
+            Resonance {
+                amplitude: 2.0,
+                center_frequency: 5.0,
+                linewidth: 0.5,
+            },
+            Resonance {
+                amplitude: 1.0,
+                center_frequency: 5.05,
+                linewidth: 0.5,
+            },
+            Resonance {
+                amplitude: 1.5,
+                center_frequency: 20.0,
+                linewidth: 0.5,
+            },
+        ];
+
+        merge_close_peaks(&mut resonances, 0.2);
+
+        assert_eq!(resonances.len(), 2);
+        assert_eq!(resonances[0].center_frequency, 5.0);
+    }
+}
@@ -2,8 +2,82 @@
 //!
 //! FFT-basierte spektrale Analyse für Paket-Klassifikation
 
+use gbdt::config::Config as GbdtConfig;
+use gbdt::decision_tree::{Data as GbdtData, DataVec as GbdtDataVec};
+use gbdt::gradient_boost::GBDT;
 use rustfft::{num_complex::Complex, FftPlanner};
 use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Fensterfunktion, die [`SpectralFingerprint::compute_with`] vor der FFT
+/// auf jedes Segment anwendet, um spektrales Leck-Verhalten (spectral
+/// leakage) durch abrupte Segmentränder zu reduzieren.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowFunction {
+    /// Kein Fenster (alle Koeffizienten 1.0) — Verhalten von
+    /// [`SpectralFingerprint::compute`].
+    Rectangular,
+    /// Hann-Fenster: `0.5 - 0.5*cos(2π·n/(N-1))`.
+    Hann,
+    /// Hamming-Fenster: `0.54 - 0.46*cos(2π·n/(N-1))`.
+    Hamming,
+    /// Blackman-Fenster: `0.42 - 0.5*cos(2π·n/(N-1)) + 0.08*cos(4π·n/(N-1))`.
+    Blackman,
+}
+
+impl WindowFunction {
+    /// Berechnet die `n` Fensterkoeffizienten dieser Fensterfunktion.
+    fn coefficients(&self, n: usize) -> Vec<f64> {
+        if n < 2 {
+            return vec![1.0; n];
+        }
+
+        let denom = (n - 1) as f64;
+        match self {
+            WindowFunction::Rectangular => vec![1.0; n],
+            WindowFunction::Hann => (0..n)
+                .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f64 / denom).cos())
+                .collect(),
+            WindowFunction::Hamming => (0..n)
+                .map(|i| 0.54 - 0.46 * (2.0 * PI * i as f64 / denom).cos())
+                .collect(),
+            WindowFunction::Blackman => (0..n)
+                .map(|i| {
+                    let phase = 2.0 * PI * i as f64 / denom;
+                    0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos()
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Konfiguration für [`SpectralFingerprint::compute_with`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpectralConfig {
+    /// Größe der FFT je Segment (Power of 2).
+    pub fft_size: usize,
+    /// Fensterfunktion, die vor jeder Segment-FFT angewendet wird.
+    pub window: WindowFunction,
+    /// Überlappung aufeinanderfolgender Segmente als Anteil von
+    /// `fft_size`, z. B. `0.5` für 50% Überlappung. Muss in `[0, 1)` liegen.
+    pub overlap: f64,
+    /// Obergrenze der gemittelten Segmente (Welch-Mittelung), oder `None`
+    /// für so viele Segmente, wie in `data` Platz finden.
+    pub segments: Option<usize>,
+}
+
+impl Default for SpectralConfig {
+    /// Rechteckfenster, keine Überlappung, ein einziges Segment — identisch
+    /// zum Verhalten von [`SpectralFingerprint::compute`].
+    fn default() -> Self {
+        Self {
+            fft_size: 256,
+            window: WindowFunction::Rectangular,
+            overlap: 0.0,
+            segments: Some(1),
+        }
+    }
+}
 
 /// Spektrales Fingerprint eines Datenpakets
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,6 +143,81 @@ impl SpectralFingerprint {
         }
     }
 
+    /// Berechnet ein spektrales Fingerprint wie [`Self::compute`], jedoch
+    /// konfigurierbar über [`SpectralConfig`]: ein Fenster gegen
+    /// spektrales Leck-Verhalten ([`WindowFunction`]) und
+    /// Welch-Mittelung über mehrere überlappende Segmente gegen
+    /// hochvariante Einzel-FFT-Schätzungen bei langen oder verrauschten
+    /// Paketen.
+    ///
+    /// Daten kürzer als `config.fft_size` ergeben genau ein
+    /// Null-gepaddetes Segment, identisch zu [`Self::compute`].
+    pub fn compute_with(data: &[u8], config: &SpectralConfig) -> Self {
+        assert!(config.fft_size.is_power_of_two(), "FFT-Größe muss 2^n sein");
+        assert!((0.0..1.0).contains(&config.overlap), "overlap muss in [0, 1) liegen");
+
+        let window_coefficients = config.window.coefficients(config.fft_size);
+        let step = ((config.fft_size as f64) * (1.0 - config.overlap)).round().max(1.0) as usize;
+
+        let mut segment_offsets = vec![0usize];
+        while segment_offsets.last().copied().unwrap_or(0) + config.fft_size < data.len() {
+            let next_offset = segment_offsets.last().unwrap() + step;
+            if config.segments.is_some_and(|max| segment_offsets.len() >= max) {
+                break;
+            }
+            segment_offsets.push(next_offset);
+        }
+
+        let mut planner = FftPlanner::<f64>::new();
+        let fft = planner.plan_fft_forward(config.fft_size);
+
+        let mut accumulated_spectrum = vec![0.0; config.fft_size];
+        for &start in &segment_offsets {
+            let end = (start + config.fft_size).min(data.len());
+            let mut signal: Vec<f64> = data[start..end].iter().map(|&b| b as f64 / 255.0).collect();
+            signal.resize(config.fft_size, 0.0);
+
+            for (sample, &coefficient) in signal.iter_mut().zip(window_coefficients.iter()) {
+                *sample *= coefficient;
+            }
+
+            let mut buffer: Vec<Complex<f64>> = signal.iter().map(|&x| Complex::new(x, 0.0)).collect();
+            fft.process(&mut buffer);
+
+            let power_spectrum: Vec<f64> = buffer.iter().map(|c| c.norm_sqr()).collect();
+            let total_power: f64 = power_spectrum.iter().sum();
+            let normalized_spectrum: Vec<f64> = if total_power > 1e-10 {
+                power_spectrum.iter().map(|&p| p / total_power).collect()
+            } else {
+                vec![1.0 / config.fft_size as f64; config.fft_size]
+            };
+
+            for (accumulated, &p) in accumulated_spectrum.iter_mut().zip(normalized_spectrum.iter()) {
+                *accumulated += p;
+            }
+        }
+
+        let segment_count = segment_offsets.len() as f64;
+        let averaged_spectrum: Vec<f64> =
+            accumulated_spectrum.iter().map(|&p| p / segment_count).collect();
+
+        let mut freq_power: Vec<(usize, f64)> = averaged_spectrum
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| (i, p))
+            .collect();
+        freq_power.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        let dominant_frequencies: Vec<usize> = freq_power.iter().take(5).map(|&(i, _)| i).collect();
+
+        let spectral_entropy = Self::compute_entropy(&averaged_spectrum);
+
+        Self {
+            power_spectrum: averaged_spectrum,
+            dominant_frequencies,
+            spectral_entropy,
+        }
+    }
+
     /// Berechnet Shannon-Entropie des Spektrums
     fn compute_entropy(spectrum: &[f64]) -> f64 {
         -spectrum
@@ -155,6 +304,340 @@ impl SpectralFingerprint {
             TrafficType::Legitimate
         }
     }
+
+    /// Baut den festen Feature-Vektor für den [`SpectralClassifier`]:
+    /// spektrale Entropie, spektrale Kurtosis, die Top-k dominanten
+    /// Frequenzindizes (normalisiert auf die Spektrumlänge) und eine feste
+    /// Anzahl gemittelter log-Power-Spektrum-Bins.
+    fn classifier_features(&self) -> Vec<f64> {
+        let len = self.power_spectrum.len().max(1);
+        let mut features = vec![self.spectral_entropy, self.spectral_kurtosis()];
+
+        for i in 0..CLASSIFIER_TOP_K_FREQUENCIES {
+            let freq = self.dominant_frequencies.get(i).copied().unwrap_or(0);
+            features.push(freq as f64 / len as f64);
+        }
+
+        let bin_size = len.div_ceil(CLASSIFIER_NUM_SPECTRUM_BINS).max(1);
+        for bin in 0..CLASSIFIER_NUM_SPECTRUM_BINS {
+            let start = bin * bin_size;
+            if start >= self.power_spectrum.len() {
+                features.push(0.0);
+                continue;
+            }
+            let end = (start + bin_size).min(self.power_spectrum.len());
+            let avg_power = self.power_spectrum[start..end].iter().sum::<f64>() / (end - start) as f64;
+            features.push((avg_power + 1e-15).ln());
+        }
+
+        features
+    }
+}
+
+/// Feste Reihenfolge der Klassen, in der [`SpectralClassifier`] ein
+/// One-vs-Rest-GBDT je [`TrafficType`] trainiert und vorhält.
+const CLASSIFIER_CLASSES: [TrafficType; 3] =
+    [TrafficType::Legitimate, TrafficType::Bot, TrafficType::Suspicious];
+
+/// Anzahl der Top-dominanten Frequenzen im Feature-Vektor des
+/// [`SpectralClassifier`].
+const CLASSIFIER_TOP_K_FREQUENCIES: usize = 3;
+/// Anzahl der gemittelten log-Power-Spektrum-Bins im Feature-Vektor.
+const CLASSIFIER_NUM_SPECTRUM_BINS: usize = 8;
+/// Boosting-Iterationen beim Training je Klassen-Modell.
+const CLASSIFIER_ITERATIONS: usize = 50;
+/// Shrinkage (Lernrate) beim Training je Klassen-Modell.
+const CLASSIFIER_SHRINKAGE: f32 = 0.1;
+/// Maximale Baumtiefe beim Training je Klassen-Modell.
+const CLASSIFIER_MAX_DEPTH: u32 = 4;
+
+/// Lernbarer Ersatz für die Schwellenwert-Heuristik in
+/// [`SpectralFingerprint::classify_traffic`]: ein One-vs-Rest-Ensemble aus
+/// Gradient-Boosted-Decision-Trees (ein Modell je [`TrafficType`]), das auf
+/// [`SpectralFingerprint::classifier_features`] trainiert wird, damit
+/// Betreiber standortspezifische Bot/Legitimate/Suspicious-Grenzen lernen
+/// können, statt sich auf feste Schwellenwerte wie `0.9 * ln(n)` zu
+/// verlassen.
+///
+/// Solange kein Modell trainiert wurde (`models` ist `None`), fällt
+/// [`Self::predict`] auf [`SpectralFingerprint::classify_traffic`] zurück.
+pub struct SpectralClassifier {
+    /// Ein GBDT-Modell je Eintrag in `CLASSIFIER_CLASSES`, oder `None`
+    /// solange [`Self::train`] noch nicht erfolgreich aufgerufen wurde.
+    models: Option<Vec<GBDT>>,
+    /// Feature-Vektor-Breite, mit der `models` trainiert wurde.
+    feature_size: usize,
+}
+
+impl SpectralClassifier {
+    /// Erstellt einen Classifier ohne trainiertes Modell; bis zum ersten
+    /// erfolgreichen [`Self::train`]-Aufruf nutzt [`Self::predict`] die
+    /// Heuristik aus [`SpectralFingerprint::classify_traffic`].
+    pub fn new() -> Self {
+        Self {
+            models: None,
+            feature_size: 0,
+        }
+    }
+
+    /// Ob bereits ein trainiertes Modell geladen ist.
+    pub fn is_trained(&self) -> bool {
+        self.models.is_some()
+    }
+
+    /// Trainiert das One-vs-Rest-GBDT-Ensemble aus gelabelten Fingerprints.
+    ///
+    /// Für jede Klasse in `CLASSIFIER_CLASSES` wird ein eigenes GBDT mit
+    /// binärem `LogLikelyhood`-Loss gefittet (Ziel-Label 1.0, wenn der
+    /// Sample dieser Klasse angehört, sonst 0.0). Tut nichts, wenn
+    /// `labeled` leer ist.
+    pub fn train(&mut self, labeled: &[(SpectralFingerprint, TrafficType)]) {
+        if labeled.is_empty() {
+            return;
+        }
+
+        let feature_rows: Vec<Vec<f64>> = labeled.iter().map(|(fp, _)| fp.classifier_features()).collect();
+        let feature_size = feature_rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        if feature_size == 0 {
+            return;
+        }
+
+        let models = CLASSIFIER_CLASSES
+            .iter()
+            .map(|&class| {
+                let mut data: GbdtDataVec = labeled
+                    .iter()
+                    .zip(feature_rows.iter())
+                    .map(|((_, label), features)| {
+                        let mut row = features.clone();
+                        row.resize(feature_size, 0.0);
+                        GbdtData::new_training_data(
+                            row.iter().map(|&v| v as f32).collect(),
+                            1.0,
+                            if *label == class { 1.0 } else { 0.0 },
+                            None,
+                        )
+                    })
+                    .collect();
+
+                let mut config = GbdtConfig::new();
+                config.set_feature_size(feature_size);
+                config.set_max_depth(CLASSIFIER_MAX_DEPTH);
+                config.set_iterations(CLASSIFIER_ITERATIONS);
+                config.set_shrinkage(CLASSIFIER_SHRINKAGE);
+                config.set_loss("LogLikelyhood");
+                config.set_debug(false);
+
+                let mut model = GBDT::new(&config);
+                model.fit(&mut data);
+                model
+            })
+            .collect();
+
+        self.models = Some(models);
+        self.feature_size = feature_size;
+    }
+
+    /// Klassifiziert ein Fingerprint und liefert zusätzlich eine
+    /// Pseudo-Wahrscheinlichkeit je Klasse (die One-vs-Rest-Scores,
+    /// auf Summe 1 normalisiert). Fällt auf
+    /// [`SpectralFingerprint::classify_traffic`] zurück, solange kein
+    /// Modell trainiert wurde.
+    pub fn predict(&self, fp: &SpectralFingerprint) -> (TrafficType, Vec<(TrafficType, f64)>) {
+        let Some(models) = self.models.as_ref() else {
+            let traffic_type = fp.classify_traffic();
+            return (traffic_type, vec![(traffic_type, 1.0)]);
+        };
+
+        let mut features = fp.classifier_features();
+        features.resize(self.feature_size, 0.0);
+        let test_data: GbdtDataVec =
+            vec![GbdtData::new_test_data(features.iter().map(|&v| v as f32).collect(), None)];
+
+        let scores: Vec<f64> = models
+            .iter()
+            .map(|model| model.predict(&test_data)[0].max(0.0) as f64)
+            .collect();
+        let total: f64 = scores.iter().sum();
+
+        let probabilities: Vec<(TrafficType, f64)> = CLASSIFIER_CLASSES
+            .iter()
+            .zip(scores.iter())
+            .map(|(&class, &score)| (class, if total > 1e-10 { score / total } else { 0.0 }))
+            .collect();
+
+        let best = probabilities
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1 .1.partial_cmp(&b.1 .1).unwrap())
+            .map(|(idx, _)| CLASSIFIER_CLASSES[idx])
+            .unwrap_or(TrafficType::Legitimate);
+
+        (best, probabilities)
+    }
+}
+
+impl Default for SpectralClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Extrahiert das von einem [`BaselineModel`] beobachtete Feature aus
+/// einem Fingerprint.
+pub type BaselineFeature = fn(&SpectralFingerprint) -> f64;
+
+/// Standard-Feature eines [`BaselineModel`]: die spektrale Entropie.
+fn default_baseline_feature(fp: &SpectralFingerprint) -> f64 {
+    fp.spectral_entropy
+}
+
+/// Online-bayes'sches Anomalie-Scoring für ein skalares
+/// Fingerprint-Feature (Standard: [`SpectralFingerprint::spectral_entropy`]),
+/// als Ersatz für feste Schwellenwerte wie in
+/// [`SpectralFingerprint::is_flat`]/[`SpectralFingerprint::classify_traffic`].
+///
+/// Modelliert das Feature mit einer Normal-Likelihood unter einer
+/// konjugierten Normal-Gamma-Prior und hält deren Hyperparameter
+/// (`μ₀, κ, α, β`) laufend aktuell. [`Self::update`] speist eine neue
+/// Beobachtung ein, [`Self::score`] liefert die posterior-prädiktive
+/// Dichte (eine Student-t-Verteilung) eines neuen Werts unter dem
+/// aktuellen Modell, und [`Self::is_anomalous`] flaggt Werte mit
+/// niedriger prädiktiver Dichte.
+#[derive(Debug, Clone)]
+pub struct BaselineModel {
+    /// Posterior-Erwartungswert μ₀.
+    mu0: f64,
+    /// Posterior-Präzisions-Skalierung κ.
+    kappa: f64,
+    /// Posterior-Gamma-Formparameter α.
+    alpha: f64,
+    /// Posterior-Gamma-Ratenparameter β.
+    beta: f64,
+    /// Feature-Extraktor, über den [`Self::observe`]/[`Self::score`] einen
+    /// Fingerprint auf den beobachteten Skalar abbilden.
+    feature: BaselineFeature,
+    /// Anzahl bisher eingespeister Beobachtungen.
+    observations: u64,
+}
+
+impl BaselineModel {
+    /// Erstellt ein Baseline-Modell über die spektrale Entropie mit einer
+    /// uninformativen Normal-Gamma-Prior (`μ₀=0, κ=1, α=1, β=1`).
+    pub fn new() -> Self {
+        Self::with_prior(0.0, 1.0, 1.0, 1.0)
+    }
+
+    /// Erstellt ein Baseline-Modell über die spektrale Entropie mit
+    /// expliziten Normal-Gamma-Hyperparametern.
+    pub fn with_prior(mu0: f64, kappa: f64, alpha: f64, beta: f64) -> Self {
+        Self::with_feature(mu0, kappa, alpha, beta, default_baseline_feature)
+    }
+
+    /// Wie [`Self::with_prior`], beobachtet jedoch `feature` statt der
+    /// Standard-spektralen Entropie.
+    pub fn with_feature(mu0: f64, kappa: f64, alpha: f64, beta: f64, feature: BaselineFeature) -> Self {
+        Self {
+            mu0,
+            kappa,
+            alpha,
+            beta,
+            feature,
+            observations: 0,
+        }
+    }
+
+    /// Anzahl bisher eingespeister Beobachtungen.
+    pub fn observations(&self) -> u64 {
+        self.observations
+    }
+
+    /// Aktualisiert die Normal-Gamma-Hyperparameter mit einer neuen
+    /// Beobachtung `x` über die Standard-Rekursionen:
+    /// `κ' = κ+1`, `μ' = (κμ₀+x)/κ'`, `α' = α+½`,
+    /// `β' = β + ½·κ(x−μ₀)²/κ'`.
+    pub fn update(&mut self, x: f64) {
+        let kappa_new = self.kappa + 1.0;
+        let mu_new = (self.kappa * self.mu0 + x) / kappa_new;
+        let alpha_new = self.alpha + 0.5;
+        let beta_new = self.beta + 0.5 * self.kappa * (x - self.mu0).powi(2) / kappa_new;
+
+        self.mu0 = mu_new;
+        self.kappa = kappa_new;
+        self.alpha = alpha_new;
+        self.beta = beta_new;
+        self.observations += 1;
+    }
+
+    /// Extrahiert `fp`'s Feature und speist es per [`Self::update`] ein.
+    pub fn observe(&mut self, fp: &SpectralFingerprint) {
+        let x = (self.feature)(fp);
+        self.update(x);
+    }
+
+    /// Posterior-prädiktive Dichte von `fp`'s Feature unter dem aktuellen
+    /// Modell: eine Student-t-Verteilung mit `ν = 2α` Freiheitsgraden,
+    /// Lagemaß `μ₀` und Skala `σ = sqrt(β(κ+1) / (ακ))`.
+    pub fn score(&self, fp: &SpectralFingerprint) -> f64 {
+        let x = (self.feature)(fp);
+        let degrees_of_freedom = 2.0 * self.alpha;
+        let scale = (self.beta * (self.kappa + 1.0) / (self.alpha * self.kappa)).sqrt();
+        student_t_pdf(x, degrees_of_freedom, self.mu0, scale)
+    }
+
+    /// Flaggt `fp` als anomal, wenn seine posterior-prädiktive Dichte
+    /// ([`Self::score`]) unter `p_threshold` liegt.
+    pub fn is_anomalous(&self, fp: &SpectralFingerprint, p_threshold: f64) -> bool {
+        self.score(fp) < p_threshold
+    }
+}
+
+impl Default for BaselineModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Dichte der Student-t-Verteilung mit `degrees_of_freedom` Freiheitsgraden,
+/// Lagemaß `location` und Skala `scale`.
+fn student_t_pdf(x: f64, degrees_of_freedom: f64, location: f64, scale: f64) -> f64 {
+    let z = (x - location) / scale;
+    let ln_normalizer = ln_gamma((degrees_of_freedom + 1.0) / 2.0)
+        - ln_gamma(degrees_of_freedom / 2.0)
+        - 0.5 * (degrees_of_freedom * PI).ln()
+        - scale.ln();
+
+    ln_normalizer.exp() * (1.0 + z * z / degrees_of_freedom).powf(-(degrees_of_freedom + 1.0) / 2.0)
+}
+
+/// Lanczos-Näherung von `ln(Γ(x))` für reelle `x`.
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_93,
+        676.520_368_121_885_1,
+        -1259.139_216_722_402_8,
+        771.323_428_777_653_13,
+        -176.615_029_162_140_59,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_312e-7,
+    ];
+
+    if x < 0.5 {
+        // Spiegelformel: Γ(x)Γ(1-x) = π / sin(πx)
+        (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x)
+    } else {
+        let x = x - 1.0;
+        let t = x + G + 0.5;
+        let sum = COEFFICIENTS[1..]
+            .iter()
+            .enumerate()
+            .fold(COEFFICIENTS[0], |acc, (i, &c)| acc + c / (x + i as f64 + 1.0));
+
+        0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+    }
 }
 
 /// Traffic-Klassifikation
@@ -178,6 +661,139 @@ impl std::fmt::Display for TrafficType {
     }
 }
 
+/// Eine gegen die [`FingerprintLibrary`] erkannte Übereinstimmung, bereits
+/// zu einem zusammenhängenden Bereich benachbarter Treffer-Fenster
+/// zusammengeführt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Detection {
+    /// Byte-Offset des ersten Fensters der Übereinstimmung (inklusive).
+    pub start_offset: usize,
+    /// Byte-Offset direkt hinter dem letzten Fenster der Übereinstimmung
+    /// (exklusive).
+    pub end_offset: usize,
+    /// Label des am besten passenden Patterns.
+    pub label: String,
+    /// Beste Pattern-Ähnlichkeit, die innerhalb des zusammengeführten
+    /// Bereichs beobachtet wurde.
+    pub score: f64,
+}
+
+/// Bibliothek bekannter Referenz-Fingerprints: "Patterns" (z. B. bekannte
+/// Angriffssignaturen) und "Anti-Patterns" (bekannter legitimer Traffic).
+/// [`FingerprintLibrary::detect`] tastet einen Byte-Stream mit einem
+/// gleitenden Fenster ab und klassifiziert jedes Fenster anhand der
+/// Kosinus-Ähnlichkeit ([`SpectralFingerprint::similarity`]) zur
+/// Bibliothek, statt nur ein einzelnes Paket einmalig zu bewerten.
+pub struct FingerprintLibrary {
+    patterns: Vec<(String, SpectralFingerprint)>,
+    anti_patterns: Vec<(String, SpectralFingerprint)>,
+    /// FFT-Größe, mit der jedes Fenster zu einem [`SpectralFingerprint`]
+    /// verarbeitet wird; muss eine Zweierpotenz sein (siehe
+    /// [`SpectralFingerprint::compute`]).
+    fft_size: usize,
+    /// Mindest-Ähnlichkeit zum besten Pattern, damit ein Fenster überhaupt
+    /// als Treffer zählt.
+    similarity_threshold: f64,
+    /// Marge, um die die beste Pattern-Ähnlichkeit die beste
+    /// Anti-Pattern-Ähnlichkeit übersteigen muss, damit ein Anti-Pattern
+    /// den Treffer nicht verwirft.
+    anti_pattern_margin: f64,
+}
+
+impl FingerprintLibrary {
+    /// Erstellt eine leere Bibliothek.
+    ///
+    /// # Arguments
+    /// * `fft_size` - FFT-Größe für Fenster-Fingerprints (Power of 2)
+    /// * `similarity_threshold` - Mindest-Ähnlichkeit für einen Treffer
+    /// * `anti_pattern_margin` - Vorsprung, den die beste Pattern-Ähnlichkeit
+    ///   vor der besten Anti-Pattern-Ähnlichkeit haben muss
+    pub fn new(fft_size: usize, similarity_threshold: f64, anti_pattern_margin: f64) -> Self {
+        Self {
+            patterns: Vec::new(),
+            anti_patterns: Vec::new(),
+            fft_size,
+            similarity_threshold,
+            anti_pattern_margin,
+        }
+    }
+
+    /// Hinterlegt `data` als benanntes Pattern (z. B. eine bekannte
+    /// Angriffssignatur).
+    pub fn add_pattern(&mut self, label: impl Into<String>, data: &[u8]) {
+        self.patterns.push((label.into(), SpectralFingerprint::compute(data, self.fft_size)));
+    }
+
+    /// Hinterlegt `data` als benanntes Anti-Pattern (z. B. bekannter
+    /// legitimer Traffic), das einen sonst passenden Treffer unterdrücken
+    /// kann.
+    pub fn add_anti_pattern(&mut self, label: impl Into<String>, data: &[u8]) {
+        self.anti_patterns.push((label.into(), SpectralFingerprint::compute(data, self.fft_size)));
+    }
+
+    /// Tastet `stream` mit einem gleitenden Fenster der Größe `window`
+    /// (Schrittweite `step`) ab, berechnet je Fenster ein
+    /// [`SpectralFingerprint`] und vergleicht es per
+    /// [`SpectralFingerprint::similarity`] gegen jedes Pattern und
+    /// Anti-Pattern. Ein Fenster gilt als Treffer, wenn seine beste
+    /// Pattern-Ähnlichkeit `similarity_threshold` überschreitet und die
+    /// beste Anti-Pattern-Ähnlichkeit um mindestens `anti_pattern_margin`
+    /// übertrifft. Benachbarte bzw. überlappende Treffer-Fenster mit
+    /// gleichem Label werden zu einer [`Detection`] zusammengeführt.
+    pub fn detect(&self, stream: &[u8], window: usize, step: usize) -> Vec<Detection> {
+        assert!(window > 0, "window muss > 0 sein");
+        assert!(step > 0, "step muss > 0 sein");
+
+        let mut window_matches: Vec<(usize, usize, String, f64)> = Vec::new();
+        let mut offset = 0;
+        while offset + window <= stream.len() {
+            let fingerprint = SpectralFingerprint::compute(&stream[offset..offset + window], self.fft_size);
+
+            let best_pattern = self
+                .patterns
+                .iter()
+                .map(|(label, pattern_fp)| (label, fingerprint.similarity(pattern_fp)))
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+            let best_anti_pattern_score = self
+                .anti_patterns
+                .iter()
+                .map(|(_, anti_fp)| fingerprint.similarity(anti_fp))
+                .fold(f64::NEG_INFINITY, f64::max);
+
+            if let Some((label, score)) = best_pattern {
+                if score > self.similarity_threshold && score > best_anti_pattern_score + self.anti_pattern_margin {
+                    window_matches.push((offset, offset + window, label.clone(), score));
+                }
+            }
+
+            offset += step;
+        }
+
+        Self::merge_adjacent(window_matches)
+    }
+
+    /// Führt aufeinanderfolgende Treffer-Fenster mit demselben Label, die
+    /// sich überlappen oder direkt aneinandergrenzen, zu einer
+    /// zusammenhängenden [`Detection`] zusammen.
+    fn merge_adjacent(window_matches: Vec<(usize, usize, String, f64)>) -> Vec<Detection> {
+        let mut detections: Vec<Detection> = Vec::new();
+
+        for (start_offset, end_offset, label, score) in window_matches {
+            if let Some(last) = detections.last_mut() {
+                if last.label == label && start_offset <= last.end_offset {
+                    last.end_offset = last.end_offset.max(end_offset);
+                    last.score = last.score.max(score);
+                    continue;
+                }
+            }
+            detections.push(Detection { start_offset, end_offset, label, score });
+        }
+
+        detections
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,6 +808,72 @@ mod tests {
         assert_eq!(fingerprint.dominant_frequencies.len(), 5);
     }
 
+    #[test]
+    fn test_compute_with_default_config_matches_compute() {
+        let data = b"compute_with default config parity check";
+        let config = SpectralConfig { fft_size: 256, ..SpectralConfig::default() };
+
+        let via_compute = SpectralFingerprint::compute(data, 256);
+        let via_compute_with = SpectralFingerprint::compute_with(data, &config);
+
+        assert_eq!(via_compute.power_spectrum, via_compute_with.power_spectrum);
+        assert_eq!(via_compute.dominant_frequencies, via_compute_with.dominant_frequencies);
+        assert_abs_diff_eq!(via_compute.spectral_entropy, via_compute_with.spectral_entropy, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_window_function_coefficients_stay_in_unit_range() {
+        for window in [
+            WindowFunction::Rectangular,
+            WindowFunction::Hann,
+            WindowFunction::Hamming,
+            WindowFunction::Blackman,
+        ] {
+            let coefficients = window.coefficients(64);
+            assert_eq!(coefficients.len(), 64);
+            for &c in &coefficients {
+                assert!((-0.1..=1.0).contains(&c), "Koeffizient {c} außerhalb des erwarteten Bereichs");
+            }
+        }
+    }
+
+    #[test]
+    fn test_welch_averaging_reduces_variance_on_noisy_signal() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let noisy_data: Vec<u8> = (0..4096).map(|_| rng.gen()).collect();
+
+        let single_segment = SpectralFingerprint::compute(&noisy_data, 256);
+
+        let welch_config = SpectralConfig {
+            fft_size: 256,
+            window: WindowFunction::Hann,
+            overlap: 0.5,
+            segments: None,
+        };
+        let welch_averaged = SpectralFingerprint::compute_with(&noisy_data, &welch_config);
+
+        fn variance(spectrum: &[f64]) -> f64 {
+            let mean = spectrum.iter().sum::<f64>() / spectrum.len() as f64;
+            spectrum.iter().map(|&p| (p - mean).powi(2)).sum::<f64>() / spectrum.len() as f64
+        }
+
+        // Welch-Mittelung über mehrere überlappende Segmente sollte ein
+        // deutlich weniger spitzes (geringere Varianz) Power-Spektrum
+        // liefern als die Einzel-Segment-FFT desselben Rauschens.
+        assert!(variance(&welch_averaged.power_spectrum) < variance(&single_segment.power_spectrum));
+        assert_abs_diff_eq!(welch_averaged.power_spectrum.iter().sum::<f64>(), 1.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_compute_with_rejects_invalid_overlap() {
+        let data = b"invalid overlap";
+        let config = SpectralConfig { overlap: 1.0, ..SpectralConfig::default() };
+
+        SpectralFingerprint::compute_with(data, &config);
+    }
+
     #[test]
     fn test_power_spectrum_normalization() {
         let data = b"normalized test";
@@ -295,4 +977,180 @@ mod tests {
         // Kurtosis sollte finite sein
         assert!(kurtosis.is_finite());
     }
+
+    #[test]
+    fn test_classifier_falls_back_to_heuristic_when_untrained() {
+        let data = b"untrained classifier fallback";
+        let fingerprint = SpectralFingerprint::compute(data, 128);
+        let classifier = SpectralClassifier::new();
+
+        assert!(!classifier.is_trained());
+
+        let (predicted, probabilities) = classifier.predict(&fingerprint);
+        assert_eq!(predicted, fingerprint.classify_traffic());
+        assert_eq!(probabilities, vec![(predicted, 1.0)]);
+    }
+
+    #[test]
+    fn test_classifier_train_produces_trained_model() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+
+        let bot_data: Vec<u8> = (0..512).map(|_| rng.gen()).collect();
+        let bot_fp = SpectralFingerprint::compute(&bot_data, 256);
+
+        let legit_data: Vec<u8> = (0..512)
+            .map(|i| ((i as f64 * 0.05).sin() * 100.0 + 128.0) as u8)
+            .collect();
+        let legit_fp = SpectralFingerprint::compute(&legit_data, 256);
+
+        let labeled = vec![
+            (bot_fp.clone(), TrafficType::Bot),
+            (legit_fp.clone(), TrafficType::Legitimate),
+        ];
+
+        let mut classifier = SpectralClassifier::new();
+        classifier.train(&labeled);
+
+        assert!(classifier.is_trained());
+
+        let (_, probabilities) = classifier.predict(&bot_fp);
+        assert_eq!(probabilities.len(), 3);
+        let total: f64 = probabilities.iter().map(|(_, p)| p).sum();
+        assert_abs_diff_eq!(total, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_classifier_train_with_empty_labels_stays_untrained() {
+        let mut classifier = SpectralClassifier::new();
+        classifier.train(&[]);
+
+        assert!(!classifier.is_trained());
+    }
+
+    fn periodic_bytes(n: usize, freq: f64) -> Vec<u8> {
+        (0..n).map(|i| ((i as f64 * freq).sin() * 100.0 + 128.0) as u8).collect()
+    }
+
+    #[test]
+    fn test_fingerprint_library_detects_known_pattern() {
+        let mut library = FingerprintLibrary::new(64, 0.9, 0.0);
+        library.add_pattern("periodic", &periodic_bytes(64, 0.2));
+
+        // Stream: Rauschen, dann das Pattern wiederholt, dann wieder Rauschen.
+        let noise = vec![1u8; 64];
+        let mut stream = noise.clone();
+        stream.extend(periodic_bytes(64, 0.2));
+        stream.extend(periodic_bytes(64, 0.2));
+        stream.extend(noise);
+
+        let detections = library.detect(&stream, 64, 32);
+
+        assert!(!detections.is_empty());
+        assert!(detections.iter().any(|d| d.label == "periodic"));
+        for detection in &detections {
+            assert!(detection.end_offset > detection.start_offset);
+            assert!(detection.score > 0.9);
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_library_merges_adjacent_windows() {
+        let mut library = FingerprintLibrary::new(64, 0.9, 0.0);
+        library.add_pattern("periodic", &periodic_bytes(64, 0.2));
+
+        let mut stream = Vec::new();
+        for _ in 0..4 {
+            stream.extend(periodic_bytes(64, 0.2));
+        }
+
+        let detections = library.detect(&stream, 64, 32);
+
+        // Überlappende Fenster desselben Patterns sollten zu einer
+        // einzigen zusammenhängenden Detection zusammengeführt werden.
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].start_offset, 0);
+        assert_eq!(detections[0].end_offset, stream.len());
+    }
+
+    #[test]
+    fn test_fingerprint_library_anti_pattern_suppresses_detection() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let noise: Vec<u8> = (0..64).map(|_| rng.gen()).collect();
+
+        let mut library = FingerprintLibrary::new(64, 0.0, 0.5);
+        library.add_pattern("noise_like", &noise);
+        library.add_anti_pattern("same_noise", &noise);
+
+        let detections = library.detect(&noise, 64, 64);
+
+        // Das Anti-Pattern ist identisch zum Pattern, also kann die
+        // Pattern-Ähnlichkeit die geforderte Marge nie erreichen.
+        assert!(detections.is_empty());
+    }
+
+    #[test]
+    fn test_ln_gamma_matches_known_values() {
+        assert_abs_diff_eq!(ln_gamma(1.0), 0.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(ln_gamma(2.0), 0.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(ln_gamma(0.5), (std::f64::consts::PI).sqrt().ln(), epsilon = 1e-9);
+        assert_abs_diff_eq!(ln_gamma(3.0), 2.0_f64.ln(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_baseline_model_update_matches_normal_gamma_recurrences() {
+        let mut model = BaselineModel::with_prior(0.0, 1.0, 1.0, 1.0);
+        model.update(1.0);
+
+        // κ'=κ+1=2, μ'=(κ·μ₀+x)/κ'=(1·0+1)/2=0.5,
+        // α'=α+½=1.5, β'=β+½·κ(x−μ₀)²/κ'=1+0.5·1·1/2=1.25
+        assert_abs_diff_eq!(model.kappa, 2.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(model.mu0, 0.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(model.alpha, 1.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(model.beta, 1.25, epsilon = 1e-12);
+        assert_eq!(model.observations(), 1);
+    }
+
+    #[test]
+    fn test_baseline_model_score_is_valid_density() {
+        let mut model = BaselineModel::new();
+        for i in 0..50 {
+            model.update(1.0 + (i as f64) * 0.01);
+        }
+
+        let data = b"baseline model score density test";
+        let fingerprint = SpectralFingerprint::compute(data, 128);
+        let density = model.score(&fingerprint);
+
+        assert!(density.is_finite());
+        assert!(density >= 0.0);
+    }
+
+    #[test]
+    fn test_baseline_model_flags_outlier_as_anomalous() {
+        let mut model = BaselineModel::new();
+        // Trainiere auf eng um 1.0 gestreuten "legitimen" Beobachtungen.
+        for i in 0..200 {
+            model.update(1.0 + (i % 5) as f64 * 0.001);
+        }
+
+        let typical = SpectralFingerprint {
+            power_spectrum: vec![0.5, 0.5],
+            dominant_frequencies: vec![0],
+            spectral_entropy: 1.0,
+        };
+        let outlier = SpectralFingerprint {
+            power_spectrum: vec![0.5, 0.5],
+            dominant_frequencies: vec![0],
+            spectral_entropy: 50.0,
+        };
+
+        let typical_density = model.score(&typical);
+        let outlier_density = model.score(&outlier);
+
+        assert!(outlier_density < typical_density);
+        assert!(model.is_anomalous(&outlier, typical_density));
+        assert!(!model.is_anomalous(&typical, outlier_density));
+    }
 }
@@ -0,0 +1,330 @@
+//! Trajektorien-Aufzeichnung für Kuramoto-Läufe
+//!
+//! Zeichnet bei jedem `evolve`/`evolve_rk4`-Schritt die Phasen, den
+//! komplexen Ordnungsparameter z = r·e^{iΘ} und die lokalen
+//! Ordnungsparameter auf und serialisiert den gesamten Lauf in ein
+//! einziges selbstbeschreibendes `tar`-Archiv: benannte Arrays als
+//! eigene Mitglieder, komplexe Tensoren als getrennte C-geordnete
+//! Real-/Imaginärteil-Ströme statt Ad-hoc-JSON.
+
+use crate::core::NUM_NODES;
+use crate::resonance::kuramoto::KuramotoNetwork;
+use num_complex::Complex64;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+/// Ein einzelner aufgezeichneter Zeitschritt
+#[derive(Debug, Clone)]
+pub struct TrajectoryStep {
+    /// Phasen φᵢ zum Zeitpunkt des Schritts
+    pub phases: [f64; NUM_NODES],
+    /// Globaler Ordnungsparameter z = r·e^{iΘ}
+    pub order_parameter: Complex64,
+    /// Lokale Ordnungsparameter pro Knoten
+    pub local_order_parameters: [f64; NUM_NODES],
+}
+
+/// Name des verwendeten Integrators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegratorKind {
+    /// Euler-Vorwärtsintegration (`evolve`)
+    Euler,
+    /// Runge-Kutta 4. Ordnung (`evolve_rk4`)
+    Rk4,
+}
+
+impl IntegratorKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            IntegratorKind::Euler => "euler",
+            IntegratorKind::Rk4 => "rk4",
+        }
+    }
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        match s {
+            "euler" => Ok(IntegratorKind::Euler),
+            "rk4" => Ok(IntegratorKind::Rk4),
+            other => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unbekannter Integrator: {other}"),
+            )),
+        }
+    }
+}
+
+/// Zeichnet die Entwicklung eines `KuramotoNetwork` über die Zeit auf
+pub struct TrajectoryRecorder {
+    frequencies: [f64; NUM_NODES],
+    coupling_matrix: [[f64; NUM_NODES]; NUM_NODES],
+    dt: f64,
+    integrator: IntegratorKind,
+    steps: Vec<TrajectoryStep>,
+}
+
+impl TrajectoryRecorder {
+    /// Erstellt einen Recorder für einen Lauf mit Zeitschritt `dt` und
+    /// dem angegebenen Integrator, basierend auf dem Startzustand von
+    /// `network`.
+    pub fn new(network: &KuramotoNetwork, dt: f64, integrator: IntegratorKind) -> Self {
+        Self {
+            frequencies: network.frequencies,
+            coupling_matrix: network.coupling_matrix,
+            dt,
+            integrator,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Führt einen Integrationsschritt auf `network` aus (entsprechend
+    /// dem konfigurierten Integrator) und zeichnet den resultierenden
+    /// Zustand auf.
+    pub fn record_step(&mut self, network: &mut KuramotoNetwork) {
+        match self.integrator {
+            IntegratorKind::Euler => network.evolve(self.dt),
+            IntegratorKind::Rk4 => network.evolve_rk4(self.dt),
+        }
+
+        let (r, theta) = network.order_parameter();
+        self.steps.push(TrajectoryStep {
+            phases: network.phases,
+            order_parameter: Complex64::from_polar(r, theta),
+            local_order_parameters: network.local_order_parameters(),
+        });
+    }
+
+    /// Anzahl bislang aufgezeichneter Schritte
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Ob noch keine Schritte aufgezeichnet wurden
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Aufgezeichnete Schritte in chronologischer Reihenfolge
+    pub fn steps(&self) -> &[TrajectoryStep] {
+        &self.steps
+    }
+
+    /// Serialisiert den gesamten Lauf in ein `tar`-Archiv unter `path`.
+    ///
+    /// Das Archiv enthält:
+    /// - `frequencies.bin`: ω-Vektor (N × f64, little-endian)
+    /// - `coupling.bin`: κ-Matrix (N×N × f64, C-geordnet, row-major)
+    /// - `order_parameter/re.bin`, `order_parameter/im.bin`: die
+    ///   Real-/Imaginärteil-Ströme des Ordnungsparameters über alle
+    ///   Schritte
+    /// - `phases.bin`: Phasen aller Schritte (steps × N × f64)
+    /// - `local_order_parameters.bin`: lokale Ordnungsparameter aller
+    ///   Schritte (steps × N × f64)
+    /// - `meta.txt`: N, dt und der verwendete Integrator
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let file = std::fs::File::create(path)?;
+        let mut builder = tar::Builder::new(file);
+
+        append_f64_slice(&mut builder, "frequencies.bin", &self.frequencies)?;
+
+        let coupling_flat: Vec<f64> = self
+            .coupling_matrix
+            .iter()
+            .flat_map(|row| row.iter().copied())
+            .collect();
+        append_f64_slice(&mut builder, "coupling.bin", &coupling_flat)?;
+
+        let re: Vec<f64> = self.steps.iter().map(|s| s.order_parameter.re).collect();
+        let im: Vec<f64> = self.steps.iter().map(|s| s.order_parameter.im).collect();
+        append_f64_slice(&mut builder, "order_parameter/re.bin", &re)?;
+        append_f64_slice(&mut builder, "order_parameter/im.bin", &im)?;
+
+        let phases_flat: Vec<f64> = self
+            .steps
+            .iter()
+            .flat_map(|s| s.phases.iter().copied())
+            .collect();
+        append_f64_slice(&mut builder, "phases.bin", &phases_flat)?;
+
+        let local_r_flat: Vec<f64> = self
+            .steps
+            .iter()
+            .flat_map(|s| s.local_order_parameters.iter().copied())
+            .collect();
+        append_f64_slice(&mut builder, "local_order_parameters.bin", &local_r_flat)?;
+
+        let metadata = format!(
+            "n={}\ndt={}\nintegrator={}\nsteps={}\n",
+            NUM_NODES,
+            self.dt,
+            self.integrator.as_str(),
+            self.steps.len()
+        );
+        append_bytes(&mut builder, "meta.txt", metadata.as_bytes())?;
+
+        builder.finish()
+    }
+
+    /// Lädt einen zuvor mit `save` geschriebenen Lauf und rekonstruiert
+    /// einen abspielbaren `TrajectoryRecorder`.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let mut archive = tar::Archive::new(file);
+
+        let mut frequencies = None;
+        let mut coupling_matrix = None;
+        let mut re = None;
+        let mut im = None;
+        let mut phases_flat = None;
+        let mut local_r_flat = None;
+        let mut dt = None;
+        let mut integrator = None;
+
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            let name = entry.path()?.to_string_lossy().into_owned();
+            let mut buf = Vec::new();
+            entry.read_to_end(&mut buf)?;
+
+            match name.as_str() {
+                "frequencies.bin" => frequencies = Some(bytes_to_f64_vec(&buf)),
+                "coupling.bin" => coupling_matrix = Some(bytes_to_f64_vec(&buf)),
+                "order_parameter/re.bin" => re = Some(bytes_to_f64_vec(&buf)),
+                "order_parameter/im.bin" => im = Some(bytes_to_f64_vec(&buf)),
+                "phases.bin" => phases_flat = Some(bytes_to_f64_vec(&buf)),
+                "local_order_parameters.bin" => local_r_flat = Some(bytes_to_f64_vec(&buf)),
+                "meta.txt" => {
+                    let text = String::from_utf8_lossy(&buf).into_owned();
+                    for line in text.lines() {
+                        if let Some(value) = line.strip_prefix("dt=") {
+                            dt = value.parse::<f64>().ok();
+                        } else if let Some(value) = line.strip_prefix("integrator=") {
+                            integrator = Some(IntegratorKind::from_str(value)?);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let missing = |what: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Archiv enthält kein Mitglied für {what}"),
+            )
+        };
+
+        let frequencies_vec = frequencies.ok_or_else(|| missing("frequencies.bin"))?;
+        let coupling_vec = coupling_matrix.ok_or_else(|| missing("coupling.bin"))?;
+        let re = re.ok_or_else(|| missing("order_parameter/re.bin"))?;
+        let im = im.ok_or_else(|| missing("order_parameter/im.bin"))?;
+        let phases_flat = phases_flat.ok_or_else(|| missing("phases.bin"))?;
+        let local_r_flat = local_r_flat.ok_or_else(|| missing("local_order_parameters.bin"))?;
+        let dt = dt.ok_or_else(|| missing("meta.txt: dt"))?;
+        let integrator = integrator.ok_or_else(|| missing("meta.txt: integrator"))?;
+
+        let frequencies: [f64; NUM_NODES] = frequencies_vec
+            .try_into()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "frequencies.bin hat falsche Länge"))?;
+
+        let mut coupling_matrix = [[0.0; NUM_NODES]; NUM_NODES];
+        for i in 0..NUM_NODES {
+            coupling_matrix[i].copy_from_slice(&coupling_vec[i * NUM_NODES..(i + 1) * NUM_NODES]);
+        }
+
+        let num_steps = re.len();
+        let mut steps = Vec::with_capacity(num_steps);
+        for step in 0..num_steps {
+            let mut phases = [0.0; NUM_NODES];
+            phases.copy_from_slice(&phases_flat[step * NUM_NODES..(step + 1) * NUM_NODES]);
+
+            let mut local_order_parameters = [0.0; NUM_NODES];
+            local_order_parameters
+                .copy_from_slice(&local_r_flat[step * NUM_NODES..(step + 1) * NUM_NODES]);
+
+            steps.push(TrajectoryStep {
+                phases,
+                order_parameter: Complex64::new(re[step], im[step]),
+                local_order_parameters,
+            });
+        }
+
+        Ok(Self {
+            frequencies,
+            coupling_matrix,
+            dt,
+            integrator,
+            steps,
+        })
+    }
+}
+
+fn append_f64_slice<W: Write>(
+    builder: &mut tar::Builder<W>,
+    name: &str,
+    data: &[f64],
+) -> io::Result<()> {
+    let bytes: Vec<u8> = data.iter().flat_map(|v| v.to_le_bytes()).collect();
+    append_bytes(builder, name, &bytes)
+}
+
+fn append_bytes<W: Write>(builder: &mut tar::Builder<W>, name: &str, data: &[u8]) -> io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder.append_data(&mut header, name, data)
+}
+
+fn bytes_to_f64_vec(bytes: &[u8]) -> Vec<f64> {
+    bytes
+        .chunks_exact(8)
+        .map(|chunk| f64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_step_tracks_order_parameter() {
+        let mut network = KuramotoNetwork::uniform(1.0, 2.0);
+        let mut recorder = TrajectoryRecorder::new(&network, 0.01, IntegratorKind::Rk4);
+
+        for _ in 0..10 {
+            recorder.record_step(&mut network);
+        }
+
+        assert_eq!(recorder.len(), 10);
+        assert!(!recorder.is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let mut network = KuramotoNetwork::uniform(1.0, 2.0);
+        network.randomize_phases();
+        let mut recorder = TrajectoryRecorder::new(&network, 0.02, IntegratorKind::Euler);
+
+        for _ in 0..5 {
+            recorder.record_step(&mut network);
+        }
+
+        let dir = std::env::temp_dir();
+        let id = crate::utils::test_support::unique_id();
+        let path = dir.join(format!("thronion_trajectory_test_{id}.tar"));
+        recorder.save(&path).unwrap();
+
+        let loaded = TrajectoryRecorder::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.len(), recorder.len());
+        assert_eq!(loaded.dt, recorder.dt);
+        assert_eq!(loaded.integrator, recorder.integrator);
+        assert_eq!(loaded.frequencies, recorder.frequencies);
+
+        for (a, b) in loaded.steps().iter().zip(recorder.steps().iter()) {
+            assert_eq!(a.phases, b.phases);
+            assert!((a.order_parameter - b.order_parameter).norm() < 1e-12);
+        }
+    }
+}
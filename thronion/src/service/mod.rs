@@ -11,11 +11,21 @@
 //! - **Main**: Service entry point and initialization
 
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use anyhow::{Context, Result};
-use tokio::sync::RwLock;
-use prometheus::{IntCounter, Histogram, Gauge, Registry, HistogramOpts, Opts};
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
+#[cfg(unix)]
+use tokio::signal::unix::{signal, SignalKind};
+use prometheus::{Encoder, IntCounter, Histogram, Gauge, Registry, HistogramOpts, Opts, TextEncoder};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server, StatusCode};
+
+/// How often the config-reload task stats the config file for changes,
+/// on top of reacting to SIGHUP.
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
 /// Thronion Configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,13 +94,21 @@ pub struct ServiceSettings {
 pub struct MonitoringSettings {
     #[serde(default = "default_true")]
     pub enable_metrics: bool,
-    
+
     #[serde(default = "default_metrics_port")]
     pub metrics_port: u16,
-    
+
+    /// Address the Prometheus `/metrics` HTTP exporter binds to.
+    #[serde(default = "default_metrics_bind_address")]
+    pub metrics_bind_address: String,
+
+    /// HTTP path the exporter answers scrapes on.
+    #[serde(default = "default_metrics_path")]
+    pub metrics_path: String,
+
     #[serde(default)]
     pub verbose_logging: bool,
-    
+
     pub log_file: Option<String>,
 }
 
@@ -117,6 +135,8 @@ fn default_bind_address() -> String { "127.0.0.1".to_string() }
 fn default_worker_threads() -> usize { 4 }
 fn default_true() -> bool { true }
 fn default_metrics_port() -> u16 { 9090 }
+fn default_metrics_bind_address() -> String { "127.0.0.1".to_string() }
+fn default_metrics_path() -> String { "/metrics".to_string() }
 fn default_max_circuits() -> usize { 10000 }
 fn default_retention() -> u64 { 3600 }
 
@@ -146,6 +166,86 @@ impl Default for ThronionSettings {
     }
 }
 
+impl ThronionSettings {
+    /// Returns a `field: old -> new` line for every field that differs
+    /// from `other`, for logging what a config reload actually changed.
+    fn diff(&self, other: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.max_regions != other.max_regions {
+            changes.push(format!(
+                "max_regions: {} -> {}",
+                self.max_regions, other.max_regions
+            ));
+        }
+        if self.learning_rate != other.learning_rate {
+            changes.push(format!(
+                "learning_rate: {} -> {}",
+                self.learning_rate, other.learning_rate
+            ));
+        }
+        if self.attack_threshold != other.attack_threshold {
+            changes.push(format!(
+                "attack_threshold: {} -> {}",
+                self.attack_threshold, other.attack_threshold
+            ));
+        }
+        if self.resonance_threshold != other.resonance_threshold {
+            changes.push(format!(
+                "resonance_threshold: {} -> {}",
+                self.resonance_threshold, other.resonance_threshold
+            ));
+        }
+        if self.optimization_interval != other.optimization_interval {
+            changes.push(format!(
+                "optimization_interval: {} -> {}",
+                self.optimization_interval, other.optimization_interval
+            ));
+        }
+        if self.coherence_threshold != other.coherence_threshold {
+            changes.push(format!(
+                "coherence_threshold: {} -> {}",
+                self.coherence_threshold, other.coherence_threshold
+            ));
+        }
+        if self.merge_threshold != other.merge_threshold {
+            changes.push(format!(
+                "merge_threshold: {} -> {}",
+                self.merge_threshold, other.merge_threshold
+            ));
+        }
+
+        changes
+    }
+}
+
+impl TorSettings {
+    /// Returns a `field: old -> new` line for every field that differs
+    /// from `other`. `control_password` is reported as changed or
+    /// unchanged without ever logging the actual secret value.
+    fn diff(&self, other: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.control_port != other.control_port {
+            changes.push(format!(
+                "tor.control_port: {} -> {}",
+                self.control_port, other.control_port
+            ));
+        }
+        if self.cookie_path != other.cookie_path {
+            changes.push(format!(
+                "tor.cookie_path: {} -> {}",
+                self.cookie_path, other.cookie_path
+            ));
+        }
+        if self.control_password != other.control_password {
+            changes.push("tor.control_password: <redacted> -> <redacted>".to_string());
+        }
+
+        changes
+    }
+}
+
 impl Default for TorSettings {
     fn default() -> Self {
         Self {
@@ -156,6 +256,29 @@ impl Default for TorSettings {
     }
 }
 
+impl ServiceSettings {
+    /// Returns a `field: old -> new` line for every field that differs
+    /// from `other`, for logging what a config reload actually changed.
+    fn diff(&self, other: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.bind_address != other.bind_address {
+            changes.push(format!(
+                "service.bind_address: {} -> {}",
+                self.bind_address, other.bind_address
+            ));
+        }
+        if self.worker_threads != other.worker_threads {
+            changes.push(format!(
+                "service.worker_threads: {} -> {}",
+                self.worker_threads, other.worker_threads
+            ));
+        }
+
+        changes
+    }
+}
+
 impl Default for ServiceSettings {
     fn default() -> Self {
         Self {
@@ -165,17 +288,89 @@ impl Default for ServiceSettings {
     }
 }
 
+impl MonitoringSettings {
+    /// Returns a `field: old -> new` line for every field that differs
+    /// from `other`, for logging what a config reload actually changed.
+    fn diff(&self, other: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.enable_metrics != other.enable_metrics {
+            changes.push(format!(
+                "monitoring.enable_metrics: {} -> {}",
+                self.enable_metrics, other.enable_metrics
+            ));
+        }
+        if self.metrics_port != other.metrics_port {
+            changes.push(format!(
+                "monitoring.metrics_port: {} -> {}",
+                self.metrics_port, other.metrics_port
+            ));
+        }
+        if self.metrics_bind_address != other.metrics_bind_address {
+            changes.push(format!(
+                "monitoring.metrics_bind_address: {} -> {}",
+                self.metrics_bind_address, other.metrics_bind_address
+            ));
+        }
+        if self.metrics_path != other.metrics_path {
+            changes.push(format!(
+                "monitoring.metrics_path: {} -> {}",
+                self.metrics_path, other.metrics_path
+            ));
+        }
+        if self.verbose_logging != other.verbose_logging {
+            changes.push(format!(
+                "monitoring.verbose_logging: {} -> {}",
+                self.verbose_logging, other.verbose_logging
+            ));
+        }
+        if self.log_file != other.log_file {
+            changes.push(format!(
+                "monitoring.log_file: {:?} -> {:?}",
+                self.log_file, other.log_file
+            ));
+        }
+
+        changes
+    }
+}
+
 impl Default for MonitoringSettings {
     fn default() -> Self {
         Self {
             enable_metrics: default_true(),
             metrics_port: default_metrics_port(),
+            metrics_bind_address: default_metrics_bind_address(),
+            metrics_path: default_metrics_path(),
             verbose_logging: false,
             log_file: Some("/var/log/thronion/thronion.log".to_string()),
         }
     }
 }
 
+impl PerformanceSettings {
+    /// Returns a `field: old -> new` line for every field that differs
+    /// from `other`, for logging what a config reload actually changed.
+    fn diff(&self, other: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.max_tracked_circuits != other.max_tracked_circuits {
+            changes.push(format!(
+                "performance.max_tracked_circuits: {} -> {}",
+                self.max_tracked_circuits, other.max_tracked_circuits
+            ));
+        }
+        if self.metadata_retention_secs != other.metadata_retention_secs {
+            changes.push(format!(
+                "performance.metadata_retention_secs: {} -> {}",
+                self.metadata_retention_secs, other.metadata_retention_secs
+            ));
+        }
+
+        changes
+    }
+}
+
 impl Default for PerformanceSettings {
     fn default() -> Self {
         Self {
@@ -321,11 +516,213 @@ impl Default for ThronionMetrics {
     }
 }
 
+/// Answers a single `/metrics` scrape by gathering `registry` through the
+/// Prometheus text exposition format. Any other path gets a 404.
+async fn handle_metrics_request(
+    req: Request<Body>,
+    registry: Arc<Registry>,
+    path: Arc<String>,
+) -> std::result::Result<Response<Body>, std::convert::Infallible> {
+    if req.uri().path() != path.as_str() {
+        return Ok(Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::empty())
+            .unwrap());
+    }
+
+    let encoder = TextEncoder::new();
+    let metric_families = registry.gather();
+    let mut buffer = Vec::new();
+
+    if let Err(err) = encoder.encode(&metric_families, &mut buffer) {
+        tracing::error!("Failed to encode Prometheus metrics: {err}");
+        return Ok(Response::builder()
+            .status(StatusCode::INTERNAL_SERVER_ERROR)
+            .body(Body::empty())
+            .unwrap());
+    }
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header("Content-Type", encoder.format_type())
+        .body(Body::from(buffer))
+        .unwrap())
+}
+
+/// Spawns the background task serving `registry` over HTTP at
+/// `bind_address:port`, answering `GET <path>` with the gathered metrics.
+/// The server shuts down gracefully (letting in-flight scrapes finish)
+/// once `shutdown` fires.
+fn spawn_metrics_server(
+    registry: Arc<Registry>,
+    bind_address: &str,
+    port: u16,
+    path: String,
+    mut shutdown: broadcast::Receiver<()>,
+) -> Result<(JoinHandle<()>, std::net::SocketAddr)> {
+    let addr: std::net::SocketAddr = format!("{bind_address}:{port}")
+        .parse()
+        .context("invalid metrics bind address")?;
+    let path = Arc::new(path);
+
+    let make_svc = make_service_fn(move |_conn| {
+        let registry = Arc::clone(&registry);
+        let path = Arc::clone(&path);
+        async move {
+            Ok::<_, std::convert::Infallible>(service_fn(move |req| {
+                handle_metrics_request(req, Arc::clone(&registry), Arc::clone(&path))
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&addr)
+        .with_context(|| format!("failed to bind metrics exporter to {addr}"))?
+        .serve(make_svc);
+    let local_addr = server.local_addr();
+    let server = server.with_graceful_shutdown(async move {
+        let _ = shutdown.recv().await;
+    });
+
+    let handle = tokio::spawn(async move {
+        if let Err(err) = server.await {
+            tracing::error!("Metrics exporter stopped unexpectedly: {err}");
+        }
+    });
+
+    Ok((handle, local_addr))
+}
+
+/// Re-reads and validates the config file at `path`, and if it differs
+/// from `*config` in any section (`thronion`, `tor`, `service`,
+/// `monitoring`, or `performance`), swaps it in and logs a diff of what
+/// changed. Returns `Ok(false)` without touching `*config` if the file
+/// is unchanged in every section, and returns `Err` (also without
+/// touching `*config`) if the file can't be read, parsed, or fails
+/// [`ThronionConfig::validate`].
+async fn reload_config_from_path(
+    config: &RwLock<ThronionConfig>,
+    path: &Path,
+) -> Result<bool> {
+    let new_config = ThronionConfig::from_file(path).context("failed to reload configuration")?;
+
+    let mut current = config.write().await;
+    let mut changes = current.thronion.diff(&new_config.thronion);
+    changes.extend(current.tor.diff(&new_config.tor));
+    changes.extend(current.service.diff(&new_config.service));
+    changes.extend(current.monitoring.diff(&new_config.monitoring));
+    changes.extend(current.performance.diff(&new_config.performance));
+
+    if changes.is_empty() {
+        return Ok(false);
+    }
+
+    for change in &changes {
+        tracing::info!("config reload: {change}");
+    }
+
+    *current = new_config;
+    Ok(true)
+}
+
+fn file_modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).ok()?.modified().ok()
+}
+
+/// Spawns the background task that hot-reloads `config` from `path`: on
+/// Unix it reacts to SIGHUP, and on every platform it polls the file's
+/// modification time every [`CONFIG_POLL_INTERVAL`] so an edit-and-save
+/// also takes effect without sending a signal. Invalid or unreadable
+/// reloads are logged and discarded, leaving the previously running
+/// config in place.
+#[cfg(unix)]
+fn spawn_config_reload_task(
+    config: Arc<RwLock<ThronionConfig>>,
+    path: PathBuf,
+    mut shutdown: broadcast::Receiver<()>,
+) -> Result<JoinHandle<()>> {
+    let mut sighup =
+        signal(SignalKind::hangup()).context("failed to install SIGHUP handler")?;
+
+    Ok(tokio::spawn(async move {
+        let mut poll = tokio::time::interval(CONFIG_POLL_INTERVAL);
+        let mut last_modified = file_modified_time(&path);
+
+        loop {
+            tokio::select! {
+                _ = sighup.recv() => {
+                    tracing::info!("received SIGHUP; reloading configuration from {}", path.display());
+                    log_reload_result(reload_config_from_path(&config, &path).await);
+                    last_modified = file_modified_time(&path);
+                }
+                _ = poll.tick() => {
+                    let modified = file_modified_time(&path);
+                    if modified.is_some() && modified != last_modified {
+                        log_reload_result(reload_config_from_path(&config, &path).await);
+                        last_modified = modified;
+                    }
+                }
+                _ = shutdown.recv() => {
+                    tracing::info!("Config reload task shutting down");
+                    break;
+                }
+            }
+        }
+    }))
+}
+
+/// Non-Unix equivalent of the above: there is no SIGHUP to react to, so
+/// this only polls the file's modification time.
+#[cfg(not(unix))]
+fn spawn_config_reload_task(
+    config: Arc<RwLock<ThronionConfig>>,
+    path: PathBuf,
+    mut shutdown: broadcast::Receiver<()>,
+) -> Result<JoinHandle<()>> {
+    Ok(tokio::spawn(async move {
+        let mut poll = tokio::time::interval(CONFIG_POLL_INTERVAL);
+        let mut last_modified = file_modified_time(&path);
+
+        loop {
+            tokio::select! {
+                _ = poll.tick() => {
+                    let modified = file_modified_time(&path);
+                    if modified.is_some() && modified != last_modified {
+                        log_reload_result(reload_config_from_path(&config, &path).await);
+                        last_modified = modified;
+                    }
+                }
+                _ = shutdown.recv() => {
+                    tracing::info!("Config reload task shutting down");
+                    break;
+                }
+            }
+        }
+    }))
+}
+
+fn log_reload_result(result: Result<bool>) {
+    match result {
+        Ok(true) => tracing::info!("Configuration reloaded"),
+        Ok(false) => {}
+        Err(err) => tracing::error!("Configuration reload rejected: {err:#}"),
+    }
+}
+
 /// Service Runtime State
 pub struct ThronionService {
-    config: ThronionConfig,
+    config: Arc<RwLock<ThronionConfig>>,
+    /// Path `config` was loaded from, if any. Set via
+    /// [`Self::with_config_path`]; required for [`Self::reload_config`]
+    /// and for [`Self::start`] to spawn the hot-reload task.
+    config_path: Option<PathBuf>,
     metrics: Arc<ThronionMetrics>,
     running: Arc<RwLock<bool>>,
+    metrics_server: RwLock<Option<JoinHandle<()>>>,
+    config_reload_task: RwLock<Option<JoinHandle<()>>>,
+    /// Broadcast to every task spawned by [`Self::start`], so
+    /// [`Self::stop`] can drain them all before it returns instead of
+    /// leaving them running past shutdown.
+    shutdown_tx: broadcast::Sender<()>,
 }
 
 impl ThronionService {
@@ -333,42 +730,154 @@ impl ThronionService {
     pub fn new(config: ThronionConfig) -> Result<Self> {
         let metrics = Arc::new(ThronionMetrics::new()?);
         let running = Arc::new(RwLock::new(false));
-        
+        let (shutdown_tx, _) = broadcast::channel(1);
+
         Ok(Self {
-            config,
+            config: Arc::new(RwLock::new(config)),
+            config_path: None,
             metrics,
             running,
+            metrics_server: RwLock::new(None),
+            config_reload_task: RwLock::new(None),
+            shutdown_tx,
         })
     }
-    
-    /// Get configuration
-    pub fn config(&self) -> &ThronionConfig {
-        &self.config
+
+    /// Like [`Self::new`], but remembers `path` so the config can later
+    /// be hot-reloaded from the same file, either explicitly via
+    /// [`Self::reload_config`] or automatically by the task
+    /// [`Self::start`] spawns to watch it for SIGHUP/changes.
+    pub fn with_config_path(config: ThronionConfig, path: impl Into<PathBuf>) -> Result<Self> {
+        let mut service = Self::new(config)?;
+        service.config_path = Some(path.into());
+        Ok(service)
     }
-    
+
+    /// Returns a snapshot of the current configuration.
+    ///
+    /// The config can change at any time via [`Self::reload_config`], so
+    /// a maintenance or optimization loop reading live thresholds (e.g.
+    /// `attack_threshold`, `resonance_threshold`) should call this fresh
+    /// every tick rather than caching the result.
+    pub async fn config(&self) -> ThronionConfig {
+        self.config.read().await.clone()
+    }
+
+    /// Re-reads the config file at the path given to
+    /// [`Self::with_config_path`], validates it, and atomically swaps it
+    /// in only if validation passes, logging a diff of what changed.
+    /// Returns `Ok(false)` if the file is unchanged. An invalid or
+    /// unreadable reload returns `Err` and leaves the running config
+    /// untouched.
+    pub async fn reload_config(&self) -> Result<bool> {
+        let path = self.config_path.as_ref().context(
+            "no config path set; construct the service with with_config_path to enable reloading",
+        )?;
+        reload_config_from_path(&self.config, path).await
+    }
+
     /// Get metrics
     pub fn metrics(&self) -> Arc<ThronionMetrics> {
         Arc::clone(&self.metrics)
     }
-    
+
     /// Check if service is running
     pub async fn is_running(&self) -> bool {
         *self.running.read().await
     }
-    
+
     /// Start the service
+    ///
+    /// When `monitoring.enable_metrics` is set, this also spawns the
+    /// Prometheus `/metrics` HTTP exporter on `monitoring.metrics_bind_address:
+    /// monitoring.metrics_port`, so the registry built by [`ThronionMetrics`]
+    /// becomes reachable by a scraper for as long as the service runs.
     pub async fn start(&self) -> Result<()> {
         let mut running = self.running.write().await;
+
+        {
+            let config = self.config.read().await;
+            if config.monitoring.enable_metrics {
+                let (handle, _addr) = spawn_metrics_server(
+                    Arc::new(self.metrics.registry().clone()),
+                    &config.monitoring.metrics_bind_address,
+                    config.monitoring.metrics_port,
+                    config.monitoring.metrics_path.clone(),
+                    self.shutdown_tx.subscribe(),
+                )?;
+                *self.metrics_server.write().await = Some(handle);
+            }
+        }
+
+        if let Some(path) = &self.config_path {
+            let handle = spawn_config_reload_task(
+                Arc::clone(&self.config),
+                path.clone(),
+                self.shutdown_tx.subscribe(),
+            )?;
+            *self.config_reload_task.write().await = Some(handle);
+        }
+
         *running = true;
         Ok(())
     }
-    
+
     /// Stop the service
+    ///
+    /// Broadcasts shutdown to every task spawned by [`Self::start`] and
+    /// awaits their join handles, so the metrics exporter, the
+    /// config-reload watcher, and any future maintenance/reader tasks
+    /// wired through [`Self::shutdown_signal`] have flushed and exited
+    /// before this returns — `running` becomes an authoritative lifecycle
+    /// flag rather than a cosmetic one.
     pub async fn stop(&self) -> Result<()> {
         let mut running = self.running.write().await;
+
+        let _ = self.shutdown_tx.send(());
+
+        if let Some(handle) = self.metrics_server.write().await.take() {
+            let _ = handle.await;
+        }
+        if let Some(handle) = self.config_reload_task.write().await.take() {
+            let _ = handle.await;
+        }
+
         *running = false;
         Ok(())
     }
+
+    /// Subscribes to the service's shutdown broadcast, for any additional
+    /// long-running task spawned alongside the metrics exporter to select
+    /// on next to its own `interval.tick()`.
+    pub fn shutdown_signal(&self) -> broadcast::Receiver<()> {
+        self.shutdown_tx.subscribe()
+    }
+
+    /// Records one maintenance-tick's worth of decision-engine statistics
+    /// into the Prometheus metrics.
+    ///
+    /// `new_total`/`new_absorbed`/`new_forwarded` are the counts of
+    /// circuits processed *during this tick* (not running totals) and are
+    /// added to the corresponding counters; the gauges are overwritten with
+    /// the snapshot values observed at tick time. This is the hook the
+    /// runtime's maintenance loop should call once per tick instead of only
+    /// logging these values.
+    pub fn record_tick(
+        &self,
+        new_total: u64,
+        new_absorbed: u64,
+        new_forwarded: u64,
+        active_regions: f64,
+        coherence_gradient: f64,
+        attack_rate: f64,
+    ) {
+        self.metrics.circuits_total.inc_by(new_total);
+        self.metrics.circuits_absorbed.inc_by(new_absorbed);
+        self.metrics.circuits_forwarded.inc_by(new_forwarded);
+        self.metrics.active_regions.set(active_regions);
+        self.metrics.coherence_gradient.set(coherence_gradient);
+        self.metrics.attack_rate.set(attack_rate);
+    }
 }
 
 #[cfg(test)]
@@ -420,5 +929,171 @@ mod tests {
         let toml_str = toml::to_string(&config).unwrap();
         assert!(toml_str.contains("max_regions"));
         assert!(toml_str.contains("control_port"));
+        assert!(toml_str.contains("metrics_path"));
+    }
+
+    #[test]
+    fn test_default_metrics_path_is_slash_metrics() {
+        let config = ThronionConfig::default();
+        assert_eq!(config.monitoring.metrics_path, "/metrics");
+    }
+
+    #[test]
+    fn test_record_tick_updates_counters_and_gauges() {
+        let config = ThronionConfig::default();
+        let service = ThronionService::new(config).unwrap();
+
+        service.record_tick(10, 3, 7, 4.0, 0.02, 0.3);
+
+        assert_eq!(service.metrics().circuits_total.get(), 10);
+        assert_eq!(service.metrics().circuits_absorbed.get(), 3);
+        assert_eq!(service.metrics().circuits_forwarded.get(), 7);
+        assert_eq!(service.metrics().active_regions.get(), 4.0);
+        assert_eq!(service.metrics().coherence_gradient.get(), 0.02);
+        assert_eq!(service.metrics().attack_rate.get(), 0.3);
+
+        service.record_tick(5, 1, 4, 2.0, 0.01, 0.1);
+        assert_eq!(service.metrics().circuits_total.get(), 15);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_exporter_serves_registry() {
+        let mut config = ThronionConfig::default();
+        config.monitoring.metrics_bind_address = "127.0.0.1".to_string();
+        config.monitoring.metrics_port = 0;
+        let service = ThronionService::new(config).unwrap();
+        service.metrics().circuits_total.inc();
+
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let (handle, addr) = spawn_metrics_server(
+            Arc::new(service.metrics().registry().clone()),
+            "127.0.0.1",
+            0,
+            "/metrics".to_string(),
+            shutdown_rx,
+        )
+        .unwrap();
+
+        let client = hyper::Client::new();
+        let uri = format!("http://{addr}/metrics").parse().unwrap();
+        let response = client.get(uri).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("thronion_circuits_total"));
+
+        let _ = shutdown_tx.send(());
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_metrics_exporter_404s_unknown_path() {
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let (handle, addr) = spawn_metrics_server(
+            Arc::new(Registry::new()),
+            "127.0.0.1",
+            0,
+            "/metrics".to_string(),
+            shutdown_rx,
+        )
+        .unwrap();
+
+        let client = hyper::Client::new();
+        let uri = format!("http://{addr}/not-metrics").parse().unwrap();
+        let response = client.get(uri).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let _ = shutdown_tx.send(());
+        handle.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_stop_awaits_metrics_server_shutdown() {
+        let mut config = ThronionConfig::default();
+        config.monitoring.metrics_port = 0;
+        let service = ThronionService::new(config).unwrap();
+
+        service.start().await.unwrap();
+        assert!(service.is_running().await);
+
+        service.stop().await.unwrap();
+        assert!(!service.is_running().await);
+    }
+
+    fn temp_config_path(name: &str) -> PathBuf {
+        let id = crate::utils::test_support::unique_id();
+        std::env::temp_dir().join(format!("thronion_config_reload_test_{name}_{id}.toml"))
+    }
+
+    #[test]
+    fn test_thronion_settings_diff_lists_changed_fields() {
+        let mut before = ThronionSettings::default();
+        let mut after = before.clone();
+        after.attack_threshold = 0.9;
+        after.max_regions = 200;
+
+        let changes = before.diff(&after);
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().any(|c| c.starts_with("attack_threshold:")));
+        assert!(changes.iter().any(|c| c.starts_with("max_regions:")));
+
+        before.attack_threshold = 0.9;
+        before.max_regions = 200;
+        assert!(before.diff(&after).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reload_config_without_path_errors() {
+        let service = ThronionService::new(ThronionConfig::default()).unwrap();
+        assert!(service.reload_config().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reload_config_applies_valid_changes() {
+        let path = temp_config_path("valid");
+        std::fs::write(&path, toml::to_string(&ThronionConfig::default()).unwrap()).unwrap();
+
+        let service = ThronionService::with_config_path(ThronionConfig::default(), &path).unwrap();
+
+        let mut updated = ThronionConfig::default();
+        updated.thronion.attack_threshold = 0.8;
+        std::fs::write(&path, toml::to_string(&updated).unwrap()).unwrap();
+
+        let reloaded = service.reload_config().await.unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(reloaded);
+        assert_eq!(service.config().await.thronion.attack_threshold, 0.8);
+    }
+
+    #[tokio::test]
+    async fn test_reload_config_no_change_returns_false() {
+        let path = temp_config_path("unchanged");
+        std::fs::write(&path, toml::to_string(&ThronionConfig::default()).unwrap()).unwrap();
+
+        let service = ThronionService::with_config_path(ThronionConfig::default(), &path).unwrap();
+        let reloaded = service.reload_config().await.unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(!reloaded);
+    }
+
+    #[tokio::test]
+    async fn test_reload_config_rejects_invalid_and_keeps_old_config() {
+        let path = temp_config_path("invalid");
+        std::fs::write(&path, toml::to_string(&ThronionConfig::default()).unwrap()).unwrap();
+
+        let service = ThronionService::with_config_path(ThronionConfig::default(), &path).unwrap();
+
+        let mut invalid = ThronionConfig::default();
+        invalid.thronion.max_regions = 0;
+        std::fs::write(&path, toml::to_string(&invalid).unwrap()).unwrap();
+
+        let result = service.reload_config().await;
+        std::fs::remove_file(&path).ok();
+
+        assert!(result.is_err());
+        assert_eq!(service.config().await.thronion.max_regions, 100);
     }
 }
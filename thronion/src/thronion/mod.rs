@@ -22,12 +22,24 @@ use crate::core::{QuantumState, HILBERT_DIM};
 use crate::delta::DeltaKernel;
 use crate::mandorla::MandorlaRegion;
 use crate::tor::{CellTypeDistribution, TimingFeatures, TorCircuitMetadata};
-use nalgebra::{SVector, Complex};
+use anyhow::{Context, Result};
+use gbdt::config::Config as GbdtConfig;
+use gbdt::decision_tree::{Data as GbdtData, DataVec as GbdtDataVec};
+use gbdt::gradient_boost::GBDT;
+use nalgebra::{Complex, DMatrix, DVector, SVector};
 use ndarray::Array1;
+use rustfft::{num_complex::Complex as FftComplex, FftPlanner};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::Path;
 
 type Complex64 = Complex<f64>;
 
+/// FFT size used for the spectral feature block (power of two)
+const SPECTRAL_FFT_SIZE: usize = 64;
+/// Number of non-DC magnitude bins kept from the spectral feature block
+const SPECTRAL_NUM_BINS: usize = 16;
+
 /// Classical traffic signature for Gabriel Cell clustering
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClassicalSignature {
@@ -41,34 +53,206 @@ pub struct ClassicalSignature {
     pub intro_ratio: f64,
     /// Total bytes transferred
     pub total_bytes: f64,
+    /// Normalized magnitude spectrum of the inter-arrival timing series
+    /// (first non-DC bins), distinguishing periodic flood timing from
+    /// broad-band benign traffic, followed by the dominant timing-FFT
+    /// bin (normalized to `[0, 1]`) and its peak-to-mean ratio from
+    /// `TimingFeatures`
+    #[serde(default)]
+    pub spectral: Vec<f64>,
 }
 
 impl ClassicalSignature {
     /// Create from Tor circuit metadata
     pub fn from_metadata(metadata: &TorCircuitMetadata, timing: &TimingFeatures, dist: &CellTypeDistribution) -> Self {
+        let mut spectral = Self::spectral_features(&metadata.cell_timings);
+        spectral.push(timing.dominant_bin as f64 / (crate::tor::TIMING_FFT_LEN / 2) as f64);
+        spectral.push(timing.peak_ratio);
+
         Self {
             mean_interval: timing.mean_interval,
             std_dev_interval: timing.std_dev_interval,
             data_ratio: dist.data_ratio,
             intro_ratio: dist.intro_ratio,
             total_bytes: metadata.total_bytes as f64,
+            spectral,
+        }
+    }
+
+    /// Extracts a normalized magnitude spectrum from the raw cell
+    /// inter-arrival timing sequence: resamples/zero-pads the interval
+    /// series to a fixed power-of-two length, removes the DC offset,
+    /// runs a real FFT, and keeps the first `SPECTRAL_NUM_BINS`
+    /// non-DC magnitudes normalized by total spectral energy.
+    fn spectral_features(cell_timings: &[std::time::Duration]) -> Vec<f64> {
+        if cell_timings.len() < 2 {
+            return vec![0.0; SPECTRAL_NUM_BINS];
+        }
+
+        let intervals: Vec<f64> = cell_timings
+            .windows(2)
+            .map(|w| (w[1].as_micros() as i128 - w[0].as_micros() as i128).abs() as f64)
+            .collect();
+
+        let mut series = resample_to_length(&intervals, SPECTRAL_FFT_SIZE);
+
+        let mean = series.iter().sum::<f64>() / series.len() as f64;
+        for value in &mut series {
+            *value -= mean;
+        }
+
+        let mut buffer: Vec<FftComplex<f64>> =
+            series.iter().map(|&x| FftComplex::new(x, 0.0)).collect();
+
+        let mut planner = FftPlanner::<f64>::new();
+        let fft = planner.plan_fft_forward(SPECTRAL_FFT_SIZE);
+        fft.process(&mut buffer);
+
+        let magnitudes: Vec<f64> = buffer
+            .iter()
+            .skip(1) // DC-Bin überspringen
+            .take(SPECTRAL_NUM_BINS)
+            .map(|c| c.norm())
+            .collect();
+
+        let total_energy: f64 = magnitudes.iter().map(|m| m * m).sum::<f64>().sqrt();
+        if total_energy > 1e-10 {
+            magnitudes.iter().map(|&m| m / total_energy).collect()
+        } else {
+            vec![0.0; SPECTRAL_NUM_BINS]
         }
     }
 
     /// Convert to feature vector for distance calculations
     pub fn to_vector(&self) -> Array1<f64> {
-        Array1::from(vec![
+        let mut features = vec![
             self.mean_interval / 1000.0,      // Normalize to milliseconds
             self.std_dev_interval / 1000.0,   // Normalize to milliseconds
             self.data_ratio,
             self.intro_ratio,
             (self.total_bytes / 1024.0).ln(), // Log-scale bytes (KB)
-        ])
+        ];
+        features.extend_from_slice(&self.spectral);
+        Array1::from(features)
     }
 }
 
-/// Hybrid Gabriel-Mandorla region combining classical and quantum representations
+/// Linearly resamples (or zero-pads) `series` to exactly `target_len`
+/// samples, preserving the overall shape of the original sequence.
+fn resample_to_length(series: &[f64], target_len: usize) -> Vec<f64> {
+    if series.is_empty() {
+        return vec![0.0; target_len];
+    }
+    if series.len() == 1 {
+        let mut padded = vec![0.0; target_len];
+        padded[0] = series[0];
+        return padded;
+    }
+
+    (0..target_len)
+        .map(|i| {
+            let position = i as f64 * (series.len() - 1) as f64 / (target_len - 1).max(1) as f64;
+            let lower = position.floor() as usize;
+            let upper = (lower + 1).min(series.len() - 1);
+            let frac = position - lower as f64;
+            series[lower] * (1.0 - frac) + series[upper] * frac
+        })
+        .collect()
+}
+
+/// Combined quantum+classical state treated as a single algebraic
+/// object, so amplitude blending (EMA, weighted merges) can be
+/// expressed with plain vector-space operations instead of ad-hoc
+/// per-field assignments.
 #[derive(Debug, Clone)]
+pub struct SemiclassicalState {
+    /// Quantum amplitude component
+    pub amplitudes: SVector<Complex64, HILBERT_DIM>,
+    /// Classical feature centroid (matches `ClassicalSignature::to_vector()`)
+    pub classical: Array1<f64>,
+}
+
+impl SemiclassicalState {
+    /// Builds a semiclassical state from a quantum/classical pair
+    pub fn from_parts(quantum: &QuantumState, classical: &ClassicalSignature) -> Self {
+        Self {
+            amplitudes: quantum.amplitudes,
+            classical: classical.to_vector(),
+        }
+    }
+
+    /// Zero element of the vector space (quantum and classical parts both zero)
+    pub fn zero(classical_dim: usize) -> Self {
+        Self {
+            amplitudes: SVector::<Complex64, HILBERT_DIM>::zeros(),
+            classical: Array1::zeros(classical_dim),
+        }
+    }
+
+    /// Renormalizes the quantum component to unit norm; the classical
+    /// part is left untouched.
+    pub fn normalize(&self) -> Self {
+        let norm = self
+            .amplitudes
+            .iter()
+            .map(|c| c.norm_sqr())
+            .sum::<f64>()
+            .sqrt();
+
+        let amplitudes = if norm > 1e-12 {
+            self.amplitudes.scale(1.0 / norm)
+        } else {
+            self.amplitudes
+        };
+
+        Self {
+            amplitudes,
+            classical: self.classical.clone(),
+        }
+    }
+
+    /// Recovers the quantum-state component
+    pub fn quantum_state(&self) -> QuantumState {
+        QuantumState::new(self.amplitudes)
+    }
+}
+
+impl std::ops::Add for SemiclassicalState {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            amplitudes: self.amplitudes + rhs.amplitudes,
+            classical: self.classical + rhs.classical,
+        }
+    }
+}
+
+impl std::ops::Sub for SemiclassicalState {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            amplitudes: self.amplitudes - rhs.amplitudes,
+            classical: self.classical - rhs.classical,
+        }
+    }
+}
+
+impl std::ops::Mul<f64> for SemiclassicalState {
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self {
+        Self {
+            amplitudes: self.amplitudes.scale(scalar),
+            classical: self.classical * scalar,
+        }
+    }
+}
+
+/// Maximum number of recent classical samples retained per region for
+/// FISTA centroid refinement
+const RECENT_SAMPLES_CAPACITY: usize = 16;
+
+/// Hybrid Gabriel-Mandorla region combining classical and quantum representations
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GabrielRegion {
     /// Classical centroid (signature)
     pub classical_center: ClassicalSignature,
@@ -82,6 +266,17 @@ pub struct GabrielRegion {
     pub sample_count: usize,
     /// Attack probability (0.0 = benign, 1.0 = attack)
     pub attack_probability: f64,
+    /// Ring buffer of recent classical sample vectors, used as the
+    /// loss data for FISTA centroid refinement. Not persisted in
+    /// checkpoints: it is a working buffer for FISTA, not learned state,
+    /// and simply starts empty again after a checkpoint load.
+    #[serde(skip)]
+    recent_samples: VecDeque<Array1<f64>>,
+    /// Marks this as a benign "anti-pattern" region, created to
+    /// suppress false positives rather than to recognize an attack.
+    /// `classify` requires the best attack-region resonance to exceed
+    /// the best anti-pattern resonance by a margin before alarming.
+    pub is_suppressor: bool,
 }
 
 impl GabrielRegion {
@@ -89,7 +284,7 @@ impl GabrielRegion {
     pub fn new(classical: ClassicalSignature, quantum: QuantumState, learning_rate: f64) -> Self {
         // Create Mandorla with self-intersection initially
         let mandorla = MandorlaRegion::new(quantum.clone(), quantum.clone(), 0.5);
-        
+
         Self {
             classical_center: classical,
             quantum_center: quantum,
@@ -97,6 +292,8 @@ impl GabrielRegion {
             learning_rate,
             sample_count: 0,
             attack_probability: 0.5, // Neutral initially
+            recent_samples: VecDeque::with_capacity(RECENT_SAMPLES_CAPACITY),
+            is_suppressor: false,
         }
     }
 
@@ -122,7 +319,14 @@ impl GabrielRegion {
     /// Update region with new sample (adaptive learning)
     pub fn update(&mut self, classical: ClassicalSignature, quantum: QuantumState, is_attack: bool) {
         self.sample_count += 1;
-        
+
+        // Retain the sample's classical feature vector for later FISTA
+        // centroid refinement
+        if self.recent_samples.len() == RECENT_SAMPLES_CAPACITY {
+            self.recent_samples.pop_front();
+        }
+        self.recent_samples.push_back(classical.to_vector());
+
         // Update classical center (exponential moving average)
         let alpha = self.learning_rate;
         self.classical_center.mean_interval = 
@@ -136,9 +340,20 @@ impl GabrielRegion {
         self.classical_center.total_bytes = 
             (1.0 - alpha) * self.classical_center.total_bytes + alpha * classical.total_bytes;
 
-        // Update quantum center (requires state blending - simplified version)
-        // In full implementation, would use Mandorla fusion
-        self.quantum_center = quantum;
+        // Update quantum center: blend as an exponential moving average in
+        // amplitude space, treating the quantum+classical pair as one
+        // algebraic object, instead of discarding accumulated history.
+        let old_state = SemiclassicalState::from_parts(&self.quantum_center, &self.classical_center);
+        let sample_state = SemiclassicalState::from_parts(&quantum, &classical);
+        let blended = (old_state * (1.0 - alpha) + sample_state * alpha).normalize();
+        let fused_quantum = blended.quantum_state();
+
+        // Fold the fused center through Mandorla self-intersection so the
+        // stored Mandorla actually tracks the fused region, rather than
+        // staying frozen at its initial self-intersection.
+        self.mandorla.center1 = self.quantum_center.clone();
+        self.mandorla.center2 = fused_quantum;
+        self.quantum_center = self.mandorla.compute_intersection();
 
         // Update attack probability
         let attack_indicator = if is_attack { 1.0 } else { 0.0 };
@@ -150,31 +365,164 @@ impl GabrielRegion {
     pub fn is_attack_region(&self) -> bool {
         self.attack_probability > 0.7
     }
+
+    /// Writes a feature vector (in the `ClassicalSignature::to_vector()`
+    /// layout) back into the classical centroid's named fields.
+    fn apply_vector(&mut self, v: &Array1<f64>) {
+        self.classical_center.mean_interval = v[0] * 1000.0;
+        self.classical_center.std_dev_interval = v[1] * 1000.0;
+        self.classical_center.data_ratio = v[2];
+        self.classical_center.intro_ratio = v[3];
+        self.classical_center.total_bytes = v[4].exp() * 1024.0;
+        self.classical_center.spectral = v.iter().skip(5).copied().collect();
+    }
+
+    /// Refines the classical centroid with FISTA (accelerated proximal
+    /// gradient with inertia), minimizing the total within-region loss
+    /// L(x) = Σ ½‖x − sample‖² over the retained recent samples.
+    ///
+    /// Momentum is reset (a standard FISTA restart guard) whenever the
+    /// loss increases between iterations. Returns the number of
+    /// iterations actually run and whether the step norm converged
+    /// below `tolerance` before exhausting `max_iterations`.
+    pub fn fista_refine(&mut self, eta: f64, max_iterations: usize, tolerance: f64) -> (usize, bool) {
+        if self.recent_samples.is_empty() {
+            return (0, true);
+        }
+
+        let dim = self.classical_center.to_vector().len();
+        if self.recent_samples.iter().any(|s| s.len() != dim) {
+            // Mixed-dimension samples (e.g. spectral block absent on some
+            // samples) cannot share a loss landscape; skip refinement.
+            return (0, true);
+        }
+
+        let samples = &self.recent_samples;
+        let loss = |x: &Array1<f64>| -> f64 {
+            samples
+                .iter()
+                .map(|sample| 0.5 * (x - sample).mapv(|d| d * d).sum())
+                .sum()
+        };
+        let gradient = |x: &Array1<f64>| -> Array1<f64> {
+            samples
+                .iter()
+                .fold(Array1::<f64>::zeros(dim), |acc, sample| acc + (x - sample))
+        };
+
+        let mut x_prev = self.classical_center.to_vector();
+        let mut x_curr = x_prev.clone();
+        let mut t_curr = 1.0_f64;
+        let mut loss_prev = loss(&x_curr);
+
+        let mut iterations = 0;
+        let mut converged = false;
+
+        for _ in 0..max_iterations {
+            iterations += 1;
+
+            let t_next = (1.0 + (1.0 + 4.0 * t_curr * t_curr).sqrt()) / 2.0;
+            let momentum = (t_curr - 1.0) / t_next;
+            let extrapolation = (x_curr.clone() - x_prev.clone()).mapv(|d| d * momentum);
+            let y: Array1<f64> = x_curr.clone() + extrapolation;
+
+            let step = gradient(&y).mapv(|d| d * eta);
+            let x_next: Array1<f64> = y - step;
+            let loss_next = loss(&x_next);
+
+            let step_norm = (x_next.clone() - x_curr.clone()).mapv(|d| d * d).sum().sqrt();
+
+            if loss_next > loss_prev {
+                // FISTA restart guard: the loss increased, so discard
+                // momentum and fall back to a plain gradient step.
+                t_curr = 1.0;
+                x_prev = x_curr.clone();
+            } else {
+                t_curr = t_next;
+                x_prev = x_curr;
+            }
+
+            x_curr = x_next;
+            loss_prev = loss(&x_curr);
+
+            if step_norm < tolerance {
+                converged = true;
+                break;
+            }
+        }
+
+        self.apply_vector(&x_curr);
+        (iterations, converged)
+    }
+}
+
+/// Tunable parameters for the QCNN-style hierarchical classical→quantum
+/// encoder: a shared 2×2 rotation block that entangles neighboring
+/// feature slots (convolution), and a pooling angle that combines
+/// adjacent convolution outputs into higher-order composite features.
+/// Both angles are translation-invariant (the same weights are swept
+/// across every position), so they can later be learned.
+#[derive(Debug, Clone, Copy)]
+pub struct EncoderParams {
+    /// Rotation angle θ of the shared convolution block
+    pub rotation_angle: f64,
+    /// Pooling angle φ combining adjacent composite features
+    pub pooling_angle: f64,
+}
+
+impl Default for EncoderParams {
+    fn default() -> Self {
+        Self {
+            rotation_angle: std::f64::consts::FRAC_PI_6,
+            pooling_angle: std::f64::consts::FRAC_PI_4,
+        }
+    }
 }
 
 /// Conversion utilities between classical and quantum representations
 pub struct ConversionUtils;
 
 impl ConversionUtils {
-    /// Convert classical signature to quantum state
+    /// Convert classical signature to quantum state using the default
+    /// encoder parameters
     /// Maps 5D classical features to 13D Hilbert space
     pub fn classical_to_quantum(signature: &ClassicalSignature) -> QuantumState {
+        Self::classical_to_quantum_with_params(signature, EncoderParams::default())
+    }
+
+    /// QCNN-style hierarchical encoding of the 5 base classical features
+    /// into the 13-D Hilbert space:
+    ///
+    /// 1. Convolution: a shared rotation block `[[cosθ,-sinθ],[sinθ,cosθ]]`
+    ///    is swept across adjacent feature pairs, entangling neighbors
+    ///    into 4 convolved values.
+    /// 2. Pooling: adjacent convolved values are combined via
+    ///    `a' = cosφ·a_i + sinφ·a_j` into 4 composite features.
+    ///
+    /// The 5 raw features, 4 convolved values and 4 pooled composites
+    /// (5+4+4=13) become the amplitudes, renormalized by `QuantumState::new`.
+    pub fn classical_to_quantum_with_params(
+        signature: &ClassicalSignature,
+        params: EncoderParams,
+    ) -> QuantumState {
         let vec = signature.to_vector();
-        
-        // Expand 5D to 13D with harmonic expansion
+        let features: Vec<f64> = (0..5).map(|i| *vec.get(i).unwrap_or(&0.0)).collect();
+
+        let (cos_theta, sin_theta) = (params.rotation_angle.cos(), params.rotation_angle.sin());
+        let conv: Vec<f64> = (0..4)
+            .map(|i| cos_theta * features[i] - sin_theta * features[i + 1])
+            .collect();
+
+        let (cos_phi, sin_phi) = (params.pooling_angle.cos(), params.pooling_angle.sin());
+        let pool: Vec<f64> = (0..4)
+            .map(|i| cos_phi * conv[i] + sin_phi * conv[(i + 1) % 4])
+            .collect();
+
         let mut amplitudes = SVector::<Complex64, HILBERT_DIM>::zeros();
-        
-        // Direct mapping for first 5 dimensions (real amplitudes)
-        for i in 0..5.min(vec.len()) {
-            amplitudes[i] = Complex::new(vec[i], 0.0);
-        }
-        
-        // Harmonic expansion for remaining dimensions
-        for i in 5..HILBERT_DIM {
-            let phase = i as f64 * std::f64::consts::PI / HILBERT_DIM as f64;
-            amplitudes[i] = Complex::new(vec[i % 5] * phase.sin(), 0.0);
+        for (i, &value) in features.iter().chain(conv.iter()).chain(pool.iter()).enumerate() {
+            amplitudes[i] = Complex::new(value, 0.0);
         }
-        
+
         QuantumState::new(amplitudes)
     }
 
@@ -190,6 +538,7 @@ impl ConversionUtils {
             data_ratio: amps[2].norm().min(1.0),
             intro_ratio: amps[3].norm().min(1.0),
             total_bytes: (amps[4].norm() * 1024.0).exp(), // Inverse log-scale
+            spectral: Vec::new(),
         }
     }
 }
@@ -206,6 +555,7 @@ mod tests {
             data_ratio: 0.8,
             intro_ratio: 0.1,
             total_bytes: 1024.0,
+            spectral: vec![],
         };
         
         let vec = sig.to_vector();
@@ -221,12 +571,41 @@ mod tests {
             data_ratio: 0.8,
             intro_ratio: 0.1,
             total_bytes: 1024.0,
+            spectral: vec![],
         };
         
         let quantum = ConversionUtils::classical_to_quantum(&sig);
         assert!(quantum.is_normalized());
     }
 
+    #[test]
+    fn test_qcnn_encoder_separates_distinct_signatures() {
+        let sig_a = ClassicalSignature {
+            mean_interval: 10.0,
+            std_dev_interval: 1.0,
+            data_ratio: 0.1,
+            intro_ratio: 0.0,
+            total_bytes: 100.0,
+            spectral: vec![],
+        };
+        let sig_b = ClassicalSignature {
+            mean_interval: 5000.0,
+            std_dev_interval: 2000.0,
+            data_ratio: 0.9,
+            intro_ratio: 0.8,
+            total_bytes: 1_000_000.0,
+            spectral: vec![],
+        };
+
+        let params = EncoderParams::default();
+        let quantum_a = ConversionUtils::classical_to_quantum_with_params(&sig_a, params);
+        let quantum_b = ConversionUtils::classical_to_quantum_with_params(&sig_b, params);
+
+        assert!(quantum_a.is_normalized());
+        assert!(quantum_b.is_normalized());
+        assert!(quantum_a.fidelity(&quantum_b) < 0.99);
+    }
+
     #[test]
     fn test_gabriel_region_creation() {
         let sig = ClassicalSignature {
@@ -235,6 +614,7 @@ mod tests {
             data_ratio: 0.8,
             intro_ratio: 0.1,
             total_bytes: 1024.0,
+            spectral: vec![],
         };
         
         let quantum = ConversionUtils::classical_to_quantum(&sig);
@@ -252,6 +632,7 @@ mod tests {
             data_ratio: 0.8,
             intro_ratio: 0.1,
             total_bytes: 1024.0,
+            spectral: vec![],
         };
         
         let quantum = ConversionUtils::classical_to_quantum(&sig);
@@ -270,6 +651,7 @@ mod tests {
             data_ratio: 0.8,
             intro_ratio: 0.1,
             total_bytes: 1024.0,
+            spectral: vec![],
         };
         
         let quantum = ConversionUtils::classical_to_quantum(&sig);
@@ -289,6 +671,7 @@ mod tests {
             data_ratio: 0.8,
             intro_ratio: 0.1,
             total_bytes: 1024.0,
+            spectral: vec![],
         };
         
         let quantum = ConversionUtils::classical_to_quantum(&sig);
@@ -301,6 +684,77 @@ mod tests {
         
         assert!(region.is_attack_region());
     }
+
+    #[test]
+    fn test_fista_refine_reduces_loss_towards_samples() {
+        let sig = ClassicalSignature {
+            mean_interval: 100.0,
+            std_dev_interval: 20.0,
+            data_ratio: 0.8,
+            intro_ratio: 0.1,
+            total_bytes: 1024.0,
+            spectral: vec![],
+        };
+
+        let quantum = ConversionUtils::classical_to_quantum(&sig);
+        let mut region = GabrielRegion::new(sig.clone(), quantum.clone(), 0.1);
+
+        let samples = [
+            ClassicalSignature {
+                mean_interval: 500.0,
+                std_dev_interval: 50.0,
+                data_ratio: 0.2,
+                intro_ratio: 0.3,
+                total_bytes: 4096.0,
+                spectral: vec![],
+            },
+            ClassicalSignature {
+                mean_interval: 520.0,
+                std_dev_interval: 55.0,
+                data_ratio: 0.25,
+                intro_ratio: 0.28,
+                total_bytes: 4200.0,
+                spectral: vec![],
+            },
+        ];
+        for sample in &samples {
+            region.update(sample.clone(), quantum.clone(), false);
+        }
+
+        let loss_before: f64 = samples
+            .iter()
+            .map(|s| (&region.classical_center.to_vector() - &s.to_vector()).mapv(|d| d * d).sum())
+            .sum();
+
+        let (iterations, _converged) = region.fista_refine(0.1, 50, 1e-9);
+
+        let loss_after: f64 = samples
+            .iter()
+            .map(|s| (&region.classical_center.to_vector() - &s.to_vector()).mapv(|d| d * d).sum())
+            .sum();
+
+        assert!(iterations > 0);
+        assert!(loss_after <= loss_before);
+    }
+
+    #[test]
+    fn test_fista_refine_no_samples_is_a_noop() {
+        let sig = ClassicalSignature {
+            mean_interval: 100.0,
+            std_dev_interval: 20.0,
+            data_ratio: 0.8,
+            intro_ratio: 0.1,
+            total_bytes: 1024.0,
+            spectral: vec![],
+        };
+
+        let quantum = ConversionUtils::classical_to_quantum(&sig);
+        let mut region = GabrielRegion::new(sig, quantum, 0.1);
+
+        let (iterations, converged) = region.fista_refine(0.1, 50, 1e-9);
+        assert_eq!(iterations, 0);
+        assert!(converged);
+    }
 }
 
 /// Thronion Decision Engine
@@ -316,6 +770,98 @@ pub struct ThronionKernel {
     max_regions: usize,
     /// Learning rate for adaptive updates
     learning_rate: f64,
+    /// Minimum margin by which the best attack-region resonance must
+    /// exceed the best anti-pattern (suppressor) resonance before
+    /// `classify` declares an attack
+    anti_pattern_margin: f64,
+    /// Optional GBDT tie-breaker, consulted only when the top two
+    /// regions by resonance disagree on the attack vote. Retrained
+    /// during `EnhancedThronionKernel::optimize()` from
+    /// `labeled_samples`.
+    gbdt_model: Option<GBDT>,
+    /// Feature-vector width the current `gbdt_model` was trained with,
+    /// used to pad/truncate inference-time vectors consistently.
+    gbdt_feature_size: usize,
+    /// Accumulated (feature vector, is_attack) pairs for GBDT
+    /// retraining, bounded to the most recent samples.
+    labeled_samples: VecDeque<(Vec<f64>, bool)>,
+    /// Number of `classify` calls where the best-matching region would
+    /// have voted attack, but an anti-pattern (suppressor) region's
+    /// resonance vetoed it via `anti_pattern_margin`. An `AtomicUsize`
+    /// so `classify_batch`'s rayon-parallel decision step can update it
+    /// without taking `&mut self`.
+    attack_calls_suppressed: std::sync::atomic::AtomicUsize,
+}
+
+/// Ring-buffer capacity for the GBDT tie-breaker's labeled sample buffer
+const GBDT_SAMPLE_BUFFER_CAPACITY: usize = 512;
+/// Minimum number of labeled samples required before `retrain_gbdt` fits
+/// a model (too few rows makes the fitted trees meaningless)
+const GBDT_MIN_TRAINING_SAMPLES: usize = 10;
+/// Number of boosting iterations used when retraining the GBDT tie-breaker
+const GBDT_ITERATIONS: usize = 50;
+/// Shrinkage (learning rate) used when retraining the GBDT tie-breaker
+const GBDT_SHRINKAGE: f32 = 0.1;
+/// Default margin by which the best attack-region resonance must
+/// exceed the best anti-pattern resonance before declaring an attack
+const DEFAULT_ANTI_PATTERN_MARGIN: f64 = 0.1;
+
+/// Checkpoint format version for [`KernelCheckpoint`]. Bump whenever a
+/// change to `KernelCheckpoint`'s fields (or to `FeatureLayoutDescriptor`)
+/// would make an older checkpoint unsafe to load as-is.
+const CHECKPOINT_SCHEMA_VERSION: u32 = 1;
+
+/// Describes the dimension and field ordering of the feature vector
+/// produced by `ClassicalSignature::to_vector()` at checkpoint time, so
+/// that loading a checkpoint written by an incompatible
+/// `MetadataExtractor`/`ClassicalSignature` layout fails loudly instead
+/// of silently misclassifying against stale region centroids.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureLayoutDescriptor {
+    /// Length of `ClassicalSignature::to_vector()`
+    pub classical_feature_dim: usize,
+    /// Name of each feature, in vector order
+    pub field_order: Vec<String>,
+}
+
+impl FeatureLayoutDescriptor {
+    /// Describes the feature layout this build of the crate produces.
+    pub fn current() -> Self {
+        let mut field_order = vec![
+            "mean_interval_ms".to_string(),
+            "std_dev_interval_ms".to_string(),
+            "data_ratio".to_string(),
+            "intro_ratio".to_string(),
+            "total_bytes_log_kb".to_string(),
+        ];
+        field_order.extend((0..SPECTRAL_NUM_BINS).map(|bin| format!("spectral_bin_{bin}")));
+        field_order.push("timing_dominant_bin_normalized".to_string());
+        field_order.push("timing_peak_ratio".to_string());
+
+        Self {
+            classical_feature_dim: field_order.len(),
+            field_order,
+        }
+    }
+}
+
+/// Versioned on-disk snapshot of a [`ThronionKernel`]'s learned state:
+/// every region (prototypes, labels, resonance parameters), the labeled
+/// sample buffer used to retrain the GBDT tie-breaker, and the feature
+/// layout in effect when the checkpoint was written. The GBDT tie-breaker
+/// model itself is not persisted (the `gbdt` crate's fitted trees are not
+/// serializable here) — `ThronionKernel::retrain_gbdt()` rebuilds it from
+/// `labeled_samples` after a checkpoint load.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KernelCheckpoint {
+    pub schema_version: u32,
+    pub feature_layout: FeatureLayoutDescriptor,
+    pub regions: Vec<GabrielRegion>,
+    pub attack_threshold: f64,
+    pub max_regions: usize,
+    pub learning_rate: f64,
+    pub anti_pattern_margin: f64,
+    pub labeled_samples: Vec<(Vec<f64>, bool)>,
 }
 
 impl ThronionKernel {
@@ -326,65 +872,324 @@ impl ThronionKernel {
             attack_threshold: 0.5,
             max_regions: 100,
             learning_rate: 0.1,
+            anti_pattern_margin: DEFAULT_ANTI_PATTERN_MARGIN,
+            gbdt_model: None,
+            gbdt_feature_size: 0,
+            labeled_samples: VecDeque::with_capacity(GBDT_SAMPLE_BUFFER_CAPACITY),
+            attack_calls_suppressed: std::sync::atomic::AtomicUsize::new(0),
         }
     }
-    
-    /// Create with custom parameters
-    pub fn with_params(attack_threshold: f64, max_regions: usize, learning_rate: f64) -> Self {
+
+    /// Create with custom parameters, including the anti-pattern margin
+    /// (see [`ThronionKernel::anti_pattern_margin`]).
+    pub fn with_params(
+        attack_threshold: f64,
+        max_regions: usize,
+        learning_rate: f64,
+        anti_pattern_margin: f64,
+    ) -> Self {
         Self {
             regions: Vec::new(),
             attack_threshold,
             max_regions,
             learning_rate,
+            anti_pattern_margin,
+            gbdt_model: None,
+            gbdt_feature_size: 0,
+            labeled_samples: VecDeque::with_capacity(GBDT_SAMPLE_BUFFER_CAPACITY),
+            attack_calls_suppressed: std::sync::atomic::AtomicUsize::new(0),
         }
     }
-    
-    /// Classify a circuit as attack or benign
+
+    /// Sets the anti-pattern margin used by `classify` (see
+    /// [`ThronionKernel::anti_pattern_margin`]).
+    pub fn set_anti_pattern_margin(&mut self, margin: f64) {
+        self.anti_pattern_margin = margin;
+    }
+
+    /// Number of `classify` calls so far where an anti-pattern region's
+    /// resonance vetoed what would otherwise have been an attack vote.
+    pub fn attack_calls_suppressed(&self) -> usize {
+        self.attack_calls_suppressed
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Builds the feature vector used for GBDT training and inference:
+    /// the classical feature vector followed by this sample's resonance
+    /// score against every currently known region.
+    fn labeled_feature_vector(&self, classical: &ClassicalSignature, quantum: &QuantumState) -> Vec<f64> {
+        let mut features = classical.to_vector().to_vec();
+        features.extend(self.regions.iter().map(|region| region.hybrid_resonance(classical, quantum)));
+        features
+    }
+
+    /// Retains a (feature vector, label) pair for later GBDT retraining.
+    fn record_labeled_sample(&mut self, classical: &ClassicalSignature, quantum: &QuantumState, is_attack: bool) {
+        if self.labeled_samples.len() == GBDT_SAMPLE_BUFFER_CAPACITY {
+            self.labeled_samples.pop_front();
+        }
+        let features = self.labeled_feature_vector(classical, quantum);
+        self.labeled_samples.push_back((features, is_attack));
+    }
+
+    /// Retrains the GBDT tie-breaker from the accumulated labeled
+    /// sample buffer. Rows are padded/truncated to a common feature
+    /// width (the widest row currently buffered), since the number of
+    /// regions — and therefore the number of resonance-score features
+    /// — changes over time. Does nothing if too few samples have been
+    /// accumulated yet.
+    pub(crate) fn retrain_gbdt(&mut self) {
+        if self.labeled_samples.len() < GBDT_MIN_TRAINING_SAMPLES {
+            return;
+        }
+
+        let feature_size = self.labeled_samples.iter().map(|(f, _)| f.len()).max().unwrap_or(0);
+        if feature_size == 0 {
+            return;
+        }
+
+        let mut data: GbdtDataVec = self
+            .labeled_samples
+            .iter()
+            .map(|(features, is_attack)| {
+                let mut row = features.clone();
+                row.resize(feature_size, 0.0);
+                GbdtData::new_training_data(
+                    row.iter().map(|&v| v as f32).collect(),
+                    1.0,
+                    if *is_attack { 1.0 } else { 0.0 },
+                    None,
+                )
+            })
+            .collect();
+
+        let mut config = GbdtConfig::new();
+        config.set_feature_size(feature_size);
+        config.set_max_depth(4);
+        config.set_iterations(GBDT_ITERATIONS);
+        config.set_shrinkage(GBDT_SHRINKAGE);
+        config.set_loss("LogLikelyhood");
+        config.set_debug(false);
+
+        let mut model = GBDT::new(&config);
+        model.fit(&mut data);
+
+        self.gbdt_model = Some(model);
+        self.gbdt_feature_size = feature_size;
+    }
+
+    /// Classify a circuit as attack or benign.
+    ///
+    /// Thin wrapper around [`ThronionKernel::classify_batch`] for a
+    /// single circuit, so the two paths always agree on behavior.
+    pub fn classify(&self, metadata: &TorCircuitMetadata, timing: &TimingFeatures, dist: &CellTypeDistribution) -> ClassificationOutcome {
+        let circuits = [(metadata.clone(), timing.clone(), dist.clone())];
+        self.classify_batch(&circuits)
+            .into_iter()
+            .next()
+            .expect("classify_batch returns exactly one outcome per input circuit")
+    }
+
+    /// Classifies many circuits in one batched pass.
     ///
-    /// Returns (is_attack, resonance_score, best_region_idx)
-    pub fn classify(&self, metadata: &TorCircuitMetadata, timing: &TimingFeatures, dist: &CellTypeDistribution) -> (bool, f64, Option<usize>) {
+    /// Precomputes every query's classical/quantum signature once, then
+    /// evaluates resonance against every region with matrix operations
+    /// instead of `classify`'s nested scalar loop: classical Euclidean
+    /// distances come from a single Gram-matrix identity
+    /// (‖q−r‖² = ‖q‖²+‖r‖²−2·q·r) and quantum fidelities
+    /// `|⟨ψ_q|ψ_r⟩|²` from one complex matrix multiply. The final
+    /// per-query decision (anti-pattern margin, GBDT tie-break) is
+    /// evaluated in parallel across queries with `rayon`.
+    pub fn classify_batch(
+        &self,
+        circuits: &[(TorCircuitMetadata, TimingFeatures, CellTypeDistribution)],
+    ) -> Vec<ClassificationOutcome> {
+        use rayon::prelude::*;
+
         if self.regions.is_empty() {
-            // No learned regions yet - default to benign
-            return (false, 0.0, None);
+            return circuits
+                .iter()
+                .map(|_| ClassificationOutcome {
+                    is_attack: false,
+                    resonance: 0.0,
+                    region_idx: None,
+                    region_vote: false,
+                    gbdt_decision: None,
+                    blended_probability: None,
+                })
+                .collect();
         }
-        
-        // Extract signatures
-        let classical = ClassicalSignature::from_metadata(metadata, timing, dist);
-        let quantum = ConversionUtils::classical_to_quantum(&classical);
-        
-        // Find best matching region
+
+        let queries: Vec<(ClassicalSignature, QuantumState)> = circuits
+            .iter()
+            .map(|(metadata, timing, dist)| {
+                let classical = ClassicalSignature::from_metadata(metadata, timing, dist);
+                let quantum = ConversionUtils::classical_to_quantum(&classical);
+                (classical, quantum)
+            })
+            .collect();
+
+        let resonance_matrix = self.batched_hybrid_resonance(&queries);
+
+        queries
+            .par_iter()
+            .enumerate()
+            .map(|(q, (classical, quantum))| {
+                let resonances = resonance_matrix.row(q);
+                let resonances: Vec<f64> = resonances.iter().copied().collect();
+                self.decide_from_resonances(classical, quantum, &resonances)
+            })
+            .collect()
+    }
+
+    /// Computes the `hybrid_resonance` score between every query and
+    /// every region as a single `(num_queries × num_regions)` matrix.
+    fn batched_hybrid_resonance(&self, queries: &[(ClassicalSignature, QuantumState)]) -> DMatrix<f64> {
+        let num_queries = queries.len();
+        let num_regions = self.regions.len();
+        let feature_len = self.regions[0].classical_center.to_vector().len();
+
+        let mut query_features = DMatrix::<f64>::zeros(num_queries, feature_len);
+        for (q, (classical, _)) in queries.iter().enumerate() {
+            let vec = classical.to_vector();
+            for f in 0..feature_len.min(vec.len()) {
+                query_features[(q, f)] = vec[f];
+            }
+        }
+        let mut region_features = DMatrix::<f64>::zeros(num_regions, feature_len);
+        for (r, region) in self.regions.iter().enumerate() {
+            let vec = region.classical_center.to_vector();
+            for f in 0..feature_len.min(vec.len()) {
+                region_features[(r, f)] = vec[f];
+            }
+        }
+
+        // ‖q−r‖² = ‖q‖²+‖r‖²−2·q·r for every (query, region) pair at once.
+        let query_norms_sq: DVector<f64> =
+            DVector::from_iterator(num_queries, query_features.row_iter().map(|row| row.dot(&row)));
+        let region_norms_sq: DVector<f64> =
+            DVector::from_iterator(num_regions, region_features.row_iter().map(|row| row.dot(&row)));
+        let cross = &query_features * region_features.transpose();
+
+        // Quantum amplitude matrices (columns = items); fidelity via a
+        // single complex matrix multiply F = |Qᴴ·R|² (elementwise norm²).
+        let mut query_amps = DMatrix::<Complex64>::zeros(HILBERT_DIM, num_queries);
+        for (q, (_, quantum)) in queries.iter().enumerate() {
+            for d in 0..HILBERT_DIM {
+                query_amps[(d, q)] = quantum.amplitudes[d];
+            }
+        }
+        let mut region_amps = DMatrix::<Complex64>::zeros(HILBERT_DIM, num_regions);
+        for (r, region) in self.regions.iter().enumerate() {
+            for d in 0..HILBERT_DIM {
+                region_amps[(d, r)] = region.quantum_center.amplitudes[d];
+            }
+        }
+        let inner_products = query_amps.adjoint() * region_amps;
+
+        const W_CLASSICAL: f64 = 0.3;
+        const W_QUANTUM: f64 = 0.7;
+
+        let mut resonance = DMatrix::<f64>::zeros(num_queries, num_regions);
+        for q in 0..num_queries {
+            for r in 0..num_regions {
+                let sq_distance = (query_norms_sq[q] + region_norms_sq[r] - 2.0 * cross[(q, r)]).max(0.0);
+                let classical_score = 1.0 / (1.0 + sq_distance.sqrt());
+                let quantum_score = inner_products[(q, r)].norm_sqr();
+                resonance[(q, r)] = W_CLASSICAL * classical_score + W_QUANTUM * quantum_score;
+            }
+        }
+        resonance
+    }
+
+    /// Turns a precomputed row of region resonances into the same
+    /// margin-gated region vote and GBDT tie-break decision that
+    /// `classify` used to compute inline.
+    fn decide_from_resonances(
+        &self,
+        classical: &ClassicalSignature,
+        quantum: &QuantumState,
+        resonances: &[f64],
+    ) -> ClassificationOutcome {
         let mut best_resonance = 0.0;
         let mut best_idx = 0;
-        
-        for (idx, region) in self.regions.iter().enumerate() {
-            let resonance = region.hybrid_resonance(&classical, &quantum);
+        let mut second_resonance = 0.0;
+        let mut second_idx: Option<usize> = None;
+        let mut best_suppressor_resonance = 0.0;
+
+        for (idx, &resonance) in resonances.iter().enumerate() {
+            let region = &self.regions[idx];
+
+            if region.is_suppressor && resonance > best_suppressor_resonance {
+                best_suppressor_resonance = resonance;
+            }
+
             if resonance > best_resonance {
+                second_resonance = best_resonance;
+                second_idx = Some(best_idx);
                 best_resonance = resonance;
                 best_idx = idx;
+            } else if resonance > second_resonance {
+                second_resonance = resonance;
+                second_idx = Some(idx);
             }
         }
-        
-        // Decision based on best region's attack probability
-        let is_attack = if best_resonance > 0.3 {
-            // Strong match to a region - use its attack probability
-            self.regions[best_idx].is_attack_region()
-        } else {
-            // Weak match - default to benign (conservative)
-            false
+
+        let region_votes = |idx: usize| -> bool {
+            let region = &self.regions[idx];
+            !region.is_suppressor && region.is_attack_region()
         };
-        
-        (is_attack, best_resonance, Some(best_idx))
+
+        // Decision based on best region's attack probability, gated by
+        // the anti-pattern margin so a strong suppressor match can veto
+        // a weak attack match.
+        let attack_candidate = best_resonance > 0.3 && region_votes(best_idx);
+        let region_vote =
+            attack_candidate && best_resonance > best_suppressor_resonance + self.anti_pattern_margin;
+
+        if attack_candidate && !region_vote {
+            self.attack_calls_suppressed
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        // Only consult the GBDT tie-breaker when the top two regions
+        // disagree on the attack vote.
+        let gbdt_decision = second_idx.and_then(|second| {
+            if region_votes(best_idx) == region_votes(second) {
+                return None;
+            }
+            self.gbdt_model.as_ref().map(|model| {
+                let mut features = self.labeled_feature_vector(classical, quantum);
+                features.resize(self.gbdt_feature_size, 0.0);
+                let test_data: GbdtDataVec =
+                    vec![GbdtData::new_test_data(features.iter().map(|&v| v as f32).collect(), None)];
+                model.predict(&test_data)[0] > 0.5
+            })
+        });
+
+        let is_attack = gbdt_decision.unwrap_or(region_vote);
+
+        ClassificationOutcome {
+            is_attack,
+            resonance: best_resonance,
+            region_idx: Some(best_idx),
+            region_vote,
+            gbdt_decision,
+            blended_probability: None,
+        }
     }
-    
+
     /// Learn from a labeled circuit (online learning)
     pub fn learn(&mut self, metadata: &TorCircuitMetadata, timing: &TimingFeatures, dist: &CellTypeDistribution, is_attack: bool) {
         let classical = ClassicalSignature::from_metadata(metadata, timing, dist);
         let quantum = ConversionUtils::classical_to_quantum(&classical);
-        
+
+        self.record_labeled_sample(&classical, &quantum, is_attack);
+
         // Find closest region
         let mut best_resonance = 0.0;
         let mut best_idx = None;
-        
+
         for (idx, region) in self.regions.iter().enumerate() {
             let resonance = region.hybrid_resonance(&classical, &quantum);
             if resonance > best_resonance {
@@ -392,7 +1197,7 @@ impl ThronionKernel {
                 best_idx = Some(idx);
             }
         }
-        
+
         if best_resonance > 0.5 {
             // Update existing region
             if let Some(idx) = best_idx {
@@ -402,13 +1207,14 @@ impl ThronionKernel {
             // Create new region if below capacity
             if self.regions.len() < self.max_regions {
                 let attack_prob = if is_attack { 1.0 } else { 0.0 };
-                let region = GabrielRegion::new(classical, quantum, attack_prob);
+                let mut region = GabrielRegion::new(classical, quantum, attack_prob);
+                region.is_suppressor = !is_attack;
                 self.regions.push(region);
             } else {
                 // Replace least confident region
                 let mut min_confidence = 1.0;
                 let mut min_idx = 0;
-                
+
                 for (idx, region) in self.regions.iter().enumerate() {
                     let confidence = (region.attack_probability - 0.5).abs();
                     if confidence < min_confidence {
@@ -416,9 +1222,11 @@ impl ThronionKernel {
                         min_idx = idx;
                     }
                 }
-                
+
                 let attack_prob = if is_attack { 1.0 } else { 0.0 };
-                self.regions[min_idx] = GabrielRegion::new(classical, quantum, attack_prob);
+                let mut region = GabrielRegion::new(classical, quantum, attack_prob);
+                region.is_suppressor = !is_attack;
+                self.regions[min_idx] = region;
             }
         }
     }
@@ -427,12 +1235,15 @@ impl ThronionKernel {
     pub fn stats(&self) -> KernelStats {
         let attack_regions = self.regions.iter().filter(|r| r.is_attack_region()).count();
         let benign_regions = self.regions.len() - attack_regions;
-        
+        let anti_pattern_regions = self.regions.iter().filter(|r| r.is_suppressor).count();
+
         KernelStats {
             total_regions: self.regions.len(),
             attack_regions,
             benign_regions,
             attack_threshold: self.attack_threshold,
+            anti_pattern_regions,
+            attack_calls_suppressed: self.attack_calls_suppressed(),
         }
     }
     
@@ -440,6 +1251,79 @@ impl ThronionKernel {
     pub fn reset(&mut self) {
         self.regions.clear();
     }
+
+    /// Snapshots the kernel's learned state into a versioned, serde-ready
+    /// [`KernelCheckpoint`]. See [`ThronionKernel::save_checkpoint`] to
+    /// write it to disk.
+    pub fn to_checkpoint(&self) -> KernelCheckpoint {
+        KernelCheckpoint {
+            schema_version: CHECKPOINT_SCHEMA_VERSION,
+            feature_layout: FeatureLayoutDescriptor::current(),
+            regions: self.regions.clone(),
+            attack_threshold: self.attack_threshold,
+            max_regions: self.max_regions,
+            learning_rate: self.learning_rate,
+            anti_pattern_margin: self.anti_pattern_margin,
+            labeled_samples: self.labeled_samples.iter().cloned().collect(),
+        }
+    }
+
+    /// Restores a kernel from a [`KernelCheckpoint`], failing loudly if
+    /// the checkpoint's schema version or feature layout does not match
+    /// what this build of the crate produces, rather than silently
+    /// matching stale region centroids against a different feature
+    /// space. The GBDT tie-breaker is not restored (see
+    /// [`KernelCheckpoint`]); call `retrain_gbdt()` afterwards if needed.
+    pub fn from_checkpoint(checkpoint: KernelCheckpoint) -> Result<Self> {
+        if checkpoint.schema_version != CHECKPOINT_SCHEMA_VERSION {
+            anyhow::bail!(
+                "checkpoint schema version {} is incompatible with this build (expected {})",
+                checkpoint.schema_version,
+                CHECKPOINT_SCHEMA_VERSION
+            );
+        }
+
+        let expected_layout = FeatureLayoutDescriptor::current();
+        if checkpoint.feature_layout != expected_layout {
+            anyhow::bail!(
+                "checkpoint feature layout (dim {}, fields {:?}) does not match this build's layout (dim {}, fields {:?})",
+                checkpoint.feature_layout.classical_feature_dim,
+                checkpoint.feature_layout.field_order,
+                expected_layout.classical_feature_dim,
+                expected_layout.field_order
+            );
+        }
+
+        let mut labeled_samples = VecDeque::with_capacity(GBDT_SAMPLE_BUFFER_CAPACITY);
+        labeled_samples.extend(checkpoint.labeled_samples);
+
+        Ok(Self {
+            regions: checkpoint.regions,
+            attack_threshold: checkpoint.attack_threshold,
+            max_regions: checkpoint.max_regions,
+            learning_rate: checkpoint.learning_rate,
+            anti_pattern_margin: checkpoint.anti_pattern_margin,
+            gbdt_model: None,
+            gbdt_feature_size: 0,
+            labeled_samples,
+            attack_calls_suppressed: std::sync::atomic::AtomicUsize::new(0),
+        })
+    }
+
+    /// Writes a TOML checkpoint of the kernel's learned state to `path`.
+    pub fn save_checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let toml = toml::to_string(&self.to_checkpoint()).context("Failed to serialize checkpoint")?;
+        std::fs::write(path.as_ref(), toml).context("Failed to write checkpoint file")?;
+        Ok(())
+    }
+
+    /// Loads a kernel from a TOML checkpoint previously written by
+    /// [`ThronionKernel::save_checkpoint`].
+    pub fn load_checkpoint<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = std::fs::read_to_string(path.as_ref()).context("Failed to read checkpoint file")?;
+        let checkpoint: KernelCheckpoint = toml::from_str(&content).context("Failed to parse checkpoint TOML")?;
+        Self::from_checkpoint(checkpoint)
+    }
 }
 
 impl Default for ThronionKernel {
@@ -455,6 +1339,224 @@ pub struct KernelStats {
     pub attack_regions: usize,
     pub benign_regions: usize,
     pub attack_threshold: f64,
+    /// Number of benign-prototype (anti-pattern) regions among
+    /// `total_regions`, i.e. regions with `is_suppressor == true`
+    pub anti_pattern_regions: usize,
+    /// Number of `classify` calls so far where an anti-pattern region's
+    /// resonance vetoed what would otherwise have been an attack vote
+    pub attack_calls_suppressed: usize,
+}
+
+/// Outcome of [`ThronionKernel::classify`], exposing both the
+/// region-vote decision (margin-gated against anti-pattern regions)
+/// and the GBDT tie-breaker's decision, if one was consulted, so
+/// callers can audit disagreements between the two.
+#[derive(Debug, Clone, Copy)]
+pub struct ClassificationOutcome {
+    /// Final attack/benign decision
+    pub is_attack: bool,
+    /// Best matching region's resonance score
+    pub resonance: f64,
+    /// Index of the best matching region, if any region exists
+    pub region_idx: Option<usize>,
+    /// Region-vote decision: best region is an attack region and beats
+    /// the best anti-pattern resonance by `anti_pattern_margin`
+    pub region_vote: bool,
+    /// GBDT tie-breaker decision, present only when the top two
+    /// regions disagreed on the attack vote and a trained model was
+    /// available
+    pub gbdt_decision: Option<bool>,
+    /// Attack probability from [`EnhancedThronionKernel`]'s standalone
+    /// [`GbdtClassifier`], blended with `resonance` into `is_attack`.
+    /// `None` when produced by [`ThronionKernel::classify`] directly, or
+    /// when the classifier has not been trained yet.
+    pub blended_probability: Option<f64>,
+}
+
+/// Number of boosting iterations used when retraining [`GbdtClassifier`]
+const GBDT_CLASSIFIER_ITERATIONS: usize = 80;
+/// Max tree depth used when retraining [`GbdtClassifier`]
+const GBDT_CLASSIFIER_MAX_DEPTH: u32 = 5;
+/// Shrinkage (learning rate) used when retraining [`GbdtClassifier`]
+const GBDT_CLASSIFIER_SHRINKAGE: f32 = 0.1;
+/// Minimum labeled samples required before [`GbdtClassifier::retrain`] fits
+/// a model
+const GBDT_CLASSIFIER_MIN_SAMPLES: usize = 10;
+/// Ring-buffer capacity for [`GbdtClassifier`]'s labeled sample buffer
+const GBDT_CLASSIFIER_BUFFER_CAPACITY: usize = 512;
+
+/// Standalone gradient-boosted decision-tree classifier over the full
+/// timing/cell-type/metadata feature space.
+///
+/// Complements [`ThronionKernel`]'s region-resonance scheme: the
+/// resonance kernel only matches against stored prototype regions,
+/// whereas this boosted ensemble can learn non-linear feature
+/// interactions from the full labeled history. It accumulates labeled
+/// feature vectors from [`GbdtClassifier::learn`] into a ring buffer and
+/// fits a new ensemble on [`GbdtClassifier::retrain`].
+pub struct GbdtClassifier {
+    /// Fitted boosted ensemble, `None` until enough labeled samples have
+    /// been accumulated
+    model: Option<GBDT>,
+    /// Feature-vector width the current `model` was trained with
+    feature_size: usize,
+    /// Accumulated (feature vector, is_attack) pairs, oldest evicted first
+    samples: VecDeque<(Vec<f64>, bool)>,
+    /// Number of boosting iterations used when retraining
+    pub iterations: usize,
+    /// Maximum tree depth used when retraining
+    pub max_depth: u32,
+    /// Shrinkage (learning rate) used when retraining
+    pub shrinkage: f32,
+}
+
+impl GbdtClassifier {
+    /// Creates a classifier with default hyperparameters, untrained.
+    pub fn new() -> Self {
+        Self {
+            model: None,
+            feature_size: 0,
+            samples: VecDeque::with_capacity(GBDT_CLASSIFIER_BUFFER_CAPACITY),
+            iterations: GBDT_CLASSIFIER_ITERATIONS,
+            max_depth: GBDT_CLASSIFIER_MAX_DEPTH,
+            shrinkage: GBDT_CLASSIFIER_SHRINKAGE,
+        }
+    }
+
+    /// Builds the flattened feature vector from `TimingFeatures` +
+    /// `CellTypeDistribution` + scalar circuit metadata
+    /// (`total_bytes`, `rendezvous_completed`), in a fixed field order.
+    pub fn feature_vector(
+        metadata: &TorCircuitMetadata,
+        timing: &TimingFeatures,
+        dist: &CellTypeDistribution,
+    ) -> Vec<f64> {
+        let mut features = vec![
+            timing.mean_interval,
+            timing.std_dev_interval,
+            timing.median_interval,
+            timing.min_interval,
+            timing.max_interval,
+            timing.dominant_bin as f64,
+            timing.peak_ratio,
+            dist.intro_ratio,
+            dist.rendezvous_ratio,
+            dist.data_ratio,
+            dist.padding_ratio,
+            dist.other_ratio,
+            metadata.total_bytes as f64,
+            if metadata.rendezvous_completed { 1.0 } else { 0.0 },
+        ];
+        features.extend_from_slice(&timing.spectrum);
+        features
+    }
+
+    /// Accumulates a labeled sample for the next `retrain` call, evicting
+    /// the oldest sample once the ring buffer is at capacity.
+    pub fn learn(
+        &mut self,
+        metadata: &TorCircuitMetadata,
+        timing: &TimingFeatures,
+        dist: &CellTypeDistribution,
+        is_attack: bool,
+    ) {
+        if self.samples.len() == GBDT_CLASSIFIER_BUFFER_CAPACITY {
+            self.samples.pop_front();
+        }
+        let features = Self::feature_vector(metadata, timing, dist);
+        self.samples.push_back((features, is_attack));
+    }
+
+    /// Refits the boosted ensemble from the accumulated labeled samples.
+    /// Does nothing if fewer than `GBDT_CLASSIFIER_MIN_SAMPLES` have been
+    /// accumulated yet.
+    pub fn retrain(&mut self) {
+        if self.samples.len() < GBDT_CLASSIFIER_MIN_SAMPLES {
+            return;
+        }
+
+        let feature_size = self.samples.iter().map(|(f, _)| f.len()).max().unwrap_or(0);
+        if feature_size == 0 {
+            return;
+        }
+
+        let mut data: GbdtDataVec = self
+            .samples
+            .iter()
+            .map(|(features, is_attack)| {
+                let mut row = features.clone();
+                row.resize(feature_size, 0.0);
+                GbdtData::new_training_data(
+                    row.iter().map(|&v| v as f32).collect(),
+                    1.0,
+                    if *is_attack { 1.0 } else { 0.0 },
+                    None,
+                )
+            })
+            .collect();
+
+        let mut config = GbdtConfig::new();
+        config.set_feature_size(feature_size);
+        config.set_max_depth(self.max_depth);
+        config.set_iterations(self.iterations);
+        config.set_shrinkage(self.shrinkage);
+        config.set_loss("LogLikelyhood");
+        config.set_debug(false);
+
+        let mut model = GBDT::new(&config);
+        model.fit(&mut data);
+
+        self.model = Some(model);
+        self.feature_size = feature_size;
+    }
+
+    /// Returns a calibrated attack probability in `[0, 1]` for the given
+    /// circuit, or `None` if the ensemble has not been fitted yet.
+    pub fn classify(
+        &self,
+        metadata: &TorCircuitMetadata,
+        timing: &TimingFeatures,
+        dist: &CellTypeDistribution,
+    ) -> Option<f64> {
+        let model = self.model.as_ref()?;
+
+        let mut features = Self::feature_vector(metadata, timing, dist);
+        features.resize(self.feature_size, 0.0);
+
+        let test_data: GbdtDataVec = vec![GbdtData::new_test_data(
+            features.iter().map(|&v| v as f32).collect(),
+            None,
+        )];
+        model.predict(&test_data).first().map(|&p| p as f64)
+    }
+
+    /// Per-feature importances of the fitted ensemble, in the same field
+    /// order as [`GbdtClassifier::feature_vector`]. Empty until the
+    /// ensemble has been fitted.
+    pub fn feature_importance(&self) -> Vec<f64> {
+        let Some(model) = self.model.as_ref() else {
+            return Vec::new();
+        };
+
+        let mut importance = vec![0.0; self.feature_size];
+        for (&feature_index, &(_split_count, gain)) in model.importance.iter() {
+            if feature_index < importance.len() {
+                importance[feature_index] = gain;
+            }
+        }
+        importance
+    }
+
+    /// Whether `retrain` has fitted an ensemble yet.
+    pub fn is_trained(&self) -> bool {
+        self.model.is_some()
+    }
+}
+
+impl Default for GbdtClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Enhanced Thronion Kernel with Delta Kernel optimization
@@ -470,33 +1572,102 @@ pub struct EnhancedThronionKernel {
     pub optimization_interval: usize,
     /// Classification counter
     pub classification_count: usize,
+    /// Total FISTA iterations run across regions in the last `optimize()` call
+    last_fista_iterations: usize,
+    /// Whether every refined region converged in the last `optimize()` call
+    last_fista_converged: bool,
+    /// Standalone GBDT classifier over the full timing/cell-type/metadata
+    /// feature space, blended with region resonance in `classify`
+    pub gbdt_classifier: GbdtClassifier,
+    /// Weight given to `gbdt_classifier`'s probability when blending with
+    /// region resonance, in `[0, 1]`. `0.0` ignores the classifier
+    /// entirely; `1.0` defers to it whenever it has a trained model.
+    pub gbdt_blend_weight: f64,
+    /// When set, `optimize()` writes a checkpoint to a rotating file
+    /// derived from this base path every time it runs (see
+    /// [`EnhancedThronionKernel::set_checkpoint_path`]). `None` disables
+    /// automatic checkpointing.
+    checkpoint_path: Option<std::path::PathBuf>,
 }
 
+/// Step size η for the FISTA gradient step on region centroids
+const FISTA_ETA: f64 = 0.1;
+/// Bound on the number of FISTA iterations per region
+const FISTA_MAX_ITERATIONS: usize = 50;
+/// Step-norm tolerance below which FISTA is considered converged
+const FISTA_TOLERANCE: f64 = 1e-6;
+/// Default weight given to the standalone GBDT classifier's probability
+/// when blending it with region resonance
+const DEFAULT_GBDT_BLEND_WEIGHT: f64 = 0.5;
+
 impl EnhancedThronionKernel {
     /// Create new enhanced kernel with Delta Kernel optimization
     pub fn new(base_kernel: ThronionKernel, delta_params: crate::delta::QRIKParams) -> Self {
         let delta_kernel = DeltaKernel::new(delta_params);
-        
+
         Self {
             base_kernel,
             delta_kernel,
             optimization_interval: 100, // Optimize every 100 classifications
             classification_count: 0,
+            last_fista_iterations: 0,
+            last_fista_converged: true,
+            gbdt_classifier: GbdtClassifier::new(),
+            gbdt_blend_weight: DEFAULT_GBDT_BLEND_WEIGHT,
+            checkpoint_path: None,
         }
     }
-    
+
+    /// Enables periodic automatic checkpointing: every time `optimize()`
+    /// runs (on the same `classification_count % optimization_interval`
+    /// cadence that triggers it), a checkpoint is written to a file
+    /// derived from `path` that alternates between two suffixes
+    /// (`.ckpt-0` / `.ckpt-1`), so a crash mid-write never destroys the
+    /// only on-disk copy of a long-running detector's learned regions.
+    pub fn set_checkpoint_path<P: Into<std::path::PathBuf>>(&mut self, path: P) {
+        self.checkpoint_path = Some(path.into());
+    }
+
+    /// Disables automatic checkpointing (see
+    /// [`EnhancedThronionKernel::set_checkpoint_path`]).
+    pub fn clear_checkpoint_path(&mut self) {
+        self.checkpoint_path = None;
+    }
+
+    /// The rotating checkpoint slot `optimize()` would write to next,
+    /// given the current `classification_count`, if automatic
+    /// checkpointing is enabled.
+    fn rotating_checkpoint_path(&self, base: &Path) -> std::path::PathBuf {
+        let slot = (self.classification_count / self.optimization_interval.max(1)) % 2;
+        let mut path = base.as_os_str().to_owned();
+        path.push(format!(".ckpt-{slot}"));
+        std::path::PathBuf::from(path)
+    }
+
     /// Classify with automatic optimization
-    pub fn classify(&mut self, metadata: &TorCircuitMetadata, timing: &TimingFeatures, dist: &CellTypeDistribution) -> (bool, f64, Option<usize>) {
+    ///
+    /// The base kernel's region-resonance decision is blended with the
+    /// standalone [`GbdtClassifier`]'s attack probability (weighted by
+    /// `gbdt_blend_weight`) whenever the classifier has a trained model;
+    /// otherwise the region-resonance decision is returned unchanged.
+    pub fn classify(&mut self, metadata: &TorCircuitMetadata, timing: &TimingFeatures, dist: &CellTypeDistribution) -> ClassificationOutcome {
         self.classification_count += 1;
-        
+
         // Perform classification
-        let result = self.base_kernel.classify(metadata, timing, dist);
-        
+        let mut result = self.base_kernel.classify(metadata, timing, dist);
+
+        if let Some(gbdt_probability) = self.gbdt_classifier.classify(metadata, timing, dist) {
+            let blended = self.gbdt_blend_weight * gbdt_probability
+                + (1.0 - self.gbdt_blend_weight) * result.resonance;
+            result.is_attack = blended >= 0.5;
+            result.blended_probability = Some(blended);
+        }
+
         // Periodic optimization
         if self.classification_count % self.optimization_interval == 0 {
             self.optimize();
         }
-        
+
         result
     }
     
@@ -504,7 +1675,10 @@ impl EnhancedThronionKernel {
     pub fn learn(&mut self, metadata: &TorCircuitMetadata, timing: &TimingFeatures, dist: &CellTypeDistribution, is_attack: bool) {
         // Standard learning
         self.base_kernel.learn(metadata, timing, dist, is_attack);
-        
+
+        // Accumulate a labeled sample for the standalone GBDT classifier
+        self.gbdt_classifier.learn(metadata, timing, dist, is_attack);
+
         // Evolve Delta Kernel
         self.delta_kernel.evolve(0.01);
     }
@@ -513,13 +1687,13 @@ impl EnhancedThronionKernel {
     pub fn optimize(&mut self) {
         // Check if optimization is needed
         let gradient = self.delta_kernel.coherence_gradient();
-        
+
         if gradient > 0.1 {
             // High gradient: system needs optimization
-            
+
             // 1. Merge similar regions based on quantum coherence
             self.merge_coherent_regions();
-            
+
             // 2. Evolve Delta Kernel towards optimal state
             for _ in 0..10 {
                 self.delta_kernel.evolve(0.01);
@@ -528,19 +1702,75 @@ impl EnhancedThronionKernel {
                 }
             }
         }
+
+        // 3. Refine region centroids with FISTA regardless of the
+        // coherence gradient, so centroids keep improving even when no
+        // merge was triggered.
+        self.refine_centroids();
+
+        // 4. Retrain the GBDT tie-breaker from the labeled samples
+        // accumulated since the last optimization pass.
+        self.base_kernel.retrain_gbdt();
+
+        // 5. Retrain the standalone GBDT classifier on the same cadence.
+        self.gbdt_classifier.retrain();
+
+        // 6. Write a rotating checkpoint if automatic checkpointing is
+        // enabled, so a crashed long-running detector can resume without
+        // losing its learned regions. `optimize()` has no `Result`
+        // return, so a write failure is logged rather than propagated.
+        if let Some(base_path) = self.checkpoint_path.clone() {
+            let path = self.rotating_checkpoint_path(&base_path);
+            if let Err(err) = self.save_checkpoint(&path) {
+                tracing::warn!("Failed to write automatic checkpoint to {:?}: {:#}", path, err);
+            }
+        }
+    }
+
+    /// Per-feature importances of the standalone GBDT classifier, in the
+    /// same field order as [`GbdtClassifier::feature_vector`]. Empty
+    /// until enough labeled samples have been seen to fit a model.
+    pub fn gbdt_feature_importance(&self) -> Vec<f64> {
+        self.gbdt_classifier.feature_importance()
+    }
+
+    /// Refines every region's classical centroid with FISTA-accelerated
+    /// proximal gradient descent on the within-region sample loss.
+    /// Aggregates the iteration counts and convergence status across
+    /// all regions for `EnhancedKernelStats`.
+    fn refine_centroids(&mut self) {
+        let mut total_iterations = 0;
+        let mut all_converged = true;
+
+        for region in &mut self.base_kernel.regions {
+            let (iterations, converged) =
+                region.fista_refine(FISTA_ETA, FISTA_MAX_ITERATIONS, FISTA_TOLERANCE);
+            total_iterations += iterations;
+            all_converged &= converged;
+        }
+
+        self.last_fista_iterations = total_iterations;
+        self.last_fista_converged = all_converged;
     }
     
     /// Merge regions with high quantum coherence (fidelity > 0.9)
     fn merge_coherent_regions(&mut self) {
         let mut to_merge: Vec<(usize, usize)> = Vec::new();
         
-        // Find pairs of regions with high fidelity
+        // Find pairs of regions with high fidelity. Attack regions and
+        // anti-pattern (suppressor) regions are never merged into each
+        // other, even at high fidelity: collapsing them would erase the
+        // veto an anti-pattern region is meant to provide.
         for i in 0..self.base_kernel.regions.len() {
             for j in (i+1)..self.base_kernel.regions.len() {
+                if self.base_kernel.regions[i].is_suppressor != self.base_kernel.regions[j].is_suppressor {
+                    continue;
+                }
+
                 let fidelity = self.base_kernel.regions[i]
                     .quantum_center
                     .fidelity(&self.base_kernel.regions[j].quantum_center);
-                
+
                 if fidelity > 0.9 {
                     to_merge.push((i, j));
                 }
@@ -558,11 +1788,20 @@ impl EnhancedThronionKernel {
                 if total_samples > 0 {
                     let w_i = region_i.sample_count as f64 / total_samples as f64;
                     let w_j = region_j.sample_count as f64 / total_samples as f64;
-                    
+
                     // Merge attack probabilities
                     let merged_prob = w_i * region_i.attack_probability + w_j * region_j.attack_probability;
+
+                    // True amplitude-weighted merge of the quantum centers,
+                    // weighted by sample counts, instead of only averaging
+                    // the attack probability.
+                    let state_i = SemiclassicalState::from_parts(&region_i.quantum_center, &region_i.classical_center);
+                    let state_j = SemiclassicalState::from_parts(&region_j.quantum_center, &region_j.classical_center);
+                    let merged_quantum = (state_i * w_i + state_j * w_j).normalize().quantum_state();
+
                     self.base_kernel.regions[*i].attack_probability = merged_prob;
                     self.base_kernel.regions[*i].sample_count = total_samples;
+                    self.base_kernel.regions[*i].quantum_center = merged_quantum;
                 }
                 
                 // Remove the merged region
@@ -581,6 +1820,178 @@ impl EnhancedThronionKernel {
         self.delta_kernel.is_stable(0.05)
     }
     
+    /// Snapshots the enhanced kernel's full learned state — base-kernel
+    /// regions, the Delta Kernel (quantum state, Hamiltonian, Kuramoto
+    /// network, coherence state, `QRIKParams`), optimization cadence, and
+    /// the standalone GBDT classifier's accumulated sample buffer — into
+    /// a versioned, serde-ready [`EnhancedKernelCheckpoint`].
+    pub fn to_checkpoint(&self) -> EnhancedKernelCheckpoint {
+        EnhancedKernelCheckpoint {
+            base: self.base_kernel.to_checkpoint(),
+            delta_kernel: self.delta_kernel.clone(),
+            optimization_interval: self.optimization_interval,
+            classification_count: self.classification_count,
+            gbdt_blend_weight: self.gbdt_blend_weight,
+            gbdt_classifier_samples: self.gbdt_classifier.samples.iter().cloned().collect(),
+            gbdt_classifier_iterations: self.gbdt_classifier.iterations,
+            gbdt_classifier_max_depth: self.gbdt_classifier.max_depth,
+            gbdt_classifier_shrinkage: self.gbdt_classifier.shrinkage,
+        }
+    }
+
+    /// Restores an enhanced kernel from an [`EnhancedKernelCheckpoint`],
+    /// failing loudly (via [`ThronionKernel::from_checkpoint`]) if the
+    /// base kernel's schema version or feature layout is incompatible.
+    /// Neither GBDT model is restored (see [`KernelCheckpoint`]); call
+    /// `base_kernel.retrain_gbdt()` / `gbdt_classifier.retrain()`
+    /// afterwards if needed. Automatic checkpointing is disabled on the
+    /// restored kernel; call `set_checkpoint_path` again if desired.
+    pub fn from_checkpoint(checkpoint: EnhancedKernelCheckpoint) -> Result<Self> {
+        let base_kernel = ThronionKernel::from_checkpoint(checkpoint.base)?;
+
+        let mut gbdt_classifier = GbdtClassifier::new();
+        gbdt_classifier.iterations = checkpoint.gbdt_classifier_iterations;
+        gbdt_classifier.max_depth = checkpoint.gbdt_classifier_max_depth;
+        gbdt_classifier.shrinkage = checkpoint.gbdt_classifier_shrinkage;
+        gbdt_classifier.samples.extend(checkpoint.gbdt_classifier_samples);
+
+        Ok(Self {
+            base_kernel,
+            delta_kernel: checkpoint.delta_kernel,
+            optimization_interval: checkpoint.optimization_interval,
+            classification_count: checkpoint.classification_count,
+            last_fista_iterations: 0,
+            last_fista_converged: true,
+            gbdt_classifier,
+            gbdt_blend_weight: checkpoint.gbdt_blend_weight,
+            checkpoint_path: None,
+        })
+    }
+
+    /// Writes a TOML checkpoint of the full enhanced-kernel state to `path`.
+    pub fn save_checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let toml = toml::to_string(&self.to_checkpoint()).context("Failed to serialize checkpoint")?;
+        std::fs::write(path.as_ref(), toml).context("Failed to write checkpoint file")?;
+        Ok(())
+    }
+
+    /// Loads an enhanced kernel from a TOML checkpoint previously written
+    /// by [`EnhancedThronionKernel::save_checkpoint`].
+    pub fn load_checkpoint<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = std::fs::read_to_string(path.as_ref()).context("Failed to read checkpoint file")?;
+        let checkpoint: EnhancedKernelCheckpoint =
+            toml::from_str(&content).context("Failed to parse checkpoint TOML")?;
+        Self::from_checkpoint(checkpoint)
+    }
+
+    /// Exports the base kernel's learned region prototypes to a minimal
+    /// ONNX graph (see [`crate::onnx`] for the low-level encoder) for
+    /// portable inference outside this crate via `onnxruntime`/`ort`.
+    ///
+    /// The graph computes, per region, the classical
+    /// distance-to-prototype resonance score `1/(1+‖x−prototype‖)` — the
+    /// classical half of [`GabrielRegion::hybrid_resonance`] — over the
+    /// `features` input (in [`FeatureLayoutDescriptor::current`]'s
+    /// layout), picks the best-matching region with `ArgMax`/`ReduceMax`,
+    /// and gates `is_attack` on that resonance exceeding
+    /// `attack_threshold` and the winning region's label. It
+    /// intentionally omits the quantum-fidelity half of
+    /// `hybrid_resonance`, the anti-pattern margin, and the GBDT
+    /// tie-breaker: none of those have a portable ONNX equivalent (no
+    /// complex-amplitude ops, no boosted-tree ops in the base operator
+    /// set), so the exported graph is a best-effort approximation of
+    /// `classify`, not a bit-exact port. The feature-layout descriptor is
+    /// embedded as ONNX metadata so a loader can detect an incompatible
+    /// feature space instead of silently misclassifying.
+    pub fn export_onnx<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let regions = &self.base_kernel.regions;
+        if regions.is_empty() {
+            anyhow::bail!("cannot export ONNX graph: kernel has no learned regions");
+        }
+
+        let feature_layout = FeatureLayoutDescriptor::current();
+        let feature_dim = feature_layout.classical_feature_dim as i64;
+        let num_regions = regions.len() as i64;
+
+        let mut prototypes = Vec::with_capacity(regions.len() * feature_layout.classical_feature_dim);
+        let mut labels = Vec::with_capacity(regions.len());
+        for region in regions {
+            let vector = region.classical_center.to_vector();
+            let mut row: Vec<f32> = vector.iter().map(|&v| v as f32).collect();
+            row.resize(feature_layout.classical_feature_dim, 0.0);
+            prototypes.extend(row);
+            labels.push(if region.is_attack_region() { 1.0f32 } else { 0.0f32 });
+        }
+        let ones = vec![1.0f32; regions.len()];
+
+        use crate::onnx::{AttributeValue, ElemType, ModelBuilder, TensorSpec};
+
+        let mut builder = ModelBuilder::new("thronion_kernel");
+        builder
+            .add_input("features", &TensorSpec::new(vec![1, feature_dim], ElemType::Float))
+            .add_initializer_f32("prototypes", &[num_regions, feature_dim], &prototypes)
+            .add_initializer_f32("region_labels", &[num_regions], &labels)
+            .add_initializer_f32("ones", &[num_regions], &ones)
+            .add_initializer_f32("half", &[], &[0.5f32])
+            .add_initializer_f32("attack_threshold", &[], &[self.base_kernel.attack_threshold as f32])
+            .add_node("Sub", "diff", &["features", "prototypes"], &["diff"], &[])
+            .add_node("Mul", "sq", &["diff", "diff"], &["sq"], &[])
+            .add_node(
+                "ReduceSum",
+                "sumsq",
+                &["sq"],
+                &["sumsq"],
+                &[("axes", AttributeValue::Ints(vec![1])), ("keepdims", AttributeValue::Int(0))],
+            )
+            .add_node("Sqrt", "dist", &["sumsq"], &["dist"], &[])
+            .add_node("Add", "dist_plus_one", &["dist", "ones"], &["dist_plus_one"], &[])
+            .add_node("Reciprocal", "resonance", &["dist_plus_one"], &["resonance"], &[])
+            .add_node(
+                "ArgMax",
+                "winning_region_idx_node",
+                &["resonance"],
+                &["winning_region_idx"],
+                &[("axis", AttributeValue::Int(0)), ("keepdims", AttributeValue::Int(0))],
+            )
+            .add_node(
+                "ReduceMax",
+                "best_resonance",
+                &["resonance"],
+                &["attack_probability"],
+                &[("axes", AttributeValue::Ints(vec![0])), ("keepdims", AttributeValue::Int(0))],
+            )
+            .add_node(
+                "Gather",
+                "winning_label",
+                &["region_labels", "winning_region_idx"],
+                &["winning_label"],
+                &[("axis", AttributeValue::Int(0))],
+            )
+            .add_node(
+                "Greater",
+                "resonance_above_threshold",
+                &["attack_probability", "attack_threshold"],
+                &["resonance_above_threshold"],
+                &[],
+            )
+            .add_node("Greater", "label_is_attack", &["winning_label", "half"], &["label_is_attack"], &[])
+            .add_node(
+                "And",
+                "is_attack_node",
+                &["resonance_above_threshold", "label_is_attack"],
+                &["is_attack"],
+                &[],
+            )
+            .add_output("attack_probability", &TensorSpec::new(vec![], ElemType::Float))
+            .add_output("winning_region_idx", &TensorSpec::new(vec![], ElemType::Int64))
+            .add_output("is_attack", &TensorSpec::new(vec![], ElemType::Bool))
+            .add_metadata("schema_version", &CHECKPOINT_SCHEMA_VERSION.to_string())
+            .add_metadata("feature_layout_dim", &feature_layout.classical_feature_dim.to_string())
+            .add_metadata("feature_layout_field_order", &feature_layout.field_order.join(","));
+
+        builder.write_to_file(path)
+    }
+
     /// Get combined statistics
     pub fn stats(&self) -> EnhancedKernelStats {
         let base_stats = self.base_kernel.stats();
@@ -590,10 +2001,31 @@ impl EnhancedThronionKernel {
             coherence_gradient: self.delta_kernel.coherence_gradient(),
             is_stable: self.is_stable(),
             classification_count: self.classification_count,
+            fista_iterations: self.last_fista_iterations,
+            fista_converged: self.last_fista_converged,
         }
     }
 }
 
+/// Versioned on-disk snapshot of an [`EnhancedThronionKernel`]'s full
+/// learned state: the base [`KernelCheckpoint`], the Delta Kernel
+/// (quantum state, Hamiltonian, Kuramoto network, coherence operators,
+/// `QRIKParams`), optimization cadence, and the standalone
+/// [`GbdtClassifier`]'s hyperparameters and labeled sample buffer. As
+/// with `KernelCheckpoint`, neither GBDT's fitted trees are persisted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnhancedKernelCheckpoint {
+    pub base: KernelCheckpoint,
+    pub delta_kernel: DeltaKernel,
+    pub optimization_interval: usize,
+    pub classification_count: usize,
+    pub gbdt_blend_weight: f64,
+    pub gbdt_classifier_samples: Vec<(Vec<f64>, bool)>,
+    pub gbdt_classifier_iterations: usize,
+    pub gbdt_classifier_max_depth: u32,
+    pub gbdt_classifier_shrinkage: f32,
+}
+
 /// Statistics for Enhanced Thronion Kernel
 #[derive(Debug, Clone)]
 pub struct EnhancedKernelStats {
@@ -601,6 +2033,10 @@ pub struct EnhancedKernelStats {
     pub coherence_gradient: f64,
     pub is_stable: bool,
     pub classification_count: usize,
+    /// Total FISTA iterations run across regions in the last `optimize()` call
+    pub fista_iterations: usize,
+    /// Whether every refined region converged in the last `optimize()` call
+    pub fista_converged: bool,
 }
 
 #[cfg(test)]
@@ -701,11 +2137,11 @@ mod kernel_tests {
         assert!(kernel.regions.len() <= 2); // At most 2 if patterns are distinct enough
         
         // Classify benign
-        let (is_attack, resonance, _) = kernel.classify(&metadata, &timing, &dist);
-        assert!(!is_attack || resonance < 0.6); // Should classify as benign
-        
+        let outcome = kernel.classify(&metadata, &timing, &dist);
+        assert!(!outcome.is_attack || outcome.resonance < 0.6); // Should classify as benign
+
         // Classify attack
-        let (_is_attack_2, _, _) = kernel.classify(&metadata_attack, &timing_attack, &dist_attack);
+        let _outcome_attack = kernel.classify(&metadata_attack, &timing_attack, &dist_attack);
         // Note: might be benign due to weak matching in simple test
         // In production, more training data would improve accuracy
     }
@@ -728,8 +2164,8 @@ mod kernel_tests {
     
     #[test]
     fn test_kernel_capacity() {
-        let mut kernel = ThronionKernel::with_params(0.5, 5, 0.1);
-        
+        let mut kernel = ThronionKernel::with_params(0.5, 5, 0.1, DEFAULT_ANTI_PATTERN_MARGIN);
+
         // Try to learn more regions than capacity
         for i in 0..10 {
             let (metadata, timing, dist) = create_test_metadata(i % 2 == 0);
@@ -739,6 +2175,148 @@ mod kernel_tests {
         // Should not exceed max capacity
         assert!(kernel.regions.len() <= 5);
     }
+
+    #[test]
+    fn test_benign_learning_creates_suppressor_region() {
+        let mut kernel = ThronionKernel::new();
+
+        let (metadata, timing, dist) = create_test_metadata(false);
+        kernel.learn(&metadata, &timing, &dist, false);
+
+        assert_eq!(kernel.regions.len(), 1);
+        assert!(kernel.regions[0].is_suppressor);
+    }
+
+    #[test]
+    fn test_anti_pattern_margin_suppresses_weak_attack_match() {
+        let mut kernel = ThronionKernel::new();
+
+        // A strong anti-pattern (suppressor) region and a weaker attack
+        // region, both matching the same traffic.
+        let (metadata_benign, timing_benign, dist_benign) = create_test_metadata(false);
+        kernel.learn(&metadata_benign, &timing_benign, &dist_benign, false);
+
+        let (metadata_attack, timing_attack, dist_attack) = create_test_metadata(true);
+        kernel.learn(&metadata_attack, &timing_attack, &dist_attack, true);
+
+        // A very large margin should make the anti-pattern always win,
+        // forcing the region vote to benign regardless of which region
+        // resonates more strongly.
+        kernel.set_anti_pattern_margin(10.0);
+        let outcome = kernel.classify(&metadata_attack, &timing_attack, &dist_attack);
+        assert!(!outcome.region_vote);
+        assert_eq!(kernel.attack_calls_suppressed(), 1);
+
+        let stats = kernel.stats();
+        assert_eq!(stats.anti_pattern_regions, 1);
+        assert_eq!(stats.attack_calls_suppressed, 1);
+    }
+
+    #[test]
+    fn test_retrain_gbdt_noop_below_minimum_samples() {
+        let mut kernel = ThronionKernel::new();
+        let (metadata, timing, dist) = create_test_metadata(true);
+        kernel.learn(&metadata, &timing, &dist, true);
+
+        kernel.retrain_gbdt();
+        assert!(kernel.gbdt_model.is_none());
+    }
+
+    #[test]
+    fn test_retrain_gbdt_fits_a_model_with_enough_samples() {
+        let mut kernel = ThronionKernel::new();
+
+        for i in 0..(GBDT_MIN_TRAINING_SAMPLES + 2) {
+            let (metadata, timing, dist) = create_test_metadata(i % 2 == 0);
+            kernel.learn(&metadata, &timing, &dist, i % 2 == 0);
+        }
+
+        kernel.retrain_gbdt();
+        assert!(kernel.gbdt_model.is_some());
+        assert!(kernel.gbdt_feature_size > 0);
+    }
+
+    #[test]
+    fn test_classify_batch_matches_single_classify() {
+        let mut kernel = ThronionKernel::new();
+
+        let (metadata_benign, timing_benign, dist_benign) = create_test_metadata(false);
+        kernel.learn(&metadata_benign, &timing_benign, &dist_benign, false);
+        let (metadata_attack, timing_attack, dist_attack) = create_test_metadata(true);
+        kernel.learn(&metadata_attack, &timing_attack, &dist_attack, true);
+
+        let circuits = vec![
+            (metadata_benign.clone(), timing_benign.clone(), dist_benign.clone()),
+            (metadata_attack.clone(), timing_attack.clone(), dist_attack.clone()),
+        ];
+        let batch_outcomes = kernel.classify_batch(&circuits);
+
+        let single_benign = kernel.classify(&metadata_benign, &timing_benign, &dist_benign);
+        let single_attack = kernel.classify(&metadata_attack, &timing_attack, &dist_attack);
+
+        assert_eq!(batch_outcomes.len(), 2);
+        assert!((batch_outcomes[0].resonance - single_benign.resonance).abs() < 1e-9);
+        assert_eq!(batch_outcomes[0].region_idx, single_benign.region_idx);
+        assert_eq!(batch_outcomes[0].is_attack, single_benign.is_attack);
+        assert!((batch_outcomes[1].resonance - single_attack.resonance).abs() < 1e-9);
+        assert_eq!(batch_outcomes[1].region_idx, single_attack.region_idx);
+        assert_eq!(batch_outcomes[1].is_attack, single_attack.is_attack);
+    }
+
+    #[test]
+    fn test_classify_batch_empty_kernel_defaults_to_benign() {
+        let kernel = ThronionKernel::new();
+        let (metadata, timing, dist) = create_test_metadata(true);
+
+        let outcomes = kernel.classify_batch(&[(metadata, timing, dist)]);
+        assert_eq!(outcomes.len(), 1);
+        assert!(!outcomes[0].is_attack);
+        assert!(outcomes[0].region_idx.is_none());
+    }
+
+    #[test]
+    fn test_checkpoint_save_and_load_roundtrip_preserves_regions() {
+        let mut kernel = ThronionKernel::new();
+        let (metadata_benign, timing_benign, dist_benign) = create_test_metadata(false);
+        kernel.learn(&metadata_benign, &timing_benign, &dist_benign, false);
+        let (metadata_attack, timing_attack, dist_attack) = create_test_metadata(true);
+        kernel.learn(&metadata_attack, &timing_attack, &dist_attack, true);
+
+        let dir = std::env::temp_dir();
+        let id = crate::utils::test_support::unique_id();
+        let path = dir.join(format!("thronion_kernel_checkpoint_test_{id}.toml"));
+        kernel.save_checkpoint(&path).unwrap();
+
+        let loaded = ThronionKernel::load_checkpoint(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.stats().total_regions, kernel.stats().total_regions);
+        assert_eq!(loaded.stats().attack_regions, kernel.stats().attack_regions);
+
+        let before = kernel.classify(&metadata_attack, &timing_attack, &dist_attack);
+        let after = loaded.classify(&metadata_attack, &timing_attack, &dist_attack);
+        assert_eq!(before.is_attack, after.is_attack);
+        assert!((before.resonance - after.resonance).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_checkpoint_rejects_mismatched_schema_version() {
+        let kernel = ThronionKernel::new();
+        let mut checkpoint = kernel.to_checkpoint();
+        checkpoint.schema_version += 1;
+
+        assert!(ThronionKernel::from_checkpoint(checkpoint).is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_rejects_mismatched_feature_layout() {
+        let kernel = ThronionKernel::new();
+        let mut checkpoint = kernel.to_checkpoint();
+        checkpoint.feature_layout.classical_feature_dim += 1;
+        checkpoint.feature_layout.field_order.push("extra_feature".to_string());
+
+        assert!(ThronionKernel::from_checkpoint(checkpoint).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -818,11 +2396,11 @@ mod enhanced_kernel_tests {
         let (metadata, timing, dist) = create_test_metadata(false);
         
         // First classification (benign, no regions yet)
-        let (is_attack, resonance, region_idx) = enhanced.classify(&metadata, &timing, &dist);
-        
+        let outcome = enhanced.classify(&metadata, &timing, &dist);
+
         // Should default to benign (no regions learned)
-        assert!(!is_attack);
-        assert!(region_idx.is_none());
+        assert!(!outcome.is_attack);
+        assert!(outcome.region_idx.is_none());
         assert_eq!(enhanced.classification_count, 1);
     }
     
@@ -909,4 +2487,229 @@ mod enhanced_kernel_tests {
         // After optimization, similar regions may have merged
         assert!(enhanced.base_kernel.regions.len() <= 3);
     }
+
+    #[test]
+    fn test_merge_never_collapses_attack_region_into_anti_pattern() {
+        let base_kernel = ThronionKernel::new();
+        let params = QRIKParams::default();
+        let mut enhanced = EnhancedThronionKernel::new(base_kernel, params);
+
+        // An attack region and an anti-pattern region sharing the exact
+        // same quantum center, so their fidelity is 1.0 and they would
+        // certainly be merge candidates if `is_suppressor` weren't
+        // checked first.
+        let (metadata, timing, dist) = create_test_metadata(true);
+        let classical = ClassicalSignature::from_metadata(&metadata, &timing, &dist);
+        let quantum = ConversionUtils::classical_to_quantum(&classical);
+
+        let mut attack_region = GabrielRegion::new(classical.clone(), quantum.clone(), 0.1);
+        attack_region.attack_probability = 1.0;
+
+        let mut anti_pattern_region = GabrielRegion::new(classical, quantum, 0.1);
+        anti_pattern_region.attack_probability = 0.0;
+        anti_pattern_region.is_suppressor = true;
+
+        enhanced.base_kernel.regions.push(attack_region);
+        enhanced.base_kernel.regions.push(anti_pattern_region);
+
+        // Call the merge step directly so this test doesn't depend on
+        // `optimize()`'s coherence-gradient threshold also being met.
+        enhanced.merge_coherent_regions();
+
+        // The attack region and the anti-pattern region must never be
+        // merged into each other, regardless of their fidelity.
+        assert_eq!(enhanced.base_kernel.regions.len(), 2);
+        assert!(enhanced.base_kernel.regions.iter().any(|r| r.is_attack_region()));
+        assert!(enhanced.base_kernel.regions.iter().any(|r| r.is_suppressor));
+    }
+
+    #[test]
+    fn test_optimize_reports_fista_stats() {
+        let base_kernel = ThronionKernel::new();
+        let params = QRIKParams::default();
+        let mut enhanced = EnhancedThronionKernel::new(base_kernel, params);
+
+        let (metadata, timing, dist) = create_test_metadata(true);
+        enhanced.learn(&metadata, &timing, &dist, true);
+        enhanced.optimize();
+
+        let stats = enhanced.stats();
+        assert!(stats.fista_iterations > 0);
+        assert!(stats.fista_converged || stats.fista_iterations == FISTA_MAX_ITERATIONS);
+    }
+
+    #[test]
+    fn test_gbdt_classifier_untrained_returns_none() {
+        let classifier = GbdtClassifier::new();
+        let (metadata, timing, dist) = create_test_metadata(true);
+
+        assert!(!classifier.is_trained());
+        assert!(classifier.classify(&metadata, &timing, &dist).is_none());
+        assert!(classifier.feature_importance().is_empty());
+    }
+
+    #[test]
+    fn test_gbdt_classifier_trains_and_predicts_after_enough_samples() {
+        let mut classifier = GbdtClassifier::new();
+
+        for i in 0..12 {
+            let (metadata, timing, dist) = create_test_metadata(i % 2 == 0);
+            classifier.learn(&metadata, &timing, &dist, i % 2 == 0);
+        }
+        classifier.retrain();
+
+        assert!(classifier.is_trained());
+        let (metadata, timing, dist) = create_test_metadata(true);
+        let probability = classifier
+            .classify(&metadata, &timing, &dist)
+            .expect("trained classifier should predict");
+        assert!((0.0..=1.0).contains(&probability));
+        assert_eq!(
+            classifier.feature_importance().len(),
+            GbdtClassifier::feature_vector(&metadata, &timing, &dist).len()
+        );
+    }
+
+    #[test]
+    fn test_enhanced_kernel_blends_gbdt_probability_with_resonance() {
+        let base_kernel = ThronionKernel::new();
+        let params = QRIKParams::default();
+        let mut enhanced = EnhancedThronionKernel::new(base_kernel, params);
+
+        for i in 0..12 {
+            let is_attack = i % 2 == 0;
+            let (metadata, timing, dist) = create_test_metadata(is_attack);
+            enhanced.learn(&metadata, &timing, &dist, is_attack);
+        }
+        enhanced.gbdt_classifier.retrain();
+
+        let (metadata, timing, dist) = create_test_metadata(true);
+        let outcome = enhanced.classify(&metadata, &timing, &dist);
+
+        assert!(outcome.blended_probability.is_some());
+        assert!(!enhanced.gbdt_feature_importance().is_empty());
+    }
+
+    #[test]
+    fn test_enhanced_checkpoint_save_and_load_roundtrip() {
+        let base_kernel = ThronionKernel::new();
+        let params = QRIKParams::default();
+        let mut enhanced = EnhancedThronionKernel::new(base_kernel, params);
+
+        let (metadata, timing, dist) = create_test_metadata(true);
+        enhanced.learn(&metadata, &timing, &dist, true);
+        enhanced.gbdt_blend_weight = 0.75;
+
+        let dir = std::env::temp_dir();
+        let id = crate::utils::test_support::unique_id();
+        let path = dir.join(format!("thronion_enhanced_checkpoint_test_{id}.toml"));
+        enhanced.save_checkpoint(&path).unwrap();
+
+        let loaded = EnhancedThronionKernel::load_checkpoint(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.optimization_interval, enhanced.optimization_interval);
+        assert_eq!(loaded.classification_count, enhanced.classification_count);
+        assert!((loaded.gbdt_blend_weight - enhanced.gbdt_blend_weight).abs() < 1e-12);
+        assert_eq!(
+            loaded.stats().base_stats.total_regions,
+            enhanced.stats().base_stats.total_regions
+        );
+    }
+
+    #[test]
+    fn test_export_onnx_graph_math_matches_classify_decision() {
+        let base_kernel = ThronionKernel::new();
+        let params = QRIKParams::default();
+        let mut enhanced = EnhancedThronionKernel::new(base_kernel, params);
+
+        let (metadata_benign, timing_benign, dist_benign) = create_test_metadata(false);
+        enhanced.learn(&metadata_benign, &timing_benign, &dist_benign, false);
+        let (metadata_attack, timing_attack, dist_attack) = create_test_metadata(true);
+        enhanced.learn(&metadata_attack, &timing_attack, &dist_attack, true);
+
+        let dir = std::env::temp_dir();
+        let id = crate::utils::test_support::unique_id();
+        let path = dir.join(format!("thronion_onnx_export_test_{id}.onnx"));
+        enhanced.export_onnx(&path).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert!(!bytes.is_empty());
+        let haystack = String::from_utf8_lossy(&bytes);
+        assert!(haystack.contains("feature_layout_field_order"));
+        assert!(haystack.contains("prototypes"));
+
+        // No ONNX runtime is available in this environment, so replay
+        // the exact fixed node sequence `export_onnx` bakes in (classical
+        // distance -> 1/(1+dist) resonance -> argmax/max -> threshold +
+        // label gate) directly in Rust, using the same region data, and
+        // compare its decision against `classify`'s.
+        let query = ClassicalSignature::from_metadata(&metadata_attack, &timing_attack, &dist_attack);
+        let query_vec = query.to_vector();
+
+        let mut best_resonance = f64::MIN;
+        let mut best_label = 0.0;
+        for region in &enhanced.base_kernel.regions {
+            let center = region.classical_center.to_vector();
+            let dist = (&query_vec - &center).mapv(|x| x * x).sum().sqrt();
+            let resonance = 1.0 / (1.0 + dist);
+            if resonance > best_resonance {
+                best_resonance = resonance;
+                best_label = if region.is_attack_region() { 1.0 } else { 0.0 };
+            }
+        }
+        let graph_is_attack = best_resonance > enhanced.base_kernel.attack_threshold && best_label > 0.5;
+
+        let outcome = enhanced.classify(&metadata_attack, &timing_attack, &dist_attack);
+        assert_eq!(graph_is_attack, outcome.is_attack);
+    }
+
+    #[test]
+    fn test_export_onnx_rejects_kernel_with_no_regions() {
+        let base_kernel = ThronionKernel::new();
+        let params = QRIKParams::default();
+        let enhanced = EnhancedThronionKernel::new(base_kernel, params);
+
+        let dir = std::env::temp_dir();
+        let id = crate::utils::test_support::unique_id();
+        let path = dir.join(format!("thronion_onnx_export_empty_test_{id}.onnx"));
+        assert!(enhanced.export_onnx(&path).is_err());
+    }
+
+    #[test]
+    fn test_automatic_checkpoint_alternates_between_two_files() {
+        let base_kernel = ThronionKernel::new();
+        let params = QRIKParams::default();
+        let mut enhanced = EnhancedThronionKernel::new(base_kernel, params);
+        enhanced.optimization_interval = 2;
+
+        let dir = std::env::temp_dir();
+        let id = crate::utils::test_support::unique_id();
+        let base_path = dir.join(format!("thronion_rotating_checkpoint_test_{id}"));
+        enhanced.set_checkpoint_path(&base_path);
+
+        // Each loop drives `classification_count` to the next multiple of
+        // `optimization_interval`, triggering one `optimize()` call (and
+        // therefore one rotating checkpoint write). Reading
+        // `rotating_checkpoint_path` right after reflects the slot that
+        // write just used, since `classification_count` hasn't changed yet.
+        for i in 0..2 {
+            let (metadata, timing, dist) = create_test_metadata(i % 2 == 0);
+            enhanced.classify(&metadata, &timing, &dist);
+        }
+        let slot0 = enhanced.rotating_checkpoint_path(&base_path);
+        assert!(slot0.exists(), "first optimize() should write a rotation slot");
+
+        for i in 0..2 {
+            let (metadata, timing, dist) = create_test_metadata(i % 2 == 0);
+            enhanced.classify(&metadata, &timing, &dist);
+        }
+        let slot1 = enhanced.rotating_checkpoint_path(&base_path);
+        assert_ne!(slot0, slot1, "successive optimize() calls should alternate slots");
+        assert!(slot1.exists(), "second optimize() should write the other rotation slot");
+
+        std::fs::remove_file(&slot0).ok();
+        std::fs::remove_file(&slot1).ok();
+    }
 }
@@ -9,22 +9,205 @@
 //! - **Circuit Monitor**: Real-time circuit tracking
 //! - **Metadata**: Circuit timing and cell type analysis
 //! - **Events**: Asynchronous event handling
+//! - **Deterministic selection**: [`CircuitMonitor::select_deterministic`] picks a
+//!   reproducible neighbor tier from tracked circuits via a weighted-hash
+//!   stable sort, seedable from a [`SpectralFingerprint`] via
+//!   [`CircuitMonitor::seed_from_fingerprint`]
 //!
 //! ## Status
 //!
 //! Phase 2 (Tor Integration) - Implementation in progress
 //!
 //! Ported from Ophanion with enhancements for Thronion fusion.
-
+//!
+//! ## Runtime abstraction
+//!
+//! `TorInterface`, [`EventProcessor`] and circuit timestamps are driven
+//! through the [`TorRuntime`] trait rather than hardwiring tokio and the
+//! wall clock, in the spirit of arti's `tor-rtcompat` `SleepProvider`
+//! plumbing. [`TokioRuntime`] is the real implementation; [`MockRuntime`]
+//! supplies a manually-advanceable clock and a scripted control-port
+//! connection so circuit-timing and event-parsing logic can be tested
+//! deterministically without a live Tor daemon.
+
+use crate::resonance::spectrum::SpectralFingerprint;
 use anyhow::{Context, Result};
+use async_trait::async_trait;
+use blake3::Hasher;
 use dashmap::DashMap;
+use rustfft::{num_complex::Complex, FftPlanner};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, mpsc};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::TcpStream;
 
+/// Abstraction over "what time is it" and "how do I get a control-port
+/// connection", so timing-dependent Tor logic can be driven by
+/// [`MockRuntime`] in tests instead of a live daemon and wall-clock
+/// `Instant`. [`TokioRuntime`] is the real, tokio-backed implementation.
+#[async_trait]
+pub trait TorRuntime: Send + Sync {
+    /// Opens a control-port connection (a real TCP socket under
+    /// [`TokioRuntime`], a scripted transcript under [`MockRuntime`]).
+    async fn connect(&self, control_port: u16) -> Result<Box<dyn TorStream>>;
+
+    /// This runtime's notion of "now" -- substituted by
+    /// [`MockRuntime`]'s manually-advanceable clock in tests, so circuit
+    /// creation times and cell intervals don't depend on real time
+    /// passing during a test run.
+    fn now(&self) -> Instant;
+
+    /// Suspends the caller for `duration` according to this runtime.
+    async fn sleep(&self, duration: Duration);
+}
+
+/// A line-oriented, bidirectional control-port connection, as used by
+/// the Tor control protocol. Implemented by a real TCP connection under
+/// [`TokioRuntime`] and by a scripted in-memory transcript under
+/// [`MockRuntime`].
+#[async_trait]
+pub trait TorStream: Send {
+    /// Writes `line` (including its own line terminator) and flushes.
+    async fn write_line(&mut self, line: &str) -> Result<()>;
+
+    /// Reads the next line, or `None` if the connection is closed.
+    async fn read_line(&mut self) -> Result<Option<String>>;
+}
+
+/// Default [`TorRuntime`]: real TCP connections via tokio and the wall
+/// clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioRuntime;
+
+#[async_trait]
+impl TorRuntime for TokioRuntime {
+    async fn connect(&self, control_port: u16) -> Result<Box<dyn TorStream>> {
+        let stream = TcpStream::connect(("127.0.0.1", control_port))
+            .await
+            .context("Failed to connect to Tor control port")?;
+        Ok(Box::new(TokioTorStream {
+            reader: BufReader::new(stream),
+        }))
+    }
+
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// [`TorStream`] backed by a real `tokio::net::TcpStream`.
+struct TokioTorStream {
+    reader: BufReader<TcpStream>,
+}
+
+#[async_trait]
+impl TorStream for TokioTorStream {
+    async fn write_line(&mut self, line: &str) -> Result<()> {
+        self.reader.write_all(line.as_bytes()).await?;
+        self.reader.flush().await?;
+        Ok(())
+    }
+
+    async fn read_line(&mut self) -> Result<Option<String>> {
+        let mut line = String::new();
+        let n = self.reader.read_line(&mut line).await?;
+        Ok(if n == 0 { None } else { Some(line) })
+    }
+}
+
+/// A [`TorRuntime`] with a manually-advanceable clock and a scripted
+/// control-port connection, so circuit-timing and event-parsing tests
+/// can assert deterministic behavior without a live Tor daemon or real
+/// time elapsing.
+#[derive(Clone)]
+pub struct MockRuntime {
+    base: Instant,
+    offset: Arc<RwLock<Duration>>,
+    /// Lines the scripted connection hands back to `read_line`, in order.
+    script: Arc<Mutex<VecDeque<String>>>,
+    /// Every line written via `write_line`, in order.
+    sent: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockRuntime {
+    /// Creates a new mock runtime with an empty script and a clock
+    /// pinned at its own creation time plus zero elapsed offset.
+    pub fn new() -> Self {
+        Self {
+            base: Instant::now(),
+            offset: Arc::new(RwLock::new(Duration::ZERO)),
+            script: Arc::new(Mutex::new(VecDeque::new())),
+            sent: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Queues `line` to be returned by the next `read_line` call on the
+    /// connection this runtime hands out via `connect`.
+    pub fn push_line(&self, line: impl Into<String>) {
+        self.script.lock().unwrap().push_back(line.into());
+    }
+
+    /// Every line written to the scripted connection so far, in order.
+    pub fn sent_lines(&self) -> Vec<String> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    /// Moves this runtime's clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.offset.write().unwrap() += duration;
+    }
+}
+
+impl Default for MockRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TorRuntime for MockRuntime {
+    async fn connect(&self, _control_port: u16) -> Result<Box<dyn TorStream>> {
+        Ok(Box::new(MockTorStream {
+            script: self.script.clone(),
+            sent: self.sent.clone(),
+        }))
+    }
+
+    fn now(&self) -> Instant {
+        self.base + *self.offset.read().unwrap()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
+
+/// The scripted control-port connection handed out by
+/// [`MockRuntime::connect`].
+struct MockTorStream {
+    script: Arc<Mutex<VecDeque<String>>>,
+    sent: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl TorStream for MockTorStream {
+    async fn write_line(&mut self, line: &str) -> Result<()> {
+        self.sent.lock().unwrap().push(line.to_string());
+        Ok(())
+    }
+
+    async fn read_line(&mut self) -> Result<Option<String>> {
+        Ok(self.script.lock().unwrap().pop_front())
+    }
+}
+
 /// Tor circuit metadata extracted from control port
 #[derive(Debug, Clone)]
 pub struct TorCircuitMetadata {
@@ -61,54 +244,128 @@ pub enum TorCellType {
     Other,
 }
 
+/// What a circuit/channel is being used for, which drives how much of
+/// its `PADDING`-cell traffic is Tor's own negotiated channel padding
+/// rather than attack-related. Mirrors the purpose arti's
+/// `ChannelUsage`/`PaddingControlState` serve: Tor pads idle channels
+/// most heavily (to hide that they're idle from traffic analysis), far
+/// less once real application data is flowing, and hidden-service
+/// introduction/rendezvous circuits have their own distinct baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelUsage {
+    /// No application data flowing on the circuit.
+    Idle,
+    /// Ordinary user/application data is flowing.
+    UserTraffic,
+    /// A hidden-service introduction or rendezvous circuit.
+    HiddenService,
+}
+
+/// Models Tor's own negotiated connection/channel padding so it isn't
+/// mistaken for attacker-inserted padding: holds the expected
+/// steady-state padding fraction for each [`ChannelUsage`], sourced from
+/// the consensus padding parameters (or from observed steady-state
+/// padding, via [`Self::new`]).
+/// [`MetadataExtractor::analyze_cell_types_with_padding_model`] subtracts
+/// this baseline from a circuit's observed padding fraction to produce
+/// `CellTypeDistribution::excess_padding_ratio`.
+pub struct PaddingModel {
+    idle_padding_ratio: f64,
+    user_traffic_padding_ratio: f64,
+    hidden_service_padding_ratio: f64,
+}
+
+impl PaddingModel {
+    /// Builds a model from explicit per-usage baselines, e.g. fit from
+    /// a circuit's own observed steady-state padding rather than the
+    /// network-wide consensus defaults.
+    pub fn new(
+        idle_padding_ratio: f64,
+        user_traffic_padding_ratio: f64,
+        hidden_service_padding_ratio: f64,
+    ) -> Self {
+        Self {
+            idle_padding_ratio,
+            user_traffic_padding_ratio,
+            hidden_service_padding_ratio,
+        }
+    }
+
+    /// Tor's consensus padding parameters keep an idle channel's cell
+    /// stream roughly half padding, drop to a small fraction once user
+    /// traffic is flowing, and add comparatively little extra padding of
+    /// their own to already-chatty hidden-service circuits.
+    pub fn from_consensus_defaults() -> Self {
+        Self::new(0.5, 0.1, 0.05)
+    }
+
+    /// Expected steady-state padding fraction for a circuit used as `usage`.
+    pub fn expected_padding_ratio(&self, usage: ChannelUsage) -> f64 {
+        match usage {
+            ChannelUsage::Idle => self.idle_padding_ratio,
+            ChannelUsage::UserTraffic => self.user_traffic_padding_ratio,
+            ChannelUsage::HiddenService => self.hidden_service_padding_ratio,
+        }
+    }
+}
+
+impl Default for PaddingModel {
+    fn default() -> Self {
+        Self::from_consensus_defaults()
+    }
+}
+
 /// Tor control port interface
 pub struct TorInterface {
     control_port: u16,
     authenticated: bool,
     event_tx: Option<broadcast::Sender<CircuitEvent>>,
+    runtime: Arc<dyn TorRuntime>,
 }
 
 impl TorInterface {
-    /// Create new Tor interface
+    /// Create new Tor interface, backed by a real [`TokioRuntime`].
     pub fn new(control_port: u16) -> Self {
+        Self::with_runtime(control_port, Arc::new(TokioRuntime))
+    }
+
+    /// Create a new Tor interface driven by `runtime` -- pass a
+    /// [`MockRuntime`] to exercise circuit-timing and event-parsing
+    /// logic deterministically, without a live Tor daemon.
+    pub fn with_runtime(control_port: u16, runtime: Arc<dyn TorRuntime>) -> Self {
         Self {
             control_port,
             authenticated: false,
             event_tx: None,
+            runtime,
         }
     }
 
-    /// Connect to Tor control port and establish TCP connection
-    pub async fn connect(&mut self) -> Result<TcpStream> {
+    /// Connect to Tor control port and establish a connection
+    pub async fn connect(&mut self) -> Result<Box<dyn TorStream>> {
         tracing::info!("Connecting to Tor control port: {}", self.control_port);
-        let stream = TcpStream::connect(("127.0.0.1", self.control_port))
-            .await
-            .context("Failed to connect to Tor control port")?;
-        Ok(stream)
+        self.runtime.connect(self.control_port).await
     }
 
     /// Authenticate with Tor control port using cookie
-    pub async fn authenticate(&mut self, stream: &mut TcpStream, cookie_path: &str) -> Result<()> {
+    pub async fn authenticate(&mut self, stream: &mut dyn TorStream, cookie_path: &str) -> Result<()> {
         tracing::info!("Authenticating with Tor using cookie: {}", cookie_path);
-        
+
         // Read cookie file
         let cookie_data = tokio::fs::read(cookie_path)
             .await
             .context("Failed to read Tor authentication cookie")?;
-        
+
         // Convert to hex
         let hex_cookie = hex::encode(&cookie_data);
-        
+
         // Send AUTHENTICATE command
         let cmd = format!("AUTHENTICATE {}\r\n", hex_cookie);
-        stream.write_all(cmd.as_bytes()).await?;
-        stream.flush().await?;
-        
+        stream.write_line(&cmd).await?;
+
         // Read response
-        let mut reader = BufReader::new(stream);
-        let mut response = String::new();
-        reader.read_line(&mut response).await?;
-        
+        let response = stream.read_line().await?.unwrap_or_default();
+
         if response.starts_with("250") {
             self.authenticated = true;
             tracing::info!("Successfully authenticated with Tor");
@@ -124,44 +381,35 @@ impl TorInterface {
     }
 
     /// Subscribe to circuit events and start event stream
-    pub async fn monitor_circuits(&mut self, stream: &mut TcpStream) -> Result<broadcast::Receiver<CircuitEvent>> {
+    pub async fn monitor_circuits(&mut self, stream: &mut dyn TorStream) -> Result<broadcast::Receiver<CircuitEvent>> {
         tracing::info!("Subscribing to Tor circuit events...");
-        
+
         // Send SETEVENTS command
-        let cmd = "SETEVENTS CIRC CIRC_MINOR\r\n";
-        stream.write_all(cmd.as_bytes()).await?;
-        stream.flush().await?;
-        
+        stream.write_line("SETEVENTS CIRC CIRC_MINOR\r\n").await?;
+
         // Read response
-        let mut reader = BufReader::new(stream);
-        let mut response = String::new();
-        reader.read_line(&mut response).await?;
-        
+        let response = stream.read_line().await?.unwrap_or_default();
+
         if !response.starts_with("250") {
             anyhow::bail!("Failed to subscribe to events: {}", response);
         }
-        
+
         // Create broadcast channel for events
         let (tx, rx) = broadcast::channel(1000);
         self.event_tx = Some(tx);
-        
+
         tracing::info!("Successfully subscribed to circuit events");
         Ok(rx)
     }
 
     /// Start async event processing loop
-    pub async fn process_events(mut stream: TcpStream, tx: broadcast::Sender<CircuitEvent>) -> Result<()> {
-        let mut reader = BufReader::new(stream);
-        let mut line = String::new();
-        
+    pub async fn process_events(mut stream: Box<dyn TorStream>, tx: broadcast::Sender<CircuitEvent>) -> Result<()> {
         loop {
-            line.clear();
-            let n = reader.read_line(&mut line).await?;
-            if n == 0 {
+            let Some(line) = stream.read_line().await? else {
                 // Connection closed
                 break;
-            }
-            
+            };
+
             // Parse circuit events
             if line.starts_with("650 CIRC") {
                 if let Some(event) = Self::parse_circuit_event(&line) {
@@ -169,7 +417,7 @@ impl TorInterface {
                 }
             }
         }
-        
+
         Ok(())
     }
 
@@ -180,19 +428,25 @@ impl TorInterface {
         if parts.len() < 4 {
             return None;
         }
-        
+
         let circuit_id: u32 = parts[2].parse().ok()?;
         let status = parts[3];
-        
+
         match status {
-            "LAUNCHED" => Some(CircuitEvent::Launched { circuit_id }),
+            "LAUNCHED" => {
+                let (purpose, hs_state, rend_query) = Self::extract_hs_fields(&parts);
+                Some(CircuitEvent::Launched { circuit_id, purpose, hs_state, rend_query })
+            },
             "EXTENDED" => {
                 // Count hops in path
                 let path = parts.get(4)?;
                 let hop_count = path.split(',').count();
                 Some(CircuitEvent::Extended { circuit_id, hop_count })
             },
-            "BUILT" => Some(CircuitEvent::Built { circuit_id }),
+            "BUILT" => {
+                let (purpose, hs_state, rend_query) = Self::extract_hs_fields(&parts);
+                Some(CircuitEvent::Built { circuit_id, purpose, hs_state, rend_query })
+            },
             "FAILED" => {
                 let reason = parts.get(4).unwrap_or(&"unknown").to_string();
                 Some(CircuitEvent::Failed { circuit_id, reason })
@@ -205,23 +459,35 @@ impl TorInterface {
         }
     }
 
+    /// Pulls the optional `PURPOSE=`, `HS_STATE=`, and `REND_QUERY=`
+    /// key=value fields out of a `CIRC` line's tokens. These only appear
+    /// on hidden-service circuits and can show up in any position after
+    /// the path/build-flags tokens, so every token is checked rather
+    /// than relying on a fixed offset.
+    fn extract_hs_fields(parts: &[&str]) -> (Option<String>, Option<String>, Option<String>) {
+        let find = |prefix: &str| {
+            parts
+                .iter()
+                .find_map(|part| part.strip_prefix(prefix).map(|v| v.to_string()))
+        };
+
+        (find("PURPOSE="), find("HS_STATE="), find("REND_QUERY="))
+    }
+
     /// Get circuit metadata using GETINFO
-    pub async fn get_circuit_metadata(&self, stream: &mut TcpStream, circuit_id: u32) -> Result<TorCircuitMetadata> {
+    pub async fn get_circuit_metadata(&self, stream: &mut dyn TorStream, circuit_id: u32) -> Result<TorCircuitMetadata> {
         // Send GETINFO circuit-status command
         let cmd = format!("GETINFO circuit-status/{}\r\n", circuit_id);
-        stream.write_all(cmd.as_bytes()).await?;
-        stream.flush().await?;
-        
+        stream.write_line(&cmd).await?;
+
         // Read response
-        let mut reader = BufReader::new(stream);
-        let mut response = String::new();
-        reader.read_line(&mut response).await?;
-        
+        let _response = stream.read_line().await?;
+
         // Parse circuit info
         // For now, return basic metadata
         Ok(TorCircuitMetadata {
             circuit_id,
-            created_at: Instant::now(),
+            created_at: self.runtime.now(),
             cell_timings: vec![],
             cell_types: vec![],
             introduction_point: None,
@@ -235,11 +501,29 @@ impl TorInterface {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CircuitEvent {
     /// Circuit launched
-    Launched { circuit_id: u32 },
+    Launched {
+        circuit_id: u32,
+        /// Parsed `PURPOSE=` field, e.g. `HS_CLIENT_INTRO`.
+        purpose: Option<String>,
+        /// Parsed `HS_STATE=` field, e.g. `HSCI_CONNECTING`.
+        hs_state: Option<String>,
+        /// Parsed `REND_QUERY=` field: the onion address this circuit
+        /// serves, for hidden-service introduction/rendezvous circuits.
+        rend_query: Option<String>,
+    },
     /// Circuit extended
     Extended { circuit_id: u32, hop_count: usize },
     /// Circuit built (ready to use)
-    Built { circuit_id: u32 },
+    Built {
+        circuit_id: u32,
+        /// Parsed `PURPOSE=` field, e.g. `HS_CLIENT_REND`.
+        purpose: Option<String>,
+        /// Parsed `HS_STATE=` field, e.g. `HSCR_JOINED`.
+        hs_state: Option<String>,
+        /// Parsed `REND_QUERY=` field: the onion address this circuit
+        /// serves, for hidden-service introduction/rendezvous circuits.
+        rend_query: Option<String>,
+    },
     /// Circuit failed
     Failed { circuit_id: u32, reason: String },
     /// Circuit closed
@@ -249,35 +533,53 @@ pub enum CircuitEvent {
 /// Event processor for handling Tor events
 pub struct EventProcessor {
     monitor: Arc<CircuitMonitor>,
+    runtime: Arc<dyn TorRuntime>,
 }
 
 impl EventProcessor {
-    /// Create new event processor
+    /// Create new event processor, backed by a real [`TokioRuntime`].
     pub fn new(monitor: Arc<CircuitMonitor>) -> Self {
-        Self { monitor }
+        Self::with_runtime(monitor, Arc::new(TokioRuntime))
+    }
+
+    /// Create a new event processor whose circuit creation times come
+    /// from `runtime` -- pass a [`MockRuntime`] so tests can control
+    /// exactly what `created_at` a tracked circuit gets.
+    pub fn with_runtime(monitor: Arc<CircuitMonitor>, runtime: Arc<dyn TorRuntime>) -> Self {
+        Self { monitor, runtime }
     }
 
     /// Process circuit event
     pub fn process_event(&self, event: CircuitEvent) {
         match event {
-            CircuitEvent::Launched { circuit_id } => {
+            CircuitEvent::Launched { circuit_id, purpose, hs_state, rend_query } => {
                 tracing::debug!("Circuit {} launched", circuit_id);
+                let cell_types = purpose_cell_type(purpose.as_deref()).into_iter().collect();
                 let metadata = TorCircuitMetadata {
                     circuit_id,
-                    created_at: Instant::now(),
+                    created_at: self.runtime.now(),
                     cell_timings: vec![],
-                    cell_types: vec![],
-                    introduction_point: None,
-                    rendezvous_completed: false,
+                    cell_types,
+                    introduction_point: rend_query,
+                    rendezvous_completed: hs_state_is_complete(hs_state.as_deref()),
                     total_bytes: 0,
                 };
                 self.monitor.track_circuit(metadata);
             }
-            CircuitEvent::Built { circuit_id } => {
+            CircuitEvent::Built { circuit_id, purpose, hs_state, rend_query } => {
                 tracing::debug!("Circuit {} built", circuit_id);
-                if let Some(mut metadata) = self.monitor.get_circuit(circuit_id) {
-                    metadata.rendezvous_completed = true;
-                    self.monitor.track_circuit(metadata);
+                if let Some(metadata) = self.monitor.get_circuit(circuit_id) {
+                    let mut metadata = metadata.write().unwrap();
+                    if let Some(rend_query) = rend_query {
+                        metadata.introduction_point = Some(rend_query);
+                    }
+                    if let Some(cell_type) = purpose_cell_type(purpose.as_deref()) {
+                        if !metadata.cell_types.contains(&cell_type) {
+                            metadata.cell_types.push(cell_type);
+                        }
+                    }
+                    metadata.rendezvous_completed =
+                        metadata.rendezvous_completed || hs_state_is_complete(hs_state.as_deref());
                 }
             }
             CircuitEvent::Failed { circuit_id, reason } | CircuitEvent::Closed { circuit_id, reason } => {
@@ -291,48 +593,458 @@ impl EventProcessor {
     }
 }
 
+/// Maps a Tor `PURPOSE=` circuit-purpose field to the [`TorCellType`]
+/// its circuit class exchanges, for the onion-service purposes the
+/// introduction/rendezvous detection cares about. Non-HS purposes (or no
+/// purpose at all) map to `None` rather than [`TorCellType::Other`],
+/// since the caller treats a cell-type hit as "this is an HS circuit".
+fn purpose_cell_type(purpose: Option<&str>) -> Option<TorCellType> {
+    match purpose? {
+        "HS_CLIENT_INTRO" | "HS_SERVICE_INTRO" => Some(TorCellType::Introduce2),
+        "HS_CLIENT_REND" => Some(TorCellType::Rendezvous1),
+        "HS_SERVICE_REND" => Some(TorCellType::Rendezvous2),
+        _ => None,
+    }
+}
+
+/// Whether an `HS_STATE=` value denotes a finished introduction or
+/// rendezvous handshake (`*_DONE` for introduction circuits, `*_JOINED`
+/// for rendezvous circuits) rather than one still in progress.
+fn hs_state_is_complete(hs_state: Option<&str>) -> bool {
+    matches!(hs_state, Some(s) if s.ends_with("_DONE") || s.ends_with("_JOINED"))
+}
+
+/// Detects an introduction-circuit flood against a specific onion
+/// service by windowing per-`rend_query` (onion-address) introduction
+/// circuit launch timestamps and flagging a sustained excess arrival
+/// rate.
+///
+/// Mirrors `DecisionEngine::decide_windowed`'s per-key sliding window
+/// and consecutive-breach gating (`ophanion-main`'s flood detector), but
+/// keys on the rendezvous query instead of an introduction-point score,
+/// and windows wall-clock arrival instants -- sourced from the injected
+/// [`TorRuntime`] so tests can drive the clock deterministically --
+/// instead of a fixed-length score buffer.
+pub struct IntroductionFloodDetector {
+    runtime: Arc<dyn TorRuntime>,
+    /// Number of most-recent introduction-circuit arrivals per
+    /// `rend_query` the arrival rate is computed over.
+    window_len: usize,
+    /// Arrival rate (introductions/sec) above which a window counts as
+    /// a breach.
+    max_intro_rate_per_sec: f64,
+    /// Consecutive breaches required before [`Self::record_introduction`]
+    /// reports an attack.
+    detection_step: usize,
+    arrivals: Mutex<HashMap<String, VecDeque<Instant>>>,
+    consecutive_breaches: Mutex<HashMap<String, usize>>,
+}
+
+impl IntroductionFloodDetector {
+    /// Builds a detector, backed by a real [`TokioRuntime`], whose
+    /// windows hold up to `window_len` arrivals and flag a `rend_query`
+    /// once its arrival rate exceeds `max_intro_rate_per_sec` for
+    /// `detection_step` consecutive introductions.
+    pub fn new(window_len: usize, max_intro_rate_per_sec: f64, detection_step: usize) -> Self {
+        Self::with_runtime(Arc::new(TokioRuntime), window_len, max_intro_rate_per_sec, detection_step)
+    }
+
+    /// Builds a detector whose arrival timestamps come from `runtime` --
+    /// pass a [`MockRuntime`] so tests can control exactly how much time
+    /// elapses between recorded introductions.
+    pub fn with_runtime(
+        runtime: Arc<dyn TorRuntime>,
+        window_len: usize,
+        max_intro_rate_per_sec: f64,
+        detection_step: usize,
+    ) -> Self {
+        Self {
+            runtime,
+            window_len,
+            max_intro_rate_per_sec,
+            detection_step,
+            arrivals: Mutex::new(HashMap::new()),
+            consecutive_breaches: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records an introduction-circuit launch for `rend_query` and
+    /// returns whether that onion service is now under a sustained
+    /// introduction flood.
+    pub fn record_introduction(&self, rend_query: &str) -> bool {
+        let now = self.runtime.now();
+
+        let mut arrivals = self.arrivals.lock().unwrap();
+        let window = arrivals.entry(rend_query.to_string()).or_insert_with(VecDeque::new);
+        window.push_back(now);
+        while window.len() > self.window_len {
+            window.pop_front();
+        }
+
+        let rate = match window.len() {
+            0 | 1 => 0.0,
+            n => {
+                let span = now.duration_since(*window.front().unwrap()).as_secs_f64();
+                if span > 0.0 {
+                    (n - 1) as f64 / span
+                } else {
+                    f64::INFINITY
+                }
+            }
+        };
+        drop(arrivals);
+
+        let mut consecutive_breaches = self.consecutive_breaches.lock().unwrap();
+        let breaches = consecutive_breaches.entry(rend_query.to_string()).or_insert(0);
+        if rate > self.max_intro_rate_per_sec {
+            *breaches += 1;
+        } else {
+            *breaches = 0;
+        }
+
+        *breaches >= self.detection_step
+    }
+}
+
+/// FFT length used for the timing spectrum. Inter-arrival gap series are
+/// resampled/zero-padded to this fixed power-of-two length so a single
+/// cached `rustfft` plan can serve every circuit regardless of how many
+/// cells it carried.
+pub(crate) const TIMING_FFT_LEN: usize = 64;
+/// Number of low-frequency magnitude bins retained in `TimingFeatures::spectrum`.
+const TIMING_SPECTRUM_BINS: usize = 16;
+
+/// Lazily-built, process-wide forward FFT plan for [`TIMING_FFT_LEN`].
+/// `rustfft`'s planner is deterministic for a fixed length, so caching it
+/// behind a `OnceLock` avoids replanning on every call without introducing
+/// any shared mutable state.
+fn timing_fft_plan() -> &'static std::sync::Arc<dyn rustfft::Fft<f64>> {
+    static PLAN: OnceLock<std::sync::Arc<dyn rustfft::Fft<f64>>> = OnceLock::new();
+    PLAN.get_or_init(|| FftPlanner::<f64>::new().plan_fft_forward(TIMING_FFT_LEN))
+}
+
+/// Online (single-pass, O(1)-per-sample, bounded-memory) estimator for
+/// the scalar statistics [`TimingFeatures`] needs -- mean, variance,
+/// min, max, and an approximate median -- so a circuit's growing
+/// interval history never has to be fully re-sorted to refresh them.
+///
+/// Mean and variance use Welford's online algorithm (count `n`, running
+/// mean `mean`, and sum-of-squared-deviations `m2`; on a new sample `x`:
+/// `delta = x - mean`, `mean += delta / n`, `m2 += delta * (x - mean)`,
+/// `variance = m2 / n`). The median is estimated with the P²
+/// (Piecewise-Parabolic) quantile algorithm (Jain & Chlamtac, 1985):
+/// five markers track the 0th/25th/50th/75th/100th percentile heights
+/// and positions, initialized (sorted) from the first five samples, and
+/// from then on each new sample nudges the markers' desired positions
+/// and -- for whichever interior marker has drifted more than one away
+/// from its desired position -- adjusts that marker's height via the
+/// parabolic formula, falling back to linear interpolation if the
+/// parabolic estimate would step outside its neighbors.
+#[derive(Debug, Clone)]
+pub struct OnlineTimingStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+    /// Samples collected so far, only retained until there are enough
+    /// (5) to initialize the P² markers.
+    startup: Vec<f64>,
+    /// P² marker heights (q1..q5), `None` until initialized from 5 samples.
+    heights: Option<[f64; 5]>,
+    /// P² marker positions (n1..n5).
+    positions: [f64; 5],
+    /// P² marker desired positions (np1..np5).
+    desired_positions: [f64; 5],
+    /// Per-marker desired-position increment (0, 0.25, 0.5, 0.75, 1.0),
+    /// targeting the 25th/50th/75th percentiles.
+    increments: [f64; 5],
+}
+
+impl OnlineTimingStats {
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            startup: Vec::with_capacity(5),
+            heights: None,
+            positions: [1.0, 2.0, 3.0, 4.0, 5.0],
+            desired_positions: [1.0, 2.0, 3.0, 4.0, 5.0],
+            increments: [0.0, 0.25, 0.5, 0.75, 1.0],
+        }
+    }
+
+    /// Folds one more sample into the running estimates.
+    pub fn update(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+
+        if self.heights.is_none() {
+            self.startup.push(x);
+            if self.startup.len() == 5 {
+                let mut sorted = self.startup.clone();
+                sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                self.heights = Some([sorted[0], sorted[1], sorted[2], sorted[3], sorted[4]]);
+            }
+            return;
+        }
+
+        let heights = self.heights.as_mut().unwrap();
+
+        // Find the cell k (0-indexed marker below x) containing the new
+        // sample, clamping into the outer markers if it's a new
+        // extreme, and bump every marker position above it.
+        let k = if x < heights[0] {
+            heights[0] = x;
+            0
+        } else if x >= heights[4] {
+            heights[4] = x;
+            3
+        } else {
+            (0..4)
+                .find(|&i| heights[i] <= x && x < heights[i + 1])
+                .unwrap_or(3)
+        };
+
+        for position in self.positions.iter_mut().skip(k + 1) {
+            *position += 1.0;
+        }
+        for (desired, increment) in self.desired_positions.iter_mut().zip(self.increments.iter()) {
+            *desired += increment;
+        }
+
+        // Adjust whichever interior marker (indices 1..=3) has drifted
+        // more than one away from its desired position.
+        for i in 1..4 {
+            let d = self.desired_positions[i] - self.positions[i];
+            if (d >= 1.0 && self.positions[i + 1] - self.positions[i] > 1.0)
+                || (d <= -1.0 && self.positions[i - 1] - self.positions[i] < -1.0)
+            {
+                let sign = d.signum();
+                let parabolic = Self::parabolic(heights, &self.positions, i, sign);
+                heights[i] = if heights[i - 1] < parabolic && parabolic < heights[i + 1] {
+                    parabolic
+                } else {
+                    Self::linear(heights, &self.positions, i, sign)
+                };
+                self.positions[i] += sign;
+            }
+        }
+    }
+
+    fn parabolic(heights: &[f64; 5], positions: &[f64; 5], i: usize, sign: f64) -> f64 {
+        let (q_prev, q, q_next) = (heights[i - 1], heights[i], heights[i + 1]);
+        let (n_prev, n, n_next) = (positions[i - 1], positions[i], positions[i + 1]);
+        q + sign / (n_next - n_prev)
+            * ((n - n_prev + sign) * (q_next - q) / (n_next - n)
+                + (n_next - n - sign) * (q - q_prev) / (n - n_prev))
+    }
+
+    fn linear(heights: &[f64; 5], positions: &[f64; 5], i: usize, sign: f64) -> f64 {
+        let j = (i as isize + sign as isize) as usize;
+        heights[i] + sign * (heights[j] - heights[i]) / (positions[j] - positions[i])
+    }
+
+    /// Number of samples folded in so far.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Population variance (`m2 / n`), matching the batch formula this replaced.
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    pub fn min(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.min
+        }
+    }
+
+    pub fn max(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.max
+        }
+    }
+
+    /// The P²-estimated median once 5+ samples have been seen (exact
+    /// median of the startup samples otherwise).
+    pub fn median(&self) -> f64 {
+        match &self.heights {
+            Some(heights) => heights[2],
+            None if self.startup.is_empty() => 0.0,
+            None => {
+                let mut sorted = self.startup.clone();
+                sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let mid = sorted.len() / 2;
+                if sorted.len() % 2 == 0 {
+                    (sorted[mid - 1] + sorted[mid]) / 2.0
+                } else {
+                    sorted[mid]
+                }
+            }
+        }
+    }
+}
+
+impl Default for OnlineTimingStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Metadata extraction utilities
 pub struct MetadataExtractor;
 
 impl MetadataExtractor {
-    /// Extract timing features from cell sequence
+    /// Extract timing features from cell sequence. The scalar statistics
+    /// (mean/std-dev/median/min/max) are computed by feeding each
+    /// inter-arrival gap through a single [`OnlineTimingStats`] pass in
+    /// O(1) per gap, rather than sorting the whole interval history on
+    /// every call.
     pub fn extract_timing_features(cell_timings: &[Duration]) -> TimingFeatures {
         if cell_timings.is_empty() {
             return TimingFeatures::default();
         }
 
-        let mut intervals: Vec<f64> = cell_timings
+        let raw_intervals: Vec<f64> = cell_timings
             .windows(2)
             .map(|w| (w[1].as_micros() as i128 - w[0].as_micros() as i128).abs() as f64)
             .collect();
 
-        if intervals.is_empty() {
+        if raw_intervals.is_empty() {
             return TimingFeatures::default();
         }
 
-        intervals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        // Spectral analysis needs the gaps in their original arrival order.
+        let (spectrum, dominant_bin, peak_ratio) = Self::spectral_timing_features(&raw_intervals);
+
+        let mut stats = OnlineTimingStats::new();
+        for &interval in &raw_intervals {
+            stats.update(interval);
+        }
+
+        TimingFeatures {
+            mean_interval: stats.mean(),
+            std_dev_interval: stats.std_dev(),
+            median_interval: stats.median(),
+            min_interval: stats.min(),
+            max_interval: stats.max(),
+            spectrum,
+            dominant_bin,
+            peak_ratio,
+        }
+    }
+
+    /// Detects a periodic timing signature in the inter-arrival gaps:
+    /// resamples `intervals` (in arrival order, NaN gaps mapped to zero) to
+    /// [`TIMING_FFT_LEN`] samples, runs a forward FFT via the cached plan,
+    /// and returns the L2-normalized magnitude of the first
+    /// [`TIMING_SPECTRUM_BINS`] bins alongside the dominant non-DC bin
+    /// index and its peak-to-mean ratio. Fewer than two gaps yields a zero
+    /// spectrum, since periodicity cannot be judged from a single gap.
+    fn spectral_timing_features(intervals: &[f64]) -> (Vec<f64>, usize, f64) {
+        if intervals.len() < 2 {
+            return (vec![0.0; TIMING_SPECTRUM_BINS], 0, 0.0);
+        }
+
+        let cleaned: Vec<f64> = intervals
+            .iter()
+            .map(|&gap| if gap.is_nan() { 0.0 } else { gap })
+            .collect();
+        let resampled = resample_to_fft_len(&cleaned);
+
+        let mut buffer: Vec<Complex<f64>> = resampled
+            .iter()
+            .map(|&value| Complex::new(value, 0.0))
+            .collect();
+        timing_fft_plan().process(&mut buffer);
 
-        let mean = intervals.iter().sum::<f64>() / intervals.len() as f64;
-        let variance = intervals.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / intervals.len() as f64;
-        let std_dev = variance.sqrt();
+        let half = TIMING_FFT_LEN / 2;
+        let magnitudes: Vec<f64> = buffer[..half].iter().map(|c| c.norm()).collect();
 
-        let median = if intervals.len() % 2 == 0 {
-            (intervals[intervals.len() / 2 - 1] + intervals[intervals.len() / 2]) / 2.0
+        let energy: f64 = magnitudes.iter().map(|m| m * m).sum::<f64>().sqrt();
+        let normalized: Vec<f64> = if energy > 1e-10 {
+            magnitudes.iter().map(|m| m / energy).collect()
         } else {
-            intervals[intervals.len() / 2]
+            vec![0.0; half]
         };
 
-        TimingFeatures {
-            mean_interval: mean,
-            std_dev_interval: std_dev,
-            median_interval: median,
-            min_interval: intervals[0],
-            max_interval: intervals[intervals.len() - 1],
-        }
+        // Skip the DC bin (index 0) when looking for the dominant
+        // frequency: a constant offset in the gaps is not periodicity.
+        let dominant_bin = normalized
+            .iter()
+            .enumerate()
+            .skip(1)
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+
+        let mean_magnitude = normalized.iter().sum::<f64>() / normalized.len() as f64;
+        let peak_ratio = if mean_magnitude > 1e-10 {
+            normalized[dominant_bin] / mean_magnitude
+        } else {
+            0.0
+        };
+
+        let mut spectrum = normalized;
+        spectrum.truncate(TIMING_SPECTRUM_BINS);
+        spectrum.resize(TIMING_SPECTRUM_BINS, 0.0);
+
+        (spectrum, dominant_bin, peak_ratio)
     }
 
-    /// Analyze cell type distribution
+    /// Analyze cell type distribution. `excess_padding_ratio` is left
+    /// equal to `padding_ratio` (i.e. no baseline subtracted) -- use
+    /// [`Self::analyze_cell_types_with_padding_model`] when a
+    /// [`PaddingModel`] and [`ChannelUsage`] are available, so negotiated
+    /// channel padding doesn't inflate the excess.
     pub fn analyze_cell_types(cell_types: &[TorCellType]) -> CellTypeDistribution {
+        let mut dist = Self::distribution_without_excess(cell_types);
+        dist.excess_padding_ratio = dist.padding_ratio;
+        dist
+    }
+
+    /// Like [`Self::analyze_cell_types`], but sets
+    /// `CellTypeDistribution::excess_padding_ratio` to the observed
+    /// padding fraction minus `model`'s expected baseline for `usage`,
+    /// clamped at zero so negotiated padding below the baseline doesn't
+    /// produce a negative excess.
+    pub fn analyze_cell_types_with_padding_model(
+        cell_types: &[TorCellType],
+        model: &PaddingModel,
+        usage: ChannelUsage,
+    ) -> CellTypeDistribution {
+        let mut dist = Self::distribution_without_excess(cell_types);
+        dist.excess_padding_ratio =
+            (dist.padding_ratio - model.expected_padding_ratio(usage)).max(0.0);
+        dist
+    }
+
+    fn distribution_without_excess(cell_types: &[TorCellType]) -> CellTypeDistribution {
         let total = cell_types.len();
         if total == 0 {
             return CellTypeDistribution::default();
@@ -360,6 +1072,7 @@ impl MetadataExtractor {
             data_ratio: data_count as f64 / total as f64,
             padding_ratio: padding_count as f64 / total as f64,
             other_ratio: other_count as f64 / total as f64,
+            excess_padding_ratio: 0.0,
         }
     }
 }
@@ -372,6 +1085,14 @@ pub struct TimingFeatures {
     pub median_interval: f64,
     pub min_interval: f64,
     pub max_interval: f64,
+    /// L2-normalized magnitude spectrum of the inter-arrival gap series
+    /// (first `TIMING_SPECTRUM_BINS` bins), distinguishing periodic
+    /// flood timing from broad-band benign traffic
+    pub spectrum: Vec<f64>,
+    /// Index of the strongest non-DC frequency bin
+    pub dominant_bin: usize,
+    /// Peak-to-mean ratio of the dominant bin's magnitude
+    pub peak_ratio: f64,
 }
 
 impl Default for TimingFeatures {
@@ -382,10 +1103,32 @@ impl Default for TimingFeatures {
             median_interval: 0.0,
             min_interval: 0.0,
             max_interval: 0.0,
+            spectrum: vec![0.0; TIMING_SPECTRUM_BINS],
+            dominant_bin: 0,
+            peak_ratio: 0.0,
         }
     }
 }
 
+/// Linearly resamples (or zero-pads) `series` to exactly [`TIMING_FFT_LEN`]
+/// samples, preserving the overall shape of the original sequence.
+fn resample_to_fft_len(series: &[f64]) -> [f64; TIMING_FFT_LEN] {
+    let mut resampled = [0.0; TIMING_FFT_LEN];
+    if series.len() == 1 {
+        resampled[0] = series[0];
+        return resampled;
+    }
+
+    for (i, slot) in resampled.iter_mut().enumerate() {
+        let position = i as f64 * (series.len() - 1) as f64 / (TIMING_FFT_LEN - 1) as f64;
+        let lower = position.floor() as usize;
+        let upper = (lower + 1).min(series.len() - 1);
+        let frac = position - lower as f64;
+        *slot = series[lower] * (1.0 - frac) + series[upper] * frac;
+    }
+    resampled
+}
+
 /// Cell type distribution features
 #[derive(Debug, Clone)]
 pub struct CellTypeDistribution {
@@ -394,6 +1137,13 @@ pub struct CellTypeDistribution {
     pub data_ratio: f64,
     pub padding_ratio: f64,
     pub other_ratio: f64,
+    /// `padding_ratio` with Tor's own negotiated channel-padding
+    /// baseline subtracted (see [`PaddingModel`]), so legitimate
+    /// padding doesn't skew anomaly detection the way raw
+    /// `padding_ratio` can. Equal to `padding_ratio` when no
+    /// [`PaddingModel`] was supplied (see
+    /// [`MetadataExtractor::analyze_cell_types`]).
+    pub excess_padding_ratio: f64,
 }
 
 impl Default for CellTypeDistribution {
@@ -404,13 +1154,24 @@ impl Default for CellTypeDistribution {
             data_ratio: 0.0,
             padding_ratio: 0.0,
             other_ratio: 0.0,
+            excess_padding_ratio: 0.0,
         }
     }
 }
 
-/// Circuit monitor for tracking active circuits
+/// Circuit monitor for tracking active circuits.
+///
+/// Circuits are stored as `Arc<RwLock<TorCircuitMetadata>>` rather than
+/// bare values, so [`Self::get_circuit`] hands out a cheap, shared
+/// handle instead of cloning the whole metadata (including its
+/// unbounded `cell_timings`/`cell_types` `Vec`s) on every lookup --
+/// mirroring arti's move to `Arc<ClientCirc>` for cheaply-shared circuit
+/// state. Callers mutate the metadata in place through the returned
+/// handle's `write()` guard rather than clone-modify-[`Self::track_circuit`],
+/// which also removes the lost-update race that pattern had between the
+/// read and the re-insert.
 pub struct CircuitMonitor {
-    circuits: Arc<DashMap<u32, TorCircuitMetadata>>,
+    circuits: Arc<DashMap<u32, Arc<RwLock<TorCircuitMetadata>>>>,
     max_circuits: usize,
 }
 
@@ -423,20 +1184,24 @@ impl CircuitMonitor {
         }
     }
 
-    /// Track or update a circuit
+    /// Track a (newly-seen) circuit, wrapping it in a fresh
+    /// `Arc<RwLock<_>>`. For an already-tracked circuit, prefer
+    /// [`Self::get_circuit`] and mutating its returned handle in place
+    /// over calling this again, since that drops any handles other
+    /// callers are still holding onto the old metadata.
     pub fn track_circuit(&self, circuit: TorCircuitMetadata) {
         let circuit_id = circuit.circuit_id;
-        
+
         // Insert or update
-        self.circuits.insert(circuit_id, circuit);
-        
+        self.circuits.insert(circuit_id, Arc::new(RwLock::new(circuit)));
+
         // Evict excess circuits if over capacity
         while self.circuits.len() > self.max_circuits {
             // Find a circuit to evict (not the one we just added)
             let to_evict = self.circuits.iter()
                 .find(|entry| *entry.key() != circuit_id)
                 .map(|entry| *entry.key());
-            
+
             if let Some(id) = to_evict {
                 self.circuits.remove(&id);
             } else {
@@ -445,8 +1210,8 @@ impl CircuitMonitor {
         }
     }
 
-    /// Get circuit by ID
-    pub fn get_circuit(&self, circuit_id: u32) -> Option<TorCircuitMetadata> {
+    /// Get a cheap, shared handle to circuit_id's tracked metadata, if any.
+    pub fn get_circuit(&self, circuit_id: u32) -> Option<Arc<RwLock<TorCircuitMetadata>>> {
         self.circuits.get(&circuit_id).map(|r| r.value().clone())
     }
 
@@ -459,6 +1224,65 @@ impl CircuitMonitor {
     pub fn circuit_count(&self) -> usize {
         self.circuits.len()
     }
+
+    /// Derives a deterministic selection seed from a traffic spectral
+    /// fingerprint, so routing decisions follow the spectral type of the
+    /// traffic that triggered them rather than an arbitrary nonce.
+    /// Hashes the power spectrum and dominant frequencies with blake3;
+    /// two fingerprints with the same spectral shape produce the same
+    /// seed, and hence the same [`Self::select_deterministic`] ordering.
+    pub fn seed_from_fingerprint(fp: &SpectralFingerprint) -> [u8; 32] {
+        let mut hasher = Hasher::new();
+        for value in &fp.power_spectrum {
+            hasher.update(&value.to_le_bytes());
+        }
+        for &freq in &fp.dominant_frequencies {
+            hasher.update(&(freq as u64).to_le_bytes());
+        }
+        *hasher.finalize().as_bytes()
+    }
+
+    /// Picks a deterministic neighbor tier of tracked circuits to route
+    /// a packet over, modeled on turbine-style peer shuffle: a per-circuit
+    /// weight is derived by hashing `seed` together with the circuit id,
+    /// then circuit ids are stable-sorted by that weighted hash. The
+    /// first `fanout` ids in this order are the "neighbor" tier returned
+    /// here, routed to directly; the remainder form the "children" tier,
+    /// reached transitively through the neighbors rather than returned
+    /// by this call. Because the order depends only on `seed` and the
+    /// circuit ids -- never on insertion order or [`DashMap`] iteration
+    /// order -- the same seed always yields the same neighbor set on
+    /// every node, unlike [`Self::track_circuit`]'s eviction, which
+    /// picks from `DashMap`'s unspecified iteration order.
+    pub fn select_deterministic(&self, seed: [u8; 32], fanout: usize) -> Vec<u32> {
+        let mut weighted: Vec<(u64, u32)> = self
+            .circuits
+            .iter()
+            .map(|entry| {
+                let circuit_id = *entry.key();
+                (Self::weighted_hash(&seed, circuit_id), circuit_id)
+            })
+            .collect();
+
+        weighted.sort_by(|a, b| a.cmp(b));
+
+        weighted
+            .into_iter()
+            .take(fanout)
+            .map(|(_, circuit_id)| circuit_id)
+            .collect()
+    }
+
+    /// Hashes `seed` together with `circuit_id` via blake3 and reduces
+    /// the digest to a `u64` weight used to order circuits in
+    /// [`Self::select_deterministic`].
+    fn weighted_hash(seed: &[u8; 32], circuit_id: u32) -> u64 {
+        let mut hasher = Hasher::new();
+        hasher.update(seed);
+        hasher.update(&circuit_id.to_le_bytes());
+        let digest = hasher.finalize();
+        u64::from_le_bytes(digest.as_bytes()[0..8].try_into().unwrap())
+    }
 }
 
 #[cfg(test)]
@@ -490,7 +1314,7 @@ mod tests {
 
         let retrieved = monitor.get_circuit(1);
         assert!(retrieved.is_some());
-        assert_eq!(retrieved.unwrap().circuit_id, 1);
+        assert_eq!(retrieved.unwrap().read().unwrap().circuit_id, 1);
     }
 
     #[test]
@@ -515,6 +1339,112 @@ mod tests {
         assert_eq!(monitor.circuit_count(), 2);
     }
 
+    #[test]
+    fn test_get_circuit_returns_shared_handle_mutations_are_visible() {
+        let monitor = CircuitMonitor::new(10);
+
+        monitor.track_circuit(TorCircuitMetadata {
+            circuit_id: 1,
+            created_at: Instant::now(),
+            cell_timings: vec![],
+            cell_types: vec![],
+            introduction_point: None,
+            rendezvous_completed: false,
+            total_bytes: 0,
+        });
+
+        let handle_a = monitor.get_circuit(1).unwrap();
+        let handle_b = monitor.get_circuit(1).unwrap();
+        assert!(Arc::ptr_eq(&handle_a, &handle_b));
+
+        handle_a.write().unwrap().rendezvous_completed = true;
+
+        assert!(handle_b.read().unwrap().rendezvous_completed);
+    }
+
+    fn track_empty_circuit(monitor: &CircuitMonitor, circuit_id: u32) {
+        monitor.track_circuit(TorCircuitMetadata {
+            circuit_id,
+            created_at: Instant::now(),
+            cell_timings: vec![],
+            cell_types: vec![],
+            introduction_point: None,
+            rendezvous_completed: false,
+            total_bytes: 0,
+        });
+    }
+
+    #[test]
+    fn test_select_deterministic_is_reproducible_across_monitors() {
+        let monitor_a = CircuitMonitor::new(10);
+        let monitor_b = CircuitMonitor::new(10);
+        for id in 1..=5 {
+            track_empty_circuit(&monitor_a, id);
+            track_empty_circuit(&monitor_b, id);
+        }
+
+        let seed = [7u8; 32];
+        let selection_a = monitor_a.select_deterministic(seed, 2);
+        let selection_b = monitor_b.select_deterministic(seed, 2);
+
+        assert_eq!(selection_a, selection_b);
+        assert_eq!(selection_a.len(), 2);
+    }
+
+    #[test]
+    fn test_select_deterministic_respects_fanout() {
+        let monitor = CircuitMonitor::new(10);
+        for id in 1..=5 {
+            track_empty_circuit(&monitor, id);
+        }
+
+        let selection = monitor.select_deterministic([1u8; 32], 3);
+        assert_eq!(selection.len(), 3);
+
+        // All selected ids must be drawn from the tracked circuit set,
+        // with no duplicates.
+        let mut seen = selection.clone();
+        seen.sort_unstable();
+        seen.dedup();
+        assert_eq!(seen.len(), 3);
+        assert!(selection.iter().all(|id| (1..=5).contains(id)));
+    }
+
+    #[test]
+    fn test_select_deterministic_differs_for_different_seeds() {
+        let monitor = CircuitMonitor::new(10);
+        for id in 1..=8 {
+            track_empty_circuit(&monitor, id);
+        }
+
+        let selection_a = monitor.select_deterministic([1u8; 32], 4);
+        let selection_b = monitor.select_deterministic([2u8; 32], 4);
+
+        assert_ne!(selection_a, selection_b);
+    }
+
+    #[test]
+    fn test_seed_from_fingerprint_is_deterministic_and_seed_sensitive() {
+        let fp_a = SpectralFingerprint {
+            power_spectrum: vec![1.0, 2.0, 3.0],
+            dominant_frequencies: vec![1, 2],
+            spectral_entropy: 0.5,
+        };
+        let fp_b = SpectralFingerprint {
+            power_spectrum: vec![4.0, 5.0, 6.0],
+            ..fp_a.clone()
+        };
+
+        assert_eq!(
+            CircuitMonitor::seed_from_fingerprint(&fp_a),
+            CircuitMonitor::seed_from_fingerprint(&fp_a)
+        );
+        assert_ne!(
+            CircuitMonitor::seed_from_fingerprint(&fp_a),
+            CircuitMonitor::seed_from_fingerprint(&fp_b)
+        );
+    }
+
     #[test]
     fn test_tor_interface_authentication() {
         let interface = TorInterface::new(9051);
@@ -528,7 +1458,12 @@ mod tests {
         let processor = EventProcessor::new(monitor.clone());
 
         // Process launch event
-        processor.process_event(CircuitEvent::Launched { circuit_id: 1 });
+        processor.process_event(CircuitEvent::Launched {
+            circuit_id: 1,
+            purpose: None,
+            hs_state: None,
+            rend_query: None,
+        });
         assert_eq!(monitor.circuit_count(), 1);
 
         // Process close event
@@ -539,6 +1474,81 @@ mod tests {
         assert_eq!(monitor.circuit_count(), 0);
     }
 
+    #[test]
+    fn test_mock_runtime_clock_advances_manually() {
+        let runtime = MockRuntime::new();
+        let start = runtime.now();
+
+        runtime.advance(Duration::from_secs(5));
+        assert_eq!(runtime.now(), start + Duration::from_secs(5));
+
+        runtime.advance(Duration::from_secs(2));
+        assert_eq!(runtime.now(), start + Duration::from_secs(7));
+    }
+
+    #[test]
+    fn test_event_processor_uses_injected_clock() {
+        let runtime = MockRuntime::new();
+        runtime.advance(Duration::from_secs(42));
+        let expected_created_at = runtime.now();
+
+        let monitor = Arc::new(CircuitMonitor::new(100));
+        let processor = EventProcessor::with_runtime(monitor.clone(), Arc::new(runtime));
+
+        processor.process_event(CircuitEvent::Launched {
+            circuit_id: 7,
+            purpose: None,
+            hs_state: None,
+            rend_query: None,
+        });
+
+        let tracked = monitor.get_circuit(7).unwrap();
+        assert_eq!(tracked.read().unwrap().created_at, expected_created_at);
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_against_scripted_mock_stream() {
+        let runtime = MockRuntime::new();
+        runtime.push_line("250 OK\r\n");
+        let mut interface = TorInterface::with_runtime(9051, Arc::new(runtime.clone()));
+
+        let cookie_id = crate::utils::test_support::unique_id();
+        let cookie_path = std::env::temp_dir().join(format!("thronion-tor-cookie-{cookie_id}"));
+        std::fs::write(&cookie_path, [0u8; 4]).unwrap();
+
+        let mut stream = interface.connect().await.unwrap();
+        interface
+            .authenticate(stream.as_mut(), cookie_path.to_str().unwrap())
+            .await
+            .unwrap();
+
+        assert!(interface.is_authenticated());
+        assert!(runtime.sent_lines()[0].starts_with("AUTHENTICATE "));
+
+        std::fs::remove_file(&cookie_path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_process_events_emits_parsed_events_from_scripted_stream() {
+        let runtime = MockRuntime::new();
+        runtime.push_line("650 CIRC 42 LAUNCHED\r\n");
+        runtime.push_line("650 CIRC 42 BUILT\r\n");
+
+        let stream = runtime.connect(9051).await.unwrap();
+        let (tx, mut rx) = broadcast::channel(10);
+
+        TorInterface::process_events(stream, tx).await.unwrap();
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            CircuitEvent::Launched { circuit_id: 42, purpose: None, hs_state: None, rend_query: None }
+        );
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            CircuitEvent::Built { circuit_id: 42, purpose: None, hs_state: None, rend_query: None }
+        );
+    }
+
     #[test]
     fn test_timing_features_extraction() {
         let timings = vec![
@@ -562,6 +1572,107 @@ mod tests {
         assert_eq!(features.mean_interval, 0.0);
     }
 
+    #[test]
+    fn test_timing_features_few_timings_yields_zero_spectrum() {
+        // A single gap carries no periodicity information.
+        let timings = vec![Duration::from_micros(100), Duration::from_micros(150)];
+        let features = MetadataExtractor::extract_timing_features(&timings);
+        assert!(features.spectrum.iter().all(|&m| m == 0.0));
+        assert_eq!(features.dominant_bin, 0);
+        assert_eq!(features.peak_ratio, 0.0);
+    }
+
+    #[test]
+    fn test_online_timing_stats_empty_defaults_to_zero() {
+        let stats = OnlineTimingStats::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), 0.0);
+        assert_eq!(stats.std_dev(), 0.0);
+        assert_eq!(stats.min(), 0.0);
+        assert_eq!(stats.max(), 0.0);
+        assert_eq!(stats.median(), 0.0);
+    }
+
+    #[test]
+    fn test_online_timing_stats_exact_below_five_samples() {
+        let mut stats = OnlineTimingStats::new();
+        for &x in &[30.0, 50.0, 60.0] {
+            stats.update(x);
+        }
+
+        assert_eq!(stats.mean(), (30.0 + 50.0 + 60.0) / 3.0);
+        assert_eq!(stats.min(), 30.0);
+        assert_eq!(stats.max(), 60.0);
+        // Below the 5-sample P² initialization threshold, the median is
+        // the exact median of what's been seen.
+        assert_eq!(stats.median(), 50.0);
+    }
+
+    #[test]
+    fn test_online_timing_stats_mean_and_variance_match_batch_formula() {
+        let samples = [12.0, 45.0, 7.0, 23.0, 89.0, 34.0, 5.0, 61.0, 18.0, 29.0];
+
+        let mut stats = OnlineTimingStats::new();
+        for &x in &samples {
+            stats.update(x);
+        }
+
+        let batch_mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let batch_variance =
+            samples.iter().map(|x| (x - batch_mean).powi(2)).sum::<f64>() / samples.len() as f64;
+
+        assert!((stats.mean() - batch_mean).abs() < 1e-9);
+        assert!((stats.variance() - batch_variance).abs() < 1e-9);
+        assert_eq!(stats.min(), 5.0);
+        assert_eq!(stats.max(), 89.0);
+    }
+
+    #[test]
+    fn test_online_timing_stats_p2_median_approximates_true_median() {
+        // Feed enough samples that the P² estimator is active, then
+        // compare against the exact sorted median.
+        let samples: Vec<f64> = (0..201).map(|i| ((i * 37) % 211) as f64).collect();
+
+        let mut stats = OnlineTimingStats::new();
+        for &x in &samples {
+            stats.update(x);
+        }
+
+        let mut sorted = samples.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let true_median = sorted[sorted.len() / 2];
+
+        assert!(
+            (stats.median() - true_median).abs() <= 10.0,
+            "P2 median {} should approximate true median {}",
+            stats.median(),
+            true_median
+        );
+        assert_eq!(stats.min(), *sorted.first().unwrap());
+        assert_eq!(stats.max(), *sorted.last().unwrap());
+    }
+
+    #[test]
+    fn test_periodic_timing_has_higher_peak_ratio_than_jitter() {
+        let periodic: Vec<Duration> = (0..32)
+            .map(|i| Duration::from_micros(if i % 2 == 0 { 50 } else { 150 }))
+            .collect();
+        let jittery: Vec<Duration> = (0..32)
+            .map(|i| Duration::from_micros(100 + (i * 37 % 53)))
+            .collect();
+
+        let periodic_features = MetadataExtractor::extract_timing_features(&periodic);
+        let jittery_features = MetadataExtractor::extract_timing_features(&jittery);
+
+        assert!(
+            periodic_features.peak_ratio > jittery_features.peak_ratio,
+            "periodic timing (ratio {}) should stand out more than jitter (ratio {})",
+            periodic_features.peak_ratio,
+            jittery_features.peak_ratio
+        );
+        assert_eq!(periodic_features.spectrum.len(), TIMING_SPECTRUM_BINS);
+    }
+
     #[test]
     fn test_cell_type_distribution() {
         let cell_types = vec![
@@ -586,10 +1697,71 @@ mod tests {
         assert_eq!(dist.data_ratio, 0.0);
     }
 
+    #[test]
+    fn test_analyze_cell_types_without_model_treats_all_padding_as_excess() {
+        let cell_types = vec![TorCellType::Padding, TorCellType::Data];
+        let dist = MetadataExtractor::analyze_cell_types(&cell_types);
+        assert_eq!(dist.excess_padding_ratio, dist.padding_ratio);
+    }
+
+    #[test]
+    fn test_padding_model_subtracts_idle_baseline_from_excess() {
+        // 3 of 6 cells are padding (ratio 0.5) -- exactly at the idle
+        // baseline, so nothing should read as excess/attack padding.
+        let cell_types = vec![
+            TorCellType::Padding,
+            TorCellType::Padding,
+            TorCellType::Padding,
+            TorCellType::Data,
+            TorCellType::Data,
+            TorCellType::Data,
+        ];
+
+        let model = PaddingModel::from_consensus_defaults();
+        let dist = MetadataExtractor::analyze_cell_types_with_padding_model(
+            &cell_types,
+            &model,
+            ChannelUsage::Idle,
+        );
+
+        assert_eq!(dist.padding_ratio, 0.5);
+        assert_eq!(dist.excess_padding_ratio, 0.0);
+    }
+
+    #[test]
+    fn test_padding_model_exposes_excess_above_baseline() {
+        // 5 of 6 cells are padding (ratio ~0.833) on a circuit carrying
+        // user traffic, whose baseline is only 0.1 -- the rest should
+        // surface as excess.
+        let cell_types = vec![
+            TorCellType::Padding,
+            TorCellType::Padding,
+            TorCellType::Padding,
+            TorCellType::Padding,
+            TorCellType::Padding,
+            TorCellType::Data,
+        ];
+
+        let model = PaddingModel::from_consensus_defaults();
+        let dist = MetadataExtractor::analyze_cell_types_with_padding_model(
+            &cell_types,
+            &model,
+            ChannelUsage::UserTraffic,
+        );
+
+        assert!((dist.excess_padding_ratio - (5.0 / 6.0 - 0.1)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_padding_model_custom_baselines_override_consensus_defaults() {
+        let model = PaddingModel::new(0.9, 0.9, 0.9);
+        assert_eq!(model.expected_padding_ratio(ChannelUsage::HiddenService), 0.9);
+    }
+
     #[test]
     fn test_circuit_event_types() {
-        let event1 = CircuitEvent::Launched { circuit_id: 1 };
-        let event2 = CircuitEvent::Built { circuit_id: 1 };
+        let event1 = CircuitEvent::Launched { circuit_id: 1, purpose: None, hs_state: None, rend_query: None };
+        let event2 = CircuitEvent::Built { circuit_id: 1, purpose: None, hs_state: None, rend_query: None };
         let event3 = CircuitEvent::Failed {
             circuit_id: 1,
             reason: "timeout".to_string(),
@@ -605,7 +1777,7 @@ mod tests {
         let line = "650 CIRC 123 LAUNCHED";
         let event = TorInterface::parse_circuit_event(line);
         assert!(event.is_some());
-        assert!(matches!(event.unwrap(), CircuitEvent::Launched { circuit_id: 123 }));
+        assert!(matches!(event.unwrap(), CircuitEvent::Launched { circuit_id: 123, .. }));
     }
 
     #[test]
@@ -626,7 +1798,7 @@ mod tests {
         let line = "650 CIRC 789 BUILT";
         let event = TorInterface::parse_circuit_event(line);
         assert!(event.is_some());
-        assert!(matches!(event.unwrap(), CircuitEvent::Built { circuit_id: 789 }));
+        assert!(matches!(event.unwrap(), CircuitEvent::Built { circuit_id: 789, .. }));
     }
 
     #[test]
@@ -654,4 +1826,138 @@ mod tests {
             panic!("Expected Closed event");
         }
     }
+
+    #[test]
+    fn test_parse_circuit_event_captures_hs_fields() {
+        let line = "650 CIRC 222 LAUNCHED BUILD_FLAGS=NEED_CAPACITY PURPOSE=HS_SERVICE_INTRO \
+                     HS_STATE=HSSI_CONNECTING REND_QUERY=abcdefghijklmnop.onion";
+        let event = TorInterface::parse_circuit_event(line);
+
+        match event {
+            Some(CircuitEvent::Launched { circuit_id, purpose, hs_state, rend_query }) => {
+                assert_eq!(circuit_id, 222);
+                assert_eq!(purpose.as_deref(), Some("HS_SERVICE_INTRO"));
+                assert_eq!(hs_state.as_deref(), Some("HSSI_CONNECTING"));
+                assert_eq!(rend_query.as_deref(), Some("abcdefghijklmnop.onion"));
+            }
+            other => panic!("Expected Launched event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_circuit_event_without_hs_fields_leaves_them_none() {
+        let line = "650 CIRC 223 BUILT";
+        let event = TorInterface::parse_circuit_event(line);
+
+        match event {
+            Some(CircuitEvent::Built { purpose, hs_state, rend_query, .. }) => {
+                assert!(purpose.is_none());
+                assert!(hs_state.is_none());
+                assert!(rend_query.is_none());
+            }
+            other => panic!("Expected Built event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_event_processor_maps_introduction_purpose_onto_metadata() {
+        let monitor = Arc::new(CircuitMonitor::new(100));
+        let processor = EventProcessor::new(monitor.clone());
+
+        processor.process_event(CircuitEvent::Launched {
+            circuit_id: 10,
+            purpose: Some("HS_SERVICE_INTRO".to_string()),
+            hs_state: Some("HSSI_CONNECTING".to_string()),
+            rend_query: Some("service.onion".to_string()),
+        });
+
+        let metadata = monitor.get_circuit(10).unwrap();
+        let metadata = metadata.read().unwrap();
+        assert_eq!(metadata.introduction_point.as_deref(), Some("service.onion"));
+        assert_eq!(metadata.cell_types, vec![TorCellType::Introduce2]);
+        assert!(!metadata.rendezvous_completed);
+    }
+
+    #[test]
+    fn test_event_processor_marks_rendezvous_completed_on_joined_state() {
+        let monitor = Arc::new(CircuitMonitor::new(100));
+        let processor = EventProcessor::new(monitor.clone());
+
+        processor.process_event(CircuitEvent::Launched {
+            circuit_id: 11,
+            purpose: Some("HS_CLIENT_REND".to_string()),
+            hs_state: Some("HSCR_ESTABLISHED_IDLE".to_string()),
+            rend_query: Some("service.onion".to_string()),
+        });
+        assert!(!monitor.get_circuit(11).unwrap().read().unwrap().rendezvous_completed);
+
+        processor.process_event(CircuitEvent::Built {
+            circuit_id: 11,
+            purpose: Some("HS_CLIENT_REND".to_string()),
+            hs_state: Some("HSCR_JOINED".to_string()),
+            rend_query: None,
+        });
+
+        let metadata = monitor.get_circuit(11).unwrap();
+        let metadata = metadata.read().unwrap();
+        assert!(metadata.rendezvous_completed);
+        assert_eq!(metadata.cell_types, vec![TorCellType::Rendezvous1]);
+        // The BUILT line carried no REND_QUERY, so the one captured at
+        // LAUNCHED should survive rather than being cleared.
+        assert_eq!(metadata.introduction_point.as_deref(), Some("service.onion"));
+    }
+
+    #[test]
+    fn test_purpose_cell_type_ignores_non_hs_purposes() {
+        assert_eq!(purpose_cell_type(Some("GENERAL")), None);
+        assert_eq!(purpose_cell_type(None), None);
+    }
+
+    #[test]
+    fn test_introduction_flood_detector_flags_sustained_high_rate() {
+        let runtime = MockRuntime::new();
+        let detector = IntroductionFloodDetector::with_runtime(Arc::new(runtime.clone()), 10, 5.0, 3);
+
+        // Ten introductions in one second is a 10/sec rate, well above
+        // the 5/sec threshold; the detector should flag once it has
+        // seen `detection_step` (3) consecutive breaching windows.
+        let mut flagged = false;
+        for _ in 0..10 {
+            flagged = detector.record_introduction("flooded.onion");
+            runtime.advance(Duration::from_millis(100));
+        }
+
+        assert!(flagged, "sustained high-rate introductions should trip the detector");
+    }
+
+    #[test]
+    fn test_introduction_flood_detector_ignores_low_rate() {
+        let runtime = MockRuntime::new();
+        let detector = IntroductionFloodDetector::with_runtime(Arc::new(runtime.clone()), 10, 5.0, 3);
+
+        // One introduction every two seconds is a 0.5/sec rate, well
+        // under the 5/sec threshold.
+        let mut flagged = false;
+        for _ in 0..10 {
+            flagged = detector.record_introduction("quiet.onion") || flagged;
+            runtime.advance(Duration::from_secs(2));
+        }
+
+        assert!(!flagged, "low-rate introductions should never trip the detector");
+    }
+
+    #[test]
+    fn test_introduction_flood_detector_keys_are_independent() {
+        let runtime = MockRuntime::new();
+        let detector = IntroductionFloodDetector::with_runtime(Arc::new(runtime.clone()), 10, 5.0, 3);
+
+        for _ in 0..10 {
+            detector.record_introduction("flooded.onion");
+            runtime.advance(Duration::from_millis(100));
+        }
+
+        // A single quiet introduction for a different onion address
+        // should not inherit the other address's breach streak.
+        assert!(!detector.record_introduction("quiet.onion"));
+    }
 }
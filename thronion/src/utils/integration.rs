@@ -119,6 +119,195 @@ where
     }
 }
 
+/// Adaptiver Dormand-Prince RK45 Integrator mit eingebetteter
+/// Fehlerschätzung
+///
+/// Berechnet aus denselben sieben Stufenauswertungen sowohl eine
+/// 5.-Ordnung- als auch eine 4.-Ordnung-Lösung; die Differenz beider
+/// dient als lokale Fehlerschätzung, mit der die Schrittweite dt
+/// gegenüber `atol`/`rtol` adaptiv gesteuert wird.
+pub struct DormandPrince45<F>
+where
+    F: Fn(f64, &[f64]) -> Vec<f64>,
+{
+    /// Derivative-Funktion f(t, x)
+    pub derivative: F,
+    /// Absolute Toleranz
+    pub atol: f64,
+    /// Relative Toleranz
+    pub rtol: f64,
+}
+
+/// Butcher-Tableau-Knoten c₂..c₇ (Dormand-Prince 5(4))
+const DP_C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+
+/// Butcher-Tableau-Koeffizienten aᵢⱼ (untere Dreiecksmatrix, zeilenweise)
+const DP_A: [[f64; 6]; 6] = [
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+        0.0,
+        0.0,
+    ],
+    [
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+        0.0,
+    ],
+    [
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ],
+];
+
+/// Gewichte b der 5.-Ordnung-Lösung (identisch zur letzten Stufe von A,
+/// FSAL-Eigenschaft)
+const DP_B5: [f64; 7] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+    0.0,
+];
+
+/// Gewichte b* der eingebetteten 4.-Ordnung-Lösung
+const DP_B4: [f64; 7] = [
+    5179.0 / 57600.0,
+    0.0,
+    7571.0 / 16695.0,
+    393.0 / 640.0,
+    -92097.0 / 339200.0,
+    187.0 / 2100.0,
+    1.0 / 40.0,
+];
+
+impl<F> DormandPrince45<F>
+where
+    F: Fn(f64, &[f64]) -> Vec<f64>,
+{
+    /// Erstellt neuen adaptiven DP45-Integrator
+    ///
+    /// # Arguments
+    /// * `derivative` - Ableitungsfunktion f(t, x)
+    /// * `atol` - Absolute Fehlertoleranz
+    /// * `rtol` - Relative Fehlertoleranz
+    pub fn new(derivative: F, atol: f64, rtol: f64) -> Self {
+        Self {
+            derivative,
+            atol,
+            rtol,
+        }
+    }
+
+    /// Berechnet die sieben Stufen und daraus die 5.- und 4.-Ordnung
+    /// Lösung für einen versuchten Schritt der Weite `dt`.
+    ///
+    /// Returns: (x5, x4) - 5.- bzw. 4.-Ordnung-Schätzung von x(t+dt)
+    fn try_step(&self, t: f64, x: &[f64], dt: f64) -> (Vec<f64>, Vec<f64>) {
+        let n = x.len();
+        let mut stages: Vec<Vec<f64>> = Vec::with_capacity(7);
+
+        stages.push((self.derivative)(t, x));
+
+        for stage in 0..6 {
+            let mut x_temp = x.to_vec();
+            for (j, k_j) in stages.iter().enumerate() {
+                let a_ij = DP_A[stage][j];
+                if a_ij != 0.0 {
+                    for i in 0..n {
+                        x_temp[i] += dt * a_ij * k_j[i];
+                    }
+                }
+            }
+            stages.push((self.derivative)(t + DP_C[stage + 1] * dt, &x_temp));
+        }
+
+        let mut x5 = x.to_vec();
+        let mut x4 = x.to_vec();
+        for (j, k_j) in stages.iter().enumerate() {
+            for i in 0..n {
+                x5[i] += dt * DP_B5[j] * k_j[i];
+                x4[i] += dt * DP_B4[j] * k_j[i];
+            }
+        }
+
+        (x5, x4)
+    }
+
+    /// Normierte Fehlerschätzung zwischen 5.- und 4.-Ordnung-Lösung
+    ///
+    /// err_norm = sqrt(mean_i (((y5_i − y4_i) / (atol + rtol·max(|y_i|,|y_new_i|)))²))
+    fn error_norm(&self, x: &[f64], x5: &[f64], x4: &[f64]) -> f64 {
+        let n = x.len();
+        let sum_sqr: f64 = (0..n)
+            .map(|i| {
+                let scale = self.atol + self.rtol * x[i].abs().max(x5[i].abs());
+                ((x5[i] - x4[i]) / scale).powi(2)
+            })
+            .sum();
+        (sum_sqr / n as f64).sqrt()
+    }
+
+    /// Integriert über [t0, tf] mit adaptiver Schrittweitensteuerung
+    ///
+    /// Schritte werden akzeptiert, wenn `err_norm <= 1`; andernfalls wird
+    /// `dt` verkleinert und der Schritt ohne Fortschritt in `t` wiederholt.
+    /// Nach jedem Schritt wird dt via
+    /// `dt_new = dt · clamp(0.9·err_norm^(-1/5), 0.2, 5.0)` angepasst.
+    ///
+    /// # Arguments
+    /// * `t0` - Startzeit
+    /// * `x0` - Anfangszustand
+    /// * `tf` - Endzeit
+    /// * `dt0` - Initiale Schrittweite (wird adaptiv angepasst)
+    pub fn integrate(&self, t0: f64, x0: &[f64], tf: f64, dt0: f64) -> Vec<(f64, Vec<f64>)> {
+        let mut result = Vec::new();
+        let mut t = t0;
+        let mut x = x0.to_vec();
+        let mut dt = dt0;
+
+        result.push((t, x.clone()));
+
+        while t < tf {
+            dt = dt.min(tf - t);
+
+            loop {
+                let (x5, x4) = self.try_step(t, &x, dt);
+                let err_norm = self.error_norm(&x, &x5, &x4).max(1e-300);
+
+                let factor = (0.9 * err_norm.powf(-1.0 / 5.0)).clamp(0.2, 5.0);
+
+                if err_norm <= 1.0 {
+                    t += dt;
+                    x = x5;
+                    dt *= factor;
+                    break;
+                } else {
+                    dt *= factor;
+                }
+            }
+
+            result.push((t, x.clone()));
+        }
+
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,4 +373,40 @@ mod tests {
         assert_abs_diff_eq!(x_final[0], 1.0, epsilon = 0.01);
         assert_abs_diff_eq!(x_final[1], 0.0, epsilon = 0.01);
     }
+
+    #[test]
+    fn test_dp45_exponential_accuracy() {
+        // dx/dt = x => x(t) = x0 * e^t
+        let integrator = DormandPrince45::new(|_, x| vec![x[0]], 1e-10, 1e-10);
+
+        let trajectory = integrator.integrate(0.0, &[1.0], 1.0, 0.1);
+
+        let (t_final, x_final) = trajectory.last().unwrap();
+        assert_abs_diff_eq!(*t_final, 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(x_final[0], std::f64::consts::E, epsilon = 1e-7);
+    }
+
+    #[test]
+    fn test_dp45_adapts_step_count() {
+        // Ein steifer Transient sollte mehr (kleinere) Schritte erzeugen
+        // als eine langsame Dynamik bei derselben Toleranz.
+        let smooth = DormandPrince45::new(|_, x| vec![0.1 * x[0]], 1e-8, 1e-8);
+        let stiff = DormandPrince45::new(|_, x| vec![50.0 * x[0]], 1e-8, 1e-8);
+
+        let smooth_traj = smooth.integrate(0.0, &[1.0], 1.0, 0.1);
+        let stiff_traj = stiff.integrate(0.0, &[1.0], 1.0, 0.1);
+
+        assert!(stiff_traj.len() > smooth_traj.len());
+    }
+
+    #[test]
+    fn test_dp45_harmonic_oscillator_energy_conservation() {
+        let integrator = DormandPrince45::new(|_, x| vec![x[1], -x[0]], 1e-10, 1e-10);
+
+        let trajectory = integrator.integrate(0.0, &[1.0, 0.0], 2.0 * std::f64::consts::PI, 0.1);
+        let (_, x_final) = trajectory.last().unwrap();
+
+        assert_abs_diff_eq!(x_final[0], 1.0, epsilon = 1e-5);
+        assert_abs_diff_eq!(x_final[1], 0.0, epsilon = 1e-5);
+    }
 }
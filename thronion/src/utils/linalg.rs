@@ -30,6 +30,252 @@ pub fn matrix_exp_hermitian_3x3(matrix: &SMatrix<f64, 3, 3>) -> SMatrix<Complex6
     result
 }
 
+/// Berechnet die induzierte 1-Norm (maximale absolute Spaltensumme) einer
+/// Matrix, benötigt um den Skalierungsfaktor für das
+/// Scaling-and-Squaring-Verfahren in [`matrix_exp`] zu bestimmen.
+fn one_norm<const N: usize>(matrix: &SMatrix<Complex64, N, N>) -> f64 {
+    (0..N)
+        .map(|j| (0..N).map(|i| matrix[(i, j)].norm()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+/// Grad-6-Padé-Approximation von exp(B) für eine bereits skalierte Matrix
+/// B (‖B‖₁ ≤ 0.5). Nutzt die [6/6]-Padé-Koeffizienten
+/// c_k = 6!(12−k)! / (12! k! (6−k)!), gruppiert in einen geraden Anteil
+/// (Potenzen B⁰, B², B⁴, B⁶) und einen ungeraden Anteil (B¹, B³, B⁵), so
+/// dass nur die Potenzen B², B⁴ und B⁶ per Horner-Schema gebildet werden
+/// müssen: N(B) = gerade + B·ungerade, D(B) = gerade − B·ungerade.
+fn pade6<const N: usize>(b: &SMatrix<Complex64, N, N>) -> SMatrix<Complex64, N, N> {
+    const C0: f64 = 1.0;
+    const C1: f64 = 1.0 / 2.0;
+    const C2: f64 = 5.0 / 44.0;
+    const C3: f64 = 1.0 / 66.0;
+    const C4: f64 = 1.0 / 792.0;
+    const C5: f64 = 1.0 / 15840.0;
+    const C6: f64 = 1.0 / 665280.0;
+
+    let identity = SMatrix::<Complex64, N, N>::identity();
+    let b2 = b * b;
+    let b4 = b2 * b2;
+    let b6 = b4 * b2;
+
+    let even = identity.scale(Complex64::new(C0, 0.0))
+        + b2.scale(Complex64::new(C2, 0.0))
+        + b4.scale(Complex64::new(C4, 0.0))
+        + b6.scale(Complex64::new(C6, 0.0));
+    let odd = identity.scale(Complex64::new(C1, 0.0))
+        + b2.scale(Complex64::new(C3, 0.0))
+        + b4.scale(Complex64::new(C5, 0.0));
+    let odd = b * odd;
+
+    let numerator = even + odd;
+    let denominator = even - odd;
+
+    let denominator_inv = denominator
+        .try_inverse()
+        .expect("Padé(6)-Nenner sollte für ‖B‖₁ ≤ 0.5 stets invertierbar sein");
+
+    denominator_inv * numerator
+}
+
+/// Matrix-Exponentiation für allgemeine (nicht notwendigerweise
+/// hermitesche) komplexe Matrizen via Scaling-and-Squaring mit
+/// Grad-6-Padé-Approximant.
+///
+/// Wählt `s` so, dass ‖A‖₁/2ˢ ≤ 0.5, approximiert exp(B) für B = A/2ˢ
+/// mittels [`pade6`] und quadriert das Ergebnis anschließend `s`-mal:
+/// exp(A) = exp(B)^(2ˢ).
+fn matrix_exp_pade6<const N: usize>(matrix: &SMatrix<Complex64, N, N>) -> SMatrix<Complex64, N, N> {
+    let norm = one_norm(matrix);
+
+    let mut s: u32 = 0;
+    let mut scaled_norm = norm;
+    while scaled_norm > 0.5 {
+        scaled_norm /= 2.0;
+        s += 1;
+    }
+
+    let factor = 2f64.powi(s as i32);
+    let b = matrix.scale(Complex64::new(1.0 / factor, 0.0));
+
+    let mut result = pade6(&b);
+    for _ in 0..s {
+        result = result * result;
+    }
+    result
+}
+
+/// Exakter unitärer Propagator U(t) = exp(−iĤt) für eine hermitesche
+/// Matrix Ĥ = VΛV†.
+///
+/// Symmetrisiert zunächst Ĥ ← (Ĥ + Ĥ†)/2, damit die Eigenwerte garantiert
+/// reell sind (rundungsbedingte Abweichungen von der Hermitizität würden
+/// sonst in [`hermitian_eigen`]s reell-symmetrischer Einbettung zu
+/// komplexen "Eigenwerten" führen), und bildet dann
+/// U(t) = V·diag(exp(−iλᵢt))·V†. Im Gegensatz zu [`matrix_exp`], das
+/// stets reelle Exponenten exp(λᵢ) bildet, erlaubt dies die komplexe
+/// Spektralfunktion λ ↦ exp(−iλt) direkt zu assemblieren, ohne den Umweg
+/// über eine (nicht-hermitesche) skalierte Matrix und den langsameren
+/// Padé-Zweig von [`matrix_exp`] zu nehmen. Dient u.a.
+/// [`crate::operators::HamiltonOperator::evolution_operator`] als exakter
+/// Ersatz für Reihenentwicklungen wie
+/// [`crate::operators::HamiltonOperator::chebyshev_evolution`].
+pub fn matrix_exp_unitary<const N: usize>(
+    hamiltonian: &SMatrix<Complex64, N, N>,
+    time: f64,
+) -> SMatrix<Complex64, N, N> {
+    let symmetrized = (hamiltonian + hamiltonian.adjoint()).scale(Complex64::new(0.5, 0.0));
+    let (energies, vectors) = hermitian_eigen(&symmetrized);
+
+    let mut result = SMatrix::<Complex64, N, N>::zeros();
+    for i in 0..N {
+        let phase = Complex64::new(0.0, -energies[i] * time).exp();
+        let v = vectors.column(i);
+        for j in 0..N {
+            for k in 0..N {
+                result[(j, k)] += v[j] * phase * v[k].conj();
+            }
+        }
+    }
+    result
+}
+
+/// Matrix-Exponentiation für beliebige komplexe N×N-Matrizen.
+///
+/// Erkennt den hermiteschen Fall via [`is_hermitian`] und nutzt dann die
+/// exakte Eigenwertzerlegung aus [`hermitian_eigen`]: für H = VΛV† gilt
+/// exp(H) = V·diag(exp(λᵢ))·V†. Für alle anderen (nicht-hermiteschen)
+/// Matrizen wird auf Scaling-and-Squaring mit Grad-6-Padé-Approximant
+/// ([`matrix_exp_pade6`]) zurückgegriffen. Dies löst die True-unitary-
+/// Zeitentwicklung exp(−iHt) für den vollen Metatron-Hamiltonian ab, statt
+/// nur den 3×3-Spielzeugpfad in [`matrix_exp_hermitian_3x3`] zu bedienen.
+pub fn matrix_exp<const N: usize>(matrix: &SMatrix<Complex64, N, N>) -> SMatrix<Complex64, N, N> {
+    const HERMITIAN_TOLERANCE: f64 = 1e-9;
+
+    if is_hermitian(matrix, HERMITIAN_TOLERANCE) {
+        let (energies, vectors) = hermitian_eigen(matrix);
+
+        let mut result = SMatrix::<Complex64, N, N>::zeros();
+        for i in 0..N {
+            let exp_eigenvalue = Complex64::new(energies[i].exp(), 0.0);
+            let v = vectors.column(i);
+            for j in 0..N {
+                for k in 0..N {
+                    result[(j, k)] += v[j] * exp_eigenvalue * v[k].conj();
+                }
+            }
+        }
+        result
+    } else {
+        matrix_exp_pade6(matrix)
+    }
+}
+
+/// Reassembliert V·diag(f(λᵢ))·V† aus einer Eigenwertzerlegung, mit `f`
+/// auf jeden Eigenwert angewandt. Gemeinsamer Kern von
+/// [`matrix_sqrt_hermitian`] und [`matrix_log_hermitian`].
+fn rebuild_from_eigen<const N: usize, F: Fn(f64) -> f64>(
+    energies: &SVector<f64, N>,
+    vectors: &SMatrix<Complex64, N, N>,
+    f: F,
+) -> SMatrix<Complex64, N, N> {
+    let mut result = SMatrix::<Complex64, N, N>::zeros();
+    for i in 0..N {
+        let mapped = Complex64::new(f(energies[i]), 0.0);
+        let v = vectors.column(i);
+        for j in 0..N {
+            for k in 0..N {
+                result[(j, k)] += v[j] * mapped * v[k].conj();
+            }
+        }
+    }
+    result
+}
+
+/// Reelle Potenzfunktion einer positiv-semidefiniten hermiteschen Matrix
+/// H = VΛV†.
+///
+/// Bildet Hᵖ = V·diag(λᵢᵖ)·V†, wobei negative Eigenwerte innerhalb von
+/// `tolerance` um Rundungsfehler auf 0 geklemmt werden (physikalische
+/// Dichtematrizen sind positiv semidefinit; kleine negative Eigenwerte
+/// entstehen nur durch numerisches Rauschen). Gemeinsamer Kern von
+/// [`matrix_sqrt_hermitian`] (p = 1/2) und beliebigen weiteren
+/// Spektralfunktionen λ ↦ λᵖ, z.B. für Fidelity-Berechnungen zwischen
+/// gemischten Zuständen (√ρ·σ·√ρ).
+///
+/// # Panics
+/// Wenn ein Eigenwert unterhalb von `−tolerance` liegt, ist `H` nicht
+/// positiv semidefinit und die Potenz nicht wohldefiniert.
+pub fn operator_power<const N: usize>(
+    matrix: &SMatrix<Complex64, N, N>,
+    power: f64,
+    tolerance: f64,
+) -> SMatrix<Complex64, N, N> {
+    let (energies, vectors) = hermitian_eigen(matrix);
+
+    for &lambda in energies.iter() {
+        assert!(
+            lambda >= -tolerance,
+            "operator_power: negativer Eigenwert {} liegt außerhalb der Toleranz {}",
+            lambda,
+            tolerance
+        );
+    }
+
+    rebuild_from_eigen(&energies, &vectors, |lambda| lambda.max(0.0).powf(power))
+}
+
+/// Matrix-Quadratwurzel einer hermiteschen Matrix H = VΛV†.
+///
+/// Spezialfall von [`operator_power`] mit p = 1/2: √H = V·diag(√λᵢ)·V†.
+///
+/// # Panics
+/// Wenn ein Eigenwert unterhalb von `−tolerance` liegt, ist `H` nicht
+/// positiv semidefinit und die Wurzel nicht wohldefiniert.
+pub fn matrix_sqrt_hermitian<const N: usize>(
+    matrix: &SMatrix<Complex64, N, N>,
+    tolerance: f64,
+) -> SMatrix<Complex64, N, N> {
+    operator_power(matrix, 0.5, tolerance)
+}
+
+/// Matrix-Logarithmus einer positiv definiten hermiteschen Matrix
+/// H = VΛV†.
+///
+/// Bildet ln(H) = V·diag(ln λᵢ)·V†.
+///
+/// # Panics
+/// Wenn ein Eigenwert ≤ 0 ist, ist der Logarithmus nicht reell definiert.
+pub fn matrix_log_hermitian<const N: usize>(
+    matrix: &SMatrix<Complex64, N, N>,
+) -> SMatrix<Complex64, N, N> {
+    let (energies, vectors) = hermitian_eigen(matrix);
+
+    for &lambda in energies.iter() {
+        assert!(
+            lambda > 0.0,
+            "matrix_log_hermitian: Eigenwert {} muss positiv sein",
+            lambda
+        );
+    }
+
+    rebuild_from_eigen(&energies, &vectors, |lambda| lambda.ln())
+}
+
+/// Prüft ob Matrix ein Projektor ist: hermitesch und idempotent (P² ≈ P).
+///
+/// Wird genutzt um z.B. `NullpointOperator::sterile_projector` zu
+/// validieren, bevor er in Fidelity- oder Overlap-Berechnungen verwendet
+/// wird.
+pub fn is_projector<const N: usize>(matrix: &SMatrix<Complex64, N, N>, tol: f64) -> bool {
+    if !is_hermitian(matrix, tol) {
+        return false;
+    }
+    let squared = matrix * matrix;
+    let diff = squared - matrix;
+    frobenius_norm(&diff) < tol
+}
+
 /// Berechnet Frobenius-Norm einer Matrix
 pub fn frobenius_norm<const N: usize>(matrix: &SMatrix<Complex64, N, N>) -> f64 {
     matrix.iter().map(|c| c.norm_sqr()).sum::<f64>().sqrt()
@@ -70,6 +316,116 @@ pub fn determinant_2x2(matrix: &SMatrix<Complex64, 2, 2>) -> Complex64 {
     matrix[(0, 0)] * matrix[(1, 1)] - matrix[(0, 1)] * matrix[(1, 0)]
 }
 
+/// Eigenwertzerlegung einer komplex-hermiteschen Matrix über die
+/// reell-symmetrische Einbettung.
+///
+/// Schreibt H = A + iB (A symmetrisch, B antisymmetrisch) und bildet die
+/// reelle symmetrische 2N×2N-Matrix M = [[A, −B], [B, A]]. `M` hat
+/// dieselben N reellen Eigenwerte wie `H`, jeweils doppelt entartet; ein
+/// Eigenvektor [u; v] von M (halbiert in die obere und untere Hälfte)
+/// liefert den komplexen Eigenvektor u + i·v von H. Da die Entartung in
+/// `SymmetricEigen`s sortierter Eigenwertliste stets benachbarte Paare
+/// bildet, wird pro Paar nur die erste Kopie behalten.
+pub fn hermitian_eigen<const N: usize>(
+    matrix: &SMatrix<Complex64, N, N>,
+) -> (SVector<f64, N>, SMatrix<Complex64, N, N>) {
+    use nalgebra::{DMatrix, SymmetricEigen};
+
+    let mut embedded = DMatrix::<f64>::zeros(2 * N, 2 * N);
+    for i in 0..N {
+        for j in 0..N {
+            let c = matrix[(i, j)];
+            embedded[(i, j)] = c.re;
+            embedded[(i, N + j)] = -c.im;
+            embedded[(N + i, j)] = c.im;
+            embedded[(N + i, N + j)] = c.re;
+        }
+    }
+
+    let eigen = SymmetricEigen::new(embedded);
+
+    let mut order: Vec<usize> = (0..2 * N).collect();
+    order.sort_by(|&a, &b| {
+        eigen.eigenvalues[a]
+            .partial_cmp(&eigen.eigenvalues[b])
+            .unwrap()
+    });
+
+    let mut energies = SVector::<f64, N>::zeros();
+    let mut vectors = SMatrix::<Complex64, N, N>::zeros();
+
+    for out_col in 0..N {
+        // Entartete Paare liegen nach der Sortierung nebeneinander; wir
+        // behalten nur den ersten Eintrag jedes Paares.
+        let idx = order[2 * out_col];
+        energies[out_col] = eigen.eigenvalues[idx];
+
+        let col = eigen.eigenvectors.column(idx);
+        let mut norm_sqr = 0.0;
+        for k in 0..N {
+            norm_sqr += col[k] * col[k] + col[N + k] * col[N + k];
+        }
+        let norm = norm_sqr.sqrt().max(1e-300);
+
+        for k in 0..N {
+            vectors[(k, out_col)] = Complex64::new(col[k] / norm, col[N + k] / norm);
+        }
+    }
+
+    (energies, vectors)
+}
+
+/// Dynamisch-dimensionierte Variante von [`hermitian_eigen`] für Matrizen,
+/// deren Größe erst zur Laufzeit bekannt ist (z.B. Zwei-Platz-Operatoren
+/// variabler lokaler Dimension in der Many-Body-MPS-Evolution).
+pub fn hermitian_eigen_dyn(
+    matrix: &nalgebra::DMatrix<Complex64>,
+) -> (nalgebra::DVector<f64>, nalgebra::DMatrix<Complex64>) {
+    use nalgebra::{DMatrix, DVector, SymmetricEigen};
+
+    let n = matrix.nrows();
+    let mut embedded = DMatrix::<f64>::zeros(2 * n, 2 * n);
+    for i in 0..n {
+        for j in 0..n {
+            let c = matrix[(i, j)];
+            embedded[(i, j)] = c.re;
+            embedded[(i, n + j)] = -c.im;
+            embedded[(n + i, j)] = c.im;
+            embedded[(n + i, n + j)] = c.re;
+        }
+    }
+
+    let eigen = SymmetricEigen::new(embedded);
+
+    let mut order: Vec<usize> = (0..2 * n).collect();
+    order.sort_by(|&a, &b| {
+        eigen.eigenvalues[a]
+            .partial_cmp(&eigen.eigenvalues[b])
+            .unwrap()
+    });
+
+    let mut energies = DVector::<f64>::zeros(n);
+    let mut vectors = DMatrix::<Complex64>::zeros(n, n);
+
+    for out_col in 0..n {
+        let idx = order[2 * out_col];
+        energies[out_col] = eigen.eigenvalues[idx];
+
+        let col = eigen.eigenvectors.column(idx);
+        let mut norm_sqr = 0.0;
+        for k in 0..n {
+            norm_sqr += col[k] * col[k] + col[n + k] * col[n + k];
+        }
+        let norm = norm_sqr.sqrt().max(1e-300);
+
+        for k in 0..n {
+            vectors[(k, out_col)] = Complex64::new(col[k] / norm, col[n + k] / norm);
+        }
+    }
+
+    (energies, vectors)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,4 +511,310 @@ mod tests {
         // det = 1*4 - 2*3 = -2
         assert_abs_diff_eq!(det.re, -2.0, epsilon = 1e-10);
     }
+
+    #[test]
+    fn test_hermitian_eigen_real_diagonal() {
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 0)] = Complex64::new(1.0, 0.0);
+        matrix[(1, 1)] = Complex64::new(3.0, 0.0);
+
+        let (eigenvalues, _) = hermitian_eigen(&matrix);
+        assert_abs_diff_eq!(eigenvalues[0], 1.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(eigenvalues[1], 3.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_hermitian_eigen_complex_off_diagonal() {
+        // H = [[0, i], [-i, 0]] hat Eigenwerte ±1
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 1)] = Complex64::new(0.0, 1.0);
+        matrix[(1, 0)] = Complex64::new(0.0, -1.0);
+
+        let (eigenvalues, eigenvectors) = hermitian_eigen(&matrix);
+        assert_abs_diff_eq!(eigenvalues[0], -1.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(eigenvalues[1], 1.0, epsilon = 1e-8);
+
+        // Eigenvektoren sollten normiert sein und H reproduzieren: H·v = λ·v
+        for col in 0..2 {
+            let v = eigenvectors.column(col).clone_owned();
+            let lambda = eigenvalues[col];
+            let hv = matrix * v;
+            for k in 0..2 {
+                assert_abs_diff_eq!(hv[k].re, (v[k] * lambda).re, epsilon = 1e-8);
+                assert_abs_diff_eq!(hv[k].im, (v[k] * lambda).im, epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hermitian_eigen_dyn_matches_fixed_size() {
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 1)] = Complex64::new(0.0, 1.0);
+        matrix[(1, 0)] = Complex64::new(0.0, -1.0);
+
+        let dyn_matrix = nalgebra::DMatrix::<Complex64>::from_fn(2, 2, |i, j| matrix[(i, j)]);
+        let (eigenvalues, _) = hermitian_eigen_dyn(&dyn_matrix);
+
+        assert_abs_diff_eq!(eigenvalues[0], -1.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(eigenvalues[1], 1.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_matrix_exp_hermitian_matches_closed_form() {
+        // H = [[0, i], [-i, 0]] hat Eigenwerte ±1 mit Eigenvektoren
+        // (1, ∓i)/√2, also exp(H) = cosh(1)·I + sinh(1)·H.
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 1)] = Complex64::new(0.0, 1.0);
+        matrix[(1, 0)] = Complex64::new(0.0, -1.0);
+
+        let result = matrix_exp(&matrix);
+
+        let cosh1 = 1.0_f64.cosh();
+        let sinh1 = 1.0_f64.sinh();
+        let expected = SMatrix::<Complex64, 2, 2>::new(
+            Complex64::new(cosh1, 0.0),
+            Complex64::new(0.0, -sinh1),
+            Complex64::new(0.0, sinh1),
+            Complex64::new(cosh1, 0.0),
+        );
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_abs_diff_eq!(result[(i, j)].re, expected[(i, j)].re, epsilon = 1e-8);
+                assert_abs_diff_eq!(result[(i, j)].im, expected[(i, j)].im, epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_exp_hermitian_consistent_with_3x3_real_path() {
+        let real_matrix = SMatrix::<f64, 3, 3>::new(2.0, 0.5, 0.0, 0.5, 1.0, 0.3, 0.0, 0.3, 3.0);
+        let complex_matrix =
+            SMatrix::<Complex64, 3, 3>::from_fn(|i, j| Complex64::new(real_matrix[(i, j)], 0.0));
+
+        let via_hermitian_3x3 = matrix_exp_hermitian_3x3(&real_matrix);
+        let via_generalized = matrix_exp(&complex_matrix);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_abs_diff_eq!(
+                    via_generalized[(i, j)].re,
+                    via_hermitian_3x3[(i, j)].re,
+                    epsilon = 1e-6
+                );
+                assert_abs_diff_eq!(
+                    via_generalized[(i, j)].im,
+                    via_hermitian_3x3[(i, j)].im,
+                    epsilon = 1e-6
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_exp_nonhermitian_nilpotent_matches_closed_form() {
+        // N = [[0, 1], [0, 0]] ist nilpotent (N² = 0), also exp(N) = I + N
+        // exakt — ein Referenzfall mit bekanntem, einfachem Ergebnis für
+        // den nicht-hermiteschen Scaling-and-Squaring/Padé(6)-Zweig.
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 1)] = Complex64::new(1.0, 0.0);
+
+        let result = matrix_exp(&matrix);
+
+        assert_abs_diff_eq!(result[(0, 0)].re, 1.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(result[(0, 1)].re, 1.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(result[(1, 0)].re, 0.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(result[(1, 1)].re, 1.0, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_matrix_exp_nonhermitian_rotation_generator_matches_closed_form() {
+        // G = [[0, -θ], [θ, 0]] ist nicht hermitesch (reell, antisymmetrisch);
+        // exp(G) ist die bekannte 2D-Rotationsmatrix um Winkel θ.
+        let theta = 0.7_f64;
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 1)] = Complex64::new(-theta, 0.0);
+        matrix[(1, 0)] = Complex64::new(theta, 0.0);
+
+        let result = matrix_exp(&matrix);
+
+        assert_abs_diff_eq!(result[(0, 0)].re, theta.cos(), epsilon = 1e-8);
+        assert_abs_diff_eq!(result[(0, 1)].re, -theta.sin(), epsilon = 1e-8);
+        assert_abs_diff_eq!(result[(1, 0)].re, theta.sin(), epsilon = 1e-8);
+        assert_abs_diff_eq!(result[(1, 1)].re, theta.cos(), epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_matrix_exp_zero_matrix_is_identity() {
+        let matrix = SMatrix::<Complex64, 3, 3>::zeros();
+        let result = matrix_exp(&matrix);
+        let identity = SMatrix::<Complex64, 3, 3>::identity();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_abs_diff_eq!(result[(i, j)].re, identity[(i, j)].re, epsilon = 1e-10);
+                assert_abs_diff_eq!(result[(i, j)].im, identity[(i, j)].im, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_sqrt_hermitian_squares_back_to_original() {
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 0)] = Complex64::new(4.0, 0.0);
+        matrix[(1, 1)] = Complex64::new(9.0, 0.0);
+
+        let sqrt_matrix = matrix_sqrt_hermitian(&matrix, 1e-9);
+        let squared = sqrt_matrix * sqrt_matrix;
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_abs_diff_eq!(squared[(i, j)].re, matrix[(i, j)].re, epsilon = 1e-8);
+                assert_abs_diff_eq!(squared[(i, j)].im, matrix[(i, j)].im, epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_sqrt_hermitian_rejects_negative_eigenvalue() {
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 0)] = Complex64::new(-4.0, 0.0);
+        matrix[(1, 1)] = Complex64::new(9.0, 0.0);
+
+        let _ = matrix_sqrt_hermitian(&matrix, 1e-9);
+    }
+
+    #[test]
+    fn test_matrix_log_hermitian_is_inverse_of_exp() {
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 0)] = Complex64::new(2.0, 0.0);
+        matrix[(1, 1)] = Complex64::new(0.5, 0.0);
+
+        let log_matrix = matrix_log_hermitian(&matrix);
+        let exp_of_log = matrix_exp(&log_matrix);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_abs_diff_eq!(exp_of_log[(i, j)].re, matrix[(i, j)].re, epsilon = 1e-8);
+                assert_abs_diff_eq!(exp_of_log[(i, j)].im, matrix[(i, j)].im, epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_log_hermitian_rejects_nonpositive_eigenvalue() {
+        let matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        let _ = matrix_log_hermitian(&matrix);
+    }
+
+    #[test]
+    fn test_matrix_exp_unitary_matches_closed_form() {
+        // H = [[0, i], [-i, 0]] hat Eigenwerte ±1 mit Eigenvektoren
+        // (1, ∓i)/√2, also exp(-iHt) = cos(t)·I - i·sin(t)·H.
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 1)] = Complex64::new(0.0, 1.0);
+        matrix[(1, 0)] = Complex64::new(0.0, -1.0);
+
+        let t = 0.8;
+        let result = matrix_exp_unitary(&matrix, t);
+
+        let cos_t = t.cos();
+        let sin_t = t.sin();
+        let expected = SMatrix::<Complex64, 2, 2>::new(
+            Complex64::new(cos_t, 0.0),
+            Complex64::new(sin_t, 0.0),
+            Complex64::new(-sin_t, 0.0),
+            Complex64::new(cos_t, 0.0),
+        );
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_abs_diff_eq!(result[(i, j)].re, expected[(i, j)].re, epsilon = 1e-8);
+                assert_abs_diff_eq!(result[(i, j)].im, expected[(i, j)].im, epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_exp_unitary_is_unitary() {
+        let matrix = SMatrix::<Complex64, 3, 3>::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.5, -0.3),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.5, 0.3),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(0.2, 0.1),
+            Complex64::new(0.0, 0.0),
+            Complex64::new(0.2, -0.1),
+            Complex64::new(-1.0, 0.0),
+        );
+
+        let propagator = matrix_exp_unitary(&matrix, 1.3);
+        assert!(is_unitary(&propagator, 1e-10));
+    }
+
+    #[test]
+    fn test_matrix_exp_unitary_zero_time_is_identity() {
+        let matrix = SMatrix::<Complex64, 2, 2>::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, -1.0),
+            Complex64::new(0.0, 1.0),
+            Complex64::new(-1.0, 0.0),
+        );
+
+        let propagator = matrix_exp_unitary(&matrix, 0.0);
+        let identity = SMatrix::<Complex64, 2, 2>::identity();
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_abs_diff_eq!(propagator[(i, j)].re, identity[(i, j)].re, epsilon = 1e-10);
+                assert_abs_diff_eq!(propagator[(i, j)].im, identity[(i, j)].im, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_operator_power_square_is_sqrt_inverse() {
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 0)] = Complex64::new(4.0, 0.0);
+        matrix[(1, 1)] = Complex64::new(9.0, 0.0);
+
+        let squared_root = operator_power(&matrix, 0.5, 1e-9);
+        let reconstructed = operator_power(&matrix, 2.0, 1e-9);
+
+        assert_abs_diff_eq!((squared_root * squared_root)[(0, 0)].re, 4.0, epsilon = 1e-8);
+        assert_abs_diff_eq!((squared_root * squared_root)[(1, 1)].re, 9.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(reconstructed[(0, 0)].re, 16.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(reconstructed[(1, 1)].re, 81.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_operator_power_rejects_negative_eigenvalue() {
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 0)] = Complex64::new(-4.0, 0.0);
+        matrix[(1, 1)] = Complex64::new(9.0, 0.0);
+
+        let _ = operator_power(&matrix, 0.5, 1e-9);
+    }
+
+    #[test]
+    fn test_is_projector_accepts_rank1_projector() {
+        // P = |0><0| ist idempotent und hermitesch
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 0)] = Complex64::new(1.0, 0.0);
+
+        assert!(is_projector(&matrix, 1e-9));
+    }
+
+    #[test]
+    fn test_is_projector_rejects_nonidempotent_matrix() {
+        let mut matrix = SMatrix::<Complex64, 2, 2>::zeros();
+        matrix[(0, 0)] = Complex64::new(2.0, 0.0);
+        matrix[(1, 1)] = Complex64::new(2.0, 0.0);
+
+        assert!(!is_projector(&matrix, 1e-9));
+    }
 }
@@ -0,0 +1,15 @@
+//! Utility-Module
+//!
+//! Gemeinsame Hilfsfunktionen, die von mehreren QRIK-Modulen genutzt werden:
+//! - Integration: Numerische ODE-Integratoren
+//! - Linalg: Matrix-Hilfsfunktionen (Normen, Unitarität, Eigenwertzerlegung)
+//! - Special: Spezielle Funktionen (z.B. Bessel-Funktionen)
+//! - Graph: Adjazenz-basierte Graph-Algorithmen (z.B. Bron–Kerbosch-Cliquensuche)
+//! - TestSupport: Eindeutige Bezeichner für `#[cfg(test)]`-Temp-Dateinamen
+
+pub mod graph;
+pub mod integration;
+pub mod linalg;
+pub mod special;
+#[cfg(test)]
+pub(crate) mod test_support;
@@ -0,0 +1,76 @@
+//! Spezielle Funktionen
+//!
+//! Hilfsfunktionen für spezielle mathematische Funktionen, die von den
+//! Evolutions-Operatoren benötigt werden (z.B. Bessel-Funktionen für die
+//! Chebyshev-Entwicklung von exp(−iĤt)).
+
+/// Bessel-Funktion erster Art J_n(x), ausgewertet über die Potenzreihe
+///
+/// J_n(x) = Σ_{m≥0} (−1)^m / (m!·(m+n)!) · (x/2)^{2m+n}
+///
+/// Konvergiert für alle endlichen x; für die hier relevanten Argumente
+/// (x = a·t mit moderaten Zeiten t) reicht eine direkte Reihensummation.
+pub fn bessel_j(n: u32, x: f64) -> f64 {
+    if x == 0.0 {
+        return if n == 0 { 1.0 } else { 0.0 };
+    }
+
+    // J_n(−x) = (−1)^n J_n(x)
+    if x < 0.0 {
+        let sign = if n % 2 == 0 { 1.0 } else { -1.0 };
+        return sign * bessel_j(n, -x);
+    }
+
+    let half_x = x / 2.0;
+    let mut term = half_x.powi(n as i32) / factorial(n);
+    let mut sum = term;
+
+    for m in 1..500 {
+        term *= -(half_x * half_x) / (m as f64 * (m as f64 + n as f64));
+        sum += term;
+        if term.abs() < 1e-16 * sum.abs().max(1e-300) {
+            break;
+        }
+    }
+
+    sum
+}
+
+/// Fakultät als f64 (vermeidet u64-Überlauf für die hier benötigten n)
+fn factorial(n: u32) -> f64 {
+    (1..=n).fold(1.0, |acc, k| acc * k as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_bessel_j0_at_zero() {
+        assert_abs_diff_eq!(bessel_j(0, 0.0), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_bessel_jn_at_zero_vanishes() {
+        assert_abs_diff_eq!(bessel_j(3, 0.0), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_bessel_j0_known_value() {
+        // J_0(1) ≈ 0.7651976865579666
+        assert_abs_diff_eq!(bessel_j(0, 1.0), 0.7651976865579666, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_bessel_j1_known_value() {
+        // J_1(2) ≈ 0.5767248077568734
+        assert_abs_diff_eq!(bessel_j(1, 2.0), 0.5767248077568734, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_bessel_negative_argument_parity() {
+        assert_abs_diff_eq!(bessel_j(1, -2.0), -bessel_j(1, 2.0), epsilon = 1e-9);
+        assert_abs_diff_eq!(bessel_j(2, -2.0), bessel_j(2, 2.0), epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,19 @@
+//! Test-Hilfsfunktionen
+//!
+//! Gemeinsame Helfer für `#[cfg(test)]`-Module, die über Testläufe hinweg
+//! eindeutige Bezeichner brauchen (z.B. für Temp-Dateinamen), ohne auf
+//! Pointer-Adressen zurückzugreifen, die über aufeinanderfolgende
+//! Testläufe an derselben Aufrufstelle durchaus wiederverwendet werden.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Liefert einen innerhalb des Prozesses eindeutigen Bezeichner,
+/// zusammengesetzt aus der Prozess-ID und einem monoton steigenden
+/// Zähler. Geeignet als Suffix für Temp-Dateinamen in Tests, die
+/// parallel oder wiederholt laufen.
+pub(crate) fn unique_id() -> u64 {
+    let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
+    (std::process::id() as u64) << 32 | counter
+}
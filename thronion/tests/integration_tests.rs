@@ -8,7 +8,7 @@ use std::time::Duration;
 
 // Helper function to create Enhanced Kernel for tests
 fn create_test_kernel(max_regions: usize, learning_rate: f64) -> EnhancedThronionKernel {
-    let base_kernel = ThronionKernel::with_params(0.5, max_regions, learning_rate);
+    let base_kernel = ThronionKernel::with_params(0.5, max_regions, learning_rate, 0.1);
     let delta_params = QRIKParams::default();
     EnhancedThronionKernel::new(base_kernel, delta_params)
 }
@@ -44,10 +44,10 @@ fn test_end_to_end_circuit_classification() {
     let mut kernel = create_test_kernel(100, 0.1);
     
     // First classification (no training data yet)
-    let (is_attack_initial, resonance, _) = kernel.classify(&metadata, &timing, &dist);
-    
+    let outcome_initial = kernel.classify(&metadata, &timing, &dist);
+
     // Conservative: should default to benign with low resonance
-    assert!(!is_attack_initial || resonance < 0.3);
+    assert!(!outcome_initial.is_attack || outcome_initial.resonance < 0.3);
     
     // Learn this as an attack pattern
     kernel.learn(&metadata, &timing, &dist, true);
@@ -77,11 +77,11 @@ fn test_end_to_end_circuit_classification() {
     let dist2 = MetadataExtractor::analyze_cell_types(&metadata2.cell_types);
     
     // Should now recognize similar pattern
-    let (_is_attack_learned, resonance_learned, region_idx) = kernel.classify(&metadata2, &timing2, &dist2);
-    
+    let outcome_learned = kernel.classify(&metadata2, &timing2, &dist2);
+
     // Should match with good resonance
-    assert!(resonance_learned > 0.3, "Expected resonance > 0.3, got {}", resonance_learned);
-    assert!(region_idx.is_some(), "Should match a learned region");
+    assert!(outcome_learned.resonance > 0.3, "Expected resonance > 0.3, got {}", outcome_learned.resonance);
+    assert!(outcome_learned.region_idx.is_some(), "Should match a learned region");
 }
 
 #[test]
@@ -141,11 +141,11 @@ fn test_benign_traffic_classification() {
     let timing2 = MetadataExtractor::extract_timing_features(&metadata2.cell_timings);
     let dist2 = MetadataExtractor::analyze_cell_types(&metadata2.cell_types);
     
-    let (is_attack, resonance, _) = kernel.classify(&metadata2, &timing2, &dist2);
-    
+    let outcome = kernel.classify(&metadata2, &timing2, &dist2);
+
     // Should classify as benign
-    assert!(!is_attack, "Benign traffic should not be classified as attack");
-    assert!(resonance > 0.3, "Should match learned benign pattern");
+    assert!(!outcome.is_attack, "Benign traffic should not be classified as attack");
+    assert!(outcome.resonance > 0.3, "Should match learned benign pattern");
 }
 
 #[test]
@@ -157,6 +157,7 @@ fn test_classical_quantum_conversion_accuracy() {
         data_ratio: 0.8,
         intro_ratio: 0.1,
         total_bytes: 1000.0,
+        spectral: vec![],
     };
     
     // Convert to quantum
@@ -229,10 +230,10 @@ fn test_online_learning_adaptation() {
     let timing = MetadataExtractor::extract_timing_features(&test_metadata.cell_timings);
     let dist = MetadataExtractor::analyze_cell_types(&test_metadata.cell_types);
     
-    let (_is_attack, resonance, _) = kernel.classify(&test_metadata, &timing, &dist);
-    
+    let outcome = kernel.classify(&test_metadata, &timing, &dist);
+
     // Should recognize attack pattern after learning
-    assert!(resonance > 0.3, "Should match learned attack patterns");
+    assert!(outcome.resonance > 0.3, "Should match learned attack patterns");
 }
 
 #[test]